@@ -12,7 +12,7 @@ use rusqlite::{params, Connection, OptionalExtension};
 use std::path::Path;
 use std::sync::Mutex;
 
-use crate::buffer_store::{BufferFrameInfo, BufferMetadata, BufferType, TimestampedByte};
+use crate::buffer_store::{BufferFrameInfo, BufferMetadata, BufferType, IdEntry, TimestampedByte};
 use crate::io::FrameMessage;
 
 /// Global database connection, protected by a Mutex.
@@ -33,7 +33,12 @@ CREATE TABLE IF NOT EXISTS frames (
     is_fd INTEGER NOT NULL DEFAULT 0,
     source_address INTEGER,
     incomplete INTEGER,
-    direction TEXT
+    direction TEXT,
+    dlc_raw INTEGER,
+    is_rtr INTEGER NOT NULL DEFAULT 0,
+    is_brs INTEGER NOT NULL DEFAULT 0,
+    is_esi INTEGER NOT NULL DEFAULT 0,
+    original_frame_id INTEGER
 );
 
 CREATE TABLE IF NOT EXISTS bytes (
@@ -90,6 +95,22 @@ pub fn initialise(app_data_dir: &Path, clear_on_start: bool) -> Result<(), Strin
         [],
     );
 
+    // Schema migration: add time_base_us column (idempotent — ignores duplicate column error)
+    let _ = conn.execute("ALTER TABLE buffer_metadata ADD COLUMN time_base_us INTEGER", []);
+
+    // Schema migration: add dlc_raw column (idempotent — ignores duplicate column error)
+    let _ = conn.execute("ALTER TABLE frames ADD COLUMN dlc_raw INTEGER", []);
+
+    // Schema migration: add is_rtr column (idempotent — ignores duplicate column error)
+    let _ = conn.execute("ALTER TABLE frames ADD COLUMN is_rtr INTEGER NOT NULL DEFAULT 0", []);
+
+    // Schema migration: add CAN FD flag columns (idempotent — ignores duplicate column error)
+    let _ = conn.execute("ALTER TABLE frames ADD COLUMN is_brs INTEGER NOT NULL DEFAULT 0", []);
+    let _ = conn.execute("ALTER TABLE frames ADD COLUMN is_esi INTEGER NOT NULL DEFAULT 0", []);
+
+    // Schema migration: add original_frame_id column (idempotent — ignores duplicate column error)
+    let _ = conn.execute("ALTER TABLE frames ADD COLUMN original_frame_id INTEGER", []);
+
     // Conditionally clear leftover data and reclaim disk space
     // Persistent (pinned) buffers survive the clear.
     if clear_on_start {
@@ -153,6 +174,11 @@ fn row_to_frame(row: &rusqlite::Row) -> rusqlite::Result<FrameMessage> {
     let is_fd: i32 = row.get("is_fd")?;
     let source_address: Option<i64> = row.get("source_address")?;
     let incomplete: Option<i32> = row.get("incomplete")?;
+    let dlc_raw: Option<i64> = row.get("dlc_raw")?;
+    let is_rtr: i32 = row.get("is_rtr")?;
+    let is_brs: i32 = row.get("is_brs")?;
+    let is_esi: i32 = row.get("is_esi")?;
+    let original_frame_id: Option<i64> = row.get("original_frame_id")?;
 
     Ok(FrameMessage {
         protocol: row.get("protocol")?,
@@ -166,6 +192,12 @@ fn row_to_frame(row: &rusqlite::Row) -> rusqlite::Result<FrameMessage> {
         source_address: source_address.map(|v| v as u16),
         incomplete: incomplete.map(|v| v != 0),
         direction: row.get("direction")?,
+        dlc_raw: dlc_raw.map(|v| v as u8),
+        is_rtr: is_rtr != 0,
+        is_brs: is_brs != 0,
+        is_esi: is_esi != 0,
+        original_frame_id: original_frame_id.map(|v| v as u32),
+        source_label: None,
     })
 }
 
@@ -195,8 +227,8 @@ pub fn insert_frames(buffer_id: &str, frames: &[FrameMessage]) -> Result<(), Str
     {
         let mut stmt = tx
             .prepare_cached(
-                "INSERT INTO frames (buffer_id, protocol, timestamp_us, frame_id, bus, dlc, payload, is_extended, is_fd, source_address, incomplete, direction)
-                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)",
+                "INSERT INTO frames (buffer_id, protocol, timestamp_us, frame_id, bus, dlc, payload, is_extended, is_fd, source_address, incomplete, direction, dlc_raw, is_rtr, is_brs, is_esi, original_frame_id)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17)",
             )
             .map_err(|e| format!("Failed to prepare statement: {}", e))?;
 
@@ -214,6 +246,11 @@ pub fn insert_frames(buffer_id: &str, frames: &[FrameMessage]) -> Result<(), Str
                 frame.source_address.map(|v| v as i64),
                 frame.incomplete.map(|v| v as i32),
                 &frame.direction,
+                frame.dlc_raw.map(|v| v as i64),
+                frame.is_rtr as i32,
+                frame.is_brs as i32,
+                frame.is_esi as i32,
+                frame.original_frame_id.map(|v| v as i64),
             ])
             .map_err(|e| format!("Failed to insert frame: {}", e))?;
         }
@@ -278,7 +315,7 @@ pub fn get_frames_paginated(
 
     let mut stmt = conn
         .prepare_cached(
-            "SELECT rowid, protocol, timestamp_us, frame_id, bus, dlc, payload, is_extended, is_fd, source_address, incomplete, direction
+            "SELECT rowid, protocol, timestamp_us, frame_id, bus, dlc, payload, is_extended, is_fd, source_address, incomplete, direction, dlc_raw, is_rtr, is_brs, is_esi, original_frame_id
              FROM frames WHERE buffer_id = ?1 ORDER BY rowid LIMIT ?2 OFFSET ?3",
         )
         .map_err(|e| format!("Failed to prepare: {}", e))?;
@@ -335,7 +372,7 @@ pub fn get_frames_paginated_filtered(
 
     // Get page
     let sql = format!(
-        "SELECT rowid, protocol, timestamp_us, frame_id, bus, dlc, payload, is_extended, is_fd, source_address, incomplete, direction
+        "SELECT rowid, protocol, timestamp_us, frame_id, bus, dlc, payload, is_extended, is_fd, source_address, incomplete, direction, dlc_raw, is_rtr, is_brs, is_esi, original_frame_id
          FROM frames WHERE buffer_id = ?1 AND frame_id IN ({}) ORDER BY rowid LIMIT ?2 OFFSET ?3",
         placeholders
     );
@@ -373,7 +410,7 @@ pub fn get_frames_tail(
 
     let (sql_data, sql_count, sql_end_time) = if frame_ids.is_empty() {
         (
-            "SELECT rowid, protocol, timestamp_us, frame_id, bus, dlc, payload, is_extended, is_fd, source_address, incomplete, direction
+            "SELECT rowid, protocol, timestamp_us, frame_id, bus, dlc, payload, is_extended, is_fd, source_address, incomplete, direction, dlc_raw, is_rtr, is_brs, is_esi, original_frame_id
              FROM frames WHERE buffer_id = ?1 ORDER BY rowid DESC LIMIT ?2"
                 .to_string(),
             "SELECT COUNT(*) FROM frames WHERE buffer_id = ?1".to_string(),
@@ -387,7 +424,7 @@ pub fn get_frames_tail(
             .join(",");
         (
             format!(
-                "SELECT rowid, protocol, timestamp_us, frame_id, bus, dlc, payload, is_extended, is_fd, source_address, incomplete, direction
+                "SELECT rowid, protocol, timestamp_us, frame_id, bus, dlc, payload, is_extended, is_fd, source_address, incomplete, direction, dlc_raw, is_rtr, is_brs, is_esi, original_frame_id
                  FROM frames WHERE buffer_id = ?1 AND frame_id IN ({}) ORDER BY rowid DESC LIMIT ?2",
                 placeholders
             ),
@@ -484,6 +521,42 @@ pub fn get_frame_info(buffer_id: &str) -> Result<Vec<BufferFrameInfo>, String> {
     Ok(result)
 }
 
+/// Distinct (bus, is_extended, frame_id) keys with their frame count and the
+/// payload and FD flag of their latest frame, sorted by frame_id then bus.
+pub fn get_unique_ids(buffer_id: &str) -> Result<Vec<IdEntry>, String> {
+    let guard = DB.lock().unwrap();
+    let conn = guard.as_ref().ok_or("Database not initialised")?;
+
+    // SQLite takes bare columns (payload, is_fd) from the row holding MAX(rowid)
+    let mut stmt = conn
+        .prepare_cached(
+            "SELECT frame_id, bus, is_extended, COUNT(*) as count, MAX(rowid), payload, is_fd
+             FROM frames WHERE buffer_id = ?1
+             GROUP BY bus, is_extended, frame_id
+             ORDER BY frame_id, bus, is_extended",
+        )
+        .map_err(|e| format!("Failed to prepare: {}", e))?;
+
+    let rows = stmt
+        .query_map(params![buffer_id], |row| {
+            Ok(IdEntry {
+                frame_id: row.get::<_, i64>("frame_id")? as u32,
+                bus: row.get::<_, i64>("bus")? as u8,
+                is_extended: row.get::<_, i64>("is_extended")? != 0,
+                count: row.get::<_, i64>("count")? as usize,
+                last_bytes: row.get("payload")?,
+                is_fd: row.get::<_, i64>("is_fd")? != 0,
+            })
+        })
+        .map_err(|e| format!("Failed to query: {}", e))?;
+
+    let mut result = Vec::new();
+    for row in rows {
+        result.push(row.map_err(|e| format!("Failed to read row: {}", e))?);
+    }
+    Ok(result)
+}
+
 /// Find the offset (row count) for a given timestamp, optionally filtered by frame IDs.
 pub fn find_offset_for_timestamp(
     buffer_id: &str,
@@ -622,8 +695,8 @@ pub fn copy_buffer_data(source_id: &str, dest_id: &str) -> Result<usize, String>
 
     let frame_count = tx
         .execute(
-            "INSERT INTO frames (buffer_id, protocol, timestamp_us, frame_id, bus, dlc, payload, is_extended, is_fd, source_address, incomplete, direction)
-             SELECT ?2, protocol, timestamp_us, frame_id, bus, dlc, payload, is_extended, is_fd, source_address, incomplete, direction
+            "INSERT INTO frames (buffer_id, protocol, timestamp_us, frame_id, bus, dlc, payload, is_extended, is_fd, source_address, incomplete, direction, dlc_raw, is_rtr, is_brs, is_esi, original_frame_id)
+             SELECT ?2, protocol, timestamp_us, frame_id, bus, dlc, payload, is_extended, is_fd, source_address, incomplete, direction, dlc_raw, is_rtr, is_brs, is_esi, original_frame_id
              FROM frames WHERE buffer_id = ?1 ORDER BY rowid",
             params![source_id, dest_id],
         )
@@ -687,8 +760,8 @@ pub fn clear_and_refill(buffer_id: &str, frames: &[FrameMessage]) -> Result<(),
     {
         let mut stmt = tx
             .prepare_cached(
-                "INSERT INTO frames (buffer_id, protocol, timestamp_us, frame_id, bus, dlc, payload, is_extended, is_fd, source_address, incomplete, direction)
-                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)",
+                "INSERT INTO frames (buffer_id, protocol, timestamp_us, frame_id, bus, dlc, payload, is_extended, is_fd, source_address, incomplete, direction, dlc_raw, is_rtr, is_brs, is_esi, original_frame_id)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17)",
             )
             .map_err(|e| format!("Failed to prepare: {}", e))?;
 
@@ -706,6 +779,11 @@ pub fn clear_and_refill(buffer_id: &str, frames: &[FrameMessage]) -> Result<(),
                 frame.source_address.map(|v| v as i64),
                 frame.incomplete.map(|v| v as i32),
                 &frame.direction,
+                frame.dlc_raw.map(|v| v as i64),
+                frame.is_rtr as i32,
+                frame.is_brs as i32,
+                frame.is_esi as i32,
+                frame.original_frame_id.map(|v| v as i64),
             ])
             .map_err(|e| format!("Failed to insert frame: {}", e))?;
         }
@@ -724,7 +802,7 @@ pub fn get_all_frames(buffer_id: &str) -> Result<Vec<FrameMessage>, String> {
 
     let mut stmt = conn
         .prepare_cached(
-            "SELECT rowid, protocol, timestamp_us, frame_id, bus, dlc, payload, is_extended, is_fd, source_address, incomplete, direction
+            "SELECT rowid, protocol, timestamp_us, frame_id, bus, dlc, payload, is_extended, is_fd, source_address, incomplete, direction, dlc_raw, is_rtr, is_brs, is_esi, original_frame_id
              FROM frames WHERE buffer_id = ?1 ORDER BY rowid",
         )
         .map_err(|e| format!("Failed to prepare: {}", e))?;
@@ -740,6 +818,38 @@ pub fn get_all_frames(buffer_id: &str) -> Result<Vec<FrameMessage>, String> {
     Ok(frames)
 }
 
+/// Frames with timestamps in `[start_us, end_us]`, in timestamp order, at most `limit`.
+pub fn get_frames_in_range(
+    buffer_id: &str,
+    start_us: u64,
+    end_us: u64,
+    limit: usize,
+) -> Result<Vec<FrameMessage>, String> {
+    let guard = DB.lock().unwrap();
+    let conn = guard.as_ref().ok_or("Database not initialised")?;
+
+    let mut stmt = conn
+        .prepare_cached(
+            "SELECT rowid, protocol, timestamp_us, frame_id, bus, dlc, payload, is_extended, is_fd, source_address, incomplete, direction, dlc_raw, is_rtr, is_brs, is_esi, original_frame_id
+             FROM frames WHERE buffer_id = ?1 AND timestamp_us >= ?2 AND timestamp_us <= ?3
+             ORDER BY timestamp_us ASC, rowid ASC LIMIT ?4",
+        )
+        .map_err(|e| format!("Failed to prepare: {}", e))?;
+
+    let rows = stmt
+        .query_map(
+            params![buffer_id, start_us as i64, end_us.min(i64::MAX as u64) as i64, limit as i64],
+            |row| row_to_frame(row),
+        )
+        .map_err(|e| format!("Failed to query: {}", e))?;
+
+    let mut frames = Vec::new();
+    for row in rows {
+        frames.push(row.map_err(|e| format!("Failed to read row: {}", e))?);
+    }
+    Ok(frames)
+}
+
 // ============================================================================
 // Buffer Reader Streaming (chunked reads for playback)
 // ============================================================================
@@ -756,7 +866,7 @@ pub fn read_frame_chunk(
 
     let mut stmt = conn
         .prepare_cached(
-            "SELECT rowid, protocol, timestamp_us, frame_id, bus, dlc, payload, is_extended, is_fd, source_address, incomplete, direction
+            "SELECT rowid, protocol, timestamp_us, frame_id, bus, dlc, payload, is_extended, is_fd, source_address, incomplete, direction, dlc_raw, is_rtr, is_brs, is_esi, original_frame_id
              FROM frames WHERE buffer_id = ?1 AND rowid > ?2 ORDER BY rowid ASC LIMIT ?3",
         )
         .map_err(|e| format!("Failed to prepare: {}", e))?;
@@ -786,7 +896,7 @@ pub fn read_frame_chunk_reverse(
 
     let mut stmt = conn
         .prepare_cached(
-            "SELECT rowid, protocol, timestamp_us, frame_id, bus, dlc, payload, is_extended, is_fd, source_address, incomplete, direction
+            "SELECT rowid, protocol, timestamp_us, frame_id, bus, dlc, payload, is_extended, is_fd, source_address, incomplete, direction, dlc_raw, is_rtr, is_brs, is_esi, original_frame_id
              FROM frames WHERE buffer_id = ?1 AND rowid < ?2 ORDER BY rowid DESC LIMIT ?3",
         )
         .map_err(|e| format!("Failed to prepare: {}", e))?;
@@ -844,34 +954,67 @@ pub fn find_rowid_for_timestamp(
     Ok(result)
 }
 
-/// Build a snapshot: most recent frame for each unique frame_id at or before the given rowid,
+/// How far back a snapshot looks for a frame ID's last value by default (2 minutes)
+pub const SNAPSHOT_LOOKBACK_US: u64 = 120_000_000;
+
+/// Build a snapshot: most recent frame for each (bus, ID) at or before the given rowid,
 /// limited by a minimum timestamp (for lookback window).
 pub fn build_snapshot(
     buffer_id: &str,
     up_to_rowid: i64,
     min_timestamp_us: u64,
+) -> Result<Vec<FrameMessage>, String> {
+    latest_per_id(buffer_id, "rowid <= ?2", up_to_rowid, min_timestamp_us)
+}
+
+/// Like `build_snapshot`, but bounded by timestamp: the last value of each
+/// (bus, ID) as of `timestamp_us`.
+pub fn build_snapshot_at_time(
+    buffer_id: &str,
+    timestamp_us: u64,
+    min_timestamp_us: u64,
+) -> Result<Vec<FrameMessage>, String> {
+    latest_per_id(buffer_id, "timestamp_us <= ?2", timestamp_us as i64, min_timestamp_us)
+}
+
+/// Most recent frame for each (bus, is_extended, frame_id) among the rows
+/// matching `bound` (a condition on `?2`) with timestamps at or after
+/// `min_timestamp_us`, ordered by bus, is_extended, then frame_id.
+///
+/// "Most recent" is the greatest `timestamp_us`, so frames appended out of
+/// order don't win; rows with equal timestamps fall back to insertion order.
+fn latest_per_id(
+    buffer_id: &str,
+    bound: &str,
+    bound_value: i64,
+    min_timestamp_us: u64,
 ) -> Result<Vec<FrameMessage>, String> {
     let guard = DB.lock().unwrap();
     let conn = guard.as_ref().ok_or("Database not initialised")?;
 
+    let sql = format!(
+        "SELECT f.rowid, f.protocol, f.timestamp_us, f.frame_id, f.bus, f.dlc, f.payload,
+                f.is_extended, f.is_fd, f.source_address, f.incomplete, f.direction, f.dlc_raw, f.is_rtr, f.is_brs, f.is_esi, f.original_frame_id
+         FROM frames f
+         INNER JOIN (
+             SELECT rowid as latest_rowid,
+                    ROW_NUMBER() OVER (
+                        PARTITION BY bus, is_extended, frame_id
+                        ORDER BY timestamp_us DESC, rowid DESC
+                    ) as rn
+             FROM frames
+             WHERE buffer_id = ?1 AND {} AND timestamp_us >= ?3
+         ) latest ON f.rowid = latest.latest_rowid AND latest.rn = 1
+         ORDER BY f.bus, f.is_extended, f.frame_id",
+        bound
+    );
     let mut stmt = conn
-        .prepare_cached(
-            "SELECT f.rowid, f.protocol, f.timestamp_us, f.frame_id, f.bus, f.dlc, f.payload,
-                    f.is_extended, f.is_fd, f.source_address, f.incomplete, f.direction
-             FROM frames f
-             INNER JOIN (
-                 SELECT frame_id, MAX(rowid) as max_rowid
-                 FROM frames
-                 WHERE buffer_id = ?1 AND rowid <= ?2 AND timestamp_us >= ?3
-                 GROUP BY frame_id
-             ) latest ON f.rowid = latest.max_rowid
-             ORDER BY f.frame_id",
-        )
+        .prepare_cached(&sql)
         .map_err(|e| format!("Failed to prepare: {}", e))?;
 
     let rows = stmt
         .query_map(
-            params![buffer_id, up_to_rowid, min_timestamp_us as i64],
+            params![buffer_id, bound_value, min_timestamp_us as i64],
             |row| row_to_frame(row),
         )
         .map_err(|e| format!("Failed to query: {}", e))?;
@@ -894,7 +1037,7 @@ pub fn get_frame_at_index(
 
     let result = conn
         .query_row(
-            "SELECT rowid, protocol, timestamp_us, frame_id, bus, dlc, payload, is_extended, is_fd, source_address, incomplete, direction
+            "SELECT rowid, protocol, timestamp_us, frame_id, bus, dlc, payload, is_extended, is_fd, source_address, incomplete, direction, dlc_raw, is_rtr, is_brs, is_esi, original_frame_id
              FROM frames WHERE buffer_id = ?1 ORDER BY rowid LIMIT 1 OFFSET ?2",
             params![buffer_id, index as i64],
             |row| row_to_frame_with_rowid(row),
@@ -924,7 +1067,7 @@ pub fn get_next_filtered_frame(
 
     let sql = if frame_ids.is_empty() {
         format!(
-            "SELECT rowid, protocol, timestamp_us, frame_id, bus, dlc, payload, is_extended, is_fd, source_address, incomplete, direction
+            "SELECT rowid, protocol, timestamp_us, frame_id, bus, dlc, payload, is_extended, is_fd, source_address, incomplete, direction, dlc_raw, is_rtr, is_brs, is_esi, original_frame_id
              FROM frames WHERE buffer_id = ?1 AND rowid {} ?2 ORDER BY rowid {} LIMIT 1",
             op, order
         )
@@ -935,7 +1078,7 @@ pub fn get_next_filtered_frame(
             .collect::<Vec<_>>()
             .join(",");
         format!(
-            "SELECT rowid, protocol, timestamp_us, frame_id, bus, dlc, payload, is_extended, is_fd, source_address, incomplete, direction
+            "SELECT rowid, protocol, timestamp_us, frame_id, bus, dlc, payload, is_extended, is_fd, source_address, incomplete, direction, dlc_raw, is_rtr, is_brs, is_esi, original_frame_id
              FROM frames WHERE buffer_id = ?1 AND rowid {} ?2 AND frame_id IN ({}) ORDER BY rowid {} LIMIT 1",
             op, placeholders, order
         )
@@ -980,6 +1123,39 @@ pub fn count_frames_before_rowid(buffer_id: &str, rowid: i64) -> Result<usize, S
     Ok(count as usize)
 }
 
+/// Timestamps of every frame with `frame_id` in the given ID format in a
+/// buffer, optionally on one bus only, in buffer (rowid) order.
+pub fn get_frame_timestamps(
+    buffer_id: &str,
+    frame_id: u32,
+    bus: Option<u8>,
+    is_extended: bool,
+) -> Result<Vec<u64>, String> {
+    let guard = DB.lock().unwrap();
+    let conn = guard.as_ref().ok_or("Database not initialised")?;
+
+    let mut stmt = conn
+        .prepare_cached(
+            "SELECT timestamp_us FROM frames
+             WHERE buffer_id = ?1 AND frame_id = ?2 AND is_extended = ?3 AND (?4 IS NULL OR bus = ?4)
+             ORDER BY rowid ASC",
+        )
+        .map_err(|e| format!("Failed to prepare: {}", e))?;
+
+    let rows = stmt
+        .query_map(
+            params![buffer_id, frame_id as i64, is_extended as i32, bus.map(i64::from)],
+            |row| row.get::<_, i64>(0),
+        )
+        .map_err(|e| format!("Failed to query: {}", e))?;
+
+    let mut result = Vec::new();
+    for row in rows {
+        result.push(row.map_err(|e| format!("Failed to read row: {}", e))? as u64);
+    }
+    Ok(result)
+}
+
 // ============================================================================
 // Byte Buffer Operations
 // ============================================================================
@@ -1211,8 +1387,8 @@ pub fn save_buffer_metadata(meta: &BufferMetadata) -> Result<(), String> {
     let buses_json = serde_json::to_string(&meta.buses).unwrap_or_else(|_| "[]".to_string());
 
     conn.execute(
-        "INSERT OR REPLACE INTO buffer_metadata (buffer_id, buffer_type, name, count, start_time_us, end_time_us, created_at, owning_session_id, persistent, buses)
-         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+        "INSERT OR REPLACE INTO buffer_metadata (buffer_id, buffer_type, name, count, start_time_us, end_time_us, created_at, owning_session_id, persistent, buses, time_base_us)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
         params![
             &meta.id,
             type_str,
@@ -1224,6 +1400,7 @@ pub fn save_buffer_metadata(meta: &BufferMetadata) -> Result<(), String> {
             &meta.owning_session_id,
             meta.persistent as i64,
             buses_json,
+            meta.time_base_us.map(|v| v as i64),
         ],
     )
     .map_err(|e| format!("Failed to save buffer metadata: {}", e))?;
@@ -1237,7 +1414,7 @@ pub fn load_all_buffer_metadata() -> Result<Vec<BufferMetadata>, String> {
     let conn = guard.as_ref().ok_or("Database not initialised")?;
 
     let mut stmt = conn
-        .prepare("SELECT buffer_id, buffer_type, name, count, start_time_us, end_time_us, created_at, owning_session_id, persistent, buses FROM buffer_metadata")
+        .prepare("SELECT buffer_id, buffer_type, name, count, start_time_us, end_time_us, created_at, owning_session_id, persistent, buses, time_base_us FROM buffer_metadata")
         .map_err(|e| format!("Failed to prepare: {}", e))?;
 
     let rows = stmt
@@ -1264,6 +1441,7 @@ pub fn load_all_buffer_metadata() -> Result<Vec<BufferMetadata>, String> {
                 owning_session_id: row.get("owning_session_id")?,
                 persistent: row.get::<_, i64>("persistent").unwrap_or(0) != 0,
                 buses,
+                time_base_us: row.get::<_, Option<i64>>("time_base_us").ok().flatten().map(|v| v as u64),
             })
         })
         .map_err(|e| format!("Failed to query: {}", e))?;
@@ -1303,6 +1481,20 @@ pub fn update_buffer_persistent(buffer_id: &str, persistent: bool) -> Result<(),
     Ok(())
 }
 
+/// Update the absolute time base of a buffer in SQLite.
+pub fn update_buffer_time_base(buffer_id: &str, time_base_us: Option<u64>) -> Result<(), String> {
+    let guard = DB.lock().unwrap();
+    let conn = guard.as_ref().ok_or("Database not initialised")?;
+
+    conn.execute(
+        "UPDATE buffer_metadata SET time_base_us = ?2 WHERE buffer_id = ?1",
+        params![buffer_id, time_base_us.map(|v| v as i64)],
+    )
+    .map_err(|e| format!("Failed to update buffer time base: {}", e))?;
+
+    Ok(())
+}
+
 /// Get distinct bus numbers from a buffer's data.
 /// Used to backfill bus metadata for buffers created before bus tracking was added.
 /// `table` should be "frames" or "bytes".
@@ -0,0 +1,811 @@
+// ui/src-tauri/src/buffer_snapshot.rs
+//
+// Native buffer snapshots: a compact binary dump of one buffer that can be
+// loaded back later to resume analysis. Unlike CSV/BLF exports this is not an
+// interchange format; it keeps every FrameMessage field exactly.
+//
+// Layout (all integers little-endian):
+//   magic "CANDRBUF" | version u16 | records_crc u32 | header_len u32 | header JSON
+//   then one record per item: record_len u32 | record bytes
+//
+// records_crc is a CRC32 over everything after the header. The writer patches
+// it in once all records are out; the reader checks it after the last record,
+// so a damaged file is rejected instead of loading garbage frames. Version 1
+// files have no CRC field and load unchecked.
+//
+// A capture tee (see capture_file.rs) writes the same layout incrementally:
+// the header says `capture` and carries no count, and the CRC is re-patched at
+// every flush. On load, records written after the last flush (a crash or a
+// capture that is still running) fail the CRC and are dropped.
+//
+// A frame record is:
+//   timestamp_us u64 | frame_id u32 | bus u8 | dlc u8 | flags u8 | protocol |
+//   data_len u32 | data | [source_address u16] | [direction] | [dlc_raw u8] |
+//   flags2 u8 | [original_frame_id u32]
+// where bracketed fields are present when their flag bit is set and strings
+// carry a u8 length prefix. flags2 (BRS, ESI, has original ID) was appended
+// later; records without it load with those bits clear.
+//
+// Records are length-prefixed so a newer writer can append fields to a record
+// and an older reader still steps over them. Data is streamed page by page in
+// both directions, so large buffers never sit in memory at once.
+
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::{BufReader, BufWriter, ErrorKind, Read, Seek, SeekFrom, Write};
+
+use crate::buffer_store::{self, BufferType, TimestampedByte};
+use crate::io::FrameMessage;
+
+/// File signature
+const SNAPSHOT_MAGIC: &[u8; 8] = b"CANDRBUF";
+/// Current format version
+const SNAPSHOT_VERSION: u16 = 2;
+/// First version with a records CRC in the header
+const CRC_VERSION: u16 = 2;
+/// File offset of the records CRC (after magic and version)
+const RECORDS_CRC_OFFSET: u64 = 10;
+/// Items read from or written to the buffer store per page
+const SNAPSHOT_PAGE_SIZE: usize = 10_000;
+/// Largest record accepted when loading (guards against corrupt length fields)
+const MAX_RECORD_LEN: usize = 16 * 1024 * 1024;
+/// Largest header JSON accepted when loading
+const MAX_HEADER_LEN: usize = 1024 * 1024;
+
+// Frame record flag bits
+const FLAG_EXTENDED: u8 = 1 << 0;
+const FLAG_FD: u8 = 1 << 1;
+const FLAG_HAS_SOURCE_ADDRESS: u8 = 1 << 2;
+const FLAG_HAS_INCOMPLETE: u8 = 1 << 3;
+const FLAG_INCOMPLETE: u8 = 1 << 4;
+const FLAG_HAS_DIRECTION: u8 = 1 << 5;
+const FLAG_HAS_DLC_RAW: u8 = 1 << 6;
+const FLAG_RTR: u8 = 1 << 7;
+
+// Frame record second flags byte (trailing, absent in older records)
+const FLAG2_BRS: u8 = 1 << 0;
+const FLAG2_ESI: u8 = 1 << 1;
+const FLAG2_HAS_ORIGINAL_ID: u8 = 1 << 2;
+
+/// Metadata stored at the start of a snapshot
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct SnapshotHeader {
+    name: String,
+    buffer_type: BufferType,
+    count: usize,
+    start_time_us: Option<u64>,
+    end_time_us: Option<u64>,
+    created_at: u64,
+    #[serde(default)]
+    buses: Vec<u8>,
+    /// Written incrementally by a capture tee (count is not known up front)
+    #[serde(default)]
+    capture: bool,
+    /// Absolute time of timestamp 0 for rebased buffers
+    #[serde(default)]
+    time_base_us: Option<u64>,
+}
+
+// ============================================================================
+// Encoding
+// ============================================================================
+
+/// Write the header with a placeholder CRC, patched by `patch_records_crc`
+fn write_header<W: Write>(w: &mut W, header: &SnapshotHeader) -> Result<(), String> {
+    let json = serde_json::to_vec(header).map_err(|e| format!("Failed to encode snapshot header: {}", e))?;
+    w.write_all(SNAPSHOT_MAGIC)
+        .and_then(|_| w.write_all(&SNAPSHOT_VERSION.to_le_bytes()))
+        .and_then(|_| w.write_all(&0u32.to_le_bytes()))
+        .and_then(|_| w.write_all(&(json.len() as u32).to_le_bytes()))
+        .and_then(|_| w.write_all(&json))
+        .map_err(|e| format!("Failed to write snapshot header: {}", e))
+}
+
+fn patch_records_crc<W: Write + Seek>(w: &mut W, crc: u32) -> Result<(), String> {
+    w.seek(SeekFrom::Start(RECORDS_CRC_OFFSET))
+        .and_then(|_| w.write_all(&crc.to_le_bytes()))
+        .map_err(|e| format!("Failed to write snapshot checksum: {}", e))
+}
+
+/// Read the header and, from version 2 on, the expected records CRC
+fn read_header<R: Read>(r: &mut R) -> Result<(SnapshotHeader, Option<u32>), String> {
+    let mut magic = [0u8; 8];
+    r.read_exact(&mut magic)
+        .map_err(|e| format!("Failed to read snapshot header: {}", e))?;
+    if &magic != SNAPSHOT_MAGIC {
+        return Err("Not a CANdor buffer snapshot".to_string());
+    }
+
+    let mut version = [0u8; 2];
+    r.read_exact(&mut version)
+        .map_err(|e| format!("Failed to read snapshot version: {}", e))?;
+    let version = u16::from_le_bytes(version);
+    if version > SNAPSHOT_VERSION {
+        return Err(format!(
+            "Snapshot version {} is newer than supported version {}",
+            version, SNAPSHOT_VERSION
+        ));
+    }
+
+    let records_crc = if version >= CRC_VERSION {
+        let mut crc = [0u8; 4];
+        r.read_exact(&mut crc)
+            .map_err(|e| format!("Failed to read snapshot header: {}", e))?;
+        Some(u32::from_le_bytes(crc))
+    } else {
+        None
+    };
+
+    let mut len = [0u8; 4];
+    r.read_exact(&mut len)
+        .map_err(|e| format!("Failed to read snapshot header: {}", e))?;
+    let len = u32::from_le_bytes(len) as usize;
+    if len > MAX_HEADER_LEN {
+        return Err(format!("Snapshot header too large ({} bytes), file may be corrupt", len));
+    }
+    let mut json = vec![0u8; len];
+    r.read_exact(&mut json)
+        .map_err(|e| format!("Failed to read snapshot header: {}", e))?;
+    let header = serde_json::from_slice(&json).map_err(|e| format!("Invalid snapshot header: {}", e))?;
+    Ok((header, records_crc))
+}
+
+/// Writer that keeps a running CRC32 of everything written through it
+struct CrcWriter<W> {
+    inner: W,
+    hasher: crc32fast::Hasher,
+}
+
+impl<W: Write> Write for CrcWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let n = self.inner.write(buf)?;
+        self.hasher.update(&buf[..n]);
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Reader that keeps a running CRC32 of everything read through it
+struct CrcReader<R> {
+    inner: R,
+    hasher: crc32fast::Hasher,
+}
+
+impl<R: Read> Read for CrcReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.hasher.update(&buf[..n]);
+        Ok(n)
+    }
+}
+
+fn write_record<W: Write>(w: &mut W, record: &[u8]) -> Result<(), String> {
+    w.write_all(&(record.len() as u32).to_le_bytes())
+        .and_then(|_| w.write_all(record))
+        .map_err(|e| format!("Failed to write snapshot record: {}", e))
+}
+
+/// Read the next record into `buf`. Returns false at a clean end of file.
+fn read_record<R: Read>(r: &mut R, buf: &mut Vec<u8>) -> Result<bool, String> {
+    let mut len = [0u8; 4];
+    match r.read_exact(&mut len) {
+        Ok(()) => {}
+        Err(e) if e.kind() == ErrorKind::UnexpectedEof => return Ok(false),
+        Err(e) => return Err(format!("Failed to read snapshot record: {}", e)),
+    }
+    let len = u32::from_le_bytes(len) as usize;
+    if len > MAX_RECORD_LEN {
+        return Err(format!("Snapshot record too large ({} bytes), file may be corrupt", len));
+    }
+    buf.resize(len, 0);
+    r.read_exact(buf)
+        .map_err(|_| "Snapshot ends in the middle of a record".to_string())?;
+    Ok(true)
+}
+
+fn encode_frame(frame: &FrameMessage, out: &mut Vec<u8>) {
+    out.clear();
+    let mut flags = 0u8;
+    if frame.is_extended {
+        flags |= FLAG_EXTENDED;
+    }
+    if frame.is_fd {
+        flags |= FLAG_FD;
+    }
+    if frame.source_address.is_some() {
+        flags |= FLAG_HAS_SOURCE_ADDRESS;
+    }
+    if let Some(incomplete) = frame.incomplete {
+        flags |= FLAG_HAS_INCOMPLETE;
+        if incomplete {
+            flags |= FLAG_INCOMPLETE;
+        }
+    }
+    if frame.direction.is_some() {
+        flags |= FLAG_HAS_DIRECTION;
+    }
+    if frame.dlc_raw.is_some() {
+        flags |= FLAG_HAS_DLC_RAW;
+    }
+    if frame.is_rtr {
+        flags |= FLAG_RTR;
+    }
+
+    out.extend_from_slice(&frame.timestamp_us.to_le_bytes());
+    out.extend_from_slice(&frame.frame_id.to_le_bytes());
+    out.push(frame.bus);
+    out.push(frame.dlc);
+    out.push(flags);
+    push_short_str(out, &frame.protocol);
+    out.extend_from_slice(&(frame.bytes.len() as u32).to_le_bytes());
+    out.extend_from_slice(&frame.bytes);
+    if let Some(addr) = frame.source_address {
+        out.extend_from_slice(&addr.to_le_bytes());
+    }
+    if let Some(direction) = &frame.direction {
+        push_short_str(out, direction);
+    }
+    if let Some(dlc_raw) = frame.dlc_raw {
+        out.push(dlc_raw);
+    }
+    let mut flags2 = 0u8;
+    if frame.is_brs {
+        flags2 |= FLAG2_BRS;
+    }
+    if frame.is_esi {
+        flags2 |= FLAG2_ESI;
+    }
+    if frame.original_frame_id.is_some() {
+        flags2 |= FLAG2_HAS_ORIGINAL_ID;
+    }
+    out.push(flags2);
+    if let Some(original) = frame.original_frame_id {
+        out.extend_from_slice(&original.to_le_bytes());
+    }
+}
+
+fn decode_frame(record: &[u8]) -> Result<FrameMessage, String> {
+    let mut cur = RecordReader { data: record, pos: 0 };
+    let timestamp_us = u64::from_le_bytes(cur.take_array()?);
+    let frame_id = u32::from_le_bytes(cur.take_array()?);
+    let [bus, dlc, flags] = cur.take_array()?;
+    let protocol = cur.take_short_str()?;
+    let data_len = u32::from_le_bytes(cur.take_array()?) as usize;
+    let bytes = cur.take(data_len)?.to_vec();
+    let source_address = if flags & FLAG_HAS_SOURCE_ADDRESS != 0 {
+        Some(u16::from_le_bytes(cur.take_array()?))
+    } else {
+        None
+    };
+    let direction = if flags & FLAG_HAS_DIRECTION != 0 {
+        Some(cur.take_short_str()?)
+    } else {
+        None
+    };
+    let dlc_raw = if flags & FLAG_HAS_DLC_RAW != 0 {
+        let [raw] = cur.take_array()?;
+        Some(raw)
+    } else {
+        None
+    };
+    // Older records end before the second flags byte
+    let flags2 = match record.get(cur.pos) {
+        Some(_) => {
+            let [flags2] = cur.take_array()?;
+            flags2
+        }
+        None => 0,
+    };
+    let original_frame_id = if flags2 & FLAG2_HAS_ORIGINAL_ID != 0 {
+        Some(u32::from_le_bytes(cur.take_array()?))
+    } else {
+        None
+    };
+    // Anything left over was added by a newer version and is ignored
+
+    Ok(FrameMessage {
+        protocol,
+        timestamp_us,
+        frame_id,
+        bus,
+        dlc,
+        bytes,
+        is_extended: flags & FLAG_EXTENDED != 0,
+        is_fd: flags & FLAG_FD != 0,
+        source_address,
+        incomplete: (flags & FLAG_HAS_INCOMPLETE != 0).then_some(flags & FLAG_INCOMPLETE != 0),
+        direction,
+        dlc_raw,
+        is_rtr: flags & FLAG_RTR != 0,
+        is_brs: flags2 & FLAG2_BRS != 0,
+        is_esi: flags2 & FLAG2_ESI != 0,
+        original_frame_id,
+        source_label: None,
+    })
+}
+
+fn encode_byte(byte: &TimestampedByte, out: &mut Vec<u8>) {
+    out.clear();
+    out.extend_from_slice(&byte.timestamp_us.to_le_bytes());
+    out.push(byte.byte);
+    out.push(byte.bus);
+}
+
+fn decode_byte(record: &[u8]) -> Result<TimestampedByte, String> {
+    let mut cur = RecordReader { data: record, pos: 0 };
+    let timestamp_us = u64::from_le_bytes(cur.take_array()?);
+    let [byte, bus] = cur.take_array()?;
+    Ok(TimestampedByte { byte, timestamp_us, bus })
+}
+
+/// Append a string with a u8 length prefix (longer strings are truncated)
+fn push_short_str(out: &mut Vec<u8>, s: &str) {
+    let mut len = s.len().min(u8::MAX as usize);
+    while !s.is_char_boundary(len) {
+        len -= 1;
+    }
+    out.push(len as u8);
+    out.extend_from_slice(&s.as_bytes()[..len]);
+}
+
+/// Bounds-checked reader over one record
+struct RecordReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> RecordReader<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8], String> {
+        let end = self.pos + n;
+        let slice = self
+            .data
+            .get(self.pos..end)
+            .ok_or_else(|| "Truncated snapshot record".to_string())?;
+        self.pos = end;
+        Ok(slice)
+    }
+
+    fn take_array<const N: usize>(&mut self) -> Result<[u8; N], String> {
+        let mut arr = [0u8; N];
+        arr.copy_from_slice(self.take(N)?);
+        Ok(arr)
+    }
+
+    fn take_short_str(&mut self) -> Result<String, String> {
+        let [len] = self.take_array()?;
+        let bytes = self.take(len as usize)?;
+        String::from_utf8(bytes.to_vec()).map_err(|_| "Invalid text in snapshot record".to_string())
+    }
+}
+
+// ============================================================================
+// Public API
+// ============================================================================
+
+/// Write a buffer to `path` as a native snapshot. Returns the number of items written.
+pub fn save_buffer(buffer_id: &str, path: &str) -> Result<usize, String> {
+    let meta = buffer_store::get_buffer_metadata(buffer_id)
+        .ok_or_else(|| format!("Buffer '{}' not found", buffer_id))?;
+    let header = SnapshotHeader {
+        name: meta.name.clone(),
+        buffer_type: meta.buffer_type.clone(),
+        count: meta.count,
+        start_time_us: meta.start_time_us,
+        end_time_us: meta.end_time_us,
+        created_at: meta.created_at,
+        buses: meta.buses.clone(),
+        capture: false,
+        time_base_us: meta.time_base_us,
+    };
+
+    let file = File::create(path).map_err(|e| format!("Failed to create '{}': {}", path, e))?;
+    let mut w = BufWriter::new(file);
+    write_header(&mut w, &header)?;
+    let mut w = CrcWriter { inner: w, hasher: crc32fast::Hasher::new() };
+
+    let mut record = Vec::new();
+    let mut written = 0;
+    loop {
+        let page_len = match meta.buffer_type {
+            BufferType::Frames => {
+                let (frames, _, _) =
+                    buffer_store::get_buffer_frames_paginated(buffer_id, written, SNAPSHOT_PAGE_SIZE);
+                for frame in &frames {
+                    encode_frame(frame, &mut record);
+                    write_record(&mut w, &record)?;
+                }
+                frames.len()
+            }
+            BufferType::Bytes => {
+                let (bytes, _) =
+                    buffer_store::get_buffer_bytes_paginated(buffer_id, written, SNAPSHOT_PAGE_SIZE);
+                for byte in &bytes {
+                    encode_byte(byte, &mut record);
+                    write_record(&mut w, &record)?;
+                }
+                bytes.len()
+            }
+        };
+        written += page_len;
+        if page_len < SNAPSHOT_PAGE_SIZE {
+            break;
+        }
+    }
+
+    let crc = w.hasher.finalize();
+    let mut file = w
+        .inner
+        .into_inner()
+        .map_err(|e| format!("Failed to write '{}': {}", path, e.error()))?;
+    patch_records_crc(&mut file, crc)?;
+    tlog!("[BufferSnapshot] Saved buffer '{}' ({} items) to '{}'", buffer_id, written, path);
+    Ok(written)
+}
+
+/// Load a snapshot from `path` into a new buffer. Returns the new buffer ID.
+pub fn load_buffer(path: &str) -> Result<String, String> {
+    let file = File::open(path).map_err(|e| format!("Failed to open '{}': {}", path, e))?;
+    let mut r = BufReader::new(file);
+    let (header, records_crc) = read_header(&mut r)?;
+    let limit = match records_crc {
+        Some(expected) if header.capture => {
+            let records_start = r
+                .stream_position()
+                .map_err(|e| format!("Failed to read '{}': {}", path, e))?;
+            let (verified, total) = verified_capture_len(&mut r, expected);
+            if verified == 0 && total > 0 {
+                return Err(format!("Capture '{}' is corrupt: no records match its checksum", path));
+            }
+            if verified < total {
+                tlog!(
+                    "[BufferSnapshot] '{}' has {} bytes written after its last flush, dropping them",
+                    path, total - verified
+                );
+            }
+            r.seek(SeekFrom::Start(records_start))
+                .map_err(|e| format!("Failed to read '{}': {}", path, e))?;
+            verified
+        }
+        _ => u64::MAX,
+    };
+    let mut r = CrcReader { inner: r.take(limit), hasher: crc32fast::Hasher::new() };
+
+    let buffer_id = buffer_store::create_buffer_inactive(header.buffer_type.clone(), header.name.clone());
+    let loaded = match read_items(&mut r, &header.buffer_type, &buffer_id) {
+        Ok(loaded) => loaded,
+        Err(e) => {
+            let _ = buffer_store::delete_buffer(&buffer_id);
+            return Err(e);
+        }
+    };
+    if let Some(expected) = records_crc {
+        let actual = r.hasher.finalize();
+        if actual != expected {
+            let _ = buffer_store::delete_buffer(&buffer_id);
+            return Err(format!(
+                "Snapshot '{}' is corrupt: checksum mismatch (expected {:08X}, got {:08X})",
+                path, expected, actual
+            ));
+        }
+    }
+
+    if !header.capture && loaded != header.count {
+        tlog!(
+            "[BufferSnapshot] '{}' header lists {} items but {} were read",
+            path, header.count, loaded
+        );
+    }
+    if header.time_base_us.is_some() {
+        buffer_store::set_buffer_time_base(&buffer_id, header.time_base_us)?;
+    }
+    tlog!("[BufferSnapshot] Loaded '{}' into buffer '{}' ({} items)", path, buffer_id, loaded);
+    Ok(buffer_id)
+}
+
+/// Scan the records of a capture file for the longest prefix whose CRC matches
+/// `expected`. Returns that prefix length and the total length of whole records.
+fn verified_capture_len<R: Read>(r: &mut R, expected: u32) -> (u64, u64) {
+    let mut r = CrcReader { inner: r, hasher: crc32fast::Hasher::new() };
+    let mut record = Vec::new();
+    let mut total = 0u64;
+    let mut verified = 0u64;
+    // A partial record at the end was cut off mid-write; stop before it
+    while let Ok(true) = read_record(&mut r, &mut record) {
+        total += 4 + record.len() as u64;
+        if r.hasher.clone().finalize() == expected {
+            verified = total;
+        }
+    }
+    (verified, total)
+}
+
+/// Native snapshot written a batch at a time by a capture tee.
+///
+/// The records CRC is patched into the header on every `flush`, so the file on
+/// disk always loads up to the last flush even if the app dies mid-capture.
+pub struct SnapshotCapture {
+    w: CrcWriter<BufWriter<File>>,
+    record: Vec<u8>,
+}
+
+impl SnapshotCapture {
+    /// Create `path` and write a capture header for a frame buffer called `name`
+    pub fn create(path: &str, name: &str) -> Result<Self, String> {
+        let header = SnapshotHeader {
+            name: name.to_string(),
+            buffer_type: BufferType::Frames,
+            count: 0,
+            start_time_us: None,
+            end_time_us: None,
+            created_at: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0),
+            buses: Vec::new(),
+            capture: true,
+            time_base_us: None,
+        };
+        let file = File::create(path).map_err(|e| format!("Failed to create '{}': {}", path, e))?;
+        let mut w = BufWriter::new(file);
+        write_header(&mut w, &header)?;
+        Ok(Self {
+            w: CrcWriter { inner: w, hasher: crc32fast::Hasher::new() },
+            record: Vec::new(),
+        })
+    }
+
+    /// Append frames (buffered until the next flush)
+    pub fn append(&mut self, frames: &[FrameMessage]) -> Result<(), String> {
+        for frame in frames {
+            encode_frame(frame, &mut self.record);
+            write_record(&mut self.w, &self.record)?;
+        }
+        Ok(())
+    }
+
+    /// Write buffered records to disk and patch the CRC to cover them
+    pub fn flush(&mut self) -> Result<(), String> {
+        let crc = self.w.hasher.clone().finalize();
+        self.w
+            .inner
+            .flush()
+            .map_err(|e| format!("Failed to write capture: {}", e))?;
+        let file = self.w.inner.get_mut();
+        patch_records_crc(file, crc)?;
+        file.seek(SeekFrom::End(0))
+            .map_err(|e| format!("Failed to write capture: {}", e))?;
+        Ok(())
+    }
+}
+
+/// Stream records into the buffer a page at a time
+fn read_items<R: Read>(r: &mut R, buffer_type: &BufferType, buffer_id: &str) -> Result<usize, String> {
+    let mut record = Vec::new();
+    let mut loaded = 0;
+    match buffer_type {
+        BufferType::Frames => {
+            let mut page = Vec::with_capacity(SNAPSHOT_PAGE_SIZE);
+            while read_record(r, &mut record)? {
+                page.push(decode_frame(&record)?);
+                if page.len() == SNAPSHOT_PAGE_SIZE {
+                    loaded += page.len();
+                    buffer_store::append_frames_to_buffer(buffer_id, std::mem::take(&mut page));
+                }
+            }
+            loaded += page.len();
+            buffer_store::append_frames_to_buffer(buffer_id, page);
+        }
+        BufferType::Bytes => {
+            let mut page = Vec::with_capacity(SNAPSHOT_PAGE_SIZE);
+            while read_record(r, &mut record)? {
+                page.push(decode_byte(&record)?);
+                if page.len() == SNAPSHOT_PAGE_SIZE {
+                    loaded += page.len();
+                    buffer_store::append_raw_bytes_to_buffer(buffer_id, std::mem::take(&mut page));
+                }
+            }
+            loaded += page.len();
+            buffer_store::append_raw_bytes_to_buffer(buffer_id, page);
+        }
+    }
+    Ok(loaded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::test_support::{can_frame, TestDb};
+
+    fn frames() -> Vec<FrameMessage> {
+        vec![
+            FrameMessage {
+                bus: 2,
+                dlc: 15,
+                is_extended: true,
+                is_fd: true,
+                direction: Some("tx".to_string()),
+                is_brs: true,
+                is_esi: true,
+                original_frame_id: Some(0x1ABC_DE00),
+                ..can_frame(0x1ABC_DEF0, 1_700_000_000_000_001, (0..64).collect())
+            },
+            FrameMessage {
+                protocol: "serial".to_string(),
+                source_address: Some(0xBEEF),
+                incomplete: Some(false),
+                ..can_frame(0x7FF, 5, Vec::new())
+            },
+            FrameMessage {
+                protocol: "modbus".to_string(),
+                bus: 1,
+                incomplete: Some(true),
+                direction: Some("rx".to_string()),
+                ..can_frame(3, 6, vec![1, 2, 3])
+            },
+            FrameMessage { dlc_raw: Some(0x0F), ..can_frame(0x123, 7, vec![0; 8]) },
+            FrameMessage { dlc: 4, is_rtr: true, ..can_frame(0x123, 7, Vec::new()) },
+        ]
+    }
+
+    #[test]
+    fn test_frame_snapshot_round_trip() {
+        let header = SnapshotHeader {
+            name: "capture".to_string(),
+            buffer_type: BufferType::Frames,
+            count: 5,
+            start_time_us: Some(5),
+            end_time_us: Some(7),
+            created_at: 42,
+            buses: vec![0, 1, 2],
+            capture: false,
+            time_base_us: Some(1_700_000_000_000_000),
+        };
+        let mut file = Vec::new();
+        write_header(&mut file, &header).unwrap();
+        let mut record = Vec::new();
+        for frame in &frames() {
+            encode_frame(frame, &mut record);
+            write_record(&mut file, &record).unwrap();
+        }
+
+        let mut r = file.as_slice();
+        let (read, _) = read_header(&mut r).unwrap();
+        assert_eq!(read.name, "capture");
+        assert_eq!(read.buffer_type, BufferType::Frames);
+        assert_eq!(read.buses, vec![0, 1, 2]);
+        assert_eq!(read.time_base_us, Some(1_700_000_000_000_000));
+
+        let mut decoded = Vec::new();
+        while read_record(&mut r, &mut record).unwrap() {
+            decoded.push(decode_frame(&record).unwrap());
+        }
+        let expected = serde_json::to_value(frames()).unwrap();
+        assert_eq!(serde_json::to_value(&decoded).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_byte_record_round_trip() {
+        let byte = TimestampedByte { byte: 0xA5, timestamp_us: 99, bus: 3 };
+        let mut record = Vec::new();
+        encode_byte(&byte, &mut record);
+        let decoded = decode_byte(&record).unwrap();
+        assert_eq!((decoded.byte, decoded.timestamp_us, decoded.bus), (0xA5, 99, 3));
+    }
+
+    #[test]
+    fn test_snapshot_rejects_bad_input() {
+        assert!(read_header(&mut &b"NOTASNAP\x01\x00"[..]).is_err());
+
+        let mut newer = SNAPSHOT_MAGIC.to_vec();
+        newer.extend_from_slice(&(SNAPSHOT_VERSION + 1).to_le_bytes());
+        assert!(read_header(&mut newer.as_slice()).unwrap_err().contains("newer"));
+
+        // A corrupt header length is rejected before anything is allocated
+        let mut huge = SNAPSHOT_MAGIC.to_vec();
+        huge.extend_from_slice(&SNAPSHOT_VERSION.to_le_bytes());
+        huge.extend_from_slice(&0u32.to_le_bytes());
+        huge.extend_from_slice(&u32::MAX.to_le_bytes());
+        assert!(read_header(&mut huge.as_slice()).unwrap_err().contains("too large"));
+
+        // Extra trailing fields from a newer writer are skipped
+        let mut record = Vec::new();
+        encode_frame(&frames()[1], &mut record);
+        record.extend_from_slice(&[0xFF, 0xFF]);
+        assert_eq!(decode_frame(&record).unwrap().source_address, Some(0xBEEF));
+
+        // Records from before the second flags byte load with BRS/ESI clear
+        encode_frame(&frames()[0], &mut record);
+        record.truncate(record.len() - 5);
+        let old = decode_frame(&record).unwrap();
+        assert!(old.is_fd && !old.is_brs && !old.is_esi && old.original_frame_id.is_none());
+
+        // A record cut short is an error, not a silent default
+        encode_frame(&frames()[0], &mut record);
+        assert!(decode_frame(&record[..20]).is_err());
+    }
+
+    /// Build a snapshot file the way `save_buffer` does, without the buffer store
+    fn snapshot_file(frames: &[FrameMessage]) -> Vec<u8> {
+        let header = SnapshotHeader {
+            name: "capture".to_string(),
+            buffer_type: BufferType::Frames,
+            count: frames.len(),
+            start_time_us: None,
+            end_time_us: None,
+            created_at: 0,
+            buses: Vec::new(),
+            capture: false,
+            time_base_us: None,
+        };
+        let mut file = std::io::Cursor::new(Vec::new());
+        write_header(&mut file, &header).unwrap();
+        let mut w = CrcWriter { inner: &mut file, hasher: crc32fast::Hasher::new() };
+        let mut record = Vec::new();
+        for frame in frames {
+            encode_frame(frame, &mut record);
+            write_record(&mut w, &record).unwrap();
+        }
+        let crc = w.hasher.finalize();
+        patch_records_crc(&mut file, crc).unwrap();
+        file.into_inner()
+    }
+
+    #[test]
+    fn test_load_rejects_corrupted_snapshot() {
+        let _db = TestDb::new("snapshot_load");
+        let dir = std::env::temp_dir();
+        let good = snapshot_file(&frames());
+        let path = dir.join(format!("candor_snapshot_good_{}.cbuf", std::process::id()));
+        std::fs::write(&path, &good).unwrap();
+        let buffer_id = load_buffer(path.to_str().unwrap()).unwrap();
+        let _ = buffer_store::delete_buffer(&buffer_id);
+
+        // Flip one payload byte of the last frame; it still decodes, so only the CRC catches it
+        let mut bad = good.clone();
+        let last = bad.len() - 4;
+        bad[last] ^= 0x01;
+        let bad_path = dir.join(format!("candor_snapshot_bad_{}.cbuf", std::process::id()));
+        std::fs::write(&bad_path, &bad).unwrap();
+        let err = load_buffer(bad_path.to_str().unwrap()).unwrap_err();
+        assert!(err.contains("corrupt"), "{}", err);
+
+        let _ = std::fs::remove_file(&path);
+        let _ = std::fs::remove_file(&bad_path);
+    }
+
+    #[test]
+    fn test_capture_loads_up_to_last_flush() {
+        let _db = TestDb::new("snapshot_capture");
+        let path = std::env::temp_dir().join(format!("candor_capture_{}.cbuf", std::process::id()));
+        let path = path.to_str().unwrap();
+        let mut capture = SnapshotCapture::create(path, "capture").unwrap();
+        capture.append(&frames()[..2]).unwrap();
+        capture.flush().unwrap();
+        // Written but never flushed, then cut off mid-record as if the app died
+        capture.append(&frames()[2..]).unwrap();
+        capture.w.inner.flush().unwrap();
+        capture.w.inner.get_mut().write_all(&[0x40, 0, 0, 0, 1, 2]).unwrap();
+        drop(capture);
+
+        let buffer_id = load_buffer(path).unwrap();
+        let count = buffer_store::get_buffer_metadata(&buffer_id).map(|m| m.count);
+        let _ = buffer_store::delete_buffer(&buffer_id);
+        let _ = std::fs::remove_file(path);
+        assert_eq!(count, Some(2));
+    }
+
+    #[test]
+    fn test_version_1_snapshot_loads_without_crc() {
+        let json = br#"{"name":"old","buffer_type":"frames","count":0,"start_time_us":null,"end_time_us":null,"created_at":0}"#;
+        let mut file = SNAPSHOT_MAGIC.to_vec();
+        file.extend_from_slice(&1u16.to_le_bytes());
+        file.extend_from_slice(&(json.len() as u32).to_le_bytes());
+        file.extend_from_slice(json);
+        let (header, crc) = read_header(&mut file.as_slice()).unwrap();
+        assert_eq!(header.name, "old");
+        assert_eq!(crc, None);
+    }
+}
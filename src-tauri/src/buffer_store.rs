@@ -70,6 +70,11 @@ pub struct BufferMetadata {
     /// Enables bus mapping/wiring when a buffer is used as a source.
     #[serde(default)]
     pub buses: Vec<u8>,
+    /// Absolute time (microseconds since the Unix epoch) that timestamp 0
+    /// corresponds to, recorded when timestamps were rebased. None when the
+    /// timestamps are already absolute.
+    #[serde(default)]
+    pub time_base_us: Option<u64>,
 }
 
 // ============================================================================
@@ -159,6 +164,7 @@ fn create_buffer_internal(buffer_type: BufferType, name: String, set_streaming:
         owning_session_id: None,
         persistent: false,
         buses: Vec::new(),
+        time_base_us: None,
     };
 
     let buffer = NamedBuffer { metadata: metadata.clone(), seen_buses: HashSet::new() };
@@ -213,6 +219,18 @@ pub fn finalize_buffer() -> Option<BufferMetadata> {
     None
 }
 
+/// Finalize a buffer that was filled without being the streaming buffer
+/// (e.g. a per-source buffer of a multi-source session): persist its settled
+/// count and timestamps. Returns None if the buffer doesn't exist.
+pub fn finalize_buffer_by_id(id: &str) -> Option<BufferMetadata> {
+    let meta = BUFFER_REGISTRY.read().unwrap().buffers.get(id)?.metadata.clone();
+    tlog!("[BufferStore] Finalized buffer '{}' with {} items", id, meta.count);
+    if let Err(e) = buffer_db::save_buffer_metadata(&meta) {
+        tlog!("[BufferStore] Failed to persist finalized buffer metadata: {}", e);
+    }
+    Some(meta)
+}
+
 /// List all buffers (returns metadata only, not data).
 /// Sets is_streaming=true for the buffer currently being streamed to.
 pub fn list_buffers() -> Vec<BufferMetadata> {
@@ -368,6 +386,27 @@ pub fn set_buffer_persistent(id: &str, persistent: bool) -> Result<BufferMetadat
     Ok(meta)
 }
 
+/// Set the absolute time a buffer's timestamp 0 corresponds to (None when
+/// its timestamps are already absolute).
+pub fn set_buffer_time_base(id: &str, time_base_us: Option<u64>) -> Result<BufferMetadata, String> {
+    let mut registry = BUFFER_REGISTRY.write().unwrap();
+    let buffer = registry.buffers.get_mut(id)
+        .ok_or_else(|| format!("Buffer '{}' not found", id))?;
+
+    buffer.metadata.time_base_us = time_base_us;
+    let meta = buffer.metadata.clone();
+
+    // Drop registry lock before touching SQLite
+    drop(registry);
+
+    if let Err(e) = buffer_db::update_buffer_time_base(id, time_base_us) {
+        tlog!("[BufferStore] Failed to persist buffer time base: {}", e);
+    }
+
+    tlog!("[BufferStore] Set buffer '{}' time base to {:?}us", id, time_base_us);
+    Ok(meta)
+}
+
 /// Hydrate the in-memory buffer registry from persisted SQLite metadata.
 /// Called on startup when `clear_buffers_on_start` is false.
 /// Verifies that data actually exists in SQLite for each metadata entry.
@@ -613,6 +652,7 @@ pub fn copy_buffer(source_buffer_id: &str, new_name: String) -> Result<String, S
             owning_session_id: None,
             persistent: false,
             buses: source_metadata.buses.clone(),
+            time_base_us: source_metadata.time_base_us,
         };
 
         let seen_buses: HashSet<u8> = source_metadata.buses.iter().copied().collect();
@@ -641,60 +681,8 @@ pub fn copy_buffer(source_buffer_id: &str, new_name: String) -> Result<String, S
 // Public API - Data Access (Frame Buffers)
 // ============================================================================
 
-/// Append frames to the active buffer.
-/// Silently returns if there's no active buffer or it's not a frame buffer.
-pub fn append_frames(new_frames: Vec<FrameMessage>) {
-    if new_frames.is_empty() {
-        return;
-    }
-
-    let active_id = {
-        let mut registry = BUFFER_REGISTRY.write().unwrap();
-
-        let active_id = match &registry.active_id {
-            Some(id) => id.clone(),
-            None => return,
-        };
-
-        if let Some(buffer) = registry.buffers.get_mut(&active_id) {
-            if buffer.metadata.buffer_type != BufferType::Frames {
-                return;
-            }
-            // Update metadata in RAM
-            if buffer.metadata.start_time_us.is_none() {
-                buffer.metadata.start_time_us = new_frames.first().map(|f| f.timestamp_us);
-            }
-            buffer.metadata.end_time_us = new_frames.last().map(|f| f.timestamp_us);
-            buffer.metadata.count += new_frames.len();
-
-            // Track distinct buses
-            let prev_len = buffer.seen_buses.len();
-            for f in &new_frames {
-                buffer.seen_buses.insert(f.bus);
-            }
-            if buffer.seen_buses.len() != prev_len {
-                let mut sorted: Vec<u8> = buffer.seen_buses.iter().copied().collect();
-                sorted.sort();
-                buffer.metadata.buses = sorted;
-            }
-        } else {
-            return;
-        }
-
-        active_id
-        // Registry lock dropped here
-    };
-
-    // Insert into SQLite (separate lock)
-    if let Err(e) = buffer_db::insert_frames(&active_id, &new_frames) {
-        tlog!("[BufferStore] Failed to insert frames: {}", e);
-    }
-}
-
 /// Append frames to a specific buffer by ID.
 /// Silently returns if buffer doesn't exist or is not a frame buffer.
-/// Only used by framing.rs which is desktop-only.
-#[cfg(not(target_os = "ios"))]
 pub fn append_frames_to_buffer(buffer_id: &str, new_frames: Vec<FrameMessage>) {
     if new_frames.is_empty() {
         return;
@@ -787,6 +775,25 @@ pub fn get_buffer_frames(id: &str) -> Option<Vec<FrameMessage>> {
     buffer_db::get_all_frames(id).ok()
 }
 
+/// Last known frame for each (bus, ID) at or before `timestamp_us`,
+/// looking back at most `lookback_us` (None for no limit).
+pub fn get_buffer_state_at(id: &str, timestamp_us: u64, lookback_us: Option<u64>) -> Option<Vec<FrameMessage>> {
+    if get_buffer_type(id) != Some(BufferType::Frames) {
+        return None;
+    }
+    let min_timestamp_us = lookback_us.map_or(0, |l| timestamp_us.saturating_sub(l));
+    buffer_db::build_snapshot_at_time(id, timestamp_us, min_timestamp_us).ok()
+}
+
+/// Distinct frame IDs in a frame buffer with their counts and latest payloads,
+/// sorted by frame_id then bus.
+pub fn get_buffer_unique_ids(id: &str) -> Option<Vec<IdEntry>> {
+    if get_buffer_type(id) != Some(BufferType::Frames) {
+        return None;
+    }
+    buffer_db::get_unique_ids(id).ok()
+}
+
 /// Get a page of frames from a specific buffer.
 /// Returns (frames, buffer_indices, total_count).
 pub fn get_buffer_frames_paginated(id: &str, offset: usize, limit: usize) -> (Vec<FrameMessage>, Vec<usize>, usize) {
@@ -910,6 +917,22 @@ pub struct BufferFrameInfo {
     pub has_dlc_mismatch: bool,
 }
 
+/// A distinct frame ID seen in a buffer, for ID pickers. Standard and
+/// extended IDs with the same number, and the same ID on different buses,
+/// are separate entries.
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct IdEntry {
+    pub frame_id: u32,
+    pub bus: u8,
+    pub is_extended: bool,
+    /// Number of frames with this ID
+    pub count: usize,
+    /// Payload of the latest frame
+    pub last_bytes: Vec<u8>,
+    /// Whether the latest frame was CAN FD
+    pub is_fd: bool,
+}
+
 /// Get unique frame IDs and their metadata from a buffer.
 pub fn get_buffer_frame_info(id: &str) -> Vec<BufferFrameInfo> {
     {
@@ -957,53 +980,6 @@ pub fn find_buffer_offset_for_timestamp(
 // Public API - Data Access (Byte Buffers)
 // ============================================================================
 
-/// Append raw bytes to the active buffer.
-/// Silently returns if there's no active buffer or it's not a byte buffer.
-pub fn append_raw_bytes(new_bytes: Vec<TimestampedByte>) {
-    if new_bytes.is_empty() {
-        return;
-    }
-
-    let active_id = {
-        let mut registry = BUFFER_REGISTRY.write().unwrap();
-
-        let active_id = match &registry.active_id {
-            Some(id) => id.clone(),
-            None => return,
-        };
-
-        if let Some(buffer) = registry.buffers.get_mut(&active_id) {
-            if buffer.metadata.buffer_type != BufferType::Bytes {
-                return;
-            }
-            if buffer.metadata.start_time_us.is_none() {
-                buffer.metadata.start_time_us = new_bytes.first().map(|b| b.timestamp_us);
-            }
-            buffer.metadata.end_time_us = new_bytes.last().map(|b| b.timestamp_us);
-            buffer.metadata.count += new_bytes.len();
-
-            // Track distinct buses
-            let prev_len = buffer.seen_buses.len();
-            for b in &new_bytes {
-                buffer.seen_buses.insert(b.bus);
-            }
-            if buffer.seen_buses.len() != prev_len {
-                let mut sorted: Vec<u8> = buffer.seen_buses.iter().copied().collect();
-                sorted.sort();
-                buffer.metadata.buses = sorted;
-            }
-        } else {
-            return;
-        }
-
-        active_id
-    };
-
-    if let Err(e) = buffer_db::insert_bytes(&active_id, &new_bytes) {
-        tlog!("[BufferStore] Failed to insert bytes: {}", e);
-    }
-}
-
 /// Append raw bytes to a specific buffer by ID.
 /// Silently returns if buffer doesn't exist or is not a byte buffer.
 pub fn append_raw_bytes_to_buffer(buffer_id: &str, new_bytes: Vec<TimestampedByte>) {
@@ -1245,7 +1221,198 @@ pub fn find_offset_for_timestamp(
 /// Legacy set_buffer (imports frames, creates new buffer).
 pub fn set_buffer(frames: Vec<FrameMessage>, filename: String) {
     let id = create_buffer(BufferType::Frames, filename);
-    append_frames(frames);
+    append_frames_to_buffer(&id, frames);
     finalize_buffer();
     tlog!("[BufferStore] Imported frames into buffer '{}'", id);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::test_support::{can_frame_at, TestDb};
+
+    #[test]
+    fn test_concurrent_appends_stay_in_their_own_buffer() {
+        let _db = TestDb::new("buffer_store");
+
+        // The second buffer is the active one; routing must not depend on that
+        let first = create_buffer(BufferType::Frames, "first".to_string());
+        let second = create_buffer(BufferType::Frames, "second".to_string());
+
+        let appenders: Vec<_> = [(first.clone(), 0x100u32, 1u8), (second.clone(), 0x200, 2)]
+            .into_iter()
+            .map(|(buffer_id, frame_id, bus)| {
+                std::thread::spawn(move || {
+                    for batch in 0..50u64 {
+                        let frames = (0..10)
+                            .map(|i| FrameMessage { bus, ..can_frame_at(frame_id, batch * 10 + i) })
+                            .collect();
+                        append_frames_to_buffer(&buffer_id, frames);
+                    }
+                })
+            })
+            .collect();
+        for appender in appenders {
+            appender.join().unwrap();
+        }
+
+        for (buffer_id, frame_id, bus) in [(&first, 0x100, 1), (&second, 0x200, 2)] {
+            let meta = get_buffer_metadata(buffer_id).unwrap();
+            assert_eq!(meta.count, 500);
+            assert_eq!(meta.buses, vec![bus]);
+            let (frames, _, total) = get_buffer_frames_paginated(buffer_id, 0, 1000);
+            assert_eq!(total, 500);
+            assert_eq!(frames.len(), 500);
+            assert!(frames.iter().all(|f| f.frame_id == frame_id && f.bus == bus));
+        }
+
+        let _ = delete_buffer(&first);
+        let _ = delete_buffer(&second);
+    }
+
+    #[test]
+    fn test_fd_frame_fields_survive_buffering() {
+        let _db = TestDb::new("buffer_store_fd");
+
+        let mut fd = FrameMessage { bus: 1, ..can_frame_at(0x1ABC_DEF0, 42) };
+        fd.is_extended = true;
+        fd.is_fd = true;
+        fd.is_brs = true;
+        fd.is_esi = true;
+        fd.dlc = 24;
+        fd.dlc_raw = Some(0x0C);
+        fd.bytes = (0..24).collect();
+        fd.direction = Some("rx".to_string());
+
+        // What the UI receives in a frame-message batch
+        let emitted = serde_json::to_value(crate::io::FrameBatchPayload {
+            frames: vec![fd.clone()],
+            active_listeners: Vec::new(),
+        })
+        .unwrap()["frames"][0]
+            .clone();
+
+        let buffer_id = create_buffer(BufferType::Frames, "fd".to_string());
+        append_frames_to_buffer(&buffer_id, vec![fd]);
+        let stored = get_buffer_frames(&buffer_id).unwrap();
+        assert_eq!(stored.len(), 1);
+        assert_eq!(serde_json::to_value(&stored[0]).unwrap(), emitted);
+
+        let _ = delete_buffer(&buffer_id);
+    }
+
+    #[test]
+    fn test_state_at_returns_latest_frame_per_bus_and_id() {
+        let _db = TestDb::new("buffer_store_state");
+
+        let buffer_id = create_buffer(BufferType::Frames, "state".to_string());
+        append_frames_to_buffer(
+            &buffer_id,
+            vec![
+                can_frame_at(0x100, 1_000),
+                can_frame_at(0x200, 2_000),
+                FrameMessage { bus: 1, ..can_frame_at(0x100, 3_000) },
+                can_frame_at(0x100, 4_000),
+                FrameMessage { is_extended: true, ..can_frame_at(0x100, 4_500) },
+                can_frame_at(0x200, 6_000),
+                // Appended last but older than the extended frame above
+                FrameMessage { is_extended: true, ..can_frame_at(0x100, 3_800) },
+            ],
+        );
+        let state = |t, lookback| -> Vec<(u8, bool, u32, u64)> {
+            get_buffer_state_at(&buffer_id, t, lookback)
+                .unwrap()
+                .iter()
+                .map(|f| (f.bus, f.is_extended, f.frame_id, f.timestamp_us))
+                .collect()
+        };
+
+        // 0x100 is tracked separately on each bus and as standard and
+        // extended IDs; 0x200 at 6000 is in the future
+        assert_eq!(
+            state(5_000, None),
+            vec![(0, false, 0x100, 4_000), (0, false, 0x200, 2_000), (0, true, 0x100, 4_500), (1, false, 0x100, 3_000)]
+        );
+        assert_eq!(
+            state(4_000, None),
+            vec![(0, false, 0x100, 4_000), (0, false, 0x200, 2_000), (0, true, 0x100, 3_800), (1, false, 0x100, 3_000)]
+        );
+        assert_eq!(
+            state(3_500, None),
+            vec![(0, false, 0x100, 1_000), (0, false, 0x200, 2_000), (1, false, 0x100, 3_000)]
+        );
+        // IDs last seen before the lookback window are left out
+        assert_eq!(
+            state(5_000, Some(2_500)),
+            vec![(0, false, 0x100, 4_000), (0, true, 0x100, 4_500), (1, false, 0x100, 3_000)]
+        );
+        assert!(state(500, None).is_empty());
+
+        let _ = delete_buffer(&buffer_id);
+    }
+
+    #[test]
+    fn test_unique_ids_keep_standard_and_extended_apart() {
+        let _db = TestDb::new("buffer_store_ids");
+
+        let mut extended = can_frame_at(0x100, 2_000);
+        extended.is_extended = true;
+        let mut latest = can_frame_at(0x100, 4_000);
+        latest.bytes = vec![0xAA, 0xBB];
+        latest.is_fd = true;
+
+        let buffer_id = create_buffer(BufferType::Frames, "ids".to_string());
+        append_frames_to_buffer(
+            &buffer_id,
+            vec![
+                can_frame_at(0x200, 500),
+                can_frame_at(0x100, 1_000),
+                extended,
+                FrameMessage { bus: 1, ..can_frame_at(0x100, 3_000) },
+                latest,
+            ],
+        );
+
+        let ids: Vec<(u32, u8, bool, usize, Vec<u8>, bool)> = get_buffer_unique_ids(&buffer_id)
+            .unwrap()
+            .into_iter()
+            .map(|e| (e.frame_id, e.bus, e.is_extended, e.count, e.last_bytes, e.is_fd))
+            .collect();
+        assert_eq!(
+            ids,
+            vec![
+                (0x100, 0, false, 2, vec![0xAA, 0xBB], true),
+                (0x100, 0, true, 1, vec![], false),
+                (0x100, 1, false, 1, vec![], false),
+                (0x200, 0, false, 1, vec![], false),
+            ]
+        );
+        assert!(get_buffer_unique_ids("no_such_buffer").is_none());
+
+        let _ = delete_buffer(&buffer_id);
+    }
+
+    #[test]
+    fn test_frame_timestamps_filter_on_bus_and_id_format() {
+        let _db = TestDb::new("buffer_store_timestamps");
+
+        let buffer_id = create_buffer(BufferType::Frames, "timestamps".to_string());
+        append_frames_to_buffer(
+            &buffer_id,
+            vec![
+                can_frame_at(0x100, 1_000),
+                FrameMessage { bus: 1, ..can_frame_at(0x100, 1_500) },
+                FrameMessage { is_extended: true, ..can_frame_at(0x100, 1_700) },
+                can_frame_at(0x100, 2_000),
+            ],
+        );
+
+        let timestamps = |bus, is_extended| buffer_db::get_frame_timestamps(&buffer_id, 0x100, bus, is_extended).unwrap();
+        assert_eq!(timestamps(None, false), vec![1_000, 1_500, 2_000]);
+        assert_eq!(timestamps(Some(0), false), vec![1_000, 2_000]);
+        assert_eq!(timestamps(Some(0), true), vec![1_700]);
+        assert!(timestamps(Some(2), false).is_empty());
+
+        let _ = delete_buffer(&buffer_id);
+    }
+}
@@ -3,11 +3,13 @@
 // Tauri commands for buffer management.
 // Handles CSV import, buffer CRUD, pagination, and multi-buffer registry.
 
+use std::collections::HashMap;
 use tauri::{AppHandle, Emitter};
 
 use crate::{
-    buffer_store::{self, BufferMetadata, BufferFrameInfo, TimestampedByte, TailResponse},
-    io::{self, FrameMessage},
+    buffer_db, buffer_snapshot,
+    buffer_store::{self, BufferMetadata, BufferFrameInfo, IdEntry, TimestampedByte, TailResponse},
+    io::{self, FrameFilter, FrameMessage},
 };
 
 /// Result of a CSV import, including buffer metadata and any sequence gap diagnostics.
@@ -19,6 +21,22 @@ pub struct CsvImportResult {
     pub total_dropped: u64,
     /// Detected sequence wraparound points (raw sequence value at each wrap)
     pub wrap_points: Vec<u64>,
+    /// Rows skipped because of malformed IDs or data
+    pub parse_errors: io::ParseErrors,
+    /// Frames that were out of timestamp order (sorted if the import asked for it)
+    pub out_of_order_frames: usize,
+    /// Frames with a data length impossible for their type (classic CAN over
+    /// 8 bytes, or a non-canonical CAN FD length); flagged `incomplete`, or
+    /// dropped when the import asked for it
+    pub invalid_length_frames: usize,
+}
+
+/// Result of a GVRET binary capture import
+#[derive(Clone, serde::Serialize)]
+pub struct GvretImportResult {
+    pub metadata: BufferMetadata,
+    /// Frames that were out of timestamp order (sorted if the import asked for it)
+    pub out_of_order_frames: usize,
 }
 
 /// Response for paginated buffer frames
@@ -46,9 +64,17 @@ pub struct PaginatedBytesResponse {
 // CSV Import Commands
 // ============================================================================
 
-/// Import a CSV file into the shared buffer
+/// Import a CSV file into the shared buffer.
+/// With `sort_by_timestamp`, frames are stably sorted by timestamp first;
+/// otherwise file order is kept. Either way the out-of-order count is returned.
+/// Frames with impossible data lengths are flagged `incomplete`, or dropped
+/// with `drop_invalid_frames`.
 #[tauri::command(rename_all = "snake_case")]
-pub async fn import_csv_to_buffer(file_path: String) -> Result<BufferMetadata, String> {
+pub async fn import_csv_to_buffer(
+    file_path: String,
+    sort_by_timestamp: Option<bool>,
+    drop_invalid_frames: Option<bool>,
+) -> Result<CsvImportResult, String> {
     // Extract filename from path
     let filename = std::path::Path::new(&file_path)
         .file_name()
@@ -57,18 +83,79 @@ pub async fn import_csv_to_buffer(file_path: String) -> Result<BufferMetadata, S
         .to_string();
 
     // Parse the CSV file
-    let frames = io::parse_csv_file(&file_path)?;
+    let (mut frames, parse_errors) = io::parse_csv_file(&file_path)?;
+    let invalid_length_frames = io::check_frame_lengths(&mut frames, drop_invalid_frames.unwrap_or(false));
+    log_invalid_lengths(&filename, invalid_length_frames);
 
     if frames.is_empty() {
         return Err("CSV file contains no valid frames".to_string());
     }
 
+    let out_of_order_frames = sort_or_count_out_of_order(&mut frames, sort_by_timestamp.unwrap_or(false), &filename);
+
     // Store in the shared buffer
     buffer_store::set_buffer(frames, filename);
 
-    // Return the metadata
-    buffer_store::get_metadata()
-        .ok_or_else(|| "Failed to store frames in buffer".to_string())
+    let metadata = buffer_store::get_metadata()
+        .ok_or_else(|| "Failed to store frames in buffer".to_string())?;
+
+    Ok(CsvImportResult {
+        metadata,
+        sequence_gaps: Vec::new(),
+        total_dropped: 0,
+        wrap_points: Vec::new(),
+        parse_errors,
+        out_of_order_frames,
+        invalid_length_frames,
+    })
+}
+
+/// Import a SavvyCAN/GVRET binary capture file into the shared buffer.
+/// With `sort_by_timestamp`, frames are stably sorted by timestamp first;
+/// otherwise file order is kept. Either way the out-of-order count is returned.
+#[tauri::command(rename_all = "snake_case")]
+pub async fn import_gvret_binary(
+    file_path: String,
+    sort_by_timestamp: Option<bool>,
+) -> Result<GvretImportResult, String> {
+    let filename = std::path::Path::new(&file_path)
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or("unknown.gvret")
+        .to_string();
+
+    let mut frames = io::parse_gvret_binary_file(&file_path)?;
+
+    if frames.is_empty() {
+        return Err("GVRET capture contains no valid frames".to_string());
+    }
+
+    let out_of_order_frames = sort_or_count_out_of_order(&mut frames, sort_by_timestamp.unwrap_or(false), &filename);
+
+    buffer_store::set_buffer(frames, filename);
+
+    let metadata = buffer_store::get_metadata()
+        .ok_or_else(|| "Failed to store frames in buffer".to_string())?;
+
+    Ok(GvretImportResult { metadata, out_of_order_frames })
+}
+
+/// Count frames out of timestamp order, sorting them (stably) when `sort` is set
+fn sort_or_count_out_of_order(frames: &mut [FrameMessage], sort: bool, filename: &str) -> usize {
+    let out_of_order = if sort {
+        io::sort_frames_by_timestamp(frames)
+    } else {
+        io::count_out_of_order(frames)
+    };
+    if out_of_order > 0 {
+        tlog!(
+            "[Buffers] {} {} out-of-order frame(s) from '{}'",
+            if sort { "Sorted" } else { "Kept" },
+            out_of_order,
+            filename
+        );
+    }
+    out_of_order
 }
 
 /// Preview a data file: read first N rows, detect delimiter/headers, suggest column mappings
@@ -82,8 +169,11 @@ pub async fn preview_csv(
     io::preview_csv_file(&file_path, max, delimiter)
 }
 
-/// Import a data file with user-provided column mappings
+/// Import a data file with user-provided column mappings.
+/// Frames with impossible data lengths are flagged `incomplete`, or dropped
+/// with `drop_invalid_frames`.
 #[tauri::command(rename_all = "snake_case")]
+#[allow(clippy::too_many_arguments)]
 pub async fn import_csv_with_mapping(
     file_path: String,
     mappings: Vec<io::CsvColumnMapping>,
@@ -91,6 +181,8 @@ pub async fn import_csv_with_mapping(
     timestamp_unit: io::TimestampUnit,
     negate_timestamps: bool,
     delimiter: io::Delimiter,
+    sort_by_timestamp: Option<bool>,
+    drop_invalid_frames: Option<bool>,
 ) -> Result<CsvImportResult, String> {
     let filename = std::path::Path::new(&file_path)
         .file_name()
@@ -98,7 +190,17 @@ pub async fn import_csv_with_mapping(
         .unwrap_or("unknown")
         .to_string();
 
-    let result = io::parse_csv_with_mapping(&file_path, &mappings, skip_first_row, timestamp_unit, negate_timestamps, delimiter)?;
+    let mut result = io::parse_csv_with_mapping(
+        &file_path,
+        &mappings,
+        skip_first_row,
+        timestamp_unit,
+        negate_timestamps,
+        delimiter,
+        sort_by_timestamp.unwrap_or(false),
+    )?;
+    let invalid_length_frames = io::check_frame_lengths(&mut result.frames, drop_invalid_frames.unwrap_or(false));
+    log_invalid_lengths(&filename, invalid_length_frames);
 
     if result.frames.is_empty() {
         return Err("File contains no valid frames with the given column mapping".to_string());
@@ -107,6 +209,8 @@ pub async fn import_csv_with_mapping(
     let sequence_gaps = result.sequence_gaps;
     let total_dropped = sequence_gaps.iter().map(|g| g.dropped).sum();
     let wrap_points = detect_wrap_points(&sequence_gaps);
+    let parse_errors = result.parse_errors;
+    let out_of_order_frames = result.out_of_order;
 
     buffer_store::set_buffer(result.frames, filename);
 
@@ -118,12 +222,20 @@ pub async fn import_csv_with_mapping(
         sequence_gaps,
         total_dropped,
         wrap_points,
+        parse_errors,
+        out_of_order_frames,
+        invalid_length_frames,
     })
 }
 
 /// Import multiple data files with shared column mappings into a single buffer.
-/// Files are parsed sequentially and concatenated in order.
+/// Files are parsed sequentially and concatenated in order. With
+/// `sort_by_timestamp`, the concatenated frames are stably sorted by timestamp
+/// before being stored (this holds every file in memory at once).
+/// Frames with impossible data lengths are flagged `incomplete`, or dropped
+/// with `drop_invalid_frames`.
 #[tauri::command(rename_all = "snake_case")]
+#[allow(clippy::too_many_arguments)]
 pub async fn import_csv_batch_with_mapping(
     app_handle: AppHandle,
     file_paths: Vec<String>,
@@ -132,6 +244,8 @@ pub async fn import_csv_batch_with_mapping(
     timestamp_unit: io::TimestampUnit,
     negate_timestamps: bool,
     delimiter: io::Delimiter,
+    sort_by_timestamp: Option<bool>,
+    drop_invalid_frames: Option<bool>,
 ) -> Result<CsvImportResult, String> {
     if file_paths.is_empty() {
         return Err("No files provided".to_string());
@@ -150,8 +264,15 @@ pub async fn import_csv_batch_with_mapping(
     let total_files = file_paths.len();
     let mut total_frames: usize = 0;
     let mut all_sequence_gaps: Vec<io::SequenceGap> = Vec::new();
+    let mut all_parse_errors = io::ParseErrors::default();
     let mut prev_file_last_seq: Option<u64> = None;
     let mut prev_file_name: Option<String> = None;
+    let sort_by_timestamp = sort_by_timestamp.unwrap_or(false);
+    let mut out_of_order_frames = 0usize;
+    let drop_invalid_frames = drop_invalid_frames.unwrap_or(false);
+    let mut invalid_length_frames = 0usize;
+    // Frames held back for the cross-file sort
+    let mut sorted_frames: Vec<FrameMessage> = Vec::new();
 
     for (i, file_path) in file_paths.iter().enumerate() {
         let fname = extract_filename(file_path);
@@ -169,11 +290,15 @@ pub async fn import_csv_batch_with_mapping(
         // Per-file header flag; falls back to false if array is shorter
         let skip_row = skip_first_row_per_file.get(i).copied().unwrap_or(false);
 
-        let result = io::parse_csv_with_mapping(
-            file_path, &mappings, skip_row, timestamp_unit, negate_timestamps, delimiter,
+        let mut result = io::parse_csv_with_mapping(
+            file_path, &mappings, skip_row, timestamp_unit, negate_timestamps, delimiter, sort_by_timestamp,
         )?;
+        let invalid = io::check_frame_lengths(&mut result.frames, drop_invalid_frames);
+        log_invalid_lengths(&fname, invalid);
+        invalid_length_frames += invalid;
 
         total_frames += result.frames.len();
+        out_of_order_frames += result.out_of_order;
 
         // Detect inter-file sequence gap (between previous file's last seq and this file's first)
         if let (Some(prev_last), Some(cur_first)) = (prev_file_last_seq, result.first_seq) {
@@ -206,12 +331,29 @@ pub async fn import_csv_batch_with_mapping(
             all_sequence_gaps.push(gap);
         }
 
+        // Prefix samples with the filename so multi-file errors can be located
+        let mut file_errors = result.parse_errors;
+        for sample in &mut file_errors.samples {
+            *sample = format!("{}: {}", fname, sample);
+        }
+        all_parse_errors.merge(file_errors);
+
         if result.last_seq.is_some() {
             prev_file_last_seq = result.last_seq;
             prev_file_name = Some(fname);
         }
 
-        buffer_store::append_frames(result.frames);
+        if sort_by_timestamp {
+            sorted_frames.extend(result.frames);
+        } else {
+            buffer_store::append_frames_to_buffer(&buffer_id, result.frames);
+        }
+    }
+
+    if sort_by_timestamp {
+        // Each file is already sorted; this counts frames that overlap an earlier file
+        out_of_order_frames += io::sort_frames_by_timestamp(&mut sorted_frames);
+        buffer_store::append_frames_to_buffer(&buffer_id, sorted_frames);
     }
 
     if total_frames == 0 {
@@ -235,9 +377,19 @@ pub async fn import_csv_batch_with_mapping(
         sequence_gaps: all_sequence_gaps,
         total_dropped,
         wrap_points,
+        parse_errors: all_parse_errors,
+        out_of_order_frames,
+        invalid_length_frames,
     })
 }
 
+/// Note impossible frame lengths found while importing `filename`
+fn log_invalid_lengths(filename: &str, invalid: usize) {
+    if invalid > 0 {
+        tlog!("[Buffers] {} frame(s) in '{}' have a data length impossible for their type", invalid, filename);
+    }
+}
+
 /// Detect sequence wraparound points from gaps.
 /// A wrap is when `to_seq` is much smaller than `from_seq` (large backward jump).
 fn detect_wrap_points(gaps: &[io::SequenceGap]) -> Vec<u64> {
@@ -404,6 +556,557 @@ pub async fn get_buffer_frames_by_id(buffer_id: String) -> Result<Vec<FrameMessa
         .ok_or_else(|| format!("Buffer '{}' not found or is not a frame buffer", buffer_id))
 }
 
+/// Extract the frames within `[match - before_us, match + after_us]` around frames
+/// matching `filter`. Frames must be in timestamp order. When `all_matches` is set,
+/// every match contributes a window and overlapping windows are merged so no frame
+/// is returned twice; otherwise only the first match is used.
+pub fn extract_window(
+    frames: &[FrameMessage],
+    filter: &FrameFilter,
+    before_us: u64,
+    after_us: u64,
+    all_matches: bool,
+) -> Vec<FrameMessage> {
+    let mut result = Vec::new();
+    // End (exclusive) of the last window emitted, used to merge overlapping windows
+    let mut emitted_end = 0usize;
+
+    for (idx, frame) in frames.iter().enumerate() {
+        if !filter.matches(frame) {
+            continue;
+        }
+
+        let start_ts = frame.timestamp_us.saturating_sub(before_us);
+        let end_ts = frame.timestamp_us.saturating_add(after_us);
+        let start = frames[..=idx].partition_point(|f| f.timestamp_us < start_ts);
+        let end = idx + frames[idx..].partition_point(|f| f.timestamp_us <= end_ts);
+
+        let from = start.max(emitted_end);
+        if from < end {
+            result.extend_from_slice(&frames[from..end]);
+        }
+        emitted_end = emitted_end.max(end);
+
+        if !all_matches {
+            break;
+        }
+    }
+
+    result
+}
+
+/// Extract a time window around the first (or every) frame in a buffer matching `filter`.
+#[tauri::command(rename_all = "snake_case")]
+pub async fn extract_buffer_window(
+    buffer_id: String,
+    filter: FrameFilter,
+    before_us: u64,
+    after_us: u64,
+    all_matches: Option<bool>,
+) -> Result<Vec<FrameMessage>, String> {
+    let frames = buffer_store::get_buffer_frames(&buffer_id)
+        .ok_or_else(|| format!("Buffer '{}' not found or is not a frame buffer", buffer_id))?;
+    Ok(extract_window(&frames, &filter, before_us, after_us, all_matches.unwrap_or(false)))
+}
+
+/// A frame ID on one bus. Standard and extended IDs with the same value are
+/// distinct.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, serde::Serialize)]
+pub struct FrameIdKey {
+    pub bus: u8,
+    pub frame_id: u32,
+    pub is_extended: bool,
+}
+
+impl FrameIdKey {
+    fn of(frame: &FrameMessage) -> Self {
+        Self { bus: frame.bus, frame_id: frame.frame_id, is_extended: frame.is_extended }
+    }
+}
+
+/// A frame ID present in both buffers whose payload bytes differ.
+#[derive(Clone, Debug, PartialEq, serde::Serialize)]
+pub struct ChangedFrameId {
+    pub bus: u8,
+    pub frame_id: u32,
+    pub is_extended: bool,
+    /// Byte positions where the set of values seen differs between the buffers
+    /// (positions present in only one buffer's payloads are included).
+    pub byte_positions: Vec<usize>,
+}
+
+/// Difference between two frame buffers, keyed by bus, frame ID and ID format.
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize)]
+pub struct BufferDiff {
+    pub only_in_a: Vec<FrameIdKey>,
+    pub only_in_b: Vec<FrameIdKey>,
+    pub changed: Vec<ChangedFrameId>,
+}
+
+/// Set of values seen at each byte position, per frame ID.
+fn byte_patterns(frames: &[FrameMessage]) -> std::collections::BTreeMap<FrameIdKey, Vec<std::collections::BTreeSet<u8>>> {
+    let mut patterns: std::collections::BTreeMap<FrameIdKey, Vec<std::collections::BTreeSet<u8>>> =
+        std::collections::BTreeMap::new();
+    for frame in frames {
+        let positions = patterns.entry(FrameIdKey::of(frame)).or_default();
+        if positions.len() < frame.bytes.len() {
+            positions.resize_with(frame.bytes.len(), Default::default);
+        }
+        for (i, byte) in frame.bytes.iter().enumerate() {
+            positions[i].insert(*byte);
+        }
+    }
+    patterns
+}
+
+/// Compare two sets of frames: IDs only in A, only in B, and shared IDs whose
+/// per-position byte values differ.
+pub fn diff_frames(a: &[FrameMessage], b: &[FrameMessage]) -> BufferDiff {
+    let patterns_a = byte_patterns(a);
+    let patterns_b = byte_patterns(b);
+    let mut diff = BufferDiff::default();
+
+    for (key, positions_a) in &patterns_a {
+        let Some(positions_b) = patterns_b.get(key) else {
+            diff.only_in_a.push(*key);
+            continue;
+        };
+        let len = positions_a.len().max(positions_b.len());
+        let byte_positions: Vec<usize> = (0..len)
+            .filter(|&i| positions_a.get(i) != positions_b.get(i))
+            .collect();
+        if !byte_positions.is_empty() {
+            diff.changed.push(ChangedFrameId {
+                bus: key.bus,
+                frame_id: key.frame_id,
+                is_extended: key.is_extended,
+                byte_positions,
+            });
+        }
+    }
+    diff.only_in_b = patterns_b
+        .keys()
+        .filter(|key| !patterns_a.contains_key(key))
+        .copied()
+        .collect();
+
+    diff
+}
+
+/// Diff two frame buffers (e.g. a baseline capture against one with an action performed).
+#[tauri::command(rename_all = "snake_case")]
+pub async fn diff_buffers(buffer_a: String, buffer_b: String) -> Result<BufferDiff, String> {
+    let frames_a = buffer_store::get_buffer_frames(&buffer_a)
+        .ok_or_else(|| format!("Buffer '{}' not found or is not a frame buffer", buffer_a))?;
+    let frames_b = buffer_store::get_buffer_frames(&buffer_b)
+        .ok_or_else(|| format!("Buffer '{}' not found or is not a frame buffer", buffer_b))?;
+    Ok(diff_frames(&frames_a, &frames_b))
+}
+
+/// Highest coefficient of variation (std dev / mean) of inter-arrival times
+/// still classed as periodic
+const PERIODIC_MAX_CV: f64 = 0.2;
+/// Fewest inter-arrival times needed before an ID can be classed as periodic
+const PERIODIC_MIN_INTERVALS: usize = 3;
+
+/// How a frame ID's transmissions are spread over time.
+#[derive(Clone, Debug, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Periodicity {
+    /// Sent on a steady schedule. `period_us` is the mean inter-arrival time and
+    /// `jitter_us` its standard deviation.
+    Periodic { period_us: u64, jitter_us: u64 },
+    /// Seen more than once, but not on a steady schedule (event-driven)
+    Sporadic,
+    /// Seen exactly once
+    OneShot,
+}
+
+/// Periodicity classification of one frame ID on one bus.
+#[derive(Clone, Debug, PartialEq, serde::Serialize)]
+pub struct PeriodicityInfo {
+    pub bus: u8,
+    pub frame_id: u32,
+    pub is_extended: bool,
+    pub count: usize,
+    pub periodicity: Periodicity,
+}
+
+/// Classify one ID from its timestamps (in timestamp order).
+fn classify_timestamps(timestamps: &[u64]) -> Periodicity {
+    if timestamps.len() < 2 {
+        return Periodicity::OneShot;
+    }
+    let intervals: Vec<f64> = timestamps
+        .windows(2)
+        .map(|w| w[1].saturating_sub(w[0]) as f64)
+        .collect();
+    let mean = intervals.iter().sum::<f64>() / intervals.len() as f64;
+    if intervals.len() < PERIODIC_MIN_INTERVALS || mean <= 0.0 {
+        return Periodicity::Sporadic;
+    }
+    let variance = intervals.iter().map(|i| (i - mean).powi(2)).sum::<f64>() / intervals.len() as f64;
+    let std_dev = variance.sqrt();
+    if std_dev / mean > PERIODIC_MAX_CV {
+        return Periodicity::Sporadic;
+    }
+    Periodicity::Periodic { period_us: mean.round() as u64, jitter_us: std_dev.round() as u64 }
+}
+
+/// Classify every frame ID in `frames` as periodic, sporadic or one-shot from the
+/// spread of its inter-arrival times. The same ID on another bus, or in the
+/// other ID format, is classified separately. Results are ordered by bus, then
+/// standard before extended, then frame ID.
+pub fn classify_frame_periodicity(frames: &[FrameMessage]) -> Vec<PeriodicityInfo> {
+    let mut by_id: std::collections::BTreeMap<(u8, bool, u32), Vec<u64>> = std::collections::BTreeMap::new();
+    for frame in frames {
+        by_id
+            .entry((frame.bus, frame.is_extended, frame.frame_id))
+            .or_default()
+            .push(frame.timestamp_us);
+    }
+    by_id
+        .into_iter()
+        .map(|((bus, is_extended, frame_id), mut timestamps)| {
+            timestamps.sort_unstable();
+            PeriodicityInfo {
+                bus,
+                frame_id,
+                is_extended,
+                count: timestamps.len(),
+                periodicity: classify_timestamps(&timestamps),
+            }
+        })
+        .collect()
+}
+
+/// Classify each frame ID in a buffer as periodic background traffic or
+/// event-driven, to separate steady traffic from interesting frames.
+#[tauri::command(rename_all = "snake_case")]
+pub async fn classify_periodicity(buffer_id: String) -> Result<Vec<PeriodicityInfo>, String> {
+    let frames = buffer_store::get_buffer_frames(&buffer_id)
+        .ok_or_else(|| format!("Buffer '{}' not found or is not a frame buffer", buffer_id))?;
+    Ok(classify_frame_periodicity(&frames))
+}
+
+/// Most bins `buffer_overview` will compute
+const MAX_OVERVIEW_BUCKETS: usize = 10_000;
+
+/// Activity in one time bin of a buffer overview.
+#[derive(Clone, Debug, PartialEq, serde::Serialize)]
+pub struct BucketStats {
+    pub start_us: u64,
+    /// End of the bin (exclusive)
+    pub end_us: u64,
+    pub frame_count: usize,
+    /// Frames per bus; buses without frames in the bin are left out
+    pub per_bus_counts: std::collections::BTreeMap<u8, usize>,
+    /// Distinct frame IDs in the bin
+    pub unique_ids: usize,
+}
+
+/// Split the time range of `frames` into `buckets` equal bins and count the
+/// activity in each, in a single pass. Empty for no frames.
+pub fn overview_frames(frames: &[FrameMessage], buckets: usize) -> Vec<BucketStats> {
+    let (Some(first), Some(last)) = (
+        frames.iter().map(|f| f.timestamp_us).min(),
+        frames.iter().map(|f| f.timestamp_us).max(),
+    ) else {
+        return Vec::new();
+    };
+    // Span is inclusive of the last frame so it lands in the final bin
+    let span = (last - first) as u128 + 1;
+    let bound = |i: usize| first + (i as u128 * span / buckets as u128) as u64;
+
+    let mut stats: Vec<BucketStats> = (0..buckets)
+        .map(|i| BucketStats {
+            start_us: bound(i),
+            end_us: bound(i + 1),
+            frame_count: 0,
+            per_bus_counts: std::collections::BTreeMap::new(),
+            unique_ids: 0,
+        })
+        .collect();
+    let mut ids: Vec<std::collections::HashSet<u32>> = vec![Default::default(); buckets];
+
+    for frame in frames {
+        let i = ((frame.timestamp_us - first) as u128 * buckets as u128 / span) as usize;
+        let bucket = &mut stats[i];
+        bucket.frame_count += 1;
+        *bucket.per_bus_counts.entry(frame.bus).or_default() += 1;
+        ids[i].insert(frame.frame_id);
+    }
+    for (bucket, ids) in stats.iter_mut().zip(ids) {
+        bucket.unique_ids = ids.len();
+    }
+    stats
+}
+
+/// Decimated activity overview of a frame buffer (e.g. for a scrub bar mini-map)
+/// without sending every frame to the UI.
+#[tauri::command(rename_all = "snake_case")]
+pub async fn buffer_overview(buffer_id: String, buckets: usize) -> Result<Vec<BucketStats>, String> {
+    if !(1..=MAX_OVERVIEW_BUCKETS).contains(&buckets) {
+        return Err(format!("Bucket count must be between 1 and {} (got {})", MAX_OVERVIEW_BUCKETS, buckets));
+    }
+    let frames = buffer_store::get_buffer_frames(&buffer_id)
+        .ok_or_else(|| format!("Buffer '{}' not found or is not a frame buffer", buffer_id))?;
+    Ok(overview_frames(&frames, buckets))
+}
+
+/// Hash of the ID inventory of `frames`: the sorted set of (bus, frame ID,
+/// extended flag, typical DLC) tuples, ignoring timing, data and frame order.
+/// The typical DLC is the most common one for the ID (the smallest on a tie).
+/// Returned as 16 hex digits (the first 8 bytes of a SHA-256).
+pub fn fingerprint_frames(frames: &[FrameMessage]) -> String {
+    use sha2::{Digest, Sha256};
+
+    let mut dlcs: std::collections::BTreeMap<(u8, u32, bool), HashMap<u8, usize>> =
+        std::collections::BTreeMap::new();
+    for frame in frames {
+        *dlcs
+            .entry((frame.bus, frame.frame_id, frame.is_extended))
+            .or_default()
+            .entry(frame.dlc)
+            .or_default() += 1;
+    }
+
+    let mut hasher = Sha256::new();
+    for ((bus, frame_id, is_extended), counts) in &dlcs {
+        let typical_dlc = counts
+            .iter()
+            .max_by(|a, b| a.1.cmp(b.1).then(b.0.cmp(a.0)))
+            .map(|(dlc, _)| *dlc)
+            .unwrap_or(0);
+        hasher.update([*bus]);
+        hasher.update(frame_id.to_be_bytes());
+        hasher.update([*is_extended as u8, typical_dlc]);
+    }
+    hasher.finalize()[..8].iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Stable fingerprint of a frame buffer's network topology, so two captures of
+/// the same vehicle/config can be matched even when their data differs.
+#[tauri::command(rename_all = "snake_case")]
+pub async fn traffic_fingerprint(buffer_id: String) -> Result<String, String> {
+    let frames = buffer_store::get_buffer_frames(&buffer_id)
+        .ok_or_else(|| format!("Buffer '{}' not found or is not a frame buffer", buffer_id))?;
+    Ok(fingerprint_frames(&frames))
+}
+
+/// Last known value of every frame ID at a point in a frame buffer: the most
+/// recent frame for each (bus, frame_id) at or before `timestamp_us`.
+///
+/// Frames older than `lookback_ms` before the timestamp are left out (default
+/// 2 minutes, the same window as paused seeking; 0 for no limit).
+#[tauri::command(rename_all = "snake_case")]
+pub async fn state_at(
+    buffer_id: String,
+    timestamp_us: u64,
+    lookback_ms: Option<u64>,
+) -> Result<Vec<FrameMessage>, String> {
+    let lookback_us = match lookback_ms {
+        None => Some(buffer_db::SNAPSHOT_LOOKBACK_US),
+        Some(0) => None,
+        Some(ms) => Some(ms.saturating_mul(1000)),
+    };
+    buffer_store::get_buffer_state_at(&buffer_id, timestamp_us, lookback_us)
+        .ok_or_else(|| format!("Buffer '{}' not found or is not a frame buffer", buffer_id))
+}
+
+/// Every distinct frame ID in a frame buffer with its frame count and latest
+/// payload, for ID pickers. Lighter than full statistics: one grouped query.
+/// Entries are keyed by (bus, is_extended, frame_id) and sorted by frame_id
+/// then bus.
+#[tauri::command(rename_all = "snake_case")]
+pub async fn unique_ids(buffer_id: String) -> Result<Vec<IdEntry>, String> {
+    buffer_store::get_buffer_unique_ids(&buffer_id)
+        .ok_or_else(|| format!("Buffer '{}' not found or is not a frame buffer", buffer_id))
+}
+
+/// A single editing step applied by `transform_buffer`.
+#[derive(Clone, Debug, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TransformOp {
+    /// Move frames on bus `from` to bus `to`
+    RemapBus { from: u8, to: u8 },
+    /// Keep only frames matching the filter
+    FilterIds(FrameFilter),
+    /// Shift timestamps so the earliest frame is at 0
+    RebaseTime,
+    /// Shorten gaps between consecutive frames to at most this many microseconds
+    ClampGaps(u64),
+    /// Shift every timestamp by this many microseconds (clamped at 0)
+    Offset(i64),
+}
+
+/// Apply transform ops in order. Frames must be in timestamp order.
+pub fn apply_transforms(frames: Vec<FrameMessage>, ops: &[TransformOp]) -> Vec<FrameMessage> {
+    transform_frames(frames, ops).0
+}
+
+/// `apply_transforms`, also returning how far the ops moved the first frame's
+/// timestamp in microseconds (clamping at 0 is not counted).
+fn transform_frames(mut frames: Vec<FrameMessage>, ops: &[TransformOp]) -> (Vec<FrameMessage>, i64) {
+    let mut shift_us = 0i64;
+    for op in ops {
+        match op {
+            TransformOp::RemapBus { from, to } => {
+                for frame in frames.iter_mut().filter(|f| f.bus == *from) {
+                    frame.bus = *to;
+                }
+            }
+            TransformOp::FilterIds(filter) => frames.retain(|f| filter.matches(f)),
+            TransformOp::RebaseTime => {
+                let start = frames.iter().map(|f| f.timestamp_us).min().unwrap_or(0);
+                for frame in &mut frames {
+                    frame.timestamp_us -= start;
+                }
+                shift_us -= start as i64;
+            }
+            TransformOp::ClampGaps(max_us) => {
+                let mut prev: Option<(u64, u64)> = None; // (original, transformed)
+                for frame in &mut frames {
+                    let original = frame.timestamp_us;
+                    if let Some((prev_original, prev_new)) = prev {
+                        let gap = original.saturating_sub(prev_original).min(*max_us);
+                        frame.timestamp_us = prev_new + gap;
+                    }
+                    prev = Some((original, frame.timestamp_us));
+                }
+            }
+            TransformOp::Offset(us) => {
+                for frame in &mut frames {
+                    frame.timestamp_us = frame.timestamp_us.saturating_add_signed(*us);
+                }
+                shift_us += us;
+            }
+        }
+    }
+    (frames, shift_us)
+}
+
+/// Copy a frame buffer into a new buffer named `dst_name` with `ops` applied.
+/// The source buffer is left untouched. Returns the new buffer ID.
+///
+/// When the ops move the timeline (`rebase_time`, `offset`), the new buffer's
+/// `time_base_us` records the absolute time its timestamp 0 corresponds to,
+/// anchored on the first frame, so replay can still report the original
+/// time of day.
+#[tauri::command(rename_all = "snake_case")]
+pub async fn transform_buffer(src: String, dst_name: String, ops: Vec<TransformOp>) -> Result<String, String> {
+    let frames = buffer_store::get_buffer_frames(&src)
+        .ok_or_else(|| format!("Buffer '{}' not found or is not a frame buffer", src))?;
+    let src_time_base = buffer_store::get_buffer_metadata(&src).and_then(|m| m.time_base_us);
+    let (frames, shift_us) = transform_frames(frames, &ops);
+    if frames.is_empty() {
+        return Err("Transform left no frames to create buffer from".to_string());
+    }
+
+    let buffer_id = buffer_store::create_buffer_inactive(buffer_store::BufferType::Frames, dst_name);
+    buffer_store::append_frames_to_buffer(&buffer_id, frames);
+    if shift_us != 0 || src_time_base.is_some() {
+        let time_base = src_time_base.unwrap_or(0) as i64 - shift_us;
+        buffer_store::set_buffer_time_base(&buffer_id, u64::try_from(time_base).ok())?;
+    }
+    Ok(buffer_id)
+}
+
+/// How `merge_buffers` lines up the timelines of its sources.
+#[derive(Clone, Debug, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AlignMode {
+    /// Keep timestamps as captured
+    Absolute,
+    /// Rebase each source so its earliest frame is at 0
+    ZeroBased,
+    /// Shift each source by its own offset in microseconds (clamped at 0)
+    Offset(Vec<i64>),
+}
+
+/// Merge several frame lists into one timeline sorted by timestamp. Frames with
+/// equal timestamps keep source order. `bus_maps`, when given, holds one
+/// `from -> to` bus map per source; buses missing from a map are left as-is.
+pub fn merge_frames(
+    sources: Vec<Vec<FrameMessage>>,
+    align: &AlignMode,
+    bus_maps: Option<&[HashMap<u8, u8>]>,
+) -> Result<Vec<FrameMessage>, String> {
+    if let AlignMode::Offset(offsets) = align {
+        if offsets.len() != sources.len() {
+            return Err(format!("Expected {} offsets, got {}", sources.len(), offsets.len()));
+        }
+    }
+    if let Some(maps) = bus_maps {
+        if maps.len() != sources.len() {
+            return Err(format!("Expected {} bus maps, got {}", sources.len(), maps.len()));
+        }
+    }
+
+    let mut merged = Vec::with_capacity(sources.iter().map(Vec::len).sum());
+    for (i, mut frames) in sources.into_iter().enumerate() {
+        match align {
+            AlignMode::Absolute => {}
+            AlignMode::ZeroBased => {
+                let start = frames.iter().map(|f| f.timestamp_us).min().unwrap_or(0);
+                for frame in &mut frames {
+                    frame.timestamp_us -= start;
+                }
+            }
+            AlignMode::Offset(offsets) => {
+                for frame in &mut frames {
+                    frame.timestamp_us = frame.timestamp_us.saturating_add_signed(offsets[i]);
+                }
+            }
+        }
+        if let Some(map) = bus_maps.map(|maps| &maps[i]) {
+            for frame in &mut frames {
+                if let Some(&to) = map.get(&frame.bus) {
+                    frame.bus = to;
+                }
+            }
+        }
+        merged.extend(frames);
+    }
+
+    merged.sort_by_key(|f| f.timestamp_us);
+    Ok(merged)
+}
+
+/// Merge frame buffers into a new buffer on one timeline, e.g. to correlate two
+/// separately captured logs. The sources are left untouched. Returns the new buffer ID.
+#[tauri::command(rename_all = "snake_case")]
+pub async fn merge_buffers(
+    buffer_ids: Vec<String>,
+    align: AlignMode,
+    bus_maps: Option<Vec<HashMap<u8, u8>>>,
+) -> Result<String, String> {
+    if buffer_ids.len() < 2 {
+        return Err("Select at least two buffers to merge".to_string());
+    }
+
+    let mut sources = Vec::with_capacity(buffer_ids.len());
+    let mut names = Vec::with_capacity(buffer_ids.len());
+    for id in &buffer_ids {
+        let frames = buffer_store::get_buffer_frames(id)
+            .ok_or_else(|| format!("Buffer '{}' not found or is not a frame buffer", id))?;
+        sources.push(frames);
+        names.push(buffer_store::get_buffer_metadata(id).map_or_else(|| id.clone(), |m| m.name));
+    }
+
+    let frames = merge_frames(sources, &align, bus_maps.as_deref())?;
+    if frames.is_empty() {
+        return Err("Merged buffers contain no frames".to_string());
+    }
+
+    let buffer_id = buffer_store::create_buffer_inactive(
+        buffer_store::BufferType::Frames,
+        format!("Merged: {}", names.join(" + ")),
+    );
+    buffer_store::append_frames_to_buffer(&buffer_id, frames);
+    Ok(buffer_id)
+}
+
 /// Get raw bytes from a specific buffer by ID
 #[tauri::command(rename_all = "snake_case")]
 pub async fn get_buffer_bytes_by_id(buffer_id: String) -> Result<Vec<TimestampedByte>, String> {
@@ -432,7 +1135,7 @@ pub async fn create_frame_buffer_from_frames(
     let buffer_id = buffer_store::create_buffer(buffer_store::BufferType::Frames, name);
 
     // Append the frames
-    buffer_store::append_frames(frames);
+    buffer_store::append_frames_to_buffer(&buffer_id, frames);
 
     // Finalize and return metadata
     buffer_store::finalize_buffer()
@@ -551,3 +1254,326 @@ pub async fn set_buffer_persistent(buffer_id: String, persistent: bool) -> Resul
 pub async fn list_orphaned_buffers() -> Vec<BufferMetadata> {
     buffer_store::list_orphaned_buffers()
 }
+
+// ============================================================================
+// Snapshot Commands
+// ============================================================================
+
+/// Save a buffer to `path` as a native snapshot that `load_buffer` can restore.
+#[tauri::command(rename_all = "snake_case")]
+pub async fn save_buffer(buffer_id: String, path: String) -> Result<(), String> {
+    buffer_snapshot::save_buffer(&buffer_id, &path).map(|_| ())
+}
+
+/// Load a native snapshot into a new orphaned buffer. Returns the buffer ID.
+#[tauri::command(rename_all = "snake_case")]
+pub async fn load_buffer(path: String) -> Result<String, String> {
+    buffer_snapshot::load_buffer(&path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::test_support::{can_frame, can_frame_at};
+
+    fn timestamps(frames: &[FrameMessage]) -> Vec<u64> {
+        frames.iter().map(|f| f.timestamp_us).collect()
+    }
+
+    fn trigger(frame_id: u32) -> FrameFilter {
+        FrameFilter { frame_id: Some(frame_id), ..Default::default() }
+    }
+
+    #[test]
+    fn test_extract_window_includes_boundaries() {
+        let frames = vec![can_frame_at(1, 89), can_frame_at(1, 90), can_frame_at(0x200, 100), can_frame_at(1, 110), can_frame_at(1, 111)];
+        let window = extract_window(&frames, &trigger(0x200), 10, 10, false);
+        assert_eq!(timestamps(&window), vec![90, 100, 110]);
+    }
+
+    #[test]
+    fn test_extract_window_includes_equal_timestamps_at_edges() {
+        let frames = vec![can_frame_at(1, 90), can_frame_at(2, 90), can_frame_at(0x200, 100), can_frame_at(3, 110), can_frame_at(4, 110)];
+        let window = extract_window(&frames, &trigger(0x200), 10, 10, false);
+        assert_eq!(timestamps(&window), vec![90, 90, 100, 110, 110]);
+    }
+
+    #[test]
+    fn test_extract_window_first_vs_all_matches() {
+        let frames = vec![
+            can_frame_at(1, 0), can_frame_at(0x200, 10), can_frame_at(1, 20),
+            can_frame_at(1, 100), can_frame_at(0x200, 110), can_frame_at(1, 120),
+        ];
+        let first = extract_window(&frames, &trigger(0x200), 10, 10, false);
+        assert_eq!(timestamps(&first), vec![0, 10, 20]);
+
+        let all = extract_window(&frames, &trigger(0x200), 10, 10, true);
+        assert_eq!(timestamps(&all), vec![0, 10, 20, 100, 110, 120]);
+    }
+
+    #[test]
+    fn test_extract_window_merges_overlapping_windows() {
+        let frames = vec![can_frame_at(0x200, 0), can_frame_at(1, 5), can_frame_at(0x200, 10), can_frame_at(1, 15)];
+        let all = extract_window(&frames, &trigger(0x200), 10, 5, true);
+        assert_eq!(timestamps(&all), vec![0, 5, 10, 15]);
+    }
+
+    #[test]
+    fn test_extract_window_no_match() {
+        let frames = vec![can_frame_at(1, 0), can_frame_at(2, 10)];
+        assert!(extract_window(&frames, &trigger(0x200), 10, 10, true).is_empty());
+    }
+
+    #[test]
+    fn test_frame_filter_data_mask() {
+        let mut f = can_frame_at(0x100, 0);
+        f.bytes = vec![0x12, 0x34];
+        let filter = FrameFilter {
+            data: Some(vec![0x10, 0x34]),
+            mask: Some(vec![0xF0]),
+            ..Default::default()
+        };
+        assert!(filter.matches(&f));
+        let strict = FrameFilter { data: Some(vec![0x10]), ..Default::default() };
+        assert!(!strict.matches(&f));
+    }
+
+    #[test]
+    fn test_diff_frames() {
+        let baseline = vec![
+            can_frame(0x100, 0, vec![0x01, 0x02, 0x03]),
+            can_frame(0x200, 0, vec![0xAA, 0x00]),
+            can_frame(0x300, 0, vec![0x10]),
+        ];
+        let pressed = vec![
+            can_frame(0x100, 0, vec![0x01, 0x02, 0x03]),
+            can_frame(0x200, 0, vec![0xAA, 0x01]),
+            can_frame(0x400, 0, vec![0x55]),
+        ];
+
+        let key = |frame_id| FrameIdKey { bus: 0, frame_id, is_extended: false };
+        let diff = diff_frames(&baseline, &pressed);
+        assert_eq!(diff.only_in_a, vec![key(0x300)]);
+        assert_eq!(diff.only_in_b, vec![key(0x400)]);
+        assert_eq!(
+            diff.changed,
+            vec![ChangedFrameId { bus: 0, frame_id: 0x200, is_extended: false, byte_positions: vec![1] }]
+        );
+    }
+
+    #[test]
+    fn test_diff_frames_length_change() {
+        let a = vec![can_frame(0x100, 0, vec![0x01])];
+        let b = vec![can_frame(0x100, 0, vec![0x01, 0x02])];
+        let diff = diff_frames(&a, &b);
+        assert_eq!(
+            diff.changed,
+            vec![ChangedFrameId { bus: 0, frame_id: 0x100, is_extended: false, byte_positions: vec![1] }]
+        );
+    }
+
+    #[test]
+    fn test_diff_frames_keys_by_bus_and_id_format() {
+        let mut extended = can_frame(0x100, 0, vec![0x01]);
+        extended.is_extended = true;
+        let mut bus1 = can_frame(0x100, 0, vec![0x01]);
+        bus1.bus = 1;
+        let a = vec![can_frame(0x100, 0, vec![0x01])];
+        let b = vec![can_frame(0x100, 0, vec![0x01]), extended, bus1];
+
+        let diff = diff_frames(&a, &b);
+        assert!(diff.only_in_a.is_empty());
+        assert_eq!(
+            diff.only_in_b,
+            vec![
+                FrameIdKey { bus: 0, frame_id: 0x100, is_extended: true },
+                FrameIdKey { bus: 1, frame_id: 0x100, is_extended: false },
+            ]
+        );
+        assert!(diff.changed.is_empty());
+    }
+
+    #[test]
+    fn test_classify_frame_periodicity() {
+        let mut frames = Vec::new();
+        // 0x100 every 10ms with +/-500us of jitter
+        for (i, jitter) in [0i64, 500, -500, 0, 500, -500].iter().enumerate() {
+            frames.push(can_frame_at(0x100, (10_000 * i as i64 + 1_000_000 + jitter) as u64));
+        }
+        // 0x200 on events: gaps of 1ms, 50ms, 3ms
+        for ts in [0, 1_000, 51_000, 54_000] {
+            frames.push(can_frame_at(0x200, ts));
+        }
+        // 0x300 once, 0x400 twice (too few intervals to judge)
+        frames.push(can_frame_at(0x300, 5_000));
+        frames.push(can_frame_at(0x400, 5_000));
+        frames.push(can_frame_at(0x400, 15_000));
+        // 0x100 as an extended ID and on bus 1 are other frames
+        frames.push(FrameMessage { is_extended: true, ..can_frame_at(0x100, 20_000) });
+        frames.push(FrameMessage { bus: 1, ..can_frame_at(0x100, 25_000) });
+        frames.sort_by_key(|f| f.timestamp_us);
+
+        let info = classify_frame_periodicity(&frames);
+        let keys: Vec<(u8, bool, u32)> = info.iter().map(|i| (i.bus, i.is_extended, i.frame_id)).collect();
+        assert_eq!(
+            keys,
+            vec![(0, false, 0x100), (0, false, 0x200), (0, false, 0x300), (0, false, 0x400), (0, true, 0x100), (1, false, 0x100)]
+        );
+        match info[0].periodicity {
+            Periodicity::Periodic { period_us, jitter_us } => {
+                assert_eq!(period_us, 9_900);
+                assert!(jitter_us > 0 && jitter_us < 1_000);
+            }
+            ref other => panic!("expected periodic, got {:?}", other),
+        }
+        assert_eq!(info[0].count, 6);
+        assert_eq!(info[1].periodicity, Periodicity::Sporadic);
+        assert_eq!(info[2].periodicity, Periodicity::OneShot);
+        assert_eq!(info[3].periodicity, Periodicity::Sporadic);
+        assert_eq!(info[4].periodicity, Periodicity::OneShot);
+    }
+
+    #[test]
+    fn test_classify_periodicity_threshold() {
+        // Alternating 8ms/12ms gaps: CV 0.2, right at the limit
+        assert_eq!(
+            classify_timestamps(&[0, 8_000, 20_000, 28_000, 40_000]),
+            Periodicity::Periodic { period_us: 10_000, jitter_us: 2_000 }
+        );
+        // Alternating 7ms/13ms gaps: CV 0.3
+        assert_eq!(classify_timestamps(&[0, 7_000, 20_000, 27_000, 40_000]), Periodicity::Sporadic);
+        // Identical timestamps have no period
+        assert_eq!(classify_timestamps(&[5, 5, 5, 5]), Periodicity::Sporadic);
+    }
+
+    #[test]
+    fn test_overview_frames() {
+        let mut frames = vec![can_frame_at(1, 100), can_frame_at(2, 120), can_frame_at(1, 150), can_frame_at(3, 199)];
+        frames[1].bus = 1;
+        let overview = overview_frames(&frames, 4);
+        assert_eq!(overview.len(), 4);
+        // Range 100..=199 split into four 25us bins
+        let bounds: Vec<(u64, u64)> = overview.iter().map(|b| (b.start_us, b.end_us)).collect();
+        assert_eq!(bounds, vec![(100, 125), (125, 150), (150, 175), (175, 200)]);
+        let counts: Vec<usize> = overview.iter().map(|b| b.frame_count).collect();
+        assert_eq!(counts, vec![2, 0, 1, 1]);
+        assert_eq!(overview[0].unique_ids, 2);
+        assert_eq!(overview[0].per_bus_counts.get(&0), Some(&1));
+        assert_eq!(overview[0].per_bus_counts.get(&1), Some(&1));
+        assert!(overview[1].per_bus_counts.is_empty());
+
+        assert!(overview_frames(&[], 4).is_empty());
+        let single = overview_frames(&[can_frame_at(1, 5)], 3);
+        assert_eq!(single.iter().map(|b| b.frame_count).sum::<usize>(), 1);
+    }
+
+    #[test]
+    fn test_fingerprint_frames_ignores_order_timing_and_data() {
+        let frames = vec![can_frame_at(1, 10), can_frame_at(2, 20), can_frame_at(1, 30), can_frame_at(3, 40)];
+        let fingerprint = fingerprint_frames(&frames);
+        assert_eq!(fingerprint.len(), 16);
+
+        let mut other: Vec<FrameMessage> = frames.iter().rev().cloned().collect();
+        for (i, f) in other.iter_mut().enumerate() {
+            f.timestamp_us = 1_000 + i as u64;
+            f.bytes = vec![0xFF];
+        }
+        assert_eq!(fingerprint_frames(&other), fingerprint);
+
+        // A stray odd-length frame doesn't change the ID's typical DLC
+        let mut odd = can_frame_at(1, 50);
+        odd.dlc = 8;
+        other.push(odd);
+        assert_eq!(fingerprint_frames(&other), fingerprint);
+
+        // A new ID, a moved bus or an extended ID does
+        assert_ne!(fingerprint_frames(&[frames.clone(), vec![can_frame_at(4, 50)]].concat()), fingerprint);
+        let mut moved = frames.clone();
+        moved[1].bus = 1;
+        assert_ne!(fingerprint_frames(&moved), fingerprint);
+        let mut extended = frames;
+        extended[3].is_extended = true;
+        assert_ne!(fingerprint_frames(&extended), fingerprint);
+    }
+
+    #[test]
+    fn test_apply_transforms_remap_and_filter() {
+        let mut frames = vec![can_frame_at(1, 10), can_frame_at(2, 20), can_frame_at(1, 30)];
+        frames[1].bus = 1;
+        let ops = vec![
+            TransformOp::RemapBus { from: 0, to: 3 },
+            TransformOp::FilterIds(trigger(1)),
+        ];
+        let result = apply_transforms(frames, &ops);
+        assert_eq!(timestamps(&result), vec![10, 30]);
+        assert!(result.iter().all(|f| f.bus == 3));
+    }
+
+    #[test]
+    fn test_apply_transforms_timing() {
+        let frames = vec![can_frame_at(1, 1_000), can_frame_at(1, 1_010), can_frame_at(1, 5_000), can_frame_at(1, 5_020)];
+        let ops = vec![TransformOp::RebaseTime, TransformOp::ClampGaps(100)];
+        let result = apply_transforms(frames, &ops);
+        assert_eq!(timestamps(&result), vec![0, 10, 110, 130]);
+
+        let result = apply_transforms(result, &[TransformOp::Offset(-20)]);
+        assert_eq!(timestamps(&result), vec![0, 0, 90, 110]);
+    }
+
+    #[test]
+    fn test_transform_frames_reports_first_frame_shift() {
+        let frames = vec![can_frame_at(1, 1_000), can_frame_at(2, 1_010), can_frame_at(1, 5_000)];
+        let ops = vec![TransformOp::FilterIds(trigger(2)), TransformOp::RebaseTime, TransformOp::Offset(50)];
+        let (result, shift_us) = transform_frames(frames, &ops);
+        assert_eq!(timestamps(&result), vec![50]);
+        // Capture time of the kept frame is its new timestamp minus the shift
+        assert_eq!(result[0].timestamp_us as i64 - shift_us, 1_010);
+
+        let (_, shift_us) = transform_frames(vec![can_frame_at(1, 100), can_frame_at(1, 900)], &[TransformOp::ClampGaps(10)]);
+        assert_eq!(shift_us, 0);
+    }
+
+    #[test]
+    fn test_transform_op_deserialize() {
+        let ops: Vec<TransformOp> = serde_json::from_str(
+            r#"[{"remap_bus":{"from":0,"to":1}},{"filter_ids":{"frame_id":256}},"rebase_time",{"clamp_gaps":500},{"offset":-10}]"#,
+        )
+        .unwrap();
+        assert_eq!(ops.len(), 5);
+        assert!(matches!(ops[3], TransformOp::ClampGaps(500)));
+    }
+
+    #[test]
+    fn test_merge_frames_alignment() {
+        let a = vec![can_frame_at(1, 1_000), can_frame_at(1, 1_030)];
+        let b = vec![can_frame_at(2, 5_000), can_frame_at(2, 5_020)];
+
+        let merged = merge_frames(vec![a.clone(), b.clone()], &AlignMode::Absolute, None).unwrap();
+        assert_eq!(timestamps(&merged), vec![1_000, 1_030, 5_000, 5_020]);
+
+        let merged = merge_frames(vec![a.clone(), b.clone()], &AlignMode::ZeroBased, None).unwrap();
+        assert_eq!(timestamps(&merged), vec![0, 20, 30, 30]);
+        // Equal timestamps keep source order
+        assert_eq!(merged.iter().map(|f| f.frame_id).collect::<Vec<_>>(), vec![1, 2, 1, 2]);
+
+        let merged = merge_frames(vec![a.clone(), b.clone()], &AlignMode::Offset(vec![0, -4_000]), None).unwrap();
+        assert_eq!(timestamps(&merged), vec![1_000, 1_000, 1_020, 1_030]);
+
+        assert!(merge_frames(vec![a, b], &AlignMode::Offset(vec![0]), None).is_err());
+    }
+
+    #[test]
+    fn test_merge_frames_bus_maps() {
+        let a = vec![can_frame_at(1, 10)];
+        let mut b = vec![can_frame_at(2, 20), can_frame_at(3, 30)];
+        b[1].bus = 1;
+        let maps = vec![HashMap::new(), HashMap::from([(0, 2), (1, 3)])];
+        let merged = merge_frames(vec![a, b], &AlignMode::Absolute, Some(&maps)).unwrap();
+        assert_eq!(merged.iter().map(|f| f.bus).collect::<Vec<_>>(), vec![0, 2, 3]);
+
+        let align: AlignMode = serde_json::from_str(r#"{"offset":[0,-10]}"#).unwrap();
+        assert!(matches!(align, AlignMode::Offset(ref o) if o == &[0, -10]));
+        let align: AlignMode = serde_json::from_str(r#""zero_based""#).unwrap();
+        assert!(matches!(align, AlignMode::ZeroBased));
+    }
+}
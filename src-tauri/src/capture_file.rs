@@ -0,0 +1,268 @@
+// ui/src-tauri/src/capture_file.rs
+//
+// Capture tee: while a session streams, every frame batch it emits is also
+// appended to a file on disk, so a long unattended capture survives a crash
+// instead of living only in the buffer until someone exports it.
+//
+// Batches arrive from `emit_frames` (and the merge task's backpressure path)
+// before the session emit filter, so the file holds the full capture like the
+// buffer does. Writes go through a buffered writer that is flushed at most
+// once per FLUSH_INTERVAL, by a timer while the bus is quiet, and when the
+// capture stops. A failed write emits
+// `capture-file-error` once and the capture keeps running; later batches are
+// still attempted, so a transient error (e.g. a full disk being cleared)
+// recovers on its own.
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::AppHandle;
+
+use crate::buffer_snapshot::SnapshotCapture;
+use crate::io::{emit_to_session, FrameMessage};
+
+/// Longest time written frames may sit in the write buffer
+const FLUSH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// On-disk format of a capture file
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CaptureFormat {
+    /// One FrameMessage JSON object per line
+    #[default]
+    Ndjson,
+    /// Native buffer snapshot, loadable with `load_buffer`
+    Native,
+}
+
+enum CaptureSink {
+    Ndjson(BufWriter<File>),
+    Native(SnapshotCapture),
+}
+
+impl CaptureSink {
+    fn open(path: &str, format: CaptureFormat, session_id: &str) -> Result<Self, String> {
+        match format {
+            CaptureFormat::Ndjson => {
+                let file = File::create(path).map_err(|e| format!("Failed to create '{}': {}", path, e))?;
+                Ok(Self::Ndjson(BufWriter::new(file)))
+            }
+            CaptureFormat::Native => Ok(Self::Native(SnapshotCapture::create(path, session_id)?)),
+        }
+    }
+
+    fn write(&mut self, frames: &[FrameMessage]) -> Result<(), String> {
+        match self {
+            Self::Ndjson(w) => {
+                for frame in frames {
+                    serde_json::to_writer(&mut *w, frame)
+                        .map_err(|e| format!("Failed to write capture: {}", e))?;
+                    w.write_all(b"\n")
+                        .map_err(|e| format!("Failed to write capture: {}", e))?;
+                }
+                Ok(())
+            }
+            Self::Native(w) => w.append(frames),
+        }
+    }
+
+    fn flush(&mut self) -> Result<(), String> {
+        match self {
+            Self::Ndjson(w) => w.flush().map_err(|e| format!("Failed to write capture: {}", e)),
+            Self::Native(w) => w.flush(),
+        }
+    }
+}
+
+/// An open capture file for one session
+struct CaptureFile {
+    /// Distinguishes a replaced capture from its successor for the flush timer
+    id: u64,
+    path: String,
+    sink: CaptureSink,
+    last_flush: Instant,
+    /// Frames written since the last flush
+    dirty: bool,
+    /// Set after a failed write so the error is only reported once per streak
+    failing: bool,
+}
+
+impl CaptureFile {
+    fn write(&mut self, frames: &[FrameMessage]) -> Result<(), String> {
+        self.sink.write(frames)?;
+        self.dirty = true;
+        self.flush_if_due(Instant::now())
+    }
+
+    /// Flush unflushed frames once FLUSH_INTERVAL has passed since the last flush
+    fn flush_if_due(&mut self, now: Instant) -> Result<(), String> {
+        if self.dirty && now.duration_since(self.last_flush) >= FLUSH_INTERVAL {
+            self.last_flush = now;
+            self.dirty = false;
+            self.sink.flush()?;
+        }
+        Ok(())
+    }
+}
+
+/// Payload for the capture-file-error event
+#[derive(Clone, Serialize)]
+struct CaptureFileErrorPayload {
+    path: String,
+    error: String,
+}
+
+static CAPTURE_FILES: Lazy<Mutex<HashMap<String, CaptureFile>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+static NEXT_CAPTURE_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Flush a capture on a timer, so frames written just before the bus goes
+/// quiet reach the disk without waiting for another batch. Ends once the
+/// capture is stopped or replaced.
+async fn flush_periodically(session_id: String, id: u64) {
+    let mut ticker = tokio::time::interval(FLUSH_INTERVAL);
+    loop {
+        ticker.tick().await;
+        let Ok(mut captures) = CAPTURE_FILES.lock() else {
+            return;
+        };
+        let Some(capture) = captures.get_mut(&session_id).filter(|c| c.id == id) else {
+            return;
+        };
+        // The next batch written reports a persistent error with an event
+        if let Err(e) = capture.flush_if_due(Instant::now()) {
+            tlog!("[capture_file] Session '{}' capture to '{}' flush failed: {}", session_id, capture.path, e);
+        }
+    }
+}
+
+/// Start teeing a session's frames to `path`, replacing any capture it already has.
+pub fn start_capture(session_id: &str, path: &str, format: CaptureFormat) -> Result<(), String> {
+    let sink = CaptureSink::open(path, format, session_id)?;
+    let id = NEXT_CAPTURE_ID.fetch_add(1, Ordering::Relaxed);
+    let capture = CaptureFile {
+        id,
+        path: path.to_string(),
+        sink,
+        last_flush: Instant::now(),
+        dirty: false,
+        failing: false,
+    };
+    let previous = CAPTURE_FILES
+        .lock()
+        .map_err(|_| "Capture file registry poisoned".to_string())?
+        .insert(session_id.to_string(), capture);
+    if let Some(mut previous) = previous {
+        let _ = previous.sink.flush();
+    }
+    tauri::async_runtime::spawn(flush_periodically(session_id.to_string(), id));
+    tlog!("[capture_file] Session '{}' capturing to '{}' ({:?})", session_id, path, format);
+    Ok(())
+}
+
+/// Flush and close a session's capture file, if it has one.
+pub fn stop_capture(session_id: &str) {
+    let Some(mut capture) = CAPTURE_FILES.lock().ok().and_then(|mut c| c.remove(session_id)) else {
+        return;
+    };
+    match capture.sink.flush() {
+        Ok(()) => tlog!("[capture_file] Session '{}' capture to '{}' closed", session_id, capture.path),
+        Err(e) => tlog!("[capture_file] Session '{}' capture to '{}' closed with error: {}", session_id, capture.path, e),
+    }
+}
+
+/// Append a batch to the session's capture file. Does nothing for sessions
+/// without one. Errors are reported with an event, never returned.
+pub fn write_frames(app: &AppHandle, session_id: &str, frames: &[FrameMessage]) {
+    if frames.is_empty() {
+        return;
+    }
+    let Ok(mut captures) = CAPTURE_FILES.lock() else {
+        return;
+    };
+    let Some(capture) = captures.get_mut(session_id) else {
+        return;
+    };
+
+    let error = match capture.write(frames) {
+        Ok(()) => {
+            if capture.failing {
+                capture.failing = false;
+                tlog!("[capture_file] Session '{}' capture to '{}' recovered", session_id, capture.path);
+            }
+            return;
+        }
+        Err(_) if capture.failing => return,
+        Err(e) => {
+            capture.failing = true;
+            CaptureFileErrorPayload { path: capture.path.clone(), error: e }
+        }
+    };
+    drop(captures);
+
+    tlog!("[capture_file] Session '{}' capture to '{}' failed: {}", session_id, error.path, error.error);
+    emit_to_session(app, "capture-file-error", session_id, error);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::test_support::can_frame_at;
+
+    #[test]
+    fn test_ndjson_capture_writes_one_frame_per_line() {
+        let path = std::env::temp_dir().join(format!("candor_capture_{}.ndjson", std::process::id()));
+        let path = path.to_str().unwrap();
+        let mut capture = CaptureFile {
+            id: 0,
+            path: path.to_string(),
+            sink: CaptureSink::open(path, CaptureFormat::Ndjson, "s").unwrap(),
+            last_flush: Instant::now(),
+            dirty: false,
+            failing: false,
+        };
+        capture.write(&[can_frame_at(0x100, 1), can_frame_at(0x200, 1)]).unwrap();
+        capture.write(&[can_frame_at(0x300, 1)]).unwrap();
+        capture.sink.flush().unwrap();
+
+        let text = std::fs::read_to_string(path).unwrap();
+        let _ = std::fs::remove_file(path);
+        let ids: Vec<u32> = text
+            .lines()
+            .map(|l| serde_json::from_str::<FrameMessage>(l).unwrap().frame_id)
+            .collect();
+        assert_eq!(ids, vec![0x100, 0x200, 0x300]);
+    }
+
+    #[test]
+    fn test_quiet_capture_flushes_when_due() {
+        let path = std::env::temp_dir().join(format!("candor_capture_flush_{}.ndjson", std::process::id()));
+        let path = path.to_str().unwrap();
+        let start = Instant::now();
+        let mut capture = CaptureFile {
+            id: 0,
+            path: path.to_string(),
+            sink: CaptureSink::open(path, CaptureFormat::Ndjson, "s").unwrap(),
+            last_flush: start,
+            dirty: false,
+            failing: false,
+        };
+        capture.write(&[can_frame_at(0x100, 1)]).unwrap();
+        assert!(std::fs::read_to_string(path).unwrap().is_empty());
+
+        // No new frames arrive; the timer's flush writes out the held batch
+        capture.flush_if_due(start + FLUSH_INTERVAL / 2).unwrap();
+        assert!(std::fs::read_to_string(path).unwrap().is_empty());
+        capture.flush_if_due(start + FLUSH_INTERVAL).unwrap();
+        let text = std::fs::read_to_string(path).unwrap();
+        let _ = std::fs::remove_file(path);
+        assert_eq!(text.lines().count(), 1);
+        assert!(!capture.dirty);
+    }
+}
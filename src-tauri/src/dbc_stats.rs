@@ -0,0 +1,366 @@
+// ui/src-tauri/src/dbc_stats.rs
+//
+// Per-signal statistics over a frame buffer, decoded with a DBC file.
+// Gives a capture summary at a glance (e.g. "RPM ranged 800-6400").
+// Also labels each observed frame ID with the node that sends it, for a
+// "by ECU" grouping of the buffer.
+
+use can_dbc::{ByteOrder, MultiplexIndicator, ValueType};
+use serde::Serialize;
+use std::collections::HashMap;
+
+use crate::buffer_store::{self, BufferFrameInfo};
+use crate::io::{FrameMessage, CAN_EXTENDED_ID_MASK};
+
+/// Range summary for one DBC signal across a buffer
+#[derive(Clone, Debug, Serialize)]
+pub struct SignalStats {
+    /// Message name from the DBC
+    pub message: String,
+    pub signal: String,
+    pub min: f64,
+    pub max: f64,
+    /// Value in the last frame that carried the signal
+    pub last: f64,
+    pub unit: String,
+    /// Number of frames the signal was decoded from
+    pub sample_count: u64,
+}
+
+/// Transmitting node for one observed frame ID
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct NodeLabel {
+    pub frame_id: u32,
+    pub is_extended: bool,
+    pub node: String,
+}
+
+// ============================================================================
+// Signal decoding
+// ============================================================================
+
+/// Extract a signal's raw (unscaled) bits from frame data.
+/// `start_bit` is in DBC numbering: the LSB for Intel signals, the MSB for Motorola.
+/// Returns None if the signal doesn't fit in the data.
+pub(crate) fn extract_raw(data: &[u8], start_bit: u64, size: u64, byte_order: &ByteOrder) -> Option<u64> {
+    if size == 0 || size > 64 {
+        return None;
+    }
+    let bit = |pos: u64| -> Option<u64> {
+        let byte = *data.get((pos / 8) as usize)?;
+        Some(((byte >> (pos % 8)) & 1) as u64)
+    };
+
+    let mut raw = 0u64;
+    match byte_order {
+        ByteOrder::LittleEndian => {
+            for i in 0..size {
+                raw |= bit(start_bit + i)? << i;
+            }
+        }
+        ByteOrder::BigEndian => {
+            // Walk from the MSB: down within a byte, then to bit 7 of the next byte
+            let mut pos = start_bit;
+            for _ in 0..size {
+                raw = (raw << 1) | bit(pos)?;
+                pos = if pos % 8 == 0 { pos + 15 } else { pos - 1 };
+            }
+        }
+    }
+    Some(raw)
+}
+
+/// Scale raw signal bits to a physical value, sign-extending `size`-bit signed values
+pub(crate) fn raw_to_physical(raw: u64, size: u64, signed: bool, factor: f64, offset: f64) -> f64 {
+    let value = if signed && size < 64 {
+        let shift = 64 - size;
+        ((raw << shift) as i64 >> shift) as f64
+    } else if signed {
+        raw as i64 as f64
+    } else {
+        raw as f64
+    };
+    value * factor + offset
+}
+
+/// Decode a signal to its physical value, or None if it doesn't fit in the data
+fn decode_signal(signal: &can_dbc::Signal, data: &[u8]) -> Option<f64> {
+    let raw = extract_raw(data, signal.start_bit, signal.size, &signal.byte_order)?;
+    let signed = matches!(signal.value_type, ValueType::Signed);
+    Some(raw_to_physical(raw, signal.size, signed, signal.factor, signal.offset))
+}
+
+/// A DBC message ID as (frame ID, is_extended), matching how frames carry it
+fn dbc_message_key(id: &can_dbc::MessageId) -> (u32, bool) {
+    (
+        id.raw() & CAN_EXTENDED_ID_MASK,
+        matches!(id, can_dbc::MessageId::Extended(_)),
+    )
+}
+
+/// Compute min/max/last for every DBC signal over `frames`.
+///
+/// Frames are matched to messages by ID and ID format, so a standard and an
+/// extended ID with the same value never share a message. Multiplexed signals only count when
+/// the message's multiplexor selects them. Signals never seen are omitted.
+/// Results are ordered by message ID, then by signal order in the DBC.
+pub fn compute_signal_statistics(dbc: &can_dbc::Dbc, frames: &[FrameMessage]) -> Vec<SignalStats> {
+    let messages: HashMap<(u32, bool), &can_dbc::Message> = dbc
+        .messages
+        .iter()
+        .map(|m| (dbc_message_key(&m.id), m))
+        .collect();
+
+    // (message id, extended, signal index) -> stats
+    let mut stats: HashMap<(u32, bool, usize), SignalStats> = HashMap::new();
+
+    for frame in frames {
+        let Some(message) = messages.get(&(frame.frame_id, frame.is_extended)) else {
+            continue;
+        };
+
+        let mux_value = message
+            .signals
+            .iter()
+            .find(|s| matches!(s.multiplexer_indicator, MultiplexIndicator::Multiplexor))
+            .and_then(|s| extract_raw(&frame.bytes, s.start_bit, s.size, &s.byte_order));
+
+        for (idx, signal) in message.signals.iter().enumerate() {
+            let selected = match signal.multiplexer_indicator {
+                MultiplexIndicator::MultiplexedSignal(v)
+                | MultiplexIndicator::MultiplexorAndMultiplexedSignal(v) => mux_value == Some(v),
+                _ => true,
+            };
+            if !selected {
+                continue;
+            }
+            let Some(value) = decode_signal(signal, &frame.bytes) else {
+                continue;
+            };
+
+            stats
+                .entry((frame.frame_id, frame.is_extended, idx))
+                .and_modify(|s| {
+                    s.min = s.min.min(value);
+                    s.max = s.max.max(value);
+                    s.last = value;
+                    s.sample_count += 1;
+                })
+                .or_insert_with(|| SignalStats {
+                    message: message.name.clone(),
+                    signal: signal.name.clone(),
+                    min: value,
+                    max: value,
+                    last: value,
+                    unit: signal.unit.clone(),
+                    sample_count: 1,
+                });
+        }
+    }
+
+    let mut out: Vec<_> = stats.into_iter().collect();
+    out.sort_by_key(|(key, _)| *key);
+    out.into_iter().map(|(_, s)| s).collect()
+}
+
+// ============================================================================
+// Node mapping
+// ============================================================================
+
+/// Label each observed frame ID with the node that transmits it.
+///
+/// A DBC message with a named transmitter (`BO_ ... ECU1`) wins. Otherwise, when
+/// `j1939` is set, extended IDs are labelled by their J1939 source address,
+/// using the DBC node that sends from that address if one is known, else
+/// "SA 0xNN". DBC messages match by ID and ID format. IDs with no known origin
+/// are left out. Results are ordered by frame ID, standard before extended.
+pub fn compute_node_map(
+    dbc: Option<&can_dbc::Dbc>,
+    ids: &[BufferFrameInfo],
+    j1939: bool,
+) -> Vec<NodeLabel> {
+    let mut transmitters: HashMap<(u32, bool), &str> = HashMap::new();
+    let mut sa_nodes: HashMap<u8, &str> = HashMap::new();
+    for message in dbc.map(|d| d.messages.as_slice()).unwrap_or_default() {
+        let can_dbc::Transmitter::NodeName(ref name) = message.transmitter else {
+            continue;
+        };
+        let (id, is_extended) = dbc_message_key(&message.id);
+        transmitters.insert((id, is_extended), name);
+        if is_extended {
+            sa_nodes.entry((id & 0xFF) as u8).or_insert(name);
+        }
+    }
+
+    // The same ID can appear once per bus; label it once
+    let mut map: std::collections::BTreeMap<(u32, bool), String> = std::collections::BTreeMap::new();
+    for info in ids {
+        let key = (info.frame_id, info.is_extended);
+        if let Some(name) = transmitters.get(&key) {
+            map.insert(key, name.to_string());
+        } else if j1939 && info.is_extended {
+            let sa = (info.frame_id & 0xFF) as u8;
+            let label = sa_nodes
+                .get(&sa)
+                .map(|name| name.to_string())
+                .unwrap_or_else(|| format!("SA 0x{:02X}", sa));
+            map.insert(key, label);
+        }
+    }
+    map.into_iter()
+        .map(|((frame_id, is_extended), node)| NodeLabel { frame_id, is_extended, node })
+        .collect()
+}
+
+// ============================================================================
+// Tauri Commands
+// ============================================================================
+
+/// Compute per-signal min/max/last over a frame buffer using DBC file content
+#[tauri::command(rename_all = "snake_case")]
+pub async fn signal_statistics(buffer_id: String, dbc_content: String) -> Result<Vec<SignalStats>, String> {
+    let dbc = can_dbc::Dbc::try_from(dbc_content.as_str())
+        .map_err(|e| format!("Failed to parse DBC file: {:?}", e))?;
+    let frames = buffer_store::get_buffer_frames(&buffer_id)
+        .ok_or_else(|| format!("Buffer '{}' not found or is not a frame buffer", buffer_id))?;
+    Ok(compute_signal_statistics(&dbc, &frames))
+}
+
+/// Map each frame ID in a buffer to its transmitting node, from DBC
+/// transmitters and/or J1939 source addresses
+#[tauri::command(rename_all = "snake_case")]
+pub async fn decode_node_map(
+    buffer_id: String,
+    dbc_content: Option<String>,
+    j1939: Option<bool>,
+) -> Result<Vec<NodeLabel>, String> {
+    let dbc = dbc_content
+        .map(|content| {
+            can_dbc::Dbc::try_from(content.as_str())
+                .map_err(|e| format!("Failed to parse DBC file: {:?}", e))
+        })
+        .transpose()?;
+    if buffer_store::get_buffer_type(&buffer_id) != Some(buffer_store::BufferType::Frames) {
+        return Err(format!("Buffer '{}' not found or is not a frame buffer", buffer_id));
+    }
+    let ids = buffer_store::get_buffer_frame_info(&buffer_id);
+    Ok(compute_node_map(dbc.as_ref(), &ids, j1939.unwrap_or(false)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::test_support::can_frame;
+
+    const DBC: &str = r#"VERSION ""
+NS_ :
+
+BS_:
+BU_: ECU1
+
+BO_ 256 EngineData: 8 ECU1
+ SG_ RPM : 0|16@1+ (1,0) [0|65535] "rpm" Vector__XXX
+ SG_ Temp : 23|8@0- (0.5,-10) [-74|53.5] "degC" Vector__XXX
+
+BO_ 512 Status: 2 ECU1
+ SG_ Page M : 0|8@1+ (1,0) [0|255] "" Vector__XXX
+ SG_ Volts m1 : 8|8@1+ (0.1,0) [0|25.5] "V" Vector__XXX
+
+"#;
+
+    #[test]
+    fn test_extract_raw_byte_orders() {
+        let data = [0x34, 0x12, 0xAB, 0xCD];
+        assert_eq!(extract_raw(&data, 0, 16, &ByteOrder::LittleEndian), Some(0x1234));
+        // Motorola: MSB at bit 23 (byte 2 bit 7), spanning bytes 2-3
+        assert_eq!(extract_raw(&data, 23, 16, &ByteOrder::BigEndian), Some(0xABCD));
+        assert_eq!(extract_raw(&data, 24, 16, &ByteOrder::LittleEndian), None);
+    }
+
+    #[test]
+    fn test_signal_statistics() {
+        let dbc = can_dbc::Dbc::try_from(DBC).unwrap();
+        let frames = vec![
+            can_frame(0x100, 0, vec![0x20, 0x03, 0xFE, 0, 0, 0, 0, 0]), // RPM 800, Temp -2 * 0.5 - 10
+            can_frame(0x100, 0, vec![0x00, 0x19, 0x28, 0, 0, 0, 0, 0]), // RPM 6400, Temp 40 * 0.5 - 10
+            can_frame(0x100, 0, vec![0xB8, 0x0B, 0x14, 0, 0, 0, 0, 0]), // RPM 3000, Temp 20 * 0.5 - 10
+            can_frame(0x200, 0, vec![0x00, 0x64]),                      // Page 0: Volts not selected
+            can_frame(0x200, 0, vec![0x01, 0x7B]),                      // Page 1: Volts 12.3
+            can_frame(0x300, 0, vec![0xFF]),                            // Not in DBC
+            // Extended ID: not EngineData
+            FrameMessage { is_extended: true, ..can_frame(0x100, 0, vec![0xFF, 0xFF, 0xFF, 0, 0, 0, 0, 0]) },
+        ];
+
+        let stats = compute_signal_statistics(&dbc, &frames);
+        let names: Vec<_> = stats.iter().map(|s| s.signal.as_str()).collect();
+        assert_eq!(names, ["RPM", "Temp", "Page", "Volts"]);
+
+        let rpm = &stats[0];
+        assert_eq!(rpm.message, "EngineData");
+        assert_eq!((rpm.min, rpm.max, rpm.last), (800.0, 6400.0, 3000.0));
+        assert_eq!(rpm.unit, "rpm");
+        assert_eq!(rpm.sample_count, 3);
+
+        let temp = &stats[1];
+        assert_eq!((temp.min, temp.max, temp.last), (-11.0, 10.0, 0.0));
+
+        let volts = &stats[3];
+        assert_eq!(volts.sample_count, 1);
+        assert!((volts.last - 12.3).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_node_map() {
+        let dbc = can_dbc::Dbc::try_from(
+            r#"VERSION ""
+NS_ :
+
+BS_:
+BU_: ECU1 Brakes
+
+BO_ 256 EngineData: 8 ECU1
+ SG_ RPM : 0|16@1+ (1,0) [0|65535] "rpm" Vector__XXX
+
+BO_ 2364540721 EBC1: 8 Brakes
+ SG_ Speed : 0|8@1+ (1,0) [0|255] "" Vector__XXX
+
+"#,
+        )
+        .unwrap();
+        let info = |frame_id: u32, is_extended: bool| BufferFrameInfo {
+            frame_id,
+            max_dlc: 8,
+            bus: 0,
+            is_extended,
+            has_dlc_mismatch: false,
+        };
+        let ids = [
+            info(0x100, false),       // DBC transmitter
+            info(0x100, true),        // Extended ID with the same value: not EngineData
+            info(0x300, false),       // Unknown standard ID
+            info(0x0CF0_0431, true),  // Same SA as the Brakes message
+            info(0x18FE_F100, true),  // Unknown SA
+        ];
+        let label = |frame_id: u32, is_extended: bool, node: &str| NodeLabel {
+            frame_id,
+            is_extended,
+            node: node.to_string(),
+        };
+
+        let map = compute_node_map(Some(&dbc), &ids, true);
+        assert_eq!(
+            map,
+            vec![
+                label(0x100, false, "ECU1"),
+                label(0x100, true, "SA 0x00"),
+                label(0x0CF0_0431, true, "Brakes"),
+                label(0x18FE_F100, true, "SA 0x00"),
+            ]
+        );
+
+        // Without J1939 only DBC transmitters are used
+        let map = compute_node_map(Some(&dbc), &ids, false);
+        assert_eq!(map, vec![label(0x100, false, "ECU1")]);
+        assert!(compute_node_map(None, &ids, false).is_empty());
+    }
+}
@@ -0,0 +1,302 @@
+// ui/src-tauri/src/frame_export.rs
+//
+// Text log export of frame buffers, written straight to disk by the backend.
+//
+// CSV and candump match the frontend's export (`frameDump.ts`) so files from
+// either path read the same. ASC follows the Vector layout read by CANalyzer
+// and python-can. `export_buffer_split_by_bus` writes one file per bus so a
+// multi-bus capture doesn't need filtering and exporting once per bus.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write as _;
+use std::path::Path;
+
+use crate::buffer_store;
+use crate::io::{FrameFilter, FrameMessage};
+
+/// Text format of an exported log
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogFormat {
+    /// `Time Stamp,ID,Extended,Dir,Bus,LEN,D1..Dn[,RTR]`
+    Csv,
+    /// `(seconds) canN ID#DATA`
+    Candump,
+    /// Vector ASCII log
+    Asc,
+}
+
+impl LogFormat {
+    fn extension(self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::Candump => "log",
+            Self::Asc => "asc",
+        }
+    }
+}
+
+/// Valid CAN FD payload lengths above 8 bytes
+const FD_LENGTHS: [usize; 7] = [12, 16, 20, 24, 32, 48, 64];
+
+/// Smallest valid CAN FD length that holds `len` bytes (`len` itself up to 8)
+fn fitting_length(len: usize) -> usize {
+    if len <= 8 {
+        return len;
+    }
+    FD_LENGTHS.iter().copied().find(|&l| l >= len).unwrap_or(64)
+}
+
+/// DLC code for a CAN FD payload length
+fn fd_dlc_code(len: usize) -> usize {
+    if len <= 8 {
+        return len;
+    }
+    9 + FD_LENGTHS.iter().position(|&l| l >= len).unwrap_or(FD_LENGTHS.len() - 1)
+}
+
+/// Data bytes covered by the frame's DLC
+fn payload(frame: &FrameMessage) -> &[u8] {
+    &frame.bytes[..frame.bytes.len().min(frame.dlc as usize)]
+}
+
+fn hex_bytes(bytes: &[u8], separator: &str) -> String {
+    bytes.iter().map(|b| format!("{:02X}", b)).collect::<Vec<_>>().join(separator)
+}
+
+fn to_csv(frames: &[FrameMessage]) -> String {
+    let max_bytes = frames.iter().map(|f| (f.dlc as usize).max(f.bytes.len())).max().unwrap_or(0);
+    let columns = fitting_length(max_bytes);
+    let has_rtr = frames.iter().any(|f| f.is_rtr);
+
+    let mut out = String::from("Time Stamp,ID,Extended,Dir,Bus,LEN");
+    for i in 1..=columns {
+        let _ = write!(out, ",D{}", i);
+    }
+    if has_rtr {
+        out.push_str(",RTR");
+    }
+    out.push('\n');
+
+    for frame in frames {
+        let dir = if frame.direction.as_deref() == Some("tx") { "Tx" } else { "Rx" };
+        let _ = write!(
+            out,
+            "{},{:08X},{},{},{},{}",
+            frame.timestamp_us, frame.frame_id, frame.is_extended, dir, frame.bus, frame.dlc
+        );
+        let data = if frame.is_rtr { &[][..] } else { payload(frame) };
+        for i in 0..columns {
+            out.push(',');
+            if let Some(b) = data.get(i) {
+                let _ = write!(out, "{:02X}", b);
+            }
+        }
+        if has_rtr {
+            let _ = write!(out, ",{}", frame.is_rtr);
+        }
+        out.push('\n');
+    }
+    out
+}
+
+fn to_candump(frames: &[FrameMessage]) -> String {
+    let lines: Vec<String> = frames
+        .iter()
+        .map(|frame| {
+            let id = if frame.is_extended {
+                format!("{:08X}", frame.frame_id)
+            } else {
+                format!("{:03X}", frame.frame_id)
+            };
+            // Classic frames with a DLC code above 8 keep it as candump's _X suffix
+            let dlc_suffix = match frame.dlc_raw {
+                Some(raw) if !frame.is_fd && raw > 8 => format!("_{:X}", raw),
+                _ => String::new(),
+            };
+            format!(
+                "({}.{:06}) can{} {}#{}{}",
+                frame.timestamp_us / 1_000_000,
+                frame.timestamp_us % 1_000_000,
+                frame.bus,
+                id,
+                hex_bytes(payload(frame), ""),
+                dlc_suffix
+            )
+        })
+        .collect();
+    lines.join("\n")
+}
+
+fn to_asc(frames: &[FrameMessage]) -> String {
+    let start_us = frames.first().map_or(0, |f| f.timestamp_us);
+    let start = chrono::DateTime::from_timestamp_micros(start_us as i64)
+        .unwrap_or_default()
+        .with_timezone(&chrono::Local)
+        .format("%a %b %d %I:%M:%S%.3f %P %Y")
+        .to_string();
+
+    let mut out = format!(
+        "date {}\nbase hex  timestamps absolute\nno internal events logged\nBegin Triggerblock {}\n",
+        start, start
+    );
+    for frame in frames {
+        let time = frame.timestamp_us.saturating_sub(start_us) as f64 / 1_000_000.0;
+        // ASC channels count from 1
+        let channel = frame.bus as u32 + 1;
+        let id = format!("{:X}{}", frame.frame_id, if frame.is_extended { "x" } else { "" });
+        let dir = if frame.direction.as_deref() == Some("tx") { "Tx" } else { "Rx" };
+        let data = payload(frame);
+        if frame.is_fd {
+            // EDL, plus BRS and ESI when set
+            let flags = 0x1000 | (u32::from(frame.is_brs) << 13) | (u32::from(frame.is_esi) << 14);
+            let _ = writeln!(
+                out,
+                "{:>11.6} CANFD {:>3} {:<4} {:>8} {:>32} {} {} {:x} {:>2} {} {:>8} {:>4} {:>8X} {:>8} {:>8} {:>8} {:>8} {:>8}",
+                time, channel, dir, id, "", frame.is_brs as u8, frame.is_esi as u8,
+                fd_dlc_code(data.len()), data.len(), hex_bytes(data, " "), 0, 0, flags, 0, 0, 0, 0, 0
+            );
+        } else if frame.is_rtr {
+            let _ = writeln!(out, "{:>11.6} {}  {:<15} {:<4} r {:x}", time, channel, id, dir, frame.dlc);
+        } else {
+            let _ = writeln!(
+                out,
+                "{:>11.6} {}  {:<15} {:<4} d {:x} {}",
+                time, channel, id, dir, data.len(), hex_bytes(data, " ")
+            );
+        }
+    }
+    out.push_str("End TriggerBlock\n");
+    out
+}
+
+/// Render frames as a text log in `format`
+pub fn format_frames(frames: &[FrameMessage], format: LogFormat) -> String {
+    match format {
+        LogFormat::Csv => to_csv(frames),
+        LogFormat::Candump => to_candump(frames),
+        LogFormat::Asc => to_asc(frames),
+    }
+}
+
+/// Group frames by bus, keeping each bus's frames in order. Buses in
+/// `buses` get an entry even when they have no frames.
+fn split_by_bus(frames: &[FrameMessage], buses: &[u8]) -> BTreeMap<u8, Vec<FrameMessage>> {
+    let mut by_bus: BTreeMap<u8, Vec<FrameMessage>> = buses.iter().map(|&b| (b, Vec::new())).collect();
+    let seen: BTreeSet<u8> = frames.iter().map(|f| f.bus).collect();
+    for bus in seen {
+        let filter = FrameFilter { bus: Some(bus), ..Default::default() };
+        by_bus.insert(bus, frames.iter().filter(|f| filter.matches(f)).cloned().collect());
+    }
+    by_bus
+}
+
+/// One file written by `export_buffer_split_by_bus`
+#[derive(Clone, Debug, Serialize)]
+pub struct BusExportFile {
+    pub bus: u8,
+    pub path: String,
+    pub frame_count: usize,
+}
+
+/// Export a frame buffer as one log file per bus in `dir`, named
+/// `<buffer_id>_bus<N>.<ext>`, each holding only that bus's frames.
+///
+/// `buses` limits the export to those buses (default: every bus in the
+/// buffer). A listed bus with no frames is skipped unless `include_empty` is
+/// set, in which case an empty log is written for it.
+#[tauri::command(rename_all = "snake_case")]
+pub async fn export_buffer_split_by_bus(
+    buffer_id: String,
+    dir: String,
+    format: LogFormat,
+    buses: Option<Vec<u8>>,
+    include_empty: Option<bool>,
+) -> Result<Vec<BusExportFile>, String> {
+    let frames = buffer_store::get_buffer_frames(&buffer_id)
+        .ok_or_else(|| format!("Buffer '{}' not found or is not a frame buffer", buffer_id))?;
+    let dir = Path::new(&dir);
+    if !dir.is_dir() {
+        return Err(format!("'{}' is not a directory", dir.display()));
+    }
+
+    let mut by_bus = split_by_bus(&frames, buses.as_deref().unwrap_or(&[]));
+    if let Some(buses) = &buses {
+        by_bus.retain(|bus, _| buses.contains(bus));
+    }
+
+    let mut written = Vec::new();
+    for (bus, bus_frames) in by_bus {
+        if bus_frames.is_empty() && !include_empty.unwrap_or(false) {
+            continue;
+        }
+        let path = dir.join(format!("{}_bus{}.{}", buffer_id, bus, format.extension()));
+        std::fs::write(&path, format_frames(&bus_frames, format))
+            .map_err(|e| format!("Failed to write '{}': {}", path.display(), e))?;
+        written.push(BusExportFile {
+            bus,
+            path: path.to_string_lossy().into_owned(),
+            frame_count: bus_frames.len(),
+        });
+    }
+    tlog!("[frame_export] Buffer '{}' exported to {} per-bus file(s)", buffer_id, written.len());
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::test_support::can_frame;
+
+    #[test]
+    fn test_split_by_bus_keeps_order_and_listed_empty_buses() {
+        let frames = vec![
+            can_frame(0x100, 1, vec![0x01]),
+            FrameMessage { bus: 2, ..can_frame(0x200, 2, vec![0x02]) },
+            can_frame(0x101, 3, vec![0x03]),
+        ];
+        let by_bus = split_by_bus(&frames, &[1]);
+        assert_eq!(by_bus.keys().copied().collect::<Vec<_>>(), vec![0, 1, 2]);
+        let ids: Vec<u32> = by_bus[&0].iter().map(|f| f.frame_id).collect();
+        assert_eq!(ids, vec![0x100, 0x101]);
+        assert!(by_bus[&1].is_empty());
+        assert_eq!(by_bus[&2].len(), 1);
+    }
+
+    #[test]
+    fn test_format_frames() {
+        let mut ext = FrameMessage { bus: 1, ..can_frame(0x18DAF110, 2_500_000, vec![0xDE, 0xAD]) };
+        ext.is_extended = true;
+        ext.direction = Some("tx".to_string());
+        let frames = vec![FrameMessage { bus: 1, ..can_frame(0x123, 2_000_000, vec![0x01, 0x02, 0x03]) }, ext];
+
+        assert_eq!(
+            format_frames(&frames, LogFormat::Csv),
+            "Time Stamp,ID,Extended,Dir,Bus,LEN,D1,D2,D3\n\
+             2000000,00000123,false,Rx,1,3,01,02,03\n\
+             2500000,18DAF110,true,Tx,1,2,DE,AD,\n"
+        );
+        assert_eq!(
+            format_frames(&frames, LogFormat::Candump),
+            "(2.000000) can1 123#010203\n(2.500000) can1 18DAF110#DEAD"
+        );
+
+        let asc = format_frames(&frames, LogFormat::Asc);
+        let lines: Vec<&str> = asc.lines().collect();
+        assert_eq!(lines[1], "base hex  timestamps absolute");
+        assert_eq!(lines[4], "   0.000000 2  123             Rx   d 3 01 02 03");
+        assert_eq!(lines[5], "   0.500000 2  18DAF110x       Tx   d 2 DE AD");
+        assert_eq!(lines[6], "End TriggerBlock");
+    }
+
+    #[test]
+    fn test_fd_lengths() {
+        assert_eq!(fitting_length(3), 3);
+        assert_eq!(fitting_length(9), 12);
+        assert_eq!(fitting_length(33), 48);
+        assert_eq!(fd_dlc_code(8), 8);
+        assert_eq!(fd_dlc_code(12), 9);
+        assert_eq!(fd_dlc_code(64), 15);
+    }
+}
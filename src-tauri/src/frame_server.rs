@@ -0,0 +1,200 @@
+// ui/src-tauri/src/frame_server.rs
+//
+// Optional local frame server for external tooling. While running, every frame
+// batch sent to the frontend is also written to connected TCP clients as one
+// JSON object per line (NDJSON), tagged with the session it came from.
+//
+// Batches are published from `emit_frames` after the session emit filter is
+// applied, so clients see exactly what the frontend sees. A client that falls
+// behind skips batches instead of stalling the emit path.
+
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use std::sync::{Arc, RwLock};
+use tokio::io::AsyncWriteExt;
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::{broadcast, watch};
+
+use crate::io::FrameMessage;
+
+/// Batches queued per client before it starts skipping
+const CLIENT_QUEUE_BATCHES: usize = 256;
+
+/// One NDJSON line as written to clients
+#[derive(Serialize)]
+struct FrameServerBatch<'a> {
+    session_id: &'a str,
+    frames: &'a [FrameMessage],
+    active_listeners: &'a [String],
+}
+
+/// Running server state
+struct FrameServer {
+    port: u16,
+    batches: broadcast::Sender<Arc<str>>,
+    shutdown: watch::Sender<bool>,
+}
+
+static FRAME_SERVER: Lazy<RwLock<Option<FrameServer>>> = Lazy::new(|| RwLock::new(None));
+
+/// Publish a frame batch to connected clients. Does nothing (not even
+/// serialisation) unless the server is running with at least one client.
+pub fn publish_frames(session_id: &str, frames: &[FrameMessage], active_listeners: &[String]) {
+    let Ok(server) = FRAME_SERVER.read() else {
+        return;
+    };
+    let Some(server) = server.as_ref() else {
+        return;
+    };
+    if server.batches.receiver_count() == 0 {
+        return;
+    }
+
+    let batch = FrameServerBatch {
+        session_id,
+        frames,
+        active_listeners,
+    };
+    match serde_json::to_string(&batch) {
+        Ok(mut line) => {
+            line.push('\n');
+            let _ = server.batches.send(Arc::from(line));
+        }
+        Err(e) => tlog!("[frame_server] Failed to serialise batch: {}", e),
+    }
+}
+
+/// Accept clients until shutdown is signalled.
+async fn accept_loop(
+    listener: TcpListener,
+    batches: broadcast::Sender<Arc<str>>,
+    mut shutdown: watch::Receiver<bool>,
+) {
+    loop {
+        tokio::select! {
+            _ = shutdown.changed() => break,
+            accepted = listener.accept() => match accepted {
+                Ok((stream, peer)) => {
+                    tlog!("[frame_server] Client connected from {}", peer);
+                    let _ = stream.set_nodelay(true);
+                    tauri::async_runtime::spawn(serve_client(
+                        stream,
+                        batches.subscribe(),
+                        shutdown.clone(),
+                    ));
+                }
+                Err(e) => tlog!("[frame_server] Accept error: {}", e),
+            },
+        }
+    }
+    tlog!("[frame_server] Stopped listening");
+}
+
+/// Forward batches to one client until it disconnects or the server stops.
+async fn serve_client(
+    mut stream: TcpStream,
+    mut batches: broadcast::Receiver<Arc<str>>,
+    mut shutdown: watch::Receiver<bool>,
+) {
+    loop {
+        tokio::select! {
+            _ = shutdown.changed() => break,
+            batch = batches.recv() => match batch {
+                Ok(line) => {
+                    if let Err(e) = stream.write_all(line.as_bytes()).await {
+                        tlog!("[frame_server] Client write failed, disconnecting: {}", e);
+                        break;
+                    }
+                }
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    tlog!("[frame_server] Client fell behind, skipped {} batch(es)", skipped);
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            },
+        }
+    }
+    let _ = stream.shutdown().await;
+}
+
+/// Stop the server if it is running, disconnecting all clients.
+fn stop_server() {
+    let server = FRAME_SERVER.write().ok().and_then(|mut s| s.take());
+    if let Some(server) = server {
+        let _ = server.shutdown.send(true);
+        tlog!("[frame_server] Stopping server on port {}", server.port);
+    }
+}
+
+// ============================================================================
+// Tauri Commands
+// ============================================================================
+
+/// Start the local frame server on `127.0.0.1:port` (0 picks a free port).
+///
+/// Clients receive one JSON line per frame batch for every session:
+/// `{"session_id": ..., "frames": [...], "active_listeners": [...]}`.
+/// A running server is stopped first. Returns the port actually bound.
+#[tauri::command]
+pub async fn start_frame_server(port: u16) -> Result<u16, String> {
+    stop_server();
+
+    let listener = TcpListener::bind(("127.0.0.1", port))
+        .await
+        .map_err(|e| format!("Failed to bind frame server on port {}: {}", port, e))?;
+    let port = listener
+        .local_addr()
+        .map_err(|e| format!("Failed to read frame server address: {}", e))?
+        .port();
+
+    let (batches, _) = broadcast::channel(CLIENT_QUEUE_BATCHES);
+    let (shutdown, shutdown_rx) = watch::channel(false);
+    tauri::async_runtime::spawn(accept_loop(listener, batches.clone(), shutdown_rx));
+
+    let mut server = FRAME_SERVER
+        .write()
+        .map_err(|e| format!("Frame server state poisoned: {}", e))?;
+    *server = Some(FrameServer {
+        port,
+        batches,
+        shutdown,
+    });
+    tlog!("[frame_server] Listening on 127.0.0.1:{}", port);
+    Ok(port)
+}
+
+/// Stop the local frame server and disconnect its clients.
+#[tauri::command]
+pub async fn stop_frame_server() -> Result<(), String> {
+    stop_server();
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::test_support::can_frame_at;
+
+    #[test]
+    fn test_publish_frames_writes_ndjson_line() {
+        let (batches, mut rx) = broadcast::channel(4);
+        let (shutdown, _) = watch::channel(false);
+        *FRAME_SERVER.write().unwrap() = Some(FrameServer {
+            port: 0,
+            batches,
+            shutdown,
+        });
+
+        publish_frames("s1", &[can_frame_at(0x123, 1)], &["listener".to_string()]);
+        stop_server();
+        // Nothing is published once the server is stopped
+        publish_frames("s1", &[can_frame_at(0x456, 1)], &[]);
+
+        let line = rx.try_recv().unwrap();
+        assert!(line.ends_with('\n'));
+        let value: serde_json::Value = serde_json::from_str(&line).unwrap();
+        assert_eq!(value["session_id"], "s1");
+        assert_eq!(value["frames"][0]["frame_id"], 0x123);
+        assert_eq!(value["active_listeners"][0], "listener");
+        assert!(rx.try_recv().is_err());
+    }
+}
@@ -62,7 +62,7 @@ mod desktop {
     use crate::{
         buffer_store,
         io::FrameMessage,
-        io::serial::{extract_frame_id, FrameIdConfig, FramingEncoding, SerialFramer},
+        io::serial::{checksum_stripped, extract_frame_id, FrameIdConfig, FramingEncoding, SerialFramer},
     };
 
     /// Per-interface framing configuration (overrides default for specific bus)
@@ -226,6 +226,10 @@ mod desktop {
 
             for (original_idx, byte) in bus_bytes.iter() {
                 let frames = framer.feed(&[byte.byte]);
+                if framer.take_discarded() > 0 {
+                    // An oversized frame was dropped; the next one starts later
+                    current_frame_start_idx = *original_idx + 1;
+                }
                 for frame in frames {
                     frame_data.push((frame.bytes, current_frame_start_idx, frame.incomplete, frame.crc_valid, *bus));
                     // Next frame starts after this byte
@@ -252,20 +256,21 @@ mod desktop {
         // Convert passed frames to FrameMessage format
         let frame_messages: Vec<FrameMessage> = passed_frames
             .into_iter()
-            .map(|(idx, (frame_bytes, start_idx, incomplete, _crc_valid, bus))| {
+            .map(|(idx, (frame_bytes, start_idx, incomplete, crc_valid, bus))| {
                 // Get timestamp from first byte of frame
                 let timestamp = bytes.get(*start_idx).map(|b| b.timestamp_us).unwrap_or(0);
 
-                // Extract frame ID if configured
+                // Extract frame ID if configured (offsets skip a validated trailing CRC)
+                let payload = checksum_stripped(frame_bytes, *crc_valid);
                 let frame_id = if let Some(ref id_config) = config.frame_id_config {
-                    extract_frame_id(frame_bytes, id_config).unwrap_or(idx as u32)
+                    extract_frame_id(payload, id_config).unwrap_or(idx as u32)
                 } else {
                     idx as u32
                 };
 
                 // Extract source address if configured
                 let source_address = if let Some(ref src_config) = config.source_address_config {
-                    extract_frame_id(frame_bytes, src_config).map(|v| v as u16)
+                    extract_frame_id(payload, src_config).map(|v| v as u16)
                 } else {
                     None
                 };
@@ -282,6 +287,12 @@ mod desktop {
                     source_address,
                     incomplete: if *incomplete { Some(true) } else { None },
                     direction: None,
+                    dlc_raw: None,
+                    is_rtr: false,
+                    is_brs: false,
+                    is_esi: false,
+                    original_frame_id: None,
+                    source_label: None,
                 }
             })
             .collect();
@@ -292,20 +303,21 @@ mod desktop {
         // Convert filtered frames to FrameMessage format (for display in Filtered tab)
         let filtered_messages: Vec<FrameMessage> = filtered_frames
             .into_iter()
-            .map(|(idx, (frame_bytes, start_idx, incomplete, _crc_valid, bus))| {
+            .map(|(idx, (frame_bytes, start_idx, incomplete, crc_valid, bus))| {
                 // Get timestamp from first byte of frame
                 let timestamp = bytes.get(*start_idx).map(|b| b.timestamp_us).unwrap_or(0);
 
-                // Extract frame ID if configured
+                // Extract frame ID if configured (offsets skip a validated trailing CRC)
+                let payload = checksum_stripped(frame_bytes, *crc_valid);
                 let frame_id = if let Some(ref id_config) = config.frame_id_config {
-                    extract_frame_id(frame_bytes, id_config).unwrap_or(idx as u32)
+                    extract_frame_id(payload, id_config).unwrap_or(idx as u32)
                 } else {
                     idx as u32
                 };
 
                 // Extract source address if configured
                 let source_address = if let Some(ref src_config) = config.source_address_config {
-                    extract_frame_id(frame_bytes, src_config).map(|v| v as u16)
+                    extract_frame_id(payload, src_config).map(|v| v as u16)
                 } else {
                     None
                 };
@@ -322,6 +334,12 @@ mod desktop {
                     source_address,
                     incomplete: if *incomplete { Some(true) } else { None },
                     direction: None,
+                    dlc_raw: None,
+                    is_rtr: false,
+                    is_brs: false,
+                    is_esi: false,
+                    original_frame_id: None,
+                    source_label: None,
                 }
             })
             .collect();
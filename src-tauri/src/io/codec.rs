@@ -86,6 +86,7 @@ mod tests {
             is_fd: false,
             is_brs: false,
             is_rtr: false,
+            rtr_dlc: None,
         }
     }
 
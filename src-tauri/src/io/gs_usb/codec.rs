@@ -89,13 +89,19 @@ impl FrameCodec for GsUsbCodec {
             timestamp_us: now_us(),
             frame_id,
             bus: channel,
-            dlc,
+            dlc: data_len as u8,
             bytes: data,
             is_extended,
             is_fd: false, // gs_usb classic doesn't support FD
             source_address: None,
             incomplete: None,
             direction: None,
+            dlc_raw: Some(dlc),
+            is_rtr: false,
+            is_brs: false,
+            is_esi: false,
+            original_frame_id: None,
+            source_label: None,
         })
     }
 
@@ -130,7 +136,7 @@ impl FrameCodec for GsUsbCodec {
         buf[4..8].copy_from_slice(&can_id.to_le_bytes());
 
         // can_dlc
-        buf[8] = frame.data.len() as u8;
+        buf[8] = frame.classic_dlc();
 
         // channel (use bus from frame)
         buf[9] = frame.bus;
@@ -141,8 +147,8 @@ impl FrameCodec for GsUsbCodec {
         // reserved
         buf[11] = 0;
 
-        // data (up to 8 bytes)
-        let len = frame.data.len().min(8);
+        // data (up to 8 bytes; none for a remote frame)
+        let len = if frame.is_rtr { 0 } else { frame.data.len().min(8) };
         buf[12..12 + len].copy_from_slice(&frame.data[..len]);
 
         Ok(buf)
@@ -177,6 +183,20 @@ mod tests {
         assert_eq!(frame.bytes, vec![0xAA, 0xBB, 0xCC, 0xDD]);
     }
 
+    #[test]
+    fn test_gs_usb_decode_classic_dlc_above_8() {
+        let mut raw = [0u8; 20];
+        raw[0..4].copy_from_slice(&0xFFFF_FFFFu32.to_le_bytes());
+        raw[4..8].copy_from_slice(&0x123u32.to_le_bytes());
+        raw[8] = 0x0F;
+        raw[12..20].copy_from_slice(&[1, 2, 3, 4, 5, 6, 7, 8]);
+
+        let frame = GsUsbCodec::decode(&raw).unwrap();
+        assert_eq!(frame.dlc, 8);
+        assert_eq!(frame.bytes.len(), 8);
+        assert_eq!(frame.dlc_raw, Some(0x0F));
+    }
+
     #[test]
     fn test_gs_usb_decode_tx_echo_rejected() {
         let mut raw = [0u8; 20];
@@ -197,6 +217,7 @@ mod tests {
             is_fd: false,
             is_brs: false,
             is_rtr: false,
+            rtr_dlc: None,
         };
 
         let encoded = GsUsbCodec::encode(&frame).unwrap();
@@ -226,6 +247,7 @@ mod tests {
             is_fd: false,
             is_brs: false,
             is_rtr: false,
+            rtr_dlc: None,
         };
 
         let encoded = GsUsbCodec::encode(&frame).unwrap();
@@ -247,6 +269,7 @@ mod tests {
             is_fd: false,
             is_brs: false,
             is_rtr: false,
+            rtr_dlc: None,
         };
 
         assert!(GsUsbCodec::encode(&frame).is_err());
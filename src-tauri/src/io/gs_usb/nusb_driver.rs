@@ -8,21 +8,24 @@
 use async_trait::async_trait;
 use nusb::transfer::{ControlIn, ControlOut, ControlType, Recipient};
 use nusb::{Interface, MaybeFuture};
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
 use std::io::Write;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::mpsc as std_mpsc;
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
-/// Timeout for USB bulk-in transfer reads.
-const BULK_TRANSFER_TIMEOUT: Duration = Duration::from_millis(50);
+/// How often the blocking transmit thread wakes to check the stop flag.
+const TRANSMIT_POLL_INTERVAL: Duration = Duration::from_millis(10);
 use tauri::AppHandle;
 
 use super::{
     can_fd_flags, can_feature, can_id_flags, can_mode, get_bittiming_for_bitrate,
     GsDeviceBittiming, GsDeviceBtConst, GsDeviceBtConstExtended, GsDeviceConfig, GsDeviceMode,
-    GsHostFrame, GsHostFrameFd,
-    GsUsbBreq, GsUsbConfig, GsUsbDeviceInfo, GsUsbProbeResult, DLC_LEN, GS_USB_HOST_FORMAT,
+    GsHostFrame, GsHostFrameFd, host_frame_timestamp,
+    GsUsbBreq, GsUsbConfig, GsUsbDeviceInfo, GsUsbProbeResult, GsUsbReadTuning, ReadBackoff,
+    len_to_fd_dlc, DLC_LEN, GS_USB_HOST_FORMAT,
     GS_USB_PIDS, GS_USB_VID,
 };
 use tokio::sync::mpsc;
@@ -30,10 +33,14 @@ use tokio::sync::mpsc;
 use crate::buffer_store::{self, BufferType};
 use crate::io::error::IoError;
 use crate::io::gvret::{apply_bus_mapping, BusMapping};
-use crate::io::types::{SourceMessage, TransmitRequest, TransmitSender};
+use crate::io::types::{
+    transmit_timeout_from_ms, SourceMessage, TransmitRequest, TransmitSender,
+    DEFAULT_TRANSMIT_TIMEOUT,
+};
 use crate::io::{
-    emit_frames, emit_session_error, emit_stream_ended, now_us, CanTransmitFrame, FrameMessage,
-    IOCapabilities, IODevice, IOState, TransmitPayload, TransmitResult,
+    emit_first_frame, emit_frames, emit_session_error, emit_stream_ended, ensure_transmit_state, now_us,
+    CanTransmitFrame, DeviceClock, FrameMessage, IOCapabilities, IODevice, IOState,
+    TimestampSource, TransmitPayload, TransmitResult, U32_MICROS_WRAP,
 };
 
 /// Encode a CAN frame into gs_usb format.
@@ -47,6 +54,11 @@ pub fn encode_frame(frame: &CanTransmitFrame, channel: u8) -> Vec<u8> {
     }
 }
 
+/// Whether an encoded host frame carries the FD flag
+fn is_fd_host_frame(data: &[u8]) -> bool {
+    data.len() > 10 && (data[10] & can_fd_flags::FD) != 0
+}
+
 /// Encode a classic CAN frame (20 bytes)
 fn encode_classic_frame(frame: &CanTransmitFrame, channel: u8) -> Vec<u8> {
     let mut buf = vec![0u8; GsHostFrame::SIZE];
@@ -65,7 +77,7 @@ fn encode_classic_frame(frame: &CanTransmitFrame, channel: u8) -> Vec<u8> {
     buf[4..8].copy_from_slice(&can_id.to_le_bytes());
 
     // can_dlc
-    buf[8] = frame.data.len().min(8) as u8;
+    buf[8] = frame.classic_dlc();
 
     // channel
     buf[9] = channel;
@@ -76,8 +88,8 @@ fn encode_classic_frame(frame: &CanTransmitFrame, channel: u8) -> Vec<u8> {
     // reserved
     buf[11] = 0;
 
-    // data (up to 8 bytes)
-    let len = frame.data.len().min(8);
+    // data (up to 8 bytes; none for a remote frame)
+    let len = if frame.is_rtr { 0 } else { frame.data.len().min(8) };
     buf[12..12 + len].copy_from_slice(&frame.data[..len]);
 
     buf
@@ -97,8 +109,9 @@ fn encode_fd_frame(frame: &CanTransmitFrame, channel: u8) -> Vec<u8> {
     }
     buf[4..8].copy_from_slice(&can_id.to_le_bytes());
 
-    // can_dlc (actual byte count for FD, up to 64)
-    buf[8] = frame.data.len().min(64) as u8;
+    // can_dlc (FD DLC code; the zeroed buffer pads data up to the DLC length)
+    let len = frame.data.len().min(64);
+    buf[8] = len_to_fd_dlc(len);
 
     // channel
     buf[9] = channel;
@@ -114,7 +127,6 @@ fn encode_fd_frame(frame: &CanTransmitFrame, channel: u8) -> Vec<u8> {
     buf[11] = 0;
 
     // data (up to 64 bytes)
-    let len = frame.data.len().min(64);
     buf[12..12 + len].copy_from_slice(&frame.data[..len]);
 
     buf
@@ -291,6 +303,8 @@ pub struct GsUsbReader {
     task_handle: Option<tauri::async_runtime::JoinHandle<()>>,
     /// Channel sender for transmit requests (allows sync transmit_frame calls)
     transmit_tx: Arc<Mutex<Option<TransmitSender>>>,
+    /// How long transmit waits for the transmit task to report the write result
+    transmit_timeout: Duration,
 }
 
 impl GsUsbReader {
@@ -303,6 +317,7 @@ impl GsUsbReader {
             cancel_flag: Arc::new(AtomicBool::new(false)),
             task_handle: None,
             transmit_tx: Arc::new(Mutex::new(None)),
+            transmit_timeout: DEFAULT_TRANSMIT_TIMEOUT,
         }
     }
 }
@@ -395,7 +410,13 @@ impl IODevice for GsUsbReader {
         &self.session_id
     }
 
+    fn set_transmit_timeout(&mut self, timeout_ms: u64) -> Result<(), String> {
+        self.transmit_timeout = transmit_timeout_from_ms(timeout_ms)?;
+        Ok(())
+    }
+
     fn transmit(&self, payload: &TransmitPayload) -> Result<TransmitResult, String> {
+        ensure_transmit_state(&self.state)?;
         let frame = match payload {
             TransmitPayload::CanFrame(f) => f,
             TransmitPayload::RawBytes(_) => {
@@ -440,19 +461,8 @@ impl IODevice for GsUsbReader {
             guard.clone().ok_or("Not connected (no transmit channel)")?
         };
 
-        // Create a sync channel to receive the result
-        let (result_tx, result_rx) = std_mpsc::sync_channel(1);
-
-        // Send the transmit request
-        tx.try_send(TransmitRequest { data, result_tx })
-            .map_err(|e| format!("Failed to queue transmit request: {}", e))?;
-
-        // Wait for the result with a timeout
-        let result = result_rx
-            .recv_timeout(std::time::Duration::from_millis(500))
-            .map_err(|e| format!("Transmit timeout or channel closed: {}", e))?;
-
-        result?;
+        // Queue the request and wait for the transmit task to report the result
+        TransmitRequest::send(&tx, data, self.transmit_timeout)?;
 
         Ok(TransmitResult::success())
     }
@@ -533,11 +543,15 @@ async fn run_gs_usb_stream(
     );
 
     // Initialize device
-    if let Err(e) = initialize_device(&interface, &config).await {
-        emit_session_error(&app_handle, &session_id, IoError::protocol(&device_name, format!("initialize: {}", e)).to_string());
-        emit_stream_ended(&app_handle, &session_id, "error", "gs_usb");
-        return;
-    }
+    let hw_timestamps = match initialize_device(&interface, &config).await {
+        Ok(hw_timestamps) => hw_timestamps,
+        Err(e) => {
+            emit_session_error(&app_handle, &session_id, IoError::protocol(&device_name, format!("initialize: {}", e)).to_string());
+            emit_stream_ended(&app_handle, &session_id, "error", "gs_usb");
+            return;
+        }
+    };
+    let mut clock = DeviceClock::new(config.timestamp_source, U32_MICROS_WRAP);
 
     tlog!("[gs_usb:{}] Device initialized, starting stream", session_id);
 
@@ -564,7 +578,7 @@ async fn run_gs_usb_stream(
                 // Spawn blocking task for transmit handling (writer uses blocking I/O)
                 let handle = tokio::task::spawn_blocking(move || {
                     while !cancel_flag_for_transmit.load(Ordering::Relaxed) {
-                        match rx.recv_timeout(std::time::Duration::from_millis(10)) {
+                        match rx.recv_timeout(TRANSMIT_POLL_INTERVAL) {
                             Ok(req) => {
                                 let result = match writer.write_all(&req.data) {
                                     Ok(_) => match writer.flush() {
@@ -600,6 +614,7 @@ async fn run_gs_usb_stream(
     let mut pending_frames: Vec<FrameMessage> = Vec::with_capacity(32);
     let mut last_emit_time = std::time::Instant::now();
     let emit_interval = Duration::from_millis(25);
+    let mut seen_first_frame = false;
 
     // Buffer size: must accommodate padding to USB max packet size (64 bytes for full-speed).
     // Devices with PAD_PKTS_TO_MAX_PKT_SIZE round up to the next packet boundary.
@@ -607,9 +622,10 @@ async fn run_gs_usb_stream(
     let buf_size = if config.enable_fd { 128 } else { 64 };
 
     // Pre-submit multiple read requests for better throughput
-    for _ in 0..4 {
+    for _ in 0..config.read_tuning.transfers() {
         bulk_in.submit(bulk_in.allocate(buf_size));
     }
+    let mut backoff = ReadBackoff::new(&config.read_tuning);
 
     // Read loop - only handles reading, transmit is handled by the dedicated task
     loop {
@@ -630,9 +646,9 @@ async fn run_gs_usb_stream(
             }
         }
 
-        // Wait for next transfer completion with timeout
+        // Wait for next transfer completion with timeout (backs off while idle)
         let read_result = tokio::time::timeout(
-            BULK_TRANSFER_TIMEOUT,
+            backoff.timeout(),
             bulk_in.next_complete(),
         )
         .await;
@@ -641,6 +657,7 @@ async fn run_gs_usb_stream(
             Ok(completion) => {
                 match completion.status {
                     Ok(()) => {
+                        backoff.on_data();
                         let len = completion.actual_len;
                         let data = &completion.buffer[..len];
 
@@ -668,6 +685,12 @@ async fn run_gs_usb_stream(
                                             source_address: None,
                                             incomplete: None,
                                             direction: None,
+                                            dlc_raw: Some(gs_frame.can_dlc),
+                                            is_rtr: false,
+                                            is_brs: gs_frame.is_brs(),
+                                            is_esi: gs_frame.is_esi(),
+                                            original_frame_id: None,
+                                            source_label: None,
                                         })
                                     } else {
                                         None
@@ -682,13 +705,19 @@ async fn run_gs_usb_stream(
                                             timestamp_us: now_us(),
                                             frame_id: gs_frame.get_can_id(),
                                             bus: config.bus_override.unwrap_or(gs_frame.channel),
-                                            dlc: gs_frame.can_dlc,
+                                            dlc: gs_frame.can_dlc.min(8),
                                             bytes: gs_frame.get_data().to_vec(),
                                             is_extended: gs_frame.is_extended(),
                                             is_fd: false,
                                             source_address: None,
                                             incomplete: None,
                                             direction: None,
+                                            dlc_raw: Some(gs_frame.can_dlc),
+                                            is_rtr: false,
+                                            is_brs: false,
+                                            is_esi: false,
+                                            original_frame_id: None,
+                                            source_label: None,
                                         })
                                     } else {
                                         None
@@ -699,7 +728,15 @@ async fn run_gs_usb_stream(
                             None
                         };
 
-                        if let Some(frame) = frame_msg {
+                        if let Some(mut frame) = frame_msg {
+                            if hw_timestamps {
+                                frame.timestamp_us = clock
+                                    .timestamp(host_frame_timestamp(data, frame.is_fd).map(u64::from));
+                            }
+                            if !seen_first_frame {
+                                seen_first_frame = true;
+                                emit_first_frame(&app_handle, &session_id, &frame);
+                            }
                             pending_frames.push(frame);
                             total_frames += 1;
                         }
@@ -716,13 +753,14 @@ async fn run_gs_usb_stream(
             }
             Err(_) => {
                 // Timeout - this is normal for live streams with no traffic
+                backoff.on_idle();
             }
         }
 
         // Emit batched frames periodically
         if last_emit_time.elapsed() >= emit_interval && !pending_frames.is_empty() {
             let frames = std::mem::take(&mut pending_frames);
-            buffer_store::append_frames(frames.clone());
+            buffer_store::append_frames_to_buffer(&buffer_id, frames.clone());
             emit_frames(&app_handle, &session_id, frames);
             last_emit_time = std::time::Instant::now();
         }
@@ -735,7 +773,7 @@ async fn run_gs_usb_stream(
 
     // Emit remaining frames
     if !pending_frames.is_empty() {
-        buffer_store::append_frames(pending_frames.clone());
+        buffer_store::append_frames_to_buffer(&buffer_id, pending_frames.clone());
         emit_frames(&app_handle, &session_id, pending_frames);
     }
 
@@ -745,8 +783,11 @@ async fn run_gs_usb_stream(
     emit_stream_ended(&app_handle, &session_id, stream_reason, "gs_usb");
 }
 
-/// Initialize the gs_usb device
-pub async fn initialize_device(interface: &Interface, config: &GsUsbConfig) -> Result<(), String> {
+/// Initialize the gs_usb device.
+///
+/// Returns whether hardware timestamps were enabled (requested via a device
+/// `timestamp_source` and supported by the device).
+pub async fn initialize_device(interface: &Interface, config: &GsUsbConfig) -> Result<bool, String> {
     // 1. Send HOST_FORMAT (byte order negotiation)
     let host_format = GS_USB_HOST_FORMAT.to_le_bytes();
     interface
@@ -790,12 +831,13 @@ pub async fn initialize_device(interface: &Interface, config: &GsUsbConfig) -> R
         );
     }
 
-    // Check FD feature flag before attempting FD setup
+    // Check FD feature flag and bitrate pair before attempting FD setup
     if config.enable_fd {
         let has_fd = bt_const.map(|c| c.feature & can_feature::FD != 0).unwrap_or(false);
         if !has_fd {
             return Err("CAN FD enabled but device does not report FD support in BT_CONST features".to_string());
         }
+        super::validate_fd_bitrates(config.bitrate, config.data_bitrate)?;
     }
 
     // 3. Reset device before configuring bittiming (matches Linux gs_usb driver sequence)
@@ -920,10 +962,18 @@ pub async fn initialize_device(interface: &Interface, config: &GsUsbConfig) -> R
             tlog!("[gs_usb] FD: No constraints available for data phase, using nominal clock {} Hz", fclk_can);
         }
 
-        // Calculate data phase timing using device constraints
+        // Calculate data phase timing using device constraints. Limits reported by
+        // BT_CONST_EXT are authoritative, so never fall back to unconstrained timing
+        // that the controller would reject or mis-sample.
         let data_timing = if let Some(ref constraints) = data_constraints {
-            super::calculate_bittiming_constrained(fclk_data, config.data_bitrate, config.data_sample_point, constraints)
-                .or_else(|| super::calculate_bittiming(fclk_data, config.data_bitrate, config.data_sample_point))
+            let constrained = super::calculate_bittiming_constrained(
+                fclk_data, config.data_bitrate, config.data_sample_point, constraints,
+            );
+            if bt_const_ext_result.is_some() {
+                constrained
+            } else {
+                constrained.or_else(|| super::calculate_bittiming(fclk_data, config.data_bitrate, config.data_sample_point))
+            }
         } else {
             super::calculate_bittiming(fclk_data, config.data_bitrate, config.data_sample_point)
         }
@@ -996,6 +1046,17 @@ pub async fn initialize_device(interface: &Interface, config: &GsUsbConfig) -> R
         mode_flags |= can_mode::PAD_PKTS_TO_MAX_PKT_SIZE;
     }
 
+    // Hardware timestamps append a u32 microsecond counter to each received frame
+    let mut hw_timestamps = false;
+    if config.timestamp_source.wants_device() {
+        if bt_const.map(|c| c.feature & can_feature::HW_TIMESTAMP != 0).unwrap_or(false) {
+            mode_flags |= can_mode::HW_TIMESTAMP;
+            hw_timestamps = true;
+        } else {
+            tlog!("[gs_usb] Device does not support hardware timestamps, using host time");
+        }
+    }
+
     let mode = GsDeviceMode {
         mode: 1, // Start
         flags: mode_flags,
@@ -1020,7 +1081,7 @@ pub async fn initialize_device(interface: &Interface, config: &GsUsbConfig) -> R
         .await
         .map_err(|e| format!("MODE failed: {:?}", e))?;
 
-    Ok(())
+    Ok(hw_timestamps)
 }
 
 /// Stop the gs_usb device
@@ -1084,6 +1145,12 @@ pub fn parse_host_frame(data: &[u8]) -> Option<FrameMessage> {
             source_address: None,
             incomplete: None,
             direction: None,
+            dlc_raw: Some(gs_frame.can_dlc),
+            is_rtr: false,
+            is_brs: gs_frame.is_brs(),
+            is_esi: gs_frame.is_esi(),
+            original_frame_id: None,
+            source_label: None,
         })
     } else {
         // Parse as classic CAN frame
@@ -1096,13 +1163,19 @@ pub fn parse_host_frame(data: &[u8]) -> Option<FrameMessage> {
             timestamp_us: now_us(),
             frame_id: gs_frame.get_can_id(),
             bus: gs_frame.channel,
-            dlc: gs_frame.can_dlc,
+            dlc: gs_frame.can_dlc.min(8),
             bytes: gs_frame.get_data().to_vec(),
             is_extended: gs_frame.is_extended(),
             is_fd: false,
             source_address: None,
             incomplete: None,
             direction: None,
+            dlc_raw: Some(gs_frame.can_dlc),
+            is_rtr: false,
+            is_brs: false,
+            is_esi: false,
+            original_frame_id: None,
+            source_label: None,
         })
     }
 }
@@ -1111,7 +1184,113 @@ pub fn parse_host_frame(data: &[u8]) -> Option<FrameMessage> {
 // Multi-Source Streaming
 // ============================================================================
 
-/// Run gs_usb source and send frames to merge task
+/// Per-channel consumer of a shared gs_usb device
+struct ChannelSink {
+    source_idx: usize,
+    bus_mappings: Vec<BusMapping>,
+    tx: mpsc::Sender<SourceMessage>,
+    /// Stamps this channel's frames (hardware timestamps are per channel)
+    clock: DeviceClock,
+    /// Whether this channel was started with hardware timestamps
+    hw_timestamps: bool,
+}
+
+/// Record whether a channel's frames carry hardware timestamps once it is initialized.
+fn set_sink_hw_timestamps(key: &str, channel: u8, hw_timestamps: bool) {
+    if let Ok(mut devices) = SHARED_DEVICES.lock() {
+        if let Some(sink) = devices.get_mut(key).and_then(|d| d.sinks.get_mut(&channel)) {
+            sink.hw_timestamps = hw_timestamps;
+        }
+    }
+}
+
+/// A gs_usb device opened by one channel source and shared with the others.
+///
+/// gs_usb multiplexes every CAN channel over the same bulk IN/OUT endpoints,
+/// with the channel number carried in each host frame. The first source to
+/// open the device owns the read pump and the OUT endpoint writer; sources
+/// for other channels attach to the claimed interface and receive their
+/// frames through a `ChannelSink`.
+struct SharedDevice {
+    interface: Interface,
+    /// Sender for the device's OUT endpoint writer (None in listen-only)
+    transmit_tx: Option<TransmitSender>,
+    /// Channel sinks fed by the read pump, keyed by channel number
+    sinks: HashMap<u8, ChannelSink>,
+}
+
+/// Open gs_usb devices, keyed by `device_key`
+static SHARED_DEVICES: Lazy<Mutex<HashMap<String, SharedDevice>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Serialises device open/attach so two channels starting together don't
+/// both try to claim the interface.
+static DEVICE_OPEN_LOCK: Lazy<tokio::sync::Mutex<()>> = Lazy::new(|| tokio::sync::Mutex::new(()));
+
+/// Stable key for a physical device: serial number when available, else bus:address
+fn device_key(serial: Option<&str>, bus: u8, address: u8) -> String {
+    match serial {
+        Some(s) if !s.is_empty() => format!("serial:{}", s),
+        _ => format!("usb:{}:{}", bus, address),
+    }
+}
+
+/// Apply a channel source's bus mappings to a received frame.
+/// Each channel is its own source, so mappings may be keyed either by the
+/// hardware channel number or by 0 (the default single-bus mapping).
+fn map_channel_frame(frame: &mut FrameMessage, mappings: &[BusMapping]) -> bool {
+    if !mappings.iter().any(|m| m.device_bus == frame.bus) {
+        frame.bus = 0;
+    }
+    apply_bus_mapping(frame, mappings)
+}
+
+/// Spawn a per-channel transmit forwarder.
+/// Stamps the channel number into each host frame (byte 9) and hands the
+/// request to the device's shared OUT endpoint writer.
+fn spawn_channel_transmit(
+    channel: u8,
+    enable_fd: bool,
+    device_tx: TransmitSender,
+    stop_flag: Arc<AtomicBool>,
+) -> TransmitSender {
+    let (channel_tx, channel_rx) = std_mpsc::sync_channel::<TransmitRequest>(32);
+    tokio::task::spawn_blocking(move || {
+        while !stop_flag.load(Ordering::Relaxed) {
+            match channel_rx.recv_timeout(TRANSMIT_POLL_INTERVAL) {
+                Ok(mut req) => {
+                    // A classic-mode channel would reject or mangle an FD host frame
+                    if !enable_fd && is_fd_host_frame(&req.data) {
+                        let _ = req.result_tx.try_send(Err(format!(
+                            "Cannot transmit FD frame: gs_usb channel {} was initialized in classic mode (enable FD in the profile)",
+                            channel
+                        )));
+                        continue;
+                    }
+                    if req.data.len() > 9 {
+                        req.data[9] = channel;
+                    }
+                    if let Err(e) = device_tx.try_send(req) {
+                        let req = match e {
+                            std_mpsc::TrySendError::Full(r) | std_mpsc::TrySendError::Disconnected(r) => r,
+                        };
+                        let _ = req.result_tx.try_send(Err("Device transmit queue unavailable".to_string()));
+                    }
+                }
+                Err(std_mpsc::RecvTimeoutError::Timeout) => {}
+                Err(std_mpsc::RecvTimeoutError::Disconnected) => break,
+            }
+        }
+    });
+    channel_tx
+}
+
+/// Run gs_usb source and send frames to merge task.
+///
+/// If another source already has this device open (multi-channel adapters such
+/// as the CANable 2 or Canine), this source attaches to the shared interface
+/// and only configures its own channel.
+#[allow(clippy::too_many_arguments)]
 pub async fn run_source(
     source_idx: usize,
     bus: u8,
@@ -1124,14 +1303,163 @@ pub async fn run_source(
     enable_fd: bool,
     data_bitrate: u32,
     data_sample_point: f32,
+    read_tuning: GsUsbReadTuning,
+    timestamp_source: TimestampSource,
     bus_mappings: Vec<BusMapping>,
     stop_flag: Arc<AtomicBool>,
     tx: mpsc::Sender<SourceMessage>,
 ) {
+    // Build config for initialization
+    let config = GsUsbConfig {
+        bus,
+        address,
+        serial: serial.clone(),
+        bitrate,
+        sample_point,
+        listen_only,
+        channel,
+        limit: None,
+        display_name: None,
+        bus_override: None,
+        enable_fd,
+        data_bitrate,
+        data_sample_point,
+        read_tuning,
+        timestamp_source,
+    };
+    let key = device_key(serial.as_deref(), bus, address);
+    let sink = ChannelSink {
+        source_idx,
+        bus_mappings,
+        tx: tx.clone(),
+        clock: DeviceClock::new(timestamp_source, U32_MICROS_WRAP),
+        hw_timestamps: false,
+    };
+
+    let open_guard = DEVICE_OPEN_LOCK.lock().await;
+
+    // Attach to the device if another channel source already owns it
+    let attached = {
+        let mut devices = SHARED_DEVICES.lock().unwrap_or_else(|e| e.into_inner());
+        match devices.get_mut(&key) {
+            Some(device) if device.sinks.contains_key(&channel) => Some(Err(format!(
+                "Channel {} of this device is already in use by another source",
+                channel
+            ))),
+            Some(device) => {
+                device.sinks.insert(channel, sink);
+                Some(Ok((device.interface.clone(), device.transmit_tx.clone())))
+            }
+            None => None,
+        }
+    };
+
+    match attached {
+        Some(Err(e)) => {
+            drop(open_guard);
+            let _ = tx.send(SourceMessage::Error(source_idx, e)).await;
+        }
+        Some(Ok((interface, device_tx))) => {
+            drop(open_guard);
+            run_attached_channel(source_idx, &key, interface, device_tx, config, stop_flag, tx).await;
+        }
+        None => {
+            run_device_owner(source_idx, key, config, sink, open_guard, stop_flag, tx).await;
+        }
+    }
+}
+
+/// Run a channel source on a device that another source already has open.
+/// The owner's read pump delivers frames; this task configures the channel,
+/// offers transmit, and tears the channel down when stopped.
+async fn run_attached_channel(
+    source_idx: usize,
+    key: &str,
+    interface: Interface,
+    device_tx: Option<TransmitSender>,
+    config: GsUsbConfig,
+    stop_flag: Arc<AtomicBool>,
+    tx: mpsc::Sender<SourceMessage>,
+) {
+    let channel = config.channel;
+    let remove_sink = || {
+        if let Ok(mut devices) = SHARED_DEVICES.lock() {
+            if let Some(device) = devices.get_mut(key) {
+                device.sinks.remove(&channel);
+            }
+        }
+    };
+
+    match initialize_device(&interface, &config).await {
+        Ok(hw_timestamps) => set_sink_hw_timestamps(key, channel, hw_timestamps),
+        Err(e) => {
+            remove_sink();
+            let _ = tx
+                .send(SourceMessage::Error(
+                    source_idx,
+                    format!("Failed to initialize channel {}: {}", channel, e),
+                ))
+                .await;
+            return;
+        }
+    }
+
+    tlog!(
+        "[gs_usb] Source {} attached to channel {} of {}:{} (bitrate: {}, listen_only: {})",
+        source_idx, channel, config.bus, config.address, config.bitrate, config.listen_only
+    );
+
+    let addr_str = format!("{}:{}", config.bus, config.address);
+    let _ = tx
+        .send(SourceMessage::Connected(source_idx, "gs_usb".to_string(), addr_str, Some(channel)))
+        .await;
+
+    if !config.listen_only {
+        if let Some(device_tx) = device_tx {
+            let channel_tx = spawn_channel_transmit(channel, config.enable_fd, device_tx, stop_flag.clone());
+            let _ = tx.send(SourceMessage::TransmitReady(source_idx, channel_tx)).await;
+        }
+    }
+
+    // Frames arrive via the owner's pump; wait for stop or for the device to go away
+    while !stop_flag.load(Ordering::Relaxed) {
+        tokio::time::sleep(TRANSMIT_POLL_INTERVAL).await;
+        let registered = SHARED_DEVICES
+            .lock()
+            .map(|d| d.get(key).is_some_and(|dev| dev.sinks.contains_key(&channel)))
+            .unwrap_or(false);
+        if !registered {
+            // Owner pump failed and already reported the error to this source
+            return;
+        }
+    }
+
+    remove_sink();
+    let _ = stop_device(&interface, &config).await;
+
+    let _ = tx
+        .send(SourceMessage::Ended(source_idx, "stopped".to_string()))
+        .await;
+}
+
+/// Open the device, register it for sharing, and run the read pump.
+/// The pump keeps running after this source's own channel stops until every
+/// attached channel has stopped too.
+async fn run_device_owner(
+    source_idx: usize,
+    key: String,
+    config: GsUsbConfig,
+    sink: ChannelSink,
+    open_guard: tokio::sync::MutexGuard<'static, ()>,
+    stop_flag: Arc<AtomicBool>,
+    tx: mpsc::Sender<SourceMessage>,
+) {
+    let channel = config.channel;
+
     // Find and open device - prefer serial number matching when available
     let device_info = match nusb::list_devices().await {
         Ok(mut devices) => devices
-            .find(|dev| device_matches(dev, serial.as_deref(), bus, address))
+            .find(|dev| device_matches(dev, config.serial.as_deref(), config.bus, config.address))
             .ok_or_else(|| "Device not found".to_string()),
         Err(e) => Err(format!("Failed to list devices: {}", e)),
     };
@@ -1170,46 +1498,7 @@ pub async fn run_source(
         }
     };
 
-    // Build config for initialization
-    let config = GsUsbConfig {
-        bus,
-        address,
-        serial: serial.clone(),
-        bitrate,
-        sample_point,
-        listen_only,
-        channel,
-        limit: None,
-        display_name: None,
-        bus_override: None,
-        enable_fd,
-        data_bitrate,
-        data_sample_point,
-    };
-
-    // Initialize device
-    if let Err(e) = initialize_device(&interface, &config).await {
-        let _ = tx
-            .send(SourceMessage::Error(
-                source_idx,
-                format!("Failed to initialize device: {}", e),
-            ))
-            .await;
-        return;
-    }
-
-    tlog!(
-        "[gs_usb] Source {} connected to {}:{} (bitrate: {}, listen_only: {})",
-        source_idx, bus, address, bitrate, listen_only
-    );
-
-    // Emit device-connected event
-    let addr_str = format!("{}:{}", bus, address);
-    let _ = tx
-        .send(SourceMessage::Connected(source_idx, "gs_usb".to_string(), addr_str, Some(channel)))
-        .await;
-
-    // Bulk IN endpoint
+    // Bulk IN endpoint (shared by all channels)
     let mut bulk_in = match interface.endpoint::<nusb::transfer::Bulk, nusb::transfer::In>(0x81) {
         Ok(ep) => ep,
         Err(e) => {
@@ -1223,22 +1512,19 @@ pub async fn run_source(
         }
     };
 
-    // Setup transmit channel if not in listen-only mode
-    let transmit_task = if !listen_only {
+    // Device-level OUT endpoint writer. Opened even if this channel is
+    // listen-only so that attached channels can still transmit.
+    let device_closed = Arc::new(AtomicBool::new(false));
+    let (device_tx, transmit_task) =
         match interface.endpoint::<nusb::transfer::Bulk, nusb::transfer::Out>(0x02) {
             Ok(ep) => {
-                let (transmit_tx, transmit_rx) =
-                    std_mpsc::sync_channel::<TransmitRequest>(32);
-                let _ = tx
-                    .send(SourceMessage::TransmitReady(source_idx, transmit_tx))
-                    .await;
-
+                let (device_tx, device_rx) = std_mpsc::sync_channel::<TransmitRequest>(32);
                 let mut writer = ep.writer(64);
-                let stop_flag_for_transmit = stop_flag.clone();
+                let closed = device_closed.clone();
 
                 let handle = tokio::task::spawn_blocking(move || {
-                    while !stop_flag_for_transmit.load(Ordering::Relaxed) {
-                        match transmit_rx.recv_timeout(std::time::Duration::from_millis(10)) {
+                    while !closed.load(Ordering::Relaxed) {
+                        match device_rx.recv_timeout(TRANSMIT_POLL_INTERVAL) {
                             Ok(req) => {
                                 let result = match writer.write_all(&req.data) {
                                     Ok(_) => match writer.flush() {
@@ -1254,76 +1540,237 @@ pub async fn run_source(
                         }
                     }
                 });
-                Some(handle)
+                (Some(device_tx), Some(handle))
             }
             Err(e) => {
                 tlog!(
                     "[gs_usb] Source {} warning: could not open bulk OUT: {}",
                     source_idx, e
                 );
-                None
+                (None, None)
+            }
+        };
+
+    // Register the device so other channel sources can attach
+    if let Ok(mut devices) = SHARED_DEVICES.lock() {
+        let mut sinks = HashMap::new();
+        sinks.insert(channel, sink);
+        devices.insert(
+            key.clone(),
+            SharedDevice {
+                interface: interface.clone(),
+                transmit_tx: device_tx.clone(),
+                sinks,
+            },
+        );
+    }
+    drop(open_guard);
+
+    // Initialize this source's channel
+    match initialize_device(&interface, &config).await {
+        Err(e) => {
+            let _ = tx
+                .send(SourceMessage::Error(
+                    source_idx,
+                    format!("Failed to initialize device: {}", e),
+                ))
+                .await;
+            // Keep pumping for any channels that attached in the meantime
+            if let Ok(mut devices) = SHARED_DEVICES.lock() {
+                if let Some(device) = devices.get_mut(&key) {
+                    device.sinks.remove(&channel);
+                }
             }
         }
-    } else {
-        None
-    };
+        Ok(hw_timestamps) => {
+            set_sink_hw_timestamps(&key, channel, hw_timestamps);
+            tlog!(
+                "[gs_usb] Source {} connected to {}:{} channel {} (bitrate: {}, listen_only: {})",
+                source_idx, config.bus, config.address, channel, config.bitrate, config.listen_only
+            );
+
+            // Emit device-connected event
+            let addr_str = format!("{}:{}", config.bus, config.address);
+            let _ = tx
+                .send(SourceMessage::Connected(source_idx, "gs_usb".to_string(), addr_str, Some(channel)))
+                .await;
+
+            if !config.listen_only {
+                if let Some(ref device_tx) = device_tx {
+                    let channel_tx = spawn_channel_transmit(channel, config.enable_fd, device_tx.clone(), stop_flag.clone());
+                    let _ = tx.send(SourceMessage::TransmitReady(source_idx, channel_tx)).await;
+                }
+            }
+        }
+    }
 
     // Buffer size: must accommodate padding to USB max packet size (64 bytes for full-speed).
     // FD frame = 76 bytes → padded to 128 bytes; classic = 32 bytes → padded to 64 bytes.
-    let buf_size = if enable_fd { 128 } else { 64 };
+    // Always size for FD so an FD-enabled attached channel is never truncated.
+    let buf_size = 128;
 
     // Pre-submit read requests
-    for _ in 0..4 {
+    for _ in 0..config.read_tuning.transfers() {
         bulk_in.submit(bulk_in.allocate(buf_size));
     }
+    let mut backoff = ReadBackoff::new(&config.read_tuning);
+    let mut own_channel_active = true;
+    let mut pump_error: Option<String> = None;
+
+    // Read pump
+    loop {
+        // Stop this source's own channel (the pump may keep running for others)
+        if own_channel_active && stop_flag.load(Ordering::Relaxed) {
+            own_channel_active = false;
+            let was_registered = SHARED_DEVICES
+                .lock()
+                .ok()
+                .and_then(|mut d| d.get_mut(&key).and_then(|dev| dev.sinks.remove(&channel)))
+                .is_some();
+            if was_registered {
+                let _ = stop_device(&interface, &config).await;
+                let _ = tx
+                    .send(SourceMessage::Ended(source_idx, "stopped".to_string()))
+                    .await;
+            }
+        }
+
+        // Exit once no channel is using the device (atomic with attach)
+        if !own_channel_active {
+            let mut devices = SHARED_DEVICES.lock().unwrap_or_else(|e| e.into_inner());
+            if devices.get(&key).is_none_or(|d| d.sinks.is_empty()) {
+                devices.remove(&key);
+                break;
+            }
+        }
 
-    // Read loop
-    while !stop_flag.load(Ordering::Relaxed) {
         let read_result =
-            tokio::time::timeout(BULK_TRANSFER_TIMEOUT, bulk_in.next_complete()).await;
+            tokio::time::timeout(backoff.timeout(), bulk_in.next_complete()).await;
 
         match read_result {
             Ok(completion) => match completion.status {
                 Ok(()) => {
+                    backoff.on_data();
                     let len = completion.actual_len;
                     let data = &completion.buffer[..len];
 
-                    // Parse frame using shared function (handles both classic and FD)
+                    // Parse frame using shared function (handles both classic and FD),
+                    // then route it to the source that owns its channel
                     if let Some(mut frame_msg) = parse_host_frame(data) {
-                        // Apply bus mapping
-                        if apply_bus_mapping(&mut frame_msg, &bus_mappings) {
-                            let _ = tx
-                                .send(SourceMessage::Frames(source_idx, vec![frame_msg]))
-                                .await;
+                        let frame_channel = frame_msg.bus;
+                        let route = SHARED_DEVICES.lock().ok().and_then(|mut d| {
+                            d.get_mut(&key)
+                                .and_then(|dev| dev.sinks.get_mut(&frame_channel))
+                                .map(|s| {
+                                    if s.hw_timestamps {
+                                        frame_msg.timestamp_us = s.clock.timestamp(
+                                            host_frame_timestamp(data, frame_msg.is_fd).map(u64::from),
+                                        );
+                                    }
+                                    (s.source_idx, s.bus_mappings.clone(), s.tx.clone())
+                                })
+                        });
+                        if let Some((idx, mappings, sink_tx)) = route {
+                            if map_channel_frame(&mut frame_msg, &mappings) {
+                                let _ = sink_tx
+                                    .send(SourceMessage::Frames(idx, vec![frame_msg]))
+                                    .await;
+                            }
                         }
                     }
 
                     bulk_in.submit(bulk_in.allocate(buf_size));
                 }
                 Err(e) => {
-                    let _ = tx
-                        .send(SourceMessage::Error(
-                            source_idx,
-                            format!("Bulk transfer error: {:?}", e),
-                        ))
-                        .await;
+                    pump_error = Some(format!("Bulk transfer error: {:?}", e));
                     break;
                 }
             },
             Err(_) => {
-                // Timeout - continue
+                // Timeout - back off while the bus is idle
+                backoff.on_idle();
             }
         }
     }
 
+    // On a pump error, unregister the device and report to every channel still attached
+    if let Some(error) = pump_error {
+        let sinks = SHARED_DEVICES
+            .lock()
+            .ok()
+            .and_then(|mut d| d.remove(&key))
+            .map(|dev| dev.sinks)
+            .unwrap_or_default();
+        for (sink_channel, sink) in sinks {
+            let _ = stop_device(&interface, &GsUsbConfig { channel: sink_channel, ..config.clone() }).await;
+            let _ = sink
+                .tx
+                .send(SourceMessage::Error(sink.source_idx, error.clone()))
+                .await;
+        }
+    }
+
     // Cleanup
+    device_closed.store(true, Ordering::Relaxed);
     if let Some(task) = transmit_task {
         task.abort();
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fd_frame(len: usize) -> CanTransmitFrame {
+        CanTransmitFrame {
+            frame_id: 0x1234_5678,
+            data: (0..len as u8).collect(),
+            bus: 0,
+            is_extended: true,
+            is_fd: true,
+            is_brs: true,
+            is_rtr: false,
+            rtr_dlc: None,
+        }
+    }
 
-    let _ = stop_device(&interface, &config).await;
+    #[test]
+    fn test_encode_fd_frame_16_bytes() {
+        let buf = encode_frame(&fd_frame(16), 1);
+        assert_eq!(buf.len(), GsHostFrameFd::SIZE);
+        assert_eq!(&buf[0..4], &0u32.to_le_bytes());
+        assert_eq!(
+            u32::from_le_bytes(buf[4..8].try_into().unwrap()),
+            0x1234_5678 | can_id_flags::EXTENDED
+        );
+        assert_eq!(buf[8], 10); // DLC code for 16 bytes
+        assert_eq!(buf[9], 1);
+        assert_eq!(buf[10], can_fd_flags::FD | can_fd_flags::BRS);
+        assert_eq!(&buf[12..28], &(0..16).collect::<Vec<u8>>()[..]);
+        assert!(buf[28..].iter().all(|&b| b == 0));
+        assert!(is_fd_host_frame(&buf));
+    }
 
-    let _ = tx
-        .send(SourceMessage::Ended(source_idx, "stopped".to_string()))
-        .await;
+    #[test]
+    fn test_encode_fd_frame_64_bytes() {
+        let mut frame = fd_frame(64);
+        frame.is_brs = false;
+        let buf = encode_frame(&frame, 0);
+        assert_eq!(buf.len(), GsHostFrameFd::SIZE);
+        assert_eq!(buf[8], 15);
+        assert_eq!(buf[10], can_fd_flags::FD);
+        assert_eq!(&buf[12..76], &frame.data[..]);
+
+        let decoded = GsHostFrameFd::from_bytes(&buf).unwrap();
+        assert_eq!(decoded.get_data(), &frame.data[..]);
+    }
+
+    #[test]
+    fn test_encode_classic_frame_has_no_fd_flag() {
+        let mut frame = fd_frame(8);
+        frame.is_fd = false;
+        let buf = encode_frame(&frame, 0);
+        assert_eq!(buf.len(), GsHostFrame::SIZE);
+        assert!(!is_fd_host_frame(&buf));
+    }
 }
@@ -0,0 +1,106 @@
+// ui/src-tauri/src/io/gvret/capture.rs
+//
+// Import of SavvyCAN/GVRET binary capture files.
+// The file is a stream of GVRET frame records as sent by the device
+// ([0xF1][0x00][Timestamp][FrameID][Bus+DLC][Data...]), so it is parsed with
+// the same code as the live TCP and USB readers.
+
+use std::fs::File;
+use std::io::{BufReader, ErrorKind, Read};
+
+use super::common::parse_gvret_frames_each;
+use crate::io::{DeviceClock, FrameMessage, TimestampSource, U32_MICROS_WRAP};
+
+/// Bytes read from the file per parse pass
+const READ_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Read GVRET frame records from `reader` until EOF.
+///
+/// Frames keep the device's microsecond timestamps, extended across 32-bit
+/// counter wraparound. Control replies, garbage between records and records
+/// with IDs wider than 29 bits are skipped and logged once.
+pub fn read_gvret_capture<R: Read>(mut reader: R) -> Result<Vec<FrameMessage>, String> {
+    let mut clock = DeviceClock::new(TimestampSource::Device, U32_MICROS_WRAP);
+    let mut bus_params = None;
+    let mut buffer = Vec::new();
+    let mut chunk = vec![0u8; READ_CHUNK_SIZE];
+    let mut frames = Vec::new();
+    let mut skipped_bytes = 0usize;
+
+    loop {
+        let n = match reader.read(&mut chunk) {
+            Ok(0) => break,
+            Ok(n) => n,
+            Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+            Err(e) => return Err(format!("Failed to read capture: {}", e)),
+        };
+        buffer.extend_from_slice(&chunk[..n]);
+        parse_gvret_frames_each(
+            &mut buffer,
+            &mut clock,
+            &mut bus_params,
+            |frame, _raw| frames.push(frame),
+            |_| {},
+            |bytes| skipped_bytes += bytes.len(),
+        );
+    }
+
+    if skipped_bytes > 0 {
+        tlog!("[gvret] Skipped {} bytes of unparseable or out-of-range records", skipped_bytes);
+    }
+
+    if !buffer.is_empty() {
+        tlog!("[gvret] Capture ended with {} bytes of incomplete record", buffer.len());
+    }
+
+    Ok(frames)
+}
+
+/// Parse an entire GVRET binary capture file and return all frames
+pub fn parse_gvret_binary_file(file_path: &str) -> Result<Vec<FrameMessage>, String> {
+    let file = File::open(file_path)
+        .map_err(|e| format!("Failed to open GVRET capture '{}': {}", file_path, e))?;
+    read_gvret_capture(BufReader::new(file))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    fn record(timestamp: u32, id: u32, data: &[u8]) -> Vec<u8> {
+        let mut rec = vec![0xF1, 0x00];
+        rec.extend_from_slice(&timestamp.to_le_bytes());
+        rec.extend_from_slice(&id.to_le_bytes());
+        rec.push(data.len() as u8);
+        rec.extend_from_slice(data);
+        rec
+    }
+
+    #[test]
+    fn test_read_capture_extends_timestamps_across_wrap() {
+        let mut data = record(u32::MAX - 9, 0x100, &[1, 2]);
+        data.extend_from_slice(&[0xF1, 0x09, 0x00, 0x00]); // Keepalive between records
+        data.extend(record(5, 0x200, &[3]));
+
+        let frames = read_gvret_capture(Cursor::new(data)).unwrap();
+
+        assert_eq!(frames.len(), 2);
+        assert_eq!(frames[0].frame_id, 0x100);
+        assert_eq!(frames[0].timestamp_us, u32::MAX as u64 - 9);
+        assert_eq!(frames[1].frame_id, 0x200);
+        assert_eq!(frames[1].timestamp_us, U32_MICROS_WRAP + 5);
+    }
+
+    #[test]
+    fn test_read_capture_across_chunk_boundary() {
+        let rec = record(1_000, 0x7FF, &[0xAA; 8]);
+        let data: Vec<u8> = rec.iter().copied().cycle().take(rec.len() * 10_000).collect();
+        assert!(data.len() > READ_CHUNK_SIZE);
+
+        let frames = read_gvret_capture(Cursor::new(data)).unwrap();
+
+        assert_eq!(frames.len(), 10_000);
+        assert!(frames.iter().all(|f| f.frame_id == 0x7FF && f.bytes == [0xAA; 8]));
+    }
+}
@@ -125,6 +125,12 @@ impl FrameCodec for GvretCodec {
             source_address: None,
             incomplete: None,
             direction: None,
+            dlc_raw: Some(dlc_nibble as u8),
+            is_rtr: false,
+            is_brs: false,
+            is_esi: false,
+            original_frame_id: None,
+            source_label: None,
         })
     }
 
@@ -170,9 +176,9 @@ impl FrameCodec for GvretCodec {
         buf.push(CMD_FRAME);
 
         // Frame ID (4 bytes, little-endian)
-        // Set bit 31 for extended ID
+        // Set bit 31 for extended ID (format follows is_extended, not the ID's magnitude)
         let frame_id = if frame.is_extended {
-            frame.frame_id | CAN_EFF_FLAG
+            (frame.frame_id & CAN_EFF_MASK) | CAN_EFF_FLAG
         } else {
             frame.frame_id & CAN_SFF_MASK
         };
@@ -242,6 +248,7 @@ mod tests {
             is_fd: false,
             is_brs: false,
             is_rtr: false,
+            rtr_dlc: None,
         };
 
         let encoded = GvretCodec::encode(&frame).unwrap();
@@ -267,6 +274,7 @@ mod tests {
             is_fd: false,
             is_brs: false,
             is_rtr: false,
+            rtr_dlc: None,
         };
 
         let encoded = GvretCodec::encode(&frame).unwrap();
@@ -290,6 +298,7 @@ mod tests {
             is_fd: false,
             is_brs: false,
             is_rtr: false,
+            rtr_dlc: None,
         };
 
         let encoded = GvretCodec::encode(&original).unwrap();
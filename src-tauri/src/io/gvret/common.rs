@@ -14,9 +14,14 @@
 //   - Standard (11-bit): Lower 11 bits, bit 31 = 0
 //   - Extended (29-bit): Lower 29 bits, bit 31 = 1 (0x80000000)
 
-use hex::ToHex;
+use std::collections::HashMap;
+use std::time::Duration;
 
-use crate::io::{now_us, CanTransmitFrame, FrameMessage, InterfaceTraits, Protocol, TemporalMode, TransmitResult};
+use crate::io::types::ByteEntry;
+use crate::io::{
+    normalize_frame_id, CanErrorPayload, CanTransmitFrame, DeviceBusConfig, DeviceClock, FrameMessage, InterfaceTraits, Protocol,
+    TemporalMode, TransmitResult,
+};
 
 // ============================================================================
 // Constants
@@ -28,6 +33,28 @@ pub const CAN_EFF_FLAG: u32 = 0x8000_0000;
 pub const CAN_SFF_MASK: u32 = 0x0000_07FF;
 /// Mask for extended (29-bit) CAN ID
 pub const CAN_EFF_MASK: u32 = 0x1FFF_FFFF;
+/// Error frame flag (bit 29 of frame ID). Stock GVRET firmware has no error
+/// frames and never sets it, as frame IDs are at most 29 bits. The WireTAP
+/// bridge (`tools/wiretap-server`) forwards SocketCAN error frames with their
+/// `can_id` intact, so the flag and the `can/error.h` layout come through:
+/// error class bits in the ID, controller status in data[1], error counters
+/// in data[6..8]. See `docs/gvret-protocol.md`.
+pub const GVRET_ERR_FLAG: u32 = 0x2000_0000;
+
+// Error classes (frame ID bits) and controller status (data[1]) of error frames
+const CAN_ERR_TX_TIMEOUT: u32 = 0x0000_0001;
+const CAN_ERR_LOSTARB: u32 = 0x0000_0002;
+const CAN_ERR_CRTL: u32 = 0x0000_0004;
+const CAN_ERR_PROT: u32 = 0x0000_0008;
+const CAN_ERR_ACK: u32 = 0x0000_0020;
+const CAN_ERR_BUSOFF: u32 = 0x0000_0040;
+const CAN_ERR_BUSERROR: u32 = 0x0000_0080;
+const CAN_ERR_RESTARTED: u32 = 0x0000_0100;
+const CAN_ERR_CNT: u32 = 0x0000_0200;
+const CAN_ERR_CRTL_RX_OVERFLOW: u8 = 0x01;
+const CAN_ERR_CRTL_TX_OVERFLOW: u8 = 0x02;
+const CAN_ERR_CRTL_WARNING: u8 = 0x04 | 0x08;
+const CAN_ERR_CRTL_PASSIVE: u8 = 0x10 | 0x20;
 
 /// GVRET sync byte
 pub const GVRET_SYNC: u8 = 0xF1;
@@ -39,10 +66,60 @@ pub const BINARY_MODE_ENABLE: [u8; 2] = [0xE7, 0xE7];
 pub const DEVICE_INFO_PROBE: [u8; 2] = [0xF1, 0x07];
 /// Number of buses query command
 pub const GVRET_CMD_NUMBUSES: [u8; 2] = [0xF1, 0x0C];
+/// CAN bus parameters query command (reply: `[0xF1][0x06]` + 5 bytes per bus for 2 buses)
+pub const GVRET_CMD_GET_CANPARAMS: [u8; 2] = [0xF1, 0x06];
+/// Query CAN FD settings (ESP32RET and later firmware only; older GVRET ignores it)
+pub const GVRET_CMD_GET_FD: [u8; 2] = [0xF1, 0x16];
+/// Keepalive command (device replies with `[0xF1][0x09][0xDE][0xAD]`)
+pub const GVRET_CMD_KEEPALIVE: [u8; 2] = [0xF1, 0x09];
+
+/// How long probes wait for a reply to the GET_FD query
+pub const FD_PROBE_TIMEOUT: Duration = Duration::from_millis(300);
 
 /// DLC to payload length mapping (CAN FD DLC codes)
 pub const DLC_LEN: [usize; 16] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 12, 16, 20, 24, 32, 48, 64];
 
+/// Read the keepalive interval from a profile's connection settings.
+///
+/// Uses `keepalive_interval_sec`; keepalives are disabled when the key is missing,
+/// zero, or not a positive number.
+pub fn keepalive_interval_from_connection(
+    connection: &HashMap<String, serde_json::Value>,
+) -> Option<Duration> {
+    connection
+        .get("keepalive_interval_sec")
+        .and_then(|v| v.as_f64().or_else(|| v.as_str().and_then(|s| s.trim().parse().ok())))
+        .filter(|secs| secs.is_finite() && *secs > 0.0)
+        .map(Duration::from_secs_f64)
+}
+
+/// Read the raw byte capture flag from a profile's connection settings.
+///
+/// With `capture_raw_bytes` set, the session keeps the device's byte stream in a
+/// Bytes buffer alongside the decoded frames.
+pub fn capture_raw_bytes_from_connection(connection: &HashMap<String, serde_json::Value>) -> bool {
+    connection
+        .get("capture_raw_bytes")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false)
+}
+
+/// Bus that raw bytes are reported on: the output bus of the first enabled mapping
+pub fn raw_bytes_bus(bus_mappings: &[BusMapping]) -> u8 {
+    bus_mappings
+        .iter()
+        .find(|m| m.enabled)
+        .map(|m| m.output_bus)
+        .unwrap_or(0)
+}
+
+/// Stamp one read's worth of device bytes for the session's Bytes buffer
+pub fn raw_byte_entries(data: &[u8], timestamp_us: u64, bus: u8) -> Vec<ByteEntry> {
+    data.iter()
+        .map(|&byte| ByteEntry { byte, timestamp_us, bus })
+        .collect()
+}
+
 // ============================================================================
 // Device Probing Helpers
 // ============================================================================
@@ -69,6 +146,31 @@ pub fn parse_numbuses_response(buffer: &[u8]) -> Option<u8> {
     None
 }
 
+/// Parse the payload of a CANPARAMS reply (the bytes after `[0xF1][0x06]`).
+///
+/// Each bus is 5 bytes: a flags byte (low nibble = enabled, high nibble =
+/// listen-only) followed by the bitrate as u32 LE.
+pub fn parse_canparams(payload: &[u8]) -> Vec<DeviceBusConfig> {
+    payload
+        .chunks_exact(5)
+        .enumerate()
+        .map(|(bus, chunk)| DeviceBusConfig {
+            bus: bus as u8,
+            enabled: chunk[0] & 0x0F != 0,
+            listen_only: chunk[0] & 0xF0 != 0,
+            bitrate: u32::from_le_bytes([chunk[1], chunk[2], chunk[3], chunk[4]]),
+        })
+        .collect()
+}
+
+/// Check whether a buffer contains a reply to the GET_FD query (`[0xF1][0x16]...`).
+///
+/// Only FD-capable firmware implements the command, so any reply means the
+/// device supports CAN FD.
+pub fn has_fd_reply(buffer: &[u8]) -> bool {
+    buffer.windows(2).any(|w| w[0] == GVRET_SYNC && w[1] == GVRET_CMD_GET_FD[1])
+}
+
 // ============================================================================
 // Device Info Types
 // ============================================================================
@@ -78,6 +180,55 @@ pub fn parse_numbuses_response(buffer: &[u8]) -> Option<u8> {
 pub struct GvretDeviceInfo {
     /// Number of CAN buses available on this device (1-5)
     pub bus_count: u8,
+    /// Whether the firmware answered the CAN FD settings query (None if not checked)
+    #[serde(default)]
+    pub supports_fd: Option<bool>,
+}
+
+/// Traffic seen on one device bus during a bus scan
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct BusScanResult {
+    /// Bus number as reported by the device (before bus mapping)
+    pub bus: u8,
+    pub frame_count: u64,
+    /// Number of distinct frame IDs seen
+    pub unique_ids: usize,
+    /// First few distinct IDs seen, in arrival order
+    pub sample_ids: Vec<u32>,
+}
+
+/// Distinct IDs kept per bus in `BusScanResult::sample_ids`
+const BUS_SCAN_SAMPLE_IDS: usize = 8;
+
+/// Accumulates per-bus traffic for a bus scan
+#[derive(Debug, Default)]
+pub struct BusScanTally {
+    buses: std::collections::BTreeMap<u8, (u64, Vec<u32>)>,
+}
+
+impl BusScanTally {
+    pub fn add(&mut self, frames: &[FrameMessage]) {
+        for frame in frames {
+            let (count, ids) = self.buses.entry(frame.bus).or_default();
+            *count += 1;
+            if !ids.contains(&frame.frame_id) {
+                ids.push(frame.frame_id);
+            }
+        }
+    }
+
+    /// Results for buses that carried traffic, ordered by bus number
+    pub fn finish(self) -> Vec<BusScanResult> {
+        self.buses
+            .into_iter()
+            .map(|(bus, (frame_count, ids))| BusScanResult {
+                bus,
+                frame_count,
+                unique_ids: ids.len(),
+                sample_ids: ids.into_iter().take(BUS_SCAN_SAMPLE_IDS).collect(),
+            })
+            .collect()
+    }
 }
 
 /// Configuration for mapping device buses to output buses
@@ -163,9 +314,7 @@ pub fn apply_bus_mapping(frame: &mut FrameMessage, mappings: &[BusMapping]) -> b
 ///     .collect()
 /// ```
 ///
-/// Available for drivers that produce `Vec<FrameMessage>` directly.
-/// For GVRET drivers, use `apply_bus_mappings_gvret` instead.
-#[allow(dead_code)]
+/// Used by gvret_tcp and gvret_usb after calling `parse_gvret_frames`.
 pub fn apply_bus_mappings_batch(
     frames: Vec<FrameMessage>,
     mappings: &[BusMapping],
@@ -182,22 +331,20 @@ pub fn apply_bus_mappings_batch(
         .collect()
 }
 
-/// Apply bus mappings to GVRET frames (which include raw hex strings).
-/// Returns only the FrameMessage portion, discarding the raw strings.
-///
-/// Used by gvret_tcp and gvret_usb after calling `parse_gvret_frames`.
-pub fn apply_bus_mappings_gvret(
-    frames: Vec<(FrameMessage, String)>,
+/// Apply bus mappings to CAN error reports, dropping those on disabled buses.
+pub fn apply_bus_mappings_to_errors(
+    errors: Vec<CanErrorPayload>,
     mappings: &[BusMapping],
-) -> Vec<FrameMessage> {
-    frames
+) -> Vec<CanErrorPayload> {
+    errors
         .into_iter()
-        .filter_map(|(mut frame, _raw)| {
-            if apply_bus_mapping(&mut frame, mappings) {
-                Some(frame)
-            } else {
-                None
+        .filter_map(|mut error| match mappings.iter().find(|m| m.device_bus == error.bus) {
+            Some(mapping) if !mapping.enabled => None,
+            Some(mapping) => {
+                error.bus = mapping.output_bus;
+                Some(error)
             }
+            None => Some(error),
         })
         .collect()
 }
@@ -208,11 +355,56 @@ pub fn apply_bus_mappings_gvret(
 
 /// Parse GVRET binary frames from a buffer
 ///
-/// Returns a list of (FrameMessage, raw_hex_string) tuples.
-/// Consumes parsed bytes from the buffer.
-pub fn parse_gvret_frames(buffer: &mut Vec<u8>) -> Vec<(FrameMessage, String)> {
+/// Consumes parsed bytes from the buffer. Frames are stamped by `clock`
+/// from the device's 32-bit microsecond timestamp. A CANPARAMS reply found in
+/// the stream is stored in `bus_params`. Error frames and resync bytes are
+/// dropped; streaming readers use `parse_gvret_frames_each` to keep them.
+pub fn parse_gvret_frames(
+    buffer: &mut Vec<u8>,
+    clock: &mut DeviceClock,
+    bus_params: &mut Option<Vec<DeviceBusConfig>>,
+) -> Vec<FrameMessage> {
+    let mut out = Vec::new();
+    parse_gvret_frames_each(buffer, clock, bus_params, |frame, _raw| out.push(frame), |_| {}, |_| {});
+    out
+}
+
+/// Like `parse_gvret_frames`, but also returns each frame's raw bytes as hex.
+///
+/// Hex-encoding allocates a String per frame, so only use this when the raw
+/// bytes are actually wanted (debug output).
+#[cfg(test)]
+pub fn parse_gvret_frames_with_raw(
+    buffer: &mut Vec<u8>,
+    clock: &mut DeviceClock,
+    bus_params: &mut Option<Vec<DeviceBusConfig>>,
+) -> Vec<(FrameMessage, String)> {
+    use hex::ToHex;
+
     let mut out = Vec::new();
+    parse_gvret_frames_each(
+        buffer,
+        clock,
+        bus_params,
+        |frame, raw| out.push((frame, raw.encode_hex::<String>())),
+        |_| {},
+        |_| {},
+    );
+    out
+}
 
+/// Parse GVRET binary frames from a buffer, as `parse_gvret_frames` does.
+/// Calls `on_frame` with each frame and the raw bytes it was parsed from,
+/// `on_error` with each decoded error frame, and `on_skip` with bytes
+/// discarded while resyncing.
+pub fn parse_gvret_frames_each(
+    buffer: &mut Vec<u8>,
+    clock: &mut DeviceClock,
+    bus_params: &mut Option<Vec<DeviceBusConfig>>,
+    mut on_frame: impl FnMut(FrameMessage, &[u8]),
+    mut on_error: impl FnMut(CanErrorPayload),
+    mut on_skip: impl FnMut(&[u8]),
+) {
     loop {
         // Find sync byte 0xF1
         let pos = match buffer.iter().position(|b| *b == GVRET_SYNC) {
@@ -220,6 +412,7 @@ pub fn parse_gvret_frames(buffer: &mut Vec<u8>) -> Vec<(FrameMessage, String)> {
             None => {
                 // Keep buffer bounded if sync is lost
                 if buffer.len() > 1024 {
+                    on_skip(buffer);
                     buffer.clear();
                 }
                 break;
@@ -228,6 +421,7 @@ pub fn parse_gvret_frames(buffer: &mut Vec<u8>) -> Vec<(FrameMessage, String)> {
 
         // Discard bytes before sync
         if pos > 0 {
+            on_skip(&buffer[..pos]);
             buffer.drain(0..pos);
         }
 
@@ -238,7 +432,7 @@ pub fn parse_gvret_frames(buffer: &mut Vec<u8>) -> Vec<(FrameMessage, String)> {
 
         let op = buffer[1];
 
-        // Control replies we ignore/skip
+        // Control replies we skip (CANPARAMS is captured into `bus_params`)
         let ctrl_len = match op {
             0x01 => Some(6),  // TIMEBASE: F1 01 <4>
             0x09 => Some(4),  // KEEPALIVE: F1 09 <2>
@@ -252,12 +446,16 @@ pub fn parse_gvret_frames(buffer: &mut Vec<u8>) -> Vec<(FrameMessage, String)> {
             if buffer.len() < len {
                 break;
             }
+            if op == GVRET_CMD_GET_CANPARAMS[1] {
+                *bus_params = Some(parse_canparams(&buffer[2..len]));
+            }
             buffer.drain(0..len);
             continue;
         }
 
         // Not a frame command - resync
         if op != GVRET_CMD_FRAME {
+            on_skip(&buffer[..1]);
             buffer.drain(0..1);
             continue;
         }
@@ -290,18 +488,28 @@ pub fn parse_gvret_frames(buffer: &mut Vec<u8>) -> Vec<(FrameMessage, String)> {
             Vec::new()
         };
 
-        let is_ext = (can_id & CAN_EFF_FLAG) != 0;
-        let arb_id = can_id & if is_ext { CAN_EFF_MASK } else { CAN_SFF_MASK };
-        let is_fd = payload_len > 8;
         let bus = (bus_dlc >> 4) & 0x0F;
 
-        // Raw hex for debugging
-        let frame_bytes = buffer[..total_len].to_vec().encode_hex::<String>();
+        // Device timestamp (LE microseconds); the clock decides whether to use it
+        let device_ts = u32::from_le_bytes(buffer[2..6].try_into().unwrap_or([0; 4]));
+        let ts_us = clock.timestamp(Some(device_ts as u64));
 
-        // Use host UNIX time in microseconds
-        let ts_us = now_us();
+        if can_id & GVRET_ERR_FLAG != 0 {
+            on_error(decode_error_frame(can_id & CAN_EFF_MASK, &data, bus, ts_us));
+            buffer.drain(0..total_len);
+            continue;
+        }
+
+        // A standard ID above 0x7FF is marked extended; bit 30 set would put the
+        // ID past 29 bits, so the record is malformed and skipped like garbage
+        let Some((arb_id, is_ext)) = normalize_frame_id(can_id & !CAN_EFF_FLAG, can_id & CAN_EFF_FLAG != 0) else {
+            on_skip(&buffer[..total_len]);
+            buffer.drain(0..total_len);
+            continue;
+        };
+        let is_fd = payload_len > 8;
 
-        out.push((
+        on_frame(
             FrameMessage {
                 protocol: "can".to_string(),
                 timestamp_us: ts_us,
@@ -314,14 +522,68 @@ pub fn parse_gvret_frames(buffer: &mut Vec<u8>) -> Vec<(FrameMessage, String)> {
                 source_address: None,
                 incomplete: None,
                 direction: None, // Received frames don't have direction set
+                dlc_raw: Some(dlc_nibble as u8),
+                is_rtr: false,
+                is_brs: false,
+                is_esi: false,
+                original_frame_id: None,
+                source_label: None,
             },
-            frame_bytes,
-        ));
+            &buffer[..total_len],
+        );
 
         buffer.drain(0..total_len);
     }
+}
 
-    out
+/// Decode an error frame's class bits and data into a `can-error` report.
+fn decode_error_frame(class: u32, data: &[u8], bus: u8, timestamp_us: u64) -> CanErrorPayload {
+    let mut kinds = Vec::new();
+    if class & (CAN_ERR_TX_TIMEOUT | CAN_ERR_ACK) != 0 {
+        kinds.push("tx_failed");
+    }
+    if class & CAN_ERR_LOSTARB != 0 {
+        kinds.push("arbitration_lost");
+    }
+    if class & CAN_ERR_CRTL != 0 {
+        let status = data.get(1).copied().unwrap_or(0);
+        if status & CAN_ERR_CRTL_RX_OVERFLOW != 0 {
+            kinds.push("rx_overflow");
+        }
+        if status & CAN_ERR_CRTL_TX_OVERFLOW != 0 {
+            kinds.push("tx_overflow");
+        }
+        if status & CAN_ERR_CRTL_WARNING != 0 {
+            kinds.push("bus_warning");
+        }
+        if status & CAN_ERR_CRTL_PASSIVE != 0 {
+            kinds.push("bus_passive");
+        }
+    }
+    if class & CAN_ERR_PROT != 0 {
+        kinds.push("protocol_error");
+    }
+    if class & CAN_ERR_BUSOFF != 0 {
+        kinds.push("bus_off");
+    }
+    if class & CAN_ERR_BUSERROR != 0 {
+        kinds.push("bus_error");
+    }
+    if class & CAN_ERR_RESTARTED != 0 {
+        kinds.push("restarted");
+    }
+    if kinds.is_empty() {
+        kinds.push("unknown");
+    }
+
+    let counters = (class & CAN_ERR_CNT != 0 && data.len() >= 8).then(|| (data[6], data[7]));
+    CanErrorPayload {
+        bus,
+        timestamp_us,
+        kinds: kinds.into_iter().map(String::from).collect(),
+        tx_error_count: counters.map(|(tx, _)| tx),
+        rx_error_count: counters.map(|(_, rx)| rx),
+    }
 }
 
 // ============================================================================
@@ -339,9 +601,10 @@ pub fn encode_gvret_frame(frame: &CanTransmitFrame) -> Vec<u8> {
     buf.push(GVRET_CMD_FRAME);
 
     // Frame ID (4 bytes, little-endian)
-    // Set bit 31 for extended ID
+    // Set bit 31 for extended ID. The format follows is_extended, not the
+    // ID's magnitude, so 0x123 can be sent as either a standard or extended frame.
     let frame_id = if frame.is_extended {
-        frame.frame_id | CAN_EFF_FLAG
+        (frame.frame_id & CAN_EFF_MASK) | CAN_EFF_FLAG
     } else {
         frame.frame_id & CAN_SFF_MASK // Mask to 11 bits for standard
     };
@@ -402,6 +665,7 @@ pub fn validate_gvret_frame(frame: &CanTransmitFrame) -> Result<(), TransmitResu
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::io::test_support::can_frame;
 
     #[test]
     fn test_encode_standard_frame() {
@@ -413,6 +677,7 @@ mod tests {
             is_fd: false,
             is_brs: false,
             is_rtr: false,
+            rtr_dlc: None,
         };
 
         let encoded = encode_gvret_frame(&frame);
@@ -439,6 +704,7 @@ mod tests {
             is_fd: false,
             is_brs: false,
             is_rtr: false,
+            rtr_dlc: None,
         };
 
         let encoded = encode_gvret_frame(&frame);
@@ -456,6 +722,31 @@ mod tests {
         assert_eq!(&encoded[8..], &[0xAA, 0xBB]);
     }
 
+    #[test]
+    fn test_encode_small_id_both_formats() {
+        let mut frame = CanTransmitFrame {
+            frame_id: 0x123,
+            data: vec![],
+            bus: 0,
+            is_extended: true,
+            is_fd: false,
+            is_brs: false,
+            is_rtr: false,
+            rtr_dlc: None,
+        };
+        // Extended: full 29-bit ID with bit 31 set
+        assert_eq!(&encode_gvret_frame(&frame)[2..6], &[0x23, 0x01, 0x00, 0x80]);
+
+        frame.is_extended = false;
+        assert_eq!(&encode_gvret_frame(&frame)[2..6], &[0x23, 0x01, 0x00, 0x00]);
+
+        // Bits above the format's width don't leak into the flag bits
+        frame.frame_id = 0x6000_0123;
+        assert_eq!(&encode_gvret_frame(&frame)[2..6], &[0x23, 0x01, 0x00, 0x00]);
+        frame.is_extended = true;
+        assert_eq!(&encode_gvret_frame(&frame)[2..6], &[0x23, 0x01, 0x00, 0x80]);
+    }
+
     #[test]
     fn test_encode_empty_frame() {
         let frame = CanTransmitFrame {
@@ -466,6 +757,7 @@ mod tests {
             is_fd: false,
             is_brs: false,
             is_rtr: false,
+            rtr_dlc: None,
         };
 
         let encoded = encode_gvret_frame(&frame);
@@ -492,10 +784,10 @@ mod tests {
             0xAA, 0xBB, 0xCC, 0xDD, // Data
         ];
 
-        let frames = parse_gvret_frames(&mut buffer);
+        let frames = parse_gvret_frames(&mut buffer, &mut DeviceClock::default(), &mut None);
 
         assert_eq!(frames.len(), 1);
-        let (frame, _) = &frames[0];
+        let frame = &frames[0];
         assert_eq!(frame.frame_id, 0x123);
         assert_eq!(frame.dlc, 4);
         assert_eq!(frame.bytes, vec![0xAA, 0xBB, 0xCC, 0xDD]);
@@ -514,15 +806,127 @@ mod tests {
             0x11, 0x22, // Data
         ];
 
-        let frames = parse_gvret_frames(&mut buffer);
+        let frames = parse_gvret_frames(&mut buffer, &mut DeviceClock::default(), &mut None);
 
         assert_eq!(frames.len(), 1);
-        let (frame, _) = &frames[0];
+        let frame = &frames[0];
         assert_eq!(frame.frame_id, 0x12345678);
         assert!(frame.is_extended);
         assert_eq!(frame.bytes, vec![0x11, 0x22]);
     }
 
+    #[test]
+    fn test_parse_out_of_range_ids() {
+        // Standard frame wider than 11 bits, extended frame with bit 30 set
+        let mut buffer = vec![
+            0xF1, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x09, 0x00, 0x00, // ID 0x900 LE
+            0x01, 0xAA,
+            0xF1, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x23, 0x01, 0x00, 0xC0, // ID 0x40000123 | 0x80000000 LE
+            0x01, 0xBB,
+        ];
+        let mut frames = Vec::new();
+        let mut skipped = Vec::new();
+
+        parse_gvret_frames_each(
+            &mut buffer,
+            &mut DeviceClock::default(),
+            &mut None,
+            |frame, _raw| frames.push(frame),
+            |_| {},
+            |bytes| skipped.extend_from_slice(bytes),
+        );
+
+        assert_eq!(frames.len(), 1);
+        assert_eq!(frames[0].frame_id, 0x900);
+        assert!(frames[0].is_extended);
+        assert_eq!(skipped.len(), 12);
+    }
+
+    #[test]
+    fn test_parse_error_frames_reported_separately() {
+        // Controller error (rx overflow + warning, counters) on bus 1, then a data frame
+        let mut buffer = vec![
+            0xF1, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x04, 0x02, 0x00, 0x20, // ID GVRET_ERR_FLAG | CNT | CRTL LE
+            0x18, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x60, 0x81,
+            0xF1, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x23, 0x01, 0x00, 0x00,
+            0x01, 0xAA,
+        ];
+        let mut frames = Vec::new();
+        let mut errors = Vec::new();
+        let mut skipped = Vec::new();
+
+        parse_gvret_frames_each(
+            &mut buffer,
+            &mut DeviceClock::default(),
+            &mut None,
+            |frame, _raw| frames.push(frame),
+            |error| errors.push(error),
+            |bytes| skipped.extend_from_slice(bytes),
+        );
+
+        assert_eq!(frames.len(), 1);
+        assert_eq!(frames[0].frame_id, 0x123);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].bus, 1);
+        assert_eq!(errors[0].kinds, vec!["rx_overflow", "bus_warning"]);
+        assert_eq!(errors[0].tx_error_count, Some(0x60));
+        assert_eq!(errors[0].rx_error_count, Some(0x81));
+        assert!(skipped.is_empty());
+
+        let mapped = apply_bus_mappings_to_errors(
+            errors,
+            &[BusMapping { device_bus: 1, enabled: true, output_bus: 3, ..Default::default() }],
+        );
+        assert_eq!(mapped[0].bus, 3);
+    }
+
+    #[test]
+    fn test_parse_reports_resync_bytes() {
+        #[rustfmt::skip]
+        let mut buffer = vec![
+            0x55, 0x66,       // noise before sync
+            0xF1, 0x42,       // unknown opcode
+            0xF1, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x23, 0x01, 0x00, 0x00,
+            0x01, 0xAA,
+        ];
+        let mut frames = Vec::new();
+        let mut skipped = Vec::new();
+
+        parse_gvret_frames_each(
+            &mut buffer,
+            &mut DeviceClock::default(),
+            &mut None,
+            |frame, _raw| frames.push(frame),
+            |_| {},
+            |bytes| skipped.extend_from_slice(bytes),
+        );
+
+        assert_eq!(frames.len(), 1);
+        assert_eq!(skipped, vec![0x55, 0x66, 0xF1, 0x42]);
+    }
+
+    #[test]
+    fn test_bus_scan_tally() {
+        let frame = |bus: u8, frame_id: u32| FrameMessage { bus, ..can_frame(frame_id, 0, Vec::new()) };
+        let mut tally = BusScanTally::default();
+        tally.add(&[frame(2, 0x100), frame(0, 0x7E8), frame(2, 0x100)]);
+        tally.add(&(0..20).map(|i| frame(2, 0x200 + i)).collect::<Vec<_>>());
+
+        let results = tally.finish();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0], BusScanResult { bus: 0, frame_count: 1, unique_ids: 1, sample_ids: vec![0x7E8] });
+        assert_eq!(results[1].bus, 2);
+        assert_eq!(results[1].frame_count, 23);
+        assert_eq!(results[1].unique_ids, 21);
+        assert_eq!(results[1].sample_ids.len(), 8);
+        assert_eq!(results[1].sample_ids[0], 0x100);
+    }
+
     #[test]
     fn test_parse_skips_control_frames() {
         // Mix of control frames and data frame
@@ -535,13 +939,81 @@ mod tests {
             0xFF, // Data
         ];
 
-        let frames = parse_gvret_frames(&mut buffer);
+        let frames = parse_gvret_frames(&mut buffer, &mut DeviceClock::default(), &mut None);
 
         assert_eq!(frames.len(), 1);
-        let (frame, _) = &frames[0];
+        let frame = &frames[0];
         assert_eq!(frame.frame_id, 0x7F);
     }
 
+    #[test]
+    fn test_parse_canparams_reply() {
+        let mut buffer = vec![
+            0xF1, 0x06, // CANPARAMS reply
+            0x01, 0x20, 0xA1, 0x07, 0x00, // Bus 0: enabled, 500000
+            0x10, 0x40, 0x42, 0x0F, 0x00, // Bus 1: disabled, listen-only, 1000000
+            0xF1, 0x00, // Data frame start
+            0x00, 0x00, 0x00, 0x00, // Timestamp
+            0x7F, 0x00, 0x00, 0x00, // ID 0x7F
+            0x01, // Bus 0, DLC 1
+            0xFF, // Data
+        ];
+        let mut bus_params = None;
+
+        let frames = parse_gvret_frames(&mut buffer, &mut DeviceClock::default(), &mut bus_params);
+
+        assert_eq!(frames.len(), 1);
+        assert_eq!(
+            bus_params,
+            Some(vec![
+                DeviceBusConfig { bus: 0, enabled: true, listen_only: false, bitrate: 500_000 },
+                DeviceBusConfig { bus: 1, enabled: false, listen_only: true, bitrate: 1_000_000 },
+            ])
+        );
+    }
+
+    #[test]
+    fn test_parse_with_raw() {
+        let mut buffer = vec![
+            0xF1, 0x00, // Sync + command
+            0x00, 0x00, 0x00, 0x00, // Timestamp
+            0x7F, 0x00, 0x00, 0x00, // ID 0x7F
+            0x01, // Bus 0, DLC 1
+            0xFF, // Data
+        ];
+
+        let frames = parse_gvret_frames_with_raw(&mut buffer, &mut DeviceClock::default(), &mut None);
+
+        assert_eq!(frames.len(), 1);
+        assert_eq!(frames[0].0.frame_id, 0x7F);
+        assert_eq!(frames[0].1, "f100000000007f00000001ff");
+    }
+
+    /// Compares parsing with and without raw hex on a large buffer.
+    /// Run with `cargo test bench_parse_raw_hex -- --ignored --nocapture`.
+    #[test]
+    #[ignore]
+    fn bench_parse_raw_hex() {
+        let frame = [
+            0xF1, 0x00, 0x00, 0x00, 0x00, 0x00, 0x23, 0x01, 0x00, 0x00, 0x08, 1, 2, 3, 4, 5, 6, 7, 8,
+        ];
+        let input: Vec<u8> = frame.iter().copied().cycle().take(frame.len() * 10_000).collect();
+
+        let mut buffer = input.clone();
+        let start = std::time::Instant::now();
+        let frames = parse_gvret_frames(&mut buffer, &mut DeviceClock::default(), &mut None);
+        let plain = start.elapsed();
+        assert_eq!(frames.len(), 10_000);
+
+        let mut buffer = input;
+        let start = std::time::Instant::now();
+        let frames = parse_gvret_frames_with_raw(&mut buffer, &mut DeviceClock::default(), &mut None);
+        let with_raw = start.elapsed();
+        assert_eq!(frames.len(), 10_000);
+
+        tlog!("[gvret] parse_gvret_frames: {:?}, with raw hex: {:?}", plain, with_raw);
+    }
+
     #[test]
     fn test_parse_incomplete_frame() {
         // Incomplete frame - not enough bytes
@@ -550,7 +1022,7 @@ mod tests {
             0x00, 0x00, // Only 2 timestamp bytes
         ];
 
-        let frames = parse_gvret_frames(&mut buffer);
+        let frames = parse_gvret_frames(&mut buffer, &mut DeviceClock::default(), &mut None);
 
         assert!(frames.is_empty());
         assert_eq!(buffer.len(), 4); // Buffer should be preserved
@@ -566,6 +1038,7 @@ mod tests {
             is_fd: false,
             is_brs: false,
             is_rtr: false,
+            rtr_dlc: None,
         };
 
         let result = validate_gvret_frame(&frame);
@@ -582,6 +1055,7 @@ mod tests {
             is_fd: true,
             is_brs: false,
             is_rtr: false,
+            rtr_dlc: None,
         };
 
         let result = validate_gvret_frame(&frame);
@@ -598,6 +1072,7 @@ mod tests {
             is_fd: false,
             is_brs: false,
             is_rtr: false,
+            rtr_dlc: None,
         };
 
         let result = validate_gvret_frame(&frame);
@@ -614,6 +1089,7 @@ mod tests {
             is_fd: false,
             is_brs: false,
             is_rtr: false,
+            rtr_dlc: None,
         };
 
         let result = validate_gvret_frame(&frame);
@@ -661,4 +1137,46 @@ mod tests {
         let buffer = vec![0xF1, 0x0C];
         assert_eq!(parse_numbuses_response(&buffer), None);
     }
+
+    #[test]
+    fn test_keepalive_interval_from_connection() {
+        let mut connection = HashMap::new();
+        assert_eq!(keepalive_interval_from_connection(&connection), None);
+
+        connection.insert("keepalive_interval_sec".to_string(), serde_json::json!(0));
+        assert_eq!(keepalive_interval_from_connection(&connection), None);
+
+        connection.insert("keepalive_interval_sec".to_string(), serde_json::json!("30"));
+        assert_eq!(keepalive_interval_from_connection(&connection), Some(Duration::from_secs(30)));
+
+        connection.insert("keepalive_interval_sec".to_string(), serde_json::json!(2.5));
+        assert_eq!(keepalive_interval_from_connection(&connection), Some(Duration::from_millis(2500)));
+    }
+
+    #[test]
+    fn test_raw_byte_capture() {
+        let mut connection = HashMap::new();
+        assert!(!capture_raw_bytes_from_connection(&connection));
+        connection.insert("capture_raw_bytes".to_string(), serde_json::json!(true));
+        assert!(capture_raw_bytes_from_connection(&connection));
+
+        let mappings = [
+            BusMapping { device_bus: 0, enabled: false, output_bus: 1, ..Default::default() },
+            BusMapping { device_bus: 1, enabled: true, output_bus: 4, ..Default::default() },
+        ];
+        assert_eq!(raw_bytes_bus(&mappings), 4);
+        assert_eq!(raw_bytes_bus(&[]), 0);
+
+        let entries = raw_byte_entries(&[0xF1, 0x00], 1_000, 4);
+        assert_eq!(entries.len(), 2);
+        assert_eq!((entries[1].byte, entries[1].timestamp_us, entries[1].bus), (0x00, 1_000, 4));
+    }
+
+    #[test]
+    fn test_has_fd_reply() {
+        assert!(has_fd_reply(&[0xF1, 0x16, 0x01, 0x00]));
+        assert!(has_fd_reply(&[0xF1, 0x09, 0xDE, 0xAD, 0xF1, 0x16]));
+        assert!(!has_fd_reply(&[0xF1, 0x0C, 0x01]));
+        assert!(!has_fd_reply(&[0xF1]));
+    }
 }
@@ -4,6 +4,7 @@
 //
 // Protocol reference: https://github.com/collin80/GVRET
 
+mod capture;
 pub mod codec;
 mod common;
 mod tcp;
@@ -11,19 +12,22 @@ mod tcp;
 mod usb;
 
 // Re-export public items
+pub use capture::parse_gvret_binary_file;
 pub use codec::GvretCodec;
-pub use common::{BusMapping, GvretDeviceInfo};
-pub use tcp::probe_gvret_tcp;
+pub use common::{BusMapping, BusScanResult, GvretDeviceInfo};
+pub use tcp::{probe_gvret_tcp, scan_gvret_tcp_buses};
 #[cfg(not(target_os = "ios"))]
-pub use usb::probe_gvret_usb;
+pub use usb::{probe_gvret_usb, scan_gvret_usb_buses};
 
 // Internal items used by multi_source and other drivers
 #[cfg(not(target_os = "ios"))]
-pub(crate) use common::apply_bus_mapping;
-pub(crate) use common::{encode_gvret_frame, validate_gvret_frame};
+pub(crate) use common::{apply_bus_mapping, apply_bus_mappings_to_errors};
+pub(crate) use common::{
+    capture_raw_bytes_from_connection, encode_gvret_frame, keepalive_interval_from_connection, validate_gvret_frame,
+};
 // parse_gvret_frames exported for tests
 #[cfg(test)]
 pub(crate) use common::parse_gvret_frames;
-pub(crate) use tcp::run_source as run_gvret_tcp_source;
+pub(crate) use tcp::{run_source as run_gvret_tcp_source, TcpSocketOptions};
 #[cfg(not(target_os = "ios"))]
 pub(crate) use usb::run_source as run_gvret_usb_source;
@@ -2,20 +2,73 @@
 //
 // GVRET TCP protocol implementation for streaming CAN data over TCP.
 
+use std::collections::HashMap;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{mpsc as std_mpsc, Arc};
 use std::time::Duration;
+use socket2::{SockRef, TcpKeepalive};
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
 use tokio::net::TcpStream;
 use tokio::sync::mpsc;
 
 use crate::io::error::IoError;
 use crate::io::types::{SourceMessage, TransmitRequest};
+use crate::io::{now_us, DeviceClock, DeviceConfigPayload, ParseErrorEntry, TimestampSource, U32_MICROS_WRAP};
 use super::common::{
-    apply_bus_mappings_gvret, parse_gvret_frames, parse_numbuses_response, BusMapping,
-    BINARY_MODE_ENABLE, DEVICE_INFO_PROBE, GVRET_CMD_NUMBUSES, GvretDeviceInfo,
+    apply_bus_mappings_batch, apply_bus_mappings_to_errors, has_fd_reply, parse_gvret_frames,
+    parse_gvret_frames_each, parse_numbuses_response, raw_byte_entries, raw_bytes_bus,
+    BusMapping, BusScanResult, BusScanTally, BINARY_MODE_ENABLE, DEVICE_INFO_PROBE, GVRET_CMD_GET_FD,
+    GVRET_CMD_KEEPALIVE, GVRET_CMD_GET_CANPARAMS, GVRET_CMD_NUMBUSES, GvretDeviceInfo, FD_PROBE_TIMEOUT,
 };
 
+// ============================================================================
+// Socket Options
+// ============================================================================
+
+/// Idle time before the OS starts sending TCP keepalive probes
+const TCP_KEEPALIVE_TIME: Duration = Duration::from_secs(10);
+/// Interval between unanswered TCP keepalive probes
+const TCP_KEEPALIVE_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Socket options for the GVRET TCP connection
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TcpSocketOptions {
+    /// Disable Nagle buffering so small transmits and replies aren't held back
+    pub nodelay: bool,
+    /// Let the OS probe an idle connection so a dead bridge is detected
+    pub keepalive: bool,
+}
+
+impl Default for TcpSocketOptions {
+    fn default() -> Self {
+        Self { nodelay: true, keepalive: true }
+    }
+}
+
+impl TcpSocketOptions {
+    /// Read `tcp_nodelay` and `tcp_keepalive` from a profile's connection settings
+    /// (both default to on).
+    pub fn from_connection(connection: &HashMap<String, serde_json::Value>) -> Self {
+        let flag = |key: &str| connection.get(key).and_then(|v| v.as_bool()).unwrap_or(true);
+        Self { nodelay: flag("tcp_nodelay"), keepalive: flag("tcp_keepalive") }
+    }
+
+    /// Apply the options to a connected stream. Failures are logged, not fatal.
+    fn apply(&self, stream: &TcpStream, source_idx: usize) {
+        if let Err(e) = stream.set_nodelay(self.nodelay) {
+            tlog!("[gvret_tcp] Source {} failed to set TCP_NODELAY: {}", source_idx, e);
+        }
+        if self.keepalive {
+            let keepalive = TcpKeepalive::new()
+                .with_time(TCP_KEEPALIVE_TIME)
+                .with_interval(TCP_KEEPALIVE_INTERVAL);
+            if let Err(e) = SockRef::from(stream).set_tcp_keepalive(&keepalive) {
+                tlog!("[gvret_tcp] Source {} failed to enable TCP keepalive: {}", source_idx, e);
+            }
+        }
+    }
+}
+
 // ============================================================================
 // Device Probing
 // ============================================================================
@@ -101,11 +154,12 @@ pub async fn probe_gvret_tcp(
 
                 // Check for NUMBUSES response
                 if let Some(bus_count) = parse_numbuses_response(&buf[..total_read]) {
+                    let supports_fd = probe_fd_support(&mut stream).await;
                     tlog!(
-                        "[probe_gvret_tcp] SUCCESS: Device at {}:{} has {} buses available",
-                        host, port, bus_count
+                        "[probe_gvret_tcp] SUCCESS: Device at {}:{} has {} buses available (FD: {:?})",
+                        host, port, bus_count, supports_fd
                     );
-                    return Ok(GvretDeviceInfo { bus_count });
+                    return Ok(GvretDeviceInfo { bus_count, supports_fd });
                 }
 
                 // If we've read enough data without finding the response, give up
@@ -124,7 +178,95 @@ pub async fn probe_gvret_tcp(
 
     // If we didn't get a response, assume 1 bus (safer default)
     tlog!("[probe_gvret_tcp] No NUMBUSES response received, defaulting to 1 bus");
-    Ok(GvretDeviceInfo { bus_count: 1 })
+    Ok(GvretDeviceInfo { bus_count: 1, supports_fd: None })
+}
+
+/// Send the GET_FD query and wait briefly for a reply.
+///
+/// Returns `Some(true)` if the firmware answered, `Some(false)` if it stayed
+/// silent (classic GVRET ignores unknown commands), or `None` if the query
+/// could not be sent.
+async fn probe_fd_support(stream: &mut TcpStream) -> Option<bool> {
+    stream.write_all(&GVRET_CMD_GET_FD).await.ok()?;
+    stream.flush().await.ok()?;
+
+    let mut buf = vec![0u8; 256];
+    let mut total_read = 0;
+    let deadline = tokio::time::Instant::now() + FD_PROBE_TIMEOUT;
+
+    while total_read < buf.len() {
+        let remaining = deadline.saturating_duration_since(tokio::time::Instant::now());
+        if remaining.is_zero() {
+            break;
+        }
+        match tokio::time::timeout(remaining, stream.read(&mut buf[total_read..])).await {
+            Ok(Ok(0)) | Ok(Err(_)) | Err(_) => break,
+            Ok(Ok(n)) => {
+                total_read += n;
+                if has_fd_reply(&buf[..total_read]) {
+                    return Some(true);
+                }
+            }
+        }
+    }
+
+    Some(false)
+}
+
+/// Listen to a GVRET TCP device for `duration_ms` and report which buses carry traffic.
+///
+/// Buses that stay silent are left out of the result. The connection is closed afterwards.
+pub async fn scan_gvret_tcp_buses(
+    host: &str,
+    port: u16,
+    timeout_sec: f64,
+    duration_ms: u64,
+) -> Result<Vec<BusScanResult>, IoError> {
+    let device = format!("gvret_tcp({}:{})", host, port);
+
+    let mut stream = match tokio::time::timeout(
+        Duration::from_secs_f64(timeout_sec),
+        TcpStream::connect((host, port)),
+    )
+    .await
+    {
+        Ok(Ok(s)) => s,
+        Ok(Err(e)) => return Err(IoError::connection(&device, e.to_string())),
+        Err(_) => return Err(IoError::timeout(&device, "connect")),
+    };
+
+    stream
+        .write_all(&BINARY_MODE_ENABLE)
+        .await
+        .map_err(|e| IoError::protocol(&device, format!("enable binary mode: {}", e)))?;
+
+    tlog!("[scan_gvret_tcp] Listening on {}:{} for {}ms", host, port, duration_ms);
+
+    let mut tally = BusScanTally::default();
+    let mut buffer = Vec::new();
+    let mut clock = DeviceClock::default();
+    let mut read_buf = vec![0u8; 4096];
+    let deadline = tokio::time::Instant::now() + Duration::from_millis(duration_ms);
+
+    loop {
+        let remaining = deadline.saturating_duration_since(tokio::time::Instant::now());
+        if remaining.is_zero() {
+            break;
+        }
+        match tokio::time::timeout(remaining, stream.read(&mut read_buf)).await {
+            Ok(Ok(0)) => break, // EOF
+            Ok(Ok(n)) => {
+                buffer.extend_from_slice(&read_buf[..n]);
+                tally.add(&parse_gvret_frames(&mut buffer, &mut clock, &mut None));
+            }
+            Ok(Err(e)) => return Err(IoError::read(&device, e.to_string())),
+            Err(_) => break, // Scan window elapsed
+        }
+    }
+
+    let results = tally.finish();
+    tlog!("[scan_gvret_tcp] {}:{} has traffic on {} bus(es)", host, port, results.len());
+    Ok(results)
 }
 
 // ============================================================================
@@ -132,11 +274,27 @@ pub async fn probe_gvret_tcp(
 // ============================================================================
 
 /// Run GVRET TCP source and send frames to merge task
+///
+/// When `keepalive_interval` is set, a GVRET keepalive command is written whenever
+/// nothing has been sent for that long, so servers that drop idle clients keep the
+/// connection open on quiet buses. Frames are stamped according to `timestamp_source`
+/// using the device's microsecond timestamp. With `capture_raw_bytes`, every byte read
+/// is also sent on as a `SourceMessage::Bytes` entry stamped with the host clock.
+///
+/// With `passive_attach`, no setup or probe commands (binary mode, device info,
+/// bus parameters, keepalives) are written: the device must already be streaming
+/// in binary mode.
+#[allow(clippy::too_many_arguments)]
 pub async fn run_source(
     source_idx: usize,
     host: String,
     port: u16,
     timeout_sec: f64,
+    socket_options: TcpSocketOptions,
+    passive_attach: bool,
+    keepalive_interval: Option<Duration>,
+    capture_raw_bytes: bool,
+    timestamp_source: TimestampSource,
     bus_mappings: Vec<BusMapping>,
     stop_flag: Arc<AtomicBool>,
     tx: mpsc::Sender<SourceMessage>,
@@ -170,26 +328,32 @@ pub async fn run_source(
         }
     };
 
+    socket_options.apply(&stream, source_idx);
+
     // Split into read/write halves
     let (mut read_half, mut write_half) = stream.into_split();
 
-    // Enable binary mode
-    if let Err(e) = write_half.write_all(&BINARY_MODE_ENABLE).await {
-        let _ = tx
-            .send(SourceMessage::Error(
-                source_idx,
-                format!("Failed to enable binary mode: {}", e),
-            ))
-            .await;
-        return;
-    }
-    let _ = write_half.flush().await;
+    if !passive_attach {
+        // Enable binary mode
+        if let Err(e) = write_half.write_all(&BINARY_MODE_ENABLE).await {
+            let _ = tx
+                .send(SourceMessage::Error(
+                    source_idx,
+                    format!("Failed to enable binary mode: {}", e),
+                ))
+                .await;
+            return;
+        }
+        let _ = write_half.flush().await;
 
-    tokio::time::sleep(Duration::from_millis(100)).await;
+        tokio::time::sleep(Duration::from_millis(100)).await;
 
-    // Send device info probe
-    let _ = write_half.write_all(&DEVICE_INFO_PROBE).await;
-    let _ = write_half.flush().await;
+        // Send device info probe and ask for the live bus configuration
+        let _ = write_half.write_all(&DEVICE_INFO_PROBE).await;
+        let _ = write_half.write_all(&GVRET_CMD_GET_CANPARAMS).await;
+        let _ = write_half.flush().await;
+    }
+    let keepalive_interval = keepalive_interval.filter(|_| !passive_attach);
 
     // Create transmit channel and send it to the merge task
     let (transmit_tx, transmit_rx) = std_mpsc::sync_channel::<TransmitRequest>(32);
@@ -216,6 +380,7 @@ pub async fn run_source(
     // This ensures transmits are processed immediately without waiting for read timeouts
     let stop_flag_for_transmit = stop_flag.clone();
     let transmit_task = tokio::spawn(async move {
+        let mut last_write = tokio::time::Instant::now();
         while !stop_flag_for_transmit.load(Ordering::SeqCst) {
             // Check for transmit requests with a short sleep to avoid busy loop
             match transmit_rx.recv_timeout(std::time::Duration::from_millis(10)) {
@@ -227,9 +392,20 @@ pub async fn run_source(
                         .map_err(|e| format!("Write error: {}", e));
                     let _ = writer.flush().await;
                     let _ = req.result_tx.send(result);
+                    last_write = tokio::time::Instant::now();
                 }
                 Err(std_mpsc::RecvTimeoutError::Timeout) => {
-                    // No request, continue loop
+                    // No request - send a keepalive if the link has been idle long enough
+                    if let Some(interval) = keepalive_interval {
+                        if last_write.elapsed() >= interval {
+                            let mut writer = write_half_for_transmit.lock().await;
+                            if let Err(e) = writer.write_all(&GVRET_CMD_KEEPALIVE).await {
+                                tlog!("[gvret_tcp] Source {} keepalive write failed: {}", source_idx, e);
+                            }
+                            let _ = writer.flush().await;
+                            last_write = tokio::time::Instant::now();
+                        }
+                    }
                 }
                 Err(std_mpsc::RecvTimeoutError::Disconnected) => {
                     // Channel closed, exit
@@ -241,7 +417,12 @@ pub async fn run_source(
 
     // Read loop - now only handles reading, transmit is handled by separate task
     let mut buffer = Vec::with_capacity(4096);
+    let mut clock = DeviceClock::new(timestamp_source, U32_MICROS_WRAP);
+    let mut bus_params = None;
+    let mut errors = Vec::new();
+    let mut skipped = Vec::new();
     let mut read_buf = [0u8; 2048];
+    let raw_bus = raw_bytes_bus(&bus_mappings);
 
     while !stop_flag.load(Ordering::SeqCst) {
         // Read with timeout
@@ -254,11 +435,51 @@ pub async fn run_source(
                 return;
             }
             Ok(Ok(n)) => {
+                if capture_raw_bytes {
+                    let entries = raw_byte_entries(&read_buf[..n], now_us(), raw_bus);
+                    let _ = tx.send(SourceMessage::Bytes(source_idx, entries)).await;
+                }
                 buffer.extend_from_slice(&read_buf[..n]);
 
                 // Parse GVRET frames and apply bus mappings
-                let frames = parse_gvret_frames(&mut buffer);
-                let mapped_frames = apply_bus_mappings_gvret(frames, &bus_mappings);
+                let mut frames = Vec::new();
+                parse_gvret_frames_each(
+                    &mut buffer,
+                    &mut clock,
+                    &mut bus_params,
+                    |frame, _raw| frames.push(frame),
+                    |error| errors.push(error),
+                    |bytes| skipped.extend_from_slice(bytes),
+                );
+                let mapped_frames = apply_bus_mappings_batch(frames, &bus_mappings);
+
+                if !errors.is_empty() {
+                    let mapped_errors = apply_bus_mappings_to_errors(std::mem::take(&mut errors), &bus_mappings);
+                    if !mapped_errors.is_empty() {
+                        let _ = tx.send(SourceMessage::CanErrors(source_idx, mapped_errors)).await;
+                    }
+                }
+
+                if !skipped.is_empty() {
+                    let entry = ParseErrorEntry::new(
+                        "gvret_resync",
+                        format!("Skipped {} bytes to resync", skipped.len()),
+                        &skipped,
+                    );
+                    skipped.clear();
+                    let _ = tx
+                        .send(SourceMessage::ParseFailures(source_idx, vec![entry]))
+                        .await;
+                }
+
+                if let Some(buses) = bus_params.take() {
+                    let _ = tx
+                        .send(SourceMessage::DeviceConfig(
+                            source_idx,
+                            DeviceConfigPayload { device_type: "gvret_tcp".to_string(), buses },
+                        ))
+                        .await;
+                }
 
                 if !mapped_frames.is_empty() {
                     let _ = tx
@@ -288,3 +509,33 @@ pub async fn run_source(
         .send(SourceMessage::Ended(source_idx, "stopped".to_string()))
         .await;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_socket_options_from_connection() {
+        let mut connection = HashMap::new();
+        assert_eq!(TcpSocketOptions::from_connection(&connection), TcpSocketOptions::default());
+
+        connection.insert("tcp_nodelay".to_string(), serde_json::json!(false));
+        assert_eq!(
+            TcpSocketOptions::from_connection(&connection),
+            TcpSocketOptions { nodelay: false, keepalive: true }
+        );
+
+        connection.insert("tcp_keepalive".to_string(), serde_json::json!(false));
+        assert_eq!(
+            TcpSocketOptions::from_connection(&connection),
+            TcpSocketOptions { nodelay: false, keepalive: false }
+        );
+
+        // Non-boolean values fall back to the default
+        connection.insert("tcp_nodelay".to_string(), serde_json::json!("off"));
+        assert_eq!(
+            TcpSocketOptions::from_connection(&connection),
+            TcpSocketOptions { nodelay: true, keepalive: false }
+        );
+    }
+}
@@ -14,9 +14,13 @@ use tokio::sync::mpsc;
 
 use crate::io::error::IoError;
 use crate::io::types::{SourceMessage, TransmitRequest};
+use crate::io::serial::{open_error, SerialLineControl};
+use crate::io::{now_us, DeviceClock, DeviceConfigPayload, ParseErrorEntry, TimestampSource, U32_MICROS_WRAP};
 use super::common::{
-    apply_bus_mappings_gvret, parse_gvret_frames, parse_numbuses_response, BusMapping,
-    BINARY_MODE_ENABLE, DEVICE_INFO_PROBE, GVRET_CMD_NUMBUSES, GvretDeviceInfo,
+    apply_bus_mappings_batch, apply_bus_mappings_to_errors, has_fd_reply, parse_gvret_frames,
+    parse_gvret_frames_each, parse_numbuses_response, raw_byte_entries, raw_bytes_bus,
+    BusMapping, BusScanResult, BusScanTally, BINARY_MODE_ENABLE, DEVICE_INFO_PROBE, GVRET_CMD_GET_FD,
+    GVRET_CMD_KEEPALIVE, GVRET_CMD_GET_CANPARAMS, GVRET_CMD_NUMBUSES, GvretDeviceInfo, FD_PROBE_TIMEOUT,
 };
 
 // ============================================================================
@@ -64,7 +68,7 @@ pub fn probe_gvret_usb(port: &str, baud_rate: u32) -> Result<GvretDeviceInfo, Io
     let mut serial_port = serialport::new(port, baud_rate)
         .timeout(Duration::from_millis(500))
         .open()
-        .map_err(|e| IoError::connection(&device, e.to_string()))?;
+        .map_err(|e| open_error(&device, port, &e))?;
 
     tlog!("[probe_gvret_usb] Opened serial port {}", port);
 
@@ -104,11 +108,12 @@ pub fn probe_gvret_usb(port: &str, baud_rate: u32) -> Result<GvretDeviceInfo, Io
 
                 // Check for NUMBUSES response
                 if let Some(bus_count) = parse_numbuses_response(&buf[..total_read]) {
+                    let supports_fd = probe_fd_support(&mut serial_port);
                     tlog!(
-                        "[probe_gvret_usb] SUCCESS: Device at {} has {} buses available",
-                        port, bus_count
+                        "[probe_gvret_usb] SUCCESS: Device at {} has {} buses available (FD: {:?})",
+                        port, bus_count, supports_fd
                     );
-                    return Ok(GvretDeviceInfo { bus_count });
+                    return Ok(GvretDeviceInfo { bus_count, supports_fd });
                 }
 
                 // If we've read enough data without finding the response, give up
@@ -127,7 +132,86 @@ pub fn probe_gvret_usb(port: &str, baud_rate: u32) -> Result<GvretDeviceInfo, Io
 
     // If we didn't get a response, assume 1 bus (safer default)
     tlog!("[probe_gvret_usb] No NUMBUSES response received, defaulting to 1 bus");
-    Ok(GvretDeviceInfo { bus_count: 1 })
+    Ok(GvretDeviceInfo { bus_count: 1, supports_fd: None })
+}
+
+/// Send the GET_FD query and wait briefly for a reply.
+///
+/// Returns `Some(true)` if the firmware answered, `Some(false)` if it stayed
+/// silent (classic GVRET ignores unknown commands), or `None` if the query
+/// could not be sent.
+fn probe_fd_support(serial_port: &mut Box<dyn serialport::SerialPort>) -> Option<bool> {
+    serial_port.write_all(&GVRET_CMD_GET_FD).ok()?;
+    let _ = serial_port.flush();
+    let _ = serial_port.set_timeout(Duration::from_millis(50));
+
+    let mut buf = vec![0u8; 256];
+    let mut total_read = 0;
+    let deadline = std::time::Instant::now() + FD_PROBE_TIMEOUT;
+
+    while total_read < buf.len() && std::time::Instant::now() < deadline {
+        match serial_port.read(&mut buf[total_read..]) {
+            Ok(0) => break,
+            Ok(n) => {
+                total_read += n;
+                if has_fd_reply(&buf[..total_read]) {
+                    return Some(true);
+                }
+            }
+            Err(ref e) if e.kind() == std::io::ErrorKind::TimedOut => {}
+            Err(_) => break,
+        }
+    }
+
+    Some(false)
+}
+
+/// Listen to a GVRET USB device for `duration_ms` and report which buses carry traffic.
+///
+/// Buses that stay silent are left out of the result. The port is opened with
+/// `line_control` like a streaming session and closed afterwards.
+pub fn scan_gvret_usb_buses(
+    port: &str,
+    baud_rate: u32,
+    line_control: SerialLineControl,
+    duration_ms: u64,
+) -> Result<Vec<BusScanResult>, IoError> {
+    let device = format!("gvret_usb({})", port);
+
+    let builder = serialport::new(port, baud_rate).timeout(Duration::from_millis(50));
+    let mut serial_port = line_control
+        .open(builder)
+        .map_err(|e| open_error(&device, port, &e))?;
+    let _ = serial_port.clear(serialport::ClearBuffer::All);
+
+    serial_port
+        .write_all(&BINARY_MODE_ENABLE)
+        .map_err(|e| IoError::protocol(&device, format!("enable binary mode: {}", e)))?;
+    let _ = serial_port.flush();
+
+    tlog!("[scan_gvret_usb] Listening on {} for {}ms", port, duration_ms);
+
+    let mut tally = BusScanTally::default();
+    let mut buffer = Vec::new();
+    let mut clock = DeviceClock::default();
+    let mut read_buf = vec![0u8; 4096];
+    let deadline = std::time::Instant::now() + Duration::from_millis(duration_ms);
+
+    while std::time::Instant::now() < deadline {
+        match serial_port.read(&mut read_buf) {
+            Ok(0) => {}
+            Ok(n) => {
+                buffer.extend_from_slice(&read_buf[..n]);
+                tally.add(&parse_gvret_frames(&mut buffer, &mut clock, &mut None));
+            }
+            Err(ref e) if e.kind() == std::io::ErrorKind::TimedOut => {}
+            Err(e) => return Err(IoError::read(&device, e.to_string())),
+        }
+    }
+
+    let results = tally.finish();
+    tlog!("[scan_gvret_usb] {} has traffic on {} bus(es)", port, results.len());
+    Ok(results)
 }
 
 // ============================================================================
@@ -135,25 +219,39 @@ pub fn probe_gvret_usb(port: &str, baud_rate: u32) -> Result<GvretDeviceInfo, Io
 // ============================================================================
 
 /// Run GVRET USB source and send frames to merge task
+///
+/// When `keepalive_interval` is set, a GVRET keepalive command is written whenever
+/// nothing has been sent to the device for that long. Frames are stamped according
+/// to `timestamp_source` using the device's microsecond timestamp. With
+/// `capture_raw_bytes`, every byte read is also sent on as a `SourceMessage::Bytes`
+/// entry stamped with the host clock.
+///
+/// With `passive_attach`, no setup or probe commands (binary mode, device info,
+/// bus parameters, keepalives) are written: the device must already be streaming
+/// in binary mode.
+#[allow(clippy::too_many_arguments)]
 pub async fn run_source(
     source_idx: usize,
     port: String,
     baud_rate: u32,
+    line_control: SerialLineControl,
+    passive_attach: bool,
+    keepalive_interval: Option<Duration>,
+    capture_raw_bytes: bool,
+    timestamp_source: TimestampSource,
     bus_mappings: Vec<BusMapping>,
     stop_flag: Arc<AtomicBool>,
     tx: mpsc::Sender<SourceMessage>,
 ) {
     // Open serial port
-    let serial_port = match serialport::new(&port, baud_rate)
-        .timeout(Duration::from_millis(10))
-        .open()
-    {
+    let builder = serialport::new(&port, baud_rate).timeout(Duration::from_millis(10));
+    let serial_port = match line_control.open(builder) {
         Ok(p) => p,
         Err(e) => {
             let _ = tx
                 .send(SourceMessage::Error(
                     source_idx,
-                    format!("Failed to open port: {}", e),
+                    open_error(&format!("gvret_usb({})", port), &port, &e).to_string(),
                 ))
                 .await;
             return;
@@ -167,6 +265,9 @@ pub async fn run_source(
     let init_result: Result<(), String> = (|| {
         let mut port = serial_port.lock().unwrap();
         let _ = port.clear(serialport::ClearBuffer::All);
+        if passive_attach {
+            return Ok(());
+        }
 
         // Enable binary mode
         port.write_all(&BINARY_MODE_ENABLE)
@@ -180,14 +281,16 @@ pub async fn run_source(
         return;
     }
 
-    std::thread::sleep(Duration::from_millis(100));
+    if !passive_attach {
+        std::thread::sleep(Duration::from_millis(100));
 
-    // Send device info probe
-    {
+        // Send device info probe and ask for the live bus configuration
         let mut port = serial_port.lock().unwrap();
         let _ = port.write_all(&DEVICE_INFO_PROBE);
+        let _ = port.write_all(&GVRET_CMD_GET_CANPARAMS);
         let _ = port.flush();
     }
+    let keepalive_interval = keepalive_interval.filter(|_| !passive_attach);
 
     // Create transmit channel and send it to the merge task
     let (transmit_tx, transmit_rx) = std_mpsc::sync_channel::<TransmitRequest>(32);
@@ -214,6 +317,13 @@ pub async fn run_source(
     let blocking_handle = tokio::task::spawn_blocking(move || {
         let mut buffer = Vec::with_capacity(4096);
         let mut read_buf = [0u8; 2048];
+        let mut clock = DeviceClock::new(timestamp_source, U32_MICROS_WRAP);
+        let mut bus_params = None;
+        let mut errors = Vec::new();
+        let mut skipped = Vec::new();
+        let raw_bus = raw_bytes_bus(&bus_mappings);
+
+        let mut last_write = std::time::Instant::now();
 
         while !stop_flag_clone.load(Ordering::SeqCst) {
             // Check for transmit requests (non-blocking)
@@ -225,6 +335,18 @@ pub async fn run_source(
                         .map_err(|e| format!("Write error: {}", e))
                 };
                 let _ = req.result_tx.send(result);
+                last_write = std::time::Instant::now();
+            }
+
+            // Send a keepalive if nothing has been written for the configured interval
+            if let Some(interval) = keepalive_interval {
+                if last_write.elapsed() >= interval {
+                    let mut port = serial_port_clone.lock().unwrap();
+                    if let Err(e) = port.write_all(&GVRET_CMD_KEEPALIVE).and_then(|_| port.flush()) {
+                        tlog!("[gvret_usb] Source {} keepalive write failed: {}", source_idx, e);
+                    }
+                    last_write = std::time::Instant::now();
+                }
             }
 
             // Read data
@@ -239,11 +361,47 @@ pub async fn run_source(
                     std::thread::sleep(Duration::from_millis(10));
                 }
                 Ok(n) => {
+                    if capture_raw_bytes {
+                        let entries = raw_byte_entries(&read_buf[..n], now_us(), raw_bus);
+                        let _ = tx_clone.blocking_send(SourceMessage::Bytes(source_idx, entries));
+                    }
                     buffer.extend_from_slice(&read_buf[..n]);
 
                     // Parse GVRET frames and apply bus mappings
-                    let frames = parse_gvret_frames(&mut buffer);
-                    let mapped_frames = apply_bus_mappings_gvret(frames, &bus_mappings);
+                    let mut frames = Vec::new();
+                    parse_gvret_frames_each(
+                        &mut buffer,
+                        &mut clock,
+                        &mut bus_params,
+                        |frame, _raw| frames.push(frame),
+                        |error| errors.push(error),
+                        |bytes| skipped.extend_from_slice(bytes),
+                    );
+                    let mapped_frames = apply_bus_mappings_batch(frames, &bus_mappings);
+
+                    if !errors.is_empty() {
+                        let mapped_errors = apply_bus_mappings_to_errors(std::mem::take(&mut errors), &bus_mappings);
+                        if !mapped_errors.is_empty() {
+                            let _ = tx_clone.blocking_send(SourceMessage::CanErrors(source_idx, mapped_errors));
+                        }
+                    }
+
+                    if !skipped.is_empty() {
+                        let entry = ParseErrorEntry::new(
+                            "gvret_resync",
+                            format!("Skipped {} bytes to resync", skipped.len()),
+                            &skipped,
+                        );
+                        skipped.clear();
+                        let _ = tx_clone.blocking_send(SourceMessage::ParseFailures(source_idx, vec![entry]));
+                    }
+
+                    if let Some(buses) = bus_params.take() {
+                        let _ = tx_clone.blocking_send(SourceMessage::DeviceConfig(
+                            source_idx,
+                            DeviceConfigPayload { device_type: "gvret_usb".to_string(), buses },
+                        ));
+                    }
 
                     if !mapped_frames.is_empty() {
                         let _ = tx_clone
@@ -277,6 +435,7 @@ pub async fn run_source(
 #[cfg(test)]
 mod tests {
     use crate::io::gvret::{encode_gvret_frame, parse_gvret_frames};
+    use crate::io::DeviceClock;
     use crate::io::CanTransmitFrame;
 
     #[test]
@@ -289,6 +448,7 @@ mod tests {
             is_fd: false,
             is_brs: false,
             is_rtr: false,
+            rtr_dlc: None,
         };
 
         let encoded = encode_gvret_frame(&frame);
@@ -315,6 +475,7 @@ mod tests {
             is_fd: false,
             is_brs: false,
             is_rtr: false,
+            rtr_dlc: None,
         };
 
         let encoded = encode_gvret_frame(&frame);
@@ -342,6 +503,7 @@ mod tests {
             is_fd: false,
             is_brs: false,
             is_rtr: false,
+            rtr_dlc: None,
         };
 
         let encoded = encode_gvret_frame(&frame);
@@ -368,10 +530,10 @@ mod tests {
             0xAA, 0xBB, 0xCC, 0xDD, // Data
         ];
 
-        let frames = parse_gvret_frames(&mut buffer);
+        let frames = parse_gvret_frames(&mut buffer, &mut DeviceClock::default(), &mut None);
 
         assert_eq!(frames.len(), 1);
-        let (frame, _) = &frames[0];
+        let frame = &frames[0];
         assert_eq!(frame.frame_id, 0x123);
         assert_eq!(frame.dlc, 4);
         assert_eq!(frame.bytes, vec![0xAA, 0xBB, 0xCC, 0xDD]);
@@ -390,10 +552,10 @@ mod tests {
             0x11, 0x22, // Data
         ];
 
-        let frames = parse_gvret_frames(&mut buffer);
+        let frames = parse_gvret_frames(&mut buffer, &mut DeviceClock::default(), &mut None);
 
         assert_eq!(frames.len(), 1);
-        let (frame, _) = &frames[0];
+        let frame = &frames[0];
         assert_eq!(frame.frame_id, 0x12345678);
         assert!(frame.is_extended);
         assert_eq!(frame.bytes, vec![0x11, 0x22]);
@@ -411,10 +573,10 @@ mod tests {
             0xFF, // Data
         ];
 
-        let frames = parse_gvret_frames(&mut buffer);
+        let frames = parse_gvret_frames(&mut buffer, &mut DeviceClock::default(), &mut None);
 
         assert_eq!(frames.len(), 1);
-        let (frame, _) = &frames[0];
+        let frame = &frames[0];
         assert_eq!(frame.frame_id, 0x7F);
     }
 
@@ -426,7 +588,7 @@ mod tests {
             0x00, 0x00, // Only 2 timestamp bytes
         ];
 
-        let frames = parse_gvret_frames(&mut buffer);
+        let frames = parse_gvret_frames(&mut buffer, &mut DeviceClock::default(), &mut None);
 
         assert!(frames.is_empty());
         assert_eq!(buffer.len(), 4); // Buffer should be preserved
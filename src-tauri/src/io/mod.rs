@@ -8,6 +8,7 @@
 pub mod codec; // Frame codec trait and implementations
 mod error;
 pub mod traits; // InterfaceTraits validation
+mod timestamp;
 mod types;
 
 // Timeline readers (buffer, csv, postgres)
@@ -27,10 +28,11 @@ pub mod slcan; // pub for slcan transmit_frame access
 mod socketcan;
 
 // Re-export timeline readers
-pub use timeline::{step_frame, BufferReader, StepResult};
+pub use timeline::{emit_buffer_range, step_frame, BufferReader, EmitRangeResult, PacingReference, StepResult};
 pub use timeline::{
-    parse_csv_file, parse_csv_with_mapping, preview_csv_file, CsvColumnMapping, CsvPreview,
-    CsvReader, CsvReaderOptions, Delimiter, SequenceGap, TimestampUnit,
+    check_frame_lengths, count_out_of_order, parse_csv_file, parse_csv_with_mapping, preview_csv_file,
+    sort_frames_by_timestamp,
+    CsvColumnMapping, CsvPreview, CsvReader, CsvReaderOptions, Delimiter, SequenceGap, TimestampUnit,
 };
 pub use timeline::{PostgresConfig, PostgresReader, PostgresReaderOptions, PostgresSourceType};
 
@@ -53,15 +55,20 @@ pub use codec::{SocketCanCodec, SocketCanEncodedFrame};
 #[cfg(any(target_os = "windows", target_os = "macos"))]
 #[allow(unused_imports)]
 pub use gs_usb::GsUsbConfig;
-pub use gvret::{BusMapping, GvretDeviceInfo, probe_gvret_tcp};
+#[cfg(target_os = "linux")]
+pub use socketcan::SocketCanReader;
+pub use gvret::{BusMapping, BusScanResult, GvretDeviceInfo, parse_gvret_binary_file, probe_gvret_tcp, scan_gvret_tcp_buses};
 pub use modbus_tcp::{
     ModbusTcpConfig, ModbusTcpReader, PollGroup,
     ModbusScanConfig, ScanCompletePayload, UnitIdScanConfig,
 };
 #[cfg(not(target_os = "ios"))]
-pub use gvret::probe_gvret_usb;
-pub use multi_source::{ModbusRole, MultiSourceReader, SourceConfig};
+pub use gvret::{probe_gvret_usb, scan_gvret_usb_buses};
+pub use multi_source::{encode_source_frame, validate_transmit_for, ModbusRole, MultiSourceReader, SourceBusMappings, SourceConfig};
+pub(crate) use multi_source::{auto_stop_idle_from_profile, idle_stop_due};
+pub use timestamp::{DeviceClock, TimestampSource, U32_MICROS_WRAP};
 pub use mqtt::{MqttConfig, MqttReader};
+pub use types::DeviceInfo;
 pub use virtual_device::{VirtualDeviceConfig, VirtualDeviceReader, VirtualInterfaceConfig, VirtualTrafficType};
 #[cfg(not(target_os = "ios"))]
 #[allow(unused_imports)]
@@ -80,7 +87,7 @@ use async_trait::async_trait;
 use keepawake::{Builder as KeepAwakeBuilder, KeepAwake};
 use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::sync::RwLock;
 use std::time::{SystemTime, UNIX_EPOCH};
 use tauri::{AppHandle, Emitter, Manager};
@@ -114,6 +121,87 @@ pub struct FrameMessage {
     /// Direction: "rx" for received, "tx" for transmitted
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub direction: Option<String>,
+    /// Raw DLC code as it arrived on the wire, when the source reports one.
+    /// `dlc` is always the payload length; this keeps classic DLC 9-15 distinguishable.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub dlc_raw: Option<u8>,
+    /// Remote transmission request (classic CAN): no data, `dlc` is the requested length
+    #[serde(default)]
+    pub is_rtr: bool,
+    /// CAN FD bit rate switch: the data phase ran at the data bitrate
+    #[serde(default)]
+    pub is_brs: bool,
+    /// CAN FD error state indicator: the sender was error passive
+    #[serde(default)]
+    pub is_esi: bool,
+    /// ID the frame arrived with, when a source's `id_remap` relabelled it
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub original_frame_id: Option<u32>,
+    /// Display name of the source the frame came from, set by multi-source
+    /// sessions so a merged view can tell adapters apart on a shared bus.
+    /// Not stored in buffers.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub source_label: Option<String>,
+}
+
+/// Widest 11-bit standard CAN identifier
+pub const CAN_STANDARD_ID_MASK: u32 = 0x7FF;
+/// Widest 29-bit extended CAN identifier
+pub const CAN_EXTENDED_ID_MASK: u32 = 0x1FFF_FFFF;
+
+/// Check a frame ID against the range its format allows (11 bits standard, 29 bits extended).
+///
+/// Returns the ID and whether it is extended. A standard ID too wide for 11 bits
+/// is marked extended rather than masked, so it can't alias another frame; an ID
+/// wider than 29 bits is rejected with `None`. Every importer and line parser uses
+/// this so a malformed log gets the same treatment everywhere, and counts the IDs
+/// it had to adjust or reject so it can log them once.
+pub fn normalize_frame_id(frame_id: u32, is_extended: bool) -> Option<(u32, bool)> {
+    if frame_id > CAN_EXTENDED_ID_MASK {
+        return None;
+    }
+    Some((frame_id, is_extended || frame_id > CAN_STANDARD_ID_MASK))
+}
+
+/// Match criteria for picking frames out of a stream or buffer.
+/// Unset fields match any frame.
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct FrameFilter {
+    #[serde(default)]
+    pub frame_id: Option<u32>,
+    #[serde(default)]
+    pub bus: Option<u8>,
+    #[serde(default)]
+    pub is_extended: Option<bool>,
+    /// Expected payload bytes, compared under `mask` (missing mask bytes = 0xFF).
+    #[serde(default)]
+    pub data: Option<Vec<u8>>,
+    #[serde(default)]
+    pub mask: Option<Vec<u8>>,
+}
+
+impl FrameFilter {
+    /// Whether the frame satisfies every field set on the filter.
+    pub fn matches(&self, frame: &FrameMessage) -> bool {
+        if self.frame_id.is_some_and(|id| id != frame.frame_id)
+            || self.bus.is_some_and(|bus| bus != frame.bus)
+            || self.is_extended.is_some_and(|ext| ext != frame.is_extended)
+        {
+            return false;
+        }
+        if let Some(data) = &self.data {
+            if frame.bytes.len() < data.len() {
+                return false;
+            }
+            for (i, expected) in data.iter().enumerate() {
+                let mask = self.mask.as_ref().and_then(|m| m.get(i).copied()).unwrap_or(0xFF);
+                if frame.bytes[i] & mask != expected & mask {
+                    return false;
+                }
+            }
+        }
+        true
+    }
 }
 
 /// Frame batch payload - includes frames and the list of active listeners
@@ -127,6 +215,30 @@ pub struct FrameBatchPayload {
     pub active_listeners: Vec<String>,
 }
 
+/// A frame with its printable-ASCII rendering, emitted in place of the bare
+/// frame for sessions with the ASCII view enabled (see `set_session_ascii_view`)
+#[derive(Clone, Serialize)]
+struct AsciiFrame {
+    #[serde(flatten)]
+    frame: FrameMessage,
+    ascii: String,
+}
+
+/// `FrameBatchPayload` for sessions with the ASCII view enabled
+#[derive(Clone, Serialize)]
+struct AsciiFrameBatchPayload {
+    frames: Vec<AsciiFrame>,
+    active_listeners: Vec<String>,
+}
+
+/// Render data bytes as printable ASCII, with '.' for anything outside 0x20-0x7E
+pub fn ascii_view(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .map(|&b| if (0x20..=0x7E).contains(&b) { b as char } else { '.' })
+        .collect()
+}
+
 /// Playback position - emitted with playback-time events during buffer streaming
 #[derive(Clone, Serialize)]
 pub struct PlaybackPosition {
@@ -139,6 +251,18 @@ pub struct PlaybackPosition {
     pub frame_count: Option<usize>,
 }
 
+/// Playback range - emitted with playback-range events when buffer streaming
+/// starts and after each seek, so the UI can scale its scrubber
+#[derive(Clone, Debug, Serialize)]
+pub struct PlaybackRange {
+    /// Timestamp of the first frame in microseconds
+    pub start_us: i64,
+    /// Timestamp of the last frame in microseconds
+    pub end_us: i64,
+    /// Total frame count in buffer
+    pub total_frames: usize,
+}
+
 /// Per-bus signal generator state (returned to frontend for virtual devices)
 #[derive(Clone, Serialize)]
 pub struct VirtualBusState {
@@ -172,6 +296,19 @@ pub struct CanTransmitFrame {
     pub is_brs: bool,
     /// Remote Transmission Request
     pub is_rtr: bool,
+    /// DLC a remote frame requests; it carries no data. Defaults to the data
+    /// length. Ignored for data frames.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rtr_dlc: Option<u8>,
+}
+
+impl CanTransmitFrame {
+    /// Classic CAN DLC to send: the requested DLC for a remote frame,
+    /// otherwise the data length (at most 8)
+    pub fn classic_dlc(&self) -> u8 {
+        let len = if self.is_rtr { self.rtr_dlc.map_or(self.data.len(), usize::from) } else { self.data.len() };
+        len.min(8) as u8
+    }
 }
 
 /// Result of a transmit operation
@@ -483,6 +620,28 @@ pub enum IOState {
     Error(String),
 }
 
+impl IOState {
+    /// Lower-case description for user-facing messages
+    pub fn describe(&self) -> String {
+        match self {
+            IOState::Stopped => "stopped".to_string(),
+            IOState::Starting => "starting".to_string(),
+            IOState::Running => "running".to_string(),
+            IOState::Paused => "paused".to_string(),
+            IOState::Error(msg) => format!("in error ({})", msg),
+        }
+    }
+}
+
+/// Reject a transmit unless the device is running, so callers get a precise
+/// reason instead of a port or channel error.
+pub fn ensure_transmit_state(state: &IOState) -> Result<(), String> {
+    match state {
+        IOState::Running => Ok(()),
+        other => Err(format!("Cannot transmit: session is {}", other.describe())),
+    }
+}
+
 /// Payload emitted when a stream ends (naturally, by disconnect, or by error)
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct StreamEndedPayload {
@@ -591,6 +750,57 @@ pub trait IODevice: Send + Sync {
         Err("This device does not support reverse playback".to_string())
     }
 
+    /// Enable or disable playback of one bus's frames (buffer replay only).
+    /// Default implementation returns an error.
+    fn set_bus_enabled(&mut self, _bus: u8, _enabled: bool) -> Result<(), String> {
+        Err("This device does not support per-bus playback".to_string())
+    }
+
+    /// Set how long transmit waits for the device to confirm a write.
+    /// Default implementation returns an error.
+    fn set_transmit_timeout(&mut self, _timeout_ms: u64) -> Result<(), String> {
+        Err("This device does not support a transmit timeout".to_string())
+    }
+
+    /// Set the minimum gap between consecutive writes, in microseconds (0 disables).
+    /// Default implementation returns an error.
+    fn set_min_tx_gap(&mut self, _gap_us: u64) -> Result<(), String> {
+        Err("This device does not support a minimum transmit gap".to_string())
+    }
+
+    /// Whether writes are spaced by a minimum transmit gap.
+    /// Default implementation returns false.
+    fn transmit_paced(&self) -> bool {
+        false
+    }
+
+    /// Claim the next paced write slot and return how long the caller must wait
+    /// before transmitting. Callers wait with the session lock released.
+    /// Default implementation never waits.
+    fn reserve_transmit_slot(&self) -> std::time::Duration {
+        std::time::Duration::ZERO
+    }
+
+    /// Choose whether the session's own transmitted frames (direction "tx") are
+    /// stored in its buffer. They are emitted to the UI either way.
+    /// Default implementation returns an error.
+    fn set_buffer_tx_frames(&mut self, _enabled: bool) -> Result<(), String> {
+        Err("This device does not support excluding transmitted frames from its buffer".to_string())
+    }
+
+    /// Enable or disable transmit through one source of a multi-source session,
+    /// leaving its receive path running. Default implementation returns an error.
+    fn set_source_transmit_enabled(&mut self, _source_idx: usize, _enabled: bool) -> Result<(), String> {
+        Err("This device does not support per-source transmit control".to_string())
+    }
+
+    /// Channel for asking the connected adapter for its firmware versions and
+    /// serial number without interrupting the stream. Callers wait for the
+    /// answer after releasing the session lock. Default implementation returns an error.
+    fn device_info_sender(&self) -> Result<types::DeviceInfoSender, String> {
+        Err("This device does not report device info".to_string())
+    }
+
     /// Transmit data through the device.
     /// Devices match on the `TransmitPayload` variant they support and return
     /// an error for unsupported variants.
@@ -598,6 +808,25 @@ pub trait IODevice: Send + Sync {
         Err("This device does not support transmission".to_string())
     }
 
+    /// Transmit a burst of CAN frames, returning one result per frame in order.
+    /// Default implementation sends them one at a time through `transmit`;
+    /// devices that can write several frames at once override it.
+    fn transmit_batch(&self, frames: &[CanTransmitFrame]) -> Vec<TransmitResult> {
+        frames
+            .iter()
+            .map(|frame| {
+                self.transmit(&TransmitPayload::CanFrame(frame.clone()))
+                    .unwrap_or_else(TransmitResult::error)
+            })
+            .collect()
+    }
+
+    /// Write arbitrary bytes to the device (e.g. a GVRET control command), outside
+    /// any frame encoding. Default implementation returns an error.
+    fn transmit_device_command(&self, _bytes: &[u8]) -> Result<TransmitResult, String> {
+        Err("This device does not support raw device commands".to_string())
+    }
+
     /// Get current state
     fn state(&self) -> IOState;
 
@@ -648,6 +877,16 @@ pub trait IODevice: Send + Sync {
         Err("This device does not support bus mapping updates".to_string())
     }
 
+    /// Change one source's bus mappings in place, without reconnecting it when possible.
+    fn update_bus_mapping(&mut self, _source_idx: usize, _bus_mappings: Vec<gvret::BusMapping>) -> Result<(), String> {
+        Err("This device does not support bus mapping updates".to_string())
+    }
+
+    /// Current bus mappings of each source in a multi-source session.
+    fn bus_mappings(&self) -> Result<Vec<multi_source::SourceBusMappings>, String> {
+        Err("This device does not support bus mappings".to_string())
+    }
+
     /// Add a virtual bus generator to a running session.
     fn add_virtual_bus(&mut self, _bus: u8, _traffic_type: String, _frame_rate_hz: f64) -> Result<(), String> {
         Err("This device does not support virtual bus add".to_string())
@@ -726,6 +965,11 @@ pub struct IOSession {
     /// When all listeners went stale. During this grace period the reader is paused
     /// but the session stays alive, allowing recovery after display sleep / App Nap.
     pub suspended_at: Option<std::time::Instant>,
+    /// When the session was created (microseconds since UNIX epoch)
+    pub started_at_us: u64,
+    /// Whether transmits are allowed. Sessions start disarmed so a monitor-only
+    /// capture can't write to the bus by accident (see `arm_session_transmit`).
+    pub transmit_armed: bool,
 }
 
 /// Convert IOState to a simple string for TypeScript
@@ -779,6 +1023,96 @@ fn emit_speed_change(app: &AppHandle, session_id: &str, speed: f64) {
 static IO_SESSIONS: Lazy<Mutex<HashMap<String, IOSession>>> =
     Lazy::new(|| Mutex::new(HashMap::new()));
 
+/// Number of frames emitted per session (updated by `emit_frames`).
+/// Uses RwLock (not async Mutex) so it can be updated synchronously from reader tasks.
+static SESSION_FRAME_COUNTS: Lazy<RwLock<HashMap<String, u64>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// Per-session recent emission rate (updated by `emit_frames`, read by `session_frame_rate`).
+static SESSION_FRAME_RATES: Lazy<RwLock<HashMap<String, FrameRateWindow>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// Window over which `session_frame_rate` averages
+const FRAME_RATE_WINDOW_BUCKETS: u64 = 20;
+/// Width of one frame rate bucket (20 x 100 ms = 2 s window)
+const FRAME_RATE_BUCKET: std::time::Duration = std::time::Duration::from_millis(100);
+
+/// Frames emitted per 100 ms bucket over the last couple of seconds.
+/// Bucketing keeps the cost per batch constant however often a reader emits.
+struct FrameRateWindow {
+    started: std::time::Instant,
+    /// (bucket index since `started`, frames), oldest first
+    buckets: std::collections::VecDeque<(u64, u64)>,
+}
+
+impl FrameRateWindow {
+    fn new(now: std::time::Instant) -> Self {
+        Self { started: now, buckets: std::collections::VecDeque::new() }
+    }
+
+    fn bucket_at(&self, now: std::time::Instant) -> u64 {
+        (now.duration_since(self.started).as_millis() / FRAME_RATE_BUCKET.as_millis()) as u64
+    }
+
+    fn record(&mut self, now: std::time::Instant, frames: u64) {
+        let bucket = self.bucket_at(now);
+        match self.buckets.back_mut() {
+            Some((b, count)) if *b == bucket => *count += frames,
+            _ => self.buckets.push_back((bucket, frames)),
+        }
+        let oldest = bucket.saturating_sub(FRAME_RATE_WINDOW_BUCKETS - 1);
+        while self.buckets.front().is_some_and(|(b, _)| *b < oldest) {
+            self.buckets.pop_front();
+        }
+    }
+
+    /// Frames per second over the window ending at `now` (shorter while the session is young)
+    fn rate(&self, now: std::time::Instant) -> f64 {
+        let oldest = self.bucket_at(now).saturating_sub(FRAME_RATE_WINDOW_BUCKETS - 1);
+        let frames: u64 = self.buckets.iter().filter(|(b, _)| *b >= oldest).map(|(_, c)| c).sum();
+        let span = now.duration_since(self.started).saturating_sub(FRAME_RATE_BUCKET * oldest as u32);
+        if span.is_zero() {
+            return 0.0;
+        }
+        frames as f64 / span.as_secs_f64()
+    }
+}
+
+/// Sessions whose merge task is currently buffering frames without emitting
+/// them because the frontend can't keep up (see `set_session_backpressure`).
+static SESSION_BACKPRESSURE: Lazy<RwLock<HashSet<String>>> =
+    Lazy::new(|| RwLock::new(HashSet::new()));
+
+/// Per-session count of frames emitted after a newer frame (see `add_session_reordering_needed`).
+static SESSION_REORDERING_NEEDED: Lazy<RwLock<HashMap<String, u64>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// Per-session frame ID filter for frontend emission (see `set_session_emit_filter`).
+/// Sessions without an entry emit every frame.
+static SESSION_EMIT_FILTERS: Lazy<RwLock<HashMap<String, HashSet<u32>>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// Sessions whose emitted frames carry an `ascii` rendering of their data
+/// (see `set_session_ascii_view`). Off by default to keep high-rate buses cheap.
+static SESSION_ASCII_VIEW: Lazy<RwLock<HashSet<String>>> =
+    Lazy::new(|| RwLock::new(HashSet::new()));
+
+/// Per-source frame buffers of multi-source sessions created with
+/// `per_source_buffers`, keyed by session ID (see `add_session_source_buffer`).
+static SESSION_SOURCE_BUFFERS: Lazy<RwLock<HashMap<String, Vec<SourceBufferInfo>>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// One-shot waiters for the next frame matching a filter, keyed by session ID
+/// (see `subscribe_next_frame`). Checked in `record_emitted_frames` before the emit filter.
+static FRAME_SUBSCRIPTIONS: Lazy<std::sync::Mutex<HashMap<String, Vec<FrameSubscription>>>> =
+    Lazy::new(|| std::sync::Mutex::new(HashMap::new()));
+
+/// A pending waiter registered by `subscribe_next_frame`.
+struct FrameSubscription {
+    filter: FrameFilter,
+    tx: tokio::sync::oneshot::Sender<FrameMessage>,
+}
+
 /// Sessions that are currently closing (window close in progress)
 /// Uses RwLock (not async Mutex) so it can be checked synchronously in emit_to_session
 static CLOSING_SESSIONS: Lazy<RwLock<HashSet<String>>> = Lazy::new(|| RwLock::new(HashSet::new()));
@@ -1239,12 +1573,77 @@ pub fn emit_session_error(app: &AppHandle, session_id: &str, error: String) {
 /// Emit frames to a session with active listener filtering.
 /// This is the preferred way to emit frames - it includes the active listeners
 /// so the frontend can filter callbacks appropriately.
+///
+/// If the session has an emit filter (`set_session_emit_filter`), only frames
+/// with a matching ID are sent over IPC. Callers buffer frames before emitting,
+/// so the buffer always holds the full capture; the session's capture file, if
+/// any, is written before the filter too. The filtered batch is also
+/// published to the local frame server when one is running.
 pub fn emit_frames(
     app: &AppHandle,
     session_id: &str,
     frames: Vec<FrameMessage>,
 ) {
+    record_emitted_frames(app, session_id, &frames);
+    emit_frames_to_frontend(app, session_id, frames);
+}
+
+/// Account for a batch of live frames without sending it to the frontend:
+/// frame count and rate, frame waiters, the capture file and signal probes.
+///
+/// `emit_frames` calls this before emitting. Readers that hold frames back
+/// from the UI (e.g. under backpressure) call it directly so the session's
+/// stats and consumers still see every frame.
+pub fn record_emitted_frames(app: &AppHandle, session_id: &str, frames: &[FrameMessage]) {
+    if let Ok(mut counts) = SESSION_FRAME_COUNTS.write() {
+        *counts.entry(session_id.to_string()).or_insert(0) += frames.len() as u64;
+    }
+    if let Ok(mut rates) = SESSION_FRAME_RATES.write() {
+        let now = std::time::Instant::now();
+        rates
+            .entry(session_id.to_string())
+            .or_insert_with(|| FrameRateWindow::new(now))
+            .record(now, frames.len() as u64);
+    }
+    deliver_frame_subscriptions(session_id, frames);
+    crate::capture_file::write_frames(app, session_id, frames);
+    crate::signal_probe::deliver_frames(app, session_id, frames);
+}
+
+/// Emit a snapshot of frames that were already delivered (e.g. the latest
+/// frame per ID at a paused seek position).
+///
+/// The emit filter and ASCII view apply as for `emit_frames`, but the frames
+/// aren't counted, rate-tracked, captured or passed to subscribers and probes,
+/// so seeking leaves the session's frame count unchanged.
+pub fn emit_snapshot_frames(app: &AppHandle, session_id: &str, frames: Vec<FrameMessage>) {
+    emit_frames_to_frontend(app, session_id, frames);
+}
+
+/// Send a batch to the frontend and frame server, after the session's emit filter
+fn emit_frames_to_frontend(app: &AppHandle, session_id: &str, mut frames: Vec<FrameMessage>) {
+    if let Ok(filters) = SESSION_EMIT_FILTERS.read() {
+        if let Some(ids) = filters.get(session_id) {
+            frames.retain(|f| ids.contains(&f.frame_id));
+            if frames.is_empty() {
+                return;
+            }
+        }
+    }
     let active_listeners = get_active_listeners_sync(session_id);
+    crate::frame_server::publish_frames(session_id, &frames, &active_listeners);
+    if is_session_ascii_view(session_id) {
+        let frames = frames
+            .into_iter()
+            .map(|frame| AsciiFrame { ascii: ascii_view(&frame.bytes), frame })
+            .collect();
+        let payload = AsciiFrameBatchPayload {
+            frames,
+            active_listeners,
+        };
+        emit_to_session(app, "frame-message", session_id, payload);
+        return;
+    }
     let payload = FrameBatchPayload {
         frames,
         active_listeners,
@@ -1252,6 +1651,15 @@ pub fn emit_frames(
     emit_to_session(app, "frame-message", session_id, payload);
 }
 
+/// Emit first-frame-received the moment a session's first frame arrives.
+///
+/// Readers call this once, ahead of their first batched `frame-message`, so the
+/// UI can confirm live data without waiting for the emit interval. The frame is
+/// still delivered in the normal batch afterwards.
+pub fn emit_first_frame(app: &AppHandle, session_id: &str, frame: &FrameMessage) {
+    emit_to_session(app, "first-frame-received", session_id, frame.clone());
+}
+
 /// Emit stream-ended event with buffer info.
 ///
 /// Finalises the buffer and emits the stream-ended event with metadata.
@@ -1370,6 +1778,271 @@ pub fn emit_device_connected(app: &AppHandle, session_id: &str, device_type: &st
     emit_to_session(app, "device-connected", session_id, payload);
 }
 
+/// Payload for frames-dropped event
+#[derive(Clone, Debug, Serialize)]
+pub struct FramesDroppedPayload {
+    /// Frames dropped since the previous report
+    pub dropped: u64,
+    /// Total frames dropped in this session so far
+    pub total_dropped: u64,
+}
+
+/// Emit frames-dropped event when a source reports that the capture lost frames.
+pub fn emit_frames_dropped(app: &AppHandle, session_id: &str, dropped: u64, total_dropped: u64) {
+    let payload = FramesDroppedPayload { dropped, total_dropped };
+    emit_to_session(app, "frames-dropped", session_id, payload);
+}
+
+/// Payload for framer-overflow event
+#[derive(Clone, Debug, Serialize)]
+pub struct FramerOverflowPayload {
+    /// Bytes discarded since the previous report
+    pub discarded: u64,
+    /// Total bytes discarded in this session so far
+    pub total_discarded: u64,
+}
+
+/// Emit framer-overflow event when a serial framer discards a frame that
+/// outgrew its maximum length without a boundary.
+pub fn emit_framer_overflow(app: &AppHandle, session_id: &str, discarded: u64, total_discarded: u64) {
+    let payload = FramerOverflowPayload { discarded, total_discarded };
+    emit_to_session(app, "framer-overflow", session_id, payload);
+}
+
+/// Live configuration of one device bus, as reported by the device itself.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct DeviceBusConfig {
+    /// Bus number on the device (before bus mapping)
+    pub bus: u8,
+    pub enabled: bool,
+    pub listen_only: bool,
+    /// Nominal bitrate in bits/second
+    pub bitrate: u32,
+}
+
+/// Payload for device-config event
+#[derive(Clone, Debug, Serialize)]
+pub struct DeviceConfigPayload {
+    /// Device type (e.g., "gvret_tcp")
+    pub device_type: String,
+    pub buses: Vec<DeviceBusConfig>,
+}
+
+/// Emit device-config event when a source reports its live bus configuration.
+pub fn emit_device_config(app: &AppHandle, session_id: &str, payload: DeviceConfigPayload) {
+    emit_to_session(app, "device-config", session_id, payload);
+}
+
+/// Payload for bus-off event
+#[derive(Clone, Debug, Serialize)]
+pub struct BusOffPayload {
+    /// Interface whose controller went bus-off
+    pub interface: String,
+    /// Whether the reader restarted the interface itself
+    pub restarted: bool,
+    /// What happened and, if recovery is needed, how to recover
+    pub message: String,
+}
+
+/// Emit bus-off event when a source's CAN controller goes bus-off.
+pub fn emit_bus_off(app: &AppHandle, session_id: &str, payload: BusOffPayload) {
+    emit_to_session(app, "bus-off", session_id, payload);
+}
+
+/// Payload for can-error event
+#[derive(Clone, Debug, Serialize)]
+pub struct CanErrorPayload {
+    /// Bus the error was reported on (after bus mapping)
+    pub bus: u8,
+    /// When the device reported the error
+    pub timestamp_us: u64,
+    /// Decoded conditions, e.g. "rx_overflow", "tx_failed", "bus_warning"
+    pub kinds: Vec<String>,
+    /// Controller transmit error counter, when the device reported it
+    pub tx_error_count: Option<u8>,
+    /// Controller receive error counter, when the device reported it
+    pub rx_error_count: Option<u8>,
+}
+
+/// Emit can-error event when a source reports a CAN controller error.
+pub fn emit_can_error(app: &AppHandle, session_id: &str, payload: CanErrorPayload) {
+    emit_to_session(app, "can-error", session_id, payload);
+}
+
+/// Payload for source-stale event
+#[derive(Clone, Debug, Serialize)]
+pub struct SourceStalePayload {
+    pub source_index: usize,
+    pub display_name: String,
+    /// Output buses fed by the source
+    pub buses: Vec<u8>,
+    /// Time since the source last delivered traffic (or connected)
+    pub idle_ms: u64,
+    /// `true` when the source went quiet, `false` when traffic resumed
+    pub stale: bool,
+}
+
+/// Emit source-stale event when a source goes quiet past its liveness timeout, or recovers.
+pub fn emit_source_stale(app: &AppHandle, session_id: &str, payload: SourceStalePayload) {
+    emit_to_session(app, "source-stale", session_id, payload);
+}
+
+/// Payload for bus-safety-warning event
+#[derive(Clone, Debug, Serialize)]
+pub struct BusSafetyWarningPayload {
+    pub source_index: usize,
+    pub display_name: String,
+    /// Profile kind of the source (slcan, gs_usb, socketcan)
+    pub profile_kind: String,
+    /// Configured nominal bitrate, when the profile sets one
+    pub bitrate: Option<u32>,
+    /// OBD-II diagnostic IDs seen in the source's opening traffic
+    pub obd_ids: Vec<u32>,
+    pub message: String,
+}
+
+/// Emit bus-safety-warning event when an actively ACKing source appears to be on a vehicle bus.
+pub fn emit_bus_safety_warning(app: &AppHandle, session_id: &str, payload: BusSafetyWarningPayload) {
+    emit_to_session(app, "bus-safety-warning", session_id, payload);
+}
+
+/// Maximum number of sample messages kept in a parse error report
+const MAX_PARSE_ERROR_SAMPLES: usize = 10;
+
+/// Count of malformed input lines/cells skipped while parsing, with a few samples.
+///
+/// Used as the payload of the parse-errors event and returned from imports so
+/// users learn their input is malformed rather than silently getting fewer frames.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ParseErrors {
+    /// Number of errors recorded
+    pub count: usize,
+    /// First few error messages (at most `MAX_PARSE_ERROR_SAMPLES`)
+    pub samples: Vec<String>,
+    /// Latest failures with their offending bytes, for the session's
+    /// `recent_parse_errors` ring (not part of the event payload)
+    #[serde(skip)]
+    pub recent: VecDeque<ParseErrorEntry>,
+}
+
+impl ParseErrors {
+    /// Record an error, keeping its message if there is room for another sample.
+    pub fn record(&mut self, message: String) {
+        self.count += 1;
+        if self.samples.len() < MAX_PARSE_ERROR_SAMPLES {
+            self.samples.push(message);
+        }
+    }
+
+    /// Record an error and keep it (with its bytes) among the recent failures.
+    pub fn record_entry(&mut self, entry: ParseErrorEntry) {
+        self.record(entry.message.clone());
+        push_recent_parse_error(&mut self.recent, entry);
+    }
+
+    /// Fold another report into this one.
+    pub fn merge(&mut self, other: ParseErrors) {
+        self.count += other.count;
+        let room = MAX_PARSE_ERROR_SAMPLES.saturating_sub(self.samples.len());
+        self.samples.extend(other.samples.into_iter().take(room));
+        for entry in other.recent {
+            push_recent_parse_error(&mut self.recent, entry);
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+}
+
+/// Emit parse-errors event when a source skipped malformed input.
+pub fn emit_parse_errors(app: &AppHandle, session_id: &str, errors: ParseErrors) {
+    emit_to_session(app, "parse-errors", session_id, errors);
+}
+
+/// Most recent parse failures kept per session
+const MAX_RECENT_PARSE_ERRORS: usize = 50;
+/// Longest sample of offending bytes kept with a parse failure
+const MAX_PARSE_ERROR_SAMPLE_BYTES: usize = 32;
+
+/// One parse failure kept for diagnostics: a malformed slcan line, bytes GVRET
+/// skipped to resync, a serial frame the framer discarded.
+#[derive(Clone, Debug, Serialize)]
+pub struct ParseErrorEntry {
+    /// Host UNIX timestamp in microseconds
+    pub timestamp_us: u64,
+    /// Index of the source within the session
+    pub source_index: usize,
+    /// What failed, e.g. "slcan_line", "gvret_resync", "framer_overflow"
+    pub kind: String,
+    pub message: String,
+    /// Start of the offending bytes (at most `MAX_PARSE_ERROR_SAMPLE_BYTES`)
+    pub sample: Vec<u8>,
+}
+
+impl ParseErrorEntry {
+    /// A failure seen now; the source index is filled in by the merge task.
+    pub fn new(kind: &str, message: String, bytes: &[u8]) -> Self {
+        Self {
+            timestamp_us: now_us(),
+            source_index: 0,
+            kind: kind.to_string(),
+            message,
+            sample: bytes[..bytes.len().min(MAX_PARSE_ERROR_SAMPLE_BYTES)].to_vec(),
+        }
+    }
+}
+
+/// Append to a ring of recent parse failures, dropping the oldest once it
+/// holds `MAX_RECENT_PARSE_ERRORS`. Used for a source's batch and the session ring.
+fn push_recent_parse_error(ring: &mut VecDeque<ParseErrorEntry>, entry: ParseErrorEntry) {
+    if ring.len() == MAX_RECENT_PARSE_ERRORS {
+        ring.pop_front();
+    }
+    ring.push_back(entry);
+}
+
+/// Recent parse failures per session, oldest first (read by `recent_parse_errors`).
+/// Kept here rather than emitted so a noisy source can't flood the event channel.
+static SESSION_PARSE_ERRORS: Lazy<RwLock<HashMap<String, VecDeque<ParseErrorEntry>>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// Add a source's parse failures to the session's ring of recent failures.
+pub fn record_session_parse_errors(
+    session_id: &str,
+    source_index: usize,
+    entries: impl IntoIterator<Item = ParseErrorEntry>,
+) {
+    let mut entries = entries.into_iter().peekable();
+    if entries.peek().is_none() {
+        return;
+    }
+    let Ok(mut sessions) = SESSION_PARSE_ERRORS.write() else {
+        return;
+    };
+    let ring = sessions.entry(session_id.to_string()).or_default();
+    for mut entry in entries {
+        entry.source_index = source_index;
+        push_recent_parse_error(ring, entry);
+    }
+}
+
+/// A session's recent parse failures, oldest first.
+pub fn session_parse_errors(session_id: &str) -> Vec<ParseErrorEntry> {
+    SESSION_PARSE_ERRORS
+        .read()
+        .ok()
+        .and_then(|s| s.get(session_id).map(|ring| ring.iter().cloned().collect()))
+        .unwrap_or_default()
+}
+
+/// Forget a session's parse failures (called on session destroy)
+fn clear_session_parse_errors(session_id: &str) {
+    if let Ok(mut sessions) = SESSION_PARSE_ERRORS.write() {
+        sessions.remove(session_id);
+    }
+}
+
 /// Payload for device-probe event (global, not session-scoped)
 #[derive(Clone, Debug, Serialize)]
 pub struct DeviceProbePayload {
@@ -1512,6 +2185,8 @@ pub async fn create_session(
         listeners,
         source_names: source_names.unwrap_or_default(),
         suspended_at: None,
+        started_at_us: now_us(),
+        transmit_armed: false,
     };
 
     sessions.insert(session_id.clone(), session);
@@ -1549,6 +2224,12 @@ pub async fn get_session_capabilities(session_id: &str) -> Option<IOCapabilities
     sessions.get(session_id).map(|s| s.device.capabilities())
 }
 
+/// Whether a session's transmit is armed (None if session doesn't exist)
+pub async fn is_session_transmit_armed(session_id: &str) -> Option<bool> {
+    let sessions = IO_SESSIONS.lock().await;
+    sessions.get(session_id).map(|s| s.transmit_armed)
+}
+
 /// Get the joiner count for a session (0 if session doesn't exist)
 pub async fn get_session_joiner_count(session_id: &str) -> usize {
     let sessions = IO_SESSIONS.lock().await;
@@ -2332,6 +3013,73 @@ pub async fn update_session_direction(session_id: &str, reverse: bool) -> Result
     session.device.set_direction(reverse)
 }
 
+/// Enable or disable playback of one bus (buffer replay sessions)
+pub async fn update_session_bus_enabled(session_id: &str, bus: u8, enabled: bool) -> Result<(), String> {
+    let mut sessions = IO_SESSIONS.lock().await;
+    let session = sessions
+        .get_mut(session_id)
+        .ok_or_else(|| format!("Session '{}' not found", session_id))?;
+
+    session.device.set_bus_enabled(bus, enabled)
+}
+
+/// Set how long transmits on a session wait for the device to confirm the write.
+pub async fn update_session_transmit_timeout(session_id: &str, timeout_ms: u64) -> Result<(), String> {
+    let mut sessions = IO_SESSIONS.lock().await;
+    let session = sessions
+        .get_mut(session_id)
+        .ok_or_else(|| format!("Session '{}' not found", session_id))?;
+
+    session.device.set_transmit_timeout(timeout_ms)
+}
+
+/// Set the minimum gap between writes on a session, measured from each confirmed send.
+pub async fn update_session_min_tx_gap(session_id: &str, gap_us: u64) -> Result<(), String> {
+    let mut sessions = IO_SESSIONS.lock().await;
+    let session = sessions
+        .get_mut(session_id)
+        .ok_or_else(|| format!("Session '{}' not found", session_id))?;
+
+    session.device.set_min_tx_gap(gap_us)
+}
+
+/// Choose whether a session stores its own transmitted frames in its buffer.
+pub async fn update_session_buffer_tx_frames(session_id: &str, enabled: bool) -> Result<(), String> {
+    let mut sessions = IO_SESSIONS.lock().await;
+    let session = sessions
+        .get_mut(session_id)
+        .ok_or_else(|| format!("Session '{}' not found", session_id))?;
+
+    session.device.set_buffer_tx_frames(enabled)
+}
+
+/// Enable or disable transmit through one source of a session, keeping its RX.
+pub async fn update_session_source_transmit_enabled(session_id: &str, source_idx: usize, enabled: bool) -> Result<(), String> {
+    let mut sessions = IO_SESSIONS.lock().await;
+    let session = sessions
+        .get_mut(session_id)
+        .ok_or_else(|| format!("Session '{}' not found", session_id))?;
+
+    session.device.set_source_transmit_enabled(source_idx, enabled)
+}
+
+/// Query a running session's adapter for its firmware versions and serial number.
+/// The session lock is released before waiting, so a slow device doesn't hold
+/// up other session commands.
+pub async fn session_device_info(session_id: &str) -> Result<DeviceInfo, String> {
+    let sender = {
+        let sessions = IO_SESSIONS.lock().await;
+        let session = sessions
+            .get(session_id)
+            .ok_or_else(|| format!("Session '{}' not found", session_id))?;
+        session.device.device_info_sender()?
+    };
+
+    tokio::task::spawn_blocking(move || types::DeviceInfoRequest::send(&sender))
+        .await
+        .map_err(|e| format!("Device info query failed: {}", e))?
+}
+
 /// Switch a session to buffer replay mode.
 /// This replaces the session's reader with a BufferReader that reads from the session's
 /// owned buffer. The session stays alive and all listeners remain connected.
@@ -2491,13 +3239,229 @@ pub async fn destroy_session(session_id: &str) -> Result<(), String> {
             creator_listener_id: None,
         });
     }
+    drop(sessions);
+    // A schedule would otherwise keep waking to send into a missing session
+    let _ = crate::transmit::stop_transmit_schedule(session_id.to_string()).await;
     // Clear the closing flag now that the session is fully destroyed
     clear_session_closing(session_id);
     // Clear any stored startup error
     clear_startup_error(session_id);
+    clear_session_frame_count(session_id);
+    clear_session_parse_errors(session_id);
+    set_session_backpressure(session_id, false);
+    set_session_emit_filter(session_id, None);
+    set_session_ascii_view(session_id, false);
+    clear_session_source_buffers(session_id);
+    clear_frame_subscriptions(session_id);
+    crate::capture_file::stop_capture(session_id);
+    crate::signal_probe::clear_session_probes(session_id);
     Ok(())
 }
 
+/// Get the number of frames emitted by a session so far
+fn get_session_frame_count(session_id: &str) -> u64 {
+    SESSION_FRAME_COUNTS
+        .read()
+        .ok()
+        .and_then(|c| c.get(session_id).copied())
+        .unwrap_or(0)
+}
+
+/// Frames per second a session has emitted over the last couple of seconds
+/// (0 for sessions that have not emitted yet).
+pub fn session_frame_rate(session_id: &str) -> f64 {
+    let now = std::time::Instant::now();
+    SESSION_FRAME_RATES
+        .read()
+        .ok()
+        .and_then(|r| r.get(session_id).map(|w| w.rate(now)))
+        .unwrap_or(0.0)
+}
+
+/// Record frames a session emitted with a timestamp earlier than a frame it
+/// had already emitted (a source delivered them late).
+/// Reported as `reordering_needed` in `ActiveSessionInfo`.
+pub fn add_session_reordering_needed(session_id: &str, count: u64) {
+    if let Ok(mut counts) = SESSION_REORDERING_NEEDED.write() {
+        *counts.entry(session_id.to_string()).or_insert(0) += count;
+    }
+}
+
+/// Get the number of late frames a session has emitted out of order
+fn get_session_reordering_needed(session_id: &str) -> u64 {
+    SESSION_REORDERING_NEEDED
+        .read()
+        .ok()
+        .and_then(|c| c.get(session_id).copied())
+        .unwrap_or(0)
+}
+
+/// Mark whether a session is skipping frontend emission under backpressure.
+/// Reported as `backpressure` in `ActiveSessionInfo`.
+pub fn set_session_backpressure(session_id: &str, active: bool) {
+    if let Ok(mut sessions) = SESSION_BACKPRESSURE.write() {
+        if active {
+            sessions.insert(session_id.to_string());
+        } else {
+            sessions.remove(session_id);
+        }
+    }
+}
+
+/// Whether a session is currently skipping frontend emission under backpressure
+fn is_session_backpressured(session_id: &str) -> bool {
+    SESSION_BACKPRESSURE
+        .read()
+        .map(|s| s.contains(session_id))
+        .unwrap_or(false)
+}
+
+/// Restrict which frame IDs a session emits to the frontend (None = all).
+///
+/// Frames are still fully buffered; only `frame-message` IPC traffic is
+/// reduced. The filter is applied before the batch leaves the backend, so
+/// any frontend-side sampling or rate limiting only ever sees the filtered
+/// IDs. Paused-seek snapshots (`emit_snapshot_frames`) are filtered the same
+/// way without counting towards the session's frame count.
+pub fn set_session_emit_filter(session_id: &str, frame_ids: Option<Vec<u32>>) {
+    if let Ok(mut filters) = SESSION_EMIT_FILTERS.write() {
+        match frame_ids {
+            Some(ids) => {
+                tlog!("[reader] Session '{}' emit filter set to {} ID(s)", session_id, ids.len());
+                filters.insert(session_id.to_string(), ids.into_iter().collect());
+            }
+            None => {
+                filters.remove(session_id);
+            }
+        }
+    }
+}
+
+/// Add a printable-ASCII rendering (`ascii`) to each frame a session emits to
+/// the frontend, for a text column showing VINs, part numbers and the like.
+/// Presentation only: buffered frames and the frame server are unchanged.
+pub fn set_session_ascii_view(session_id: &str, enabled: bool) {
+    if let Ok(mut sessions) = SESSION_ASCII_VIEW.write() {
+        if enabled {
+            tlog!("[reader] Session '{}' ASCII view enabled", session_id);
+            sessions.insert(session_id.to_string());
+        } else {
+            sessions.remove(session_id);
+        }
+    }
+}
+
+/// Whether a session's emitted frames carry an ASCII rendering
+fn is_session_ascii_view(session_id: &str) -> bool {
+    SESSION_ASCII_VIEW
+        .read()
+        .map(|s| s.contains(session_id))
+        .unwrap_or(false)
+}
+
+/// A frame buffer holding one source's frames, alongside a multi-source
+/// session's merged buffer.
+#[derive(Clone, Debug, Serialize)]
+pub struct SourceBufferInfo {
+    pub source_index: usize,
+    pub profile_id: String,
+    pub display_name: String,
+    pub buffer_id: String,
+}
+
+/// Record a per-source buffer for a session. Reported as `source_buffers` in `ActiveSessionInfo`.
+pub fn add_session_source_buffer(session_id: &str, info: SourceBufferInfo) {
+    if let Ok(mut buffers) = SESSION_SOURCE_BUFFERS.write() {
+        buffers.entry(session_id.to_string()).or_default().push(info);
+    }
+}
+
+/// Forget a session's per-source buffers (the buffers themselves are kept)
+pub fn clear_session_source_buffers(session_id: &str) {
+    if let Ok(mut buffers) = SESSION_SOURCE_BUFFERS.write() {
+        buffers.remove(session_id);
+    }
+}
+
+/// Per-source buffers of a session, in the order they were created
+fn get_session_source_buffers(session_id: &str) -> Vec<SourceBufferInfo> {
+    SESSION_SOURCE_BUFFERS
+        .read()
+        .ok()
+        .and_then(|b| b.get(session_id).cloned())
+        .unwrap_or_default()
+}
+
+/// Register a one-shot waiter for the next received frame on a session that
+/// matches `filter`. The receiver resolves from the emit path, so register
+/// before transmitting a request to avoid missing a fast response. The
+/// session's own records of what it sent (direction "tx") never match, so a
+/// waiter only resolves on traffic that came back from the device, including
+/// its echo of a transmit. Dropping the receiver cancels the subscription.
+pub fn subscribe_next_frame(session_id: &str, filter: FrameFilter) -> tokio::sync::oneshot::Receiver<FrameMessage> {
+    let (tx, rx) = tokio::sync::oneshot::channel();
+    if let Ok(mut subs) = FRAME_SUBSCRIPTIONS.lock() {
+        subs.entry(session_id.to_string())
+            .or_default()
+            .push(FrameSubscription { filter, tx });
+    }
+    rx
+}
+
+/// Hand frames to any waiters registered for this session, dropping waiters
+/// that have been satisfied or whose receiver has gone away.
+/// Called by `record_emitted_frames`.
+fn deliver_frame_subscriptions(session_id: &str, frames: &[FrameMessage]) {
+    let Ok(mut subs) = FRAME_SUBSCRIPTIONS.lock() else {
+        return;
+    };
+    let Some(waiters) = subs.get_mut(session_id) else {
+        return;
+    };
+
+    let mut pending = Vec::with_capacity(waiters.len());
+    for waiter in waiters.drain(..) {
+        if waiter.tx.is_closed() {
+            continue;
+        }
+        let matched = frames
+            .iter()
+            .find(|f| f.direction.as_deref() != Some("tx") && waiter.filter.matches(f));
+        match matched {
+            Some(frame) => {
+                let _ = waiter.tx.send(frame.clone());
+            }
+            None => pending.push(waiter),
+        }
+    }
+
+    if pending.is_empty() {
+        subs.remove(session_id);
+    } else {
+        *waiters = pending;
+    }
+}
+
+/// Drop all frame waiters for a session (called on session destroy)
+fn clear_frame_subscriptions(session_id: &str) {
+    if let Ok(mut subs) = FRAME_SUBSCRIPTIONS.lock() {
+        subs.remove(session_id);
+    }
+}
+
+/// Clear the emitted frame and late frame counts and frame rate for a session (called on session destroy)
+fn clear_session_frame_count(session_id: &str) {
+    if let Ok(mut counts) = SESSION_FRAME_COUNTS.write() {
+        counts.remove(session_id);
+    }
+    if let Ok(mut counts) = SESSION_REORDERING_NEEDED.write() {
+        counts.remove(session_id);
+    }
+    if let Ok(mut rates) = SESSION_FRAME_RATES.write() {
+        rates.remove(session_id);
+    }
+}
+
 /// Check if a session exists
 #[allow(dead_code)]
 pub async fn session_exists(session_id: &str) -> bool {
@@ -2531,9 +3495,30 @@ pub struct ActiveSessionInfo {
     /// Frame count in the owned buffer
     #[serde(default)]
     pub buffer_frame_count: Option<usize>,
+    /// Per-source frame buffers kept alongside the merged buffer
+    /// (multi-source sessions created with `per_source_buffers`)
+    #[serde(default)]
+    pub source_buffers: Vec<SourceBufferInfo>,
     /// Whether the session is actively streaming data
     #[serde(default)]
     pub is_streaming: bool,
+    /// When the session was created (microseconds since UNIX epoch)
+    #[serde(default)]
+    pub started_at_us: u64,
+    /// Number of frames emitted by this session since it was created
+    #[serde(default)]
+    pub frame_count: u64,
+    /// Whether frames are being buffered without emission because the
+    /// frontend can't keep up
+    #[serde(default)]
+    pub backpressure: bool,
+    /// Whether transmits are allowed on this session
+    #[serde(default)]
+    pub transmit_armed: bool,
+    /// Frames emitted with a timestamp earlier than a frame already emitted,
+    /// because a source delivered them late (see `reorder_window_ms`)
+    #[serde(default)]
+    pub reordering_needed: u64,
 }
 
 /// List all active sessions
@@ -2578,14 +3563,66 @@ pub async fn list_sessions() -> Vec<ActiveSessionInfo> {
                 source_profile_ids,
                 buffer_id,
                 buffer_frame_count,
+                source_buffers: get_session_source_buffers(session_id),
                 is_streaming,
+                started_at_us: session.started_at_us,
+                frame_count: get_session_frame_count(session_id),
+                backpressure: is_session_backpressured(session_id),
+                transmit_armed: session.transmit_armed,
+                reordering_needed: get_session_reordering_needed(session_id),
             }
         })
         .collect()
 }
 
+/// Outcome of stopping one session as part of `stop_all_sessions`
+#[derive(Clone, Debug, Serialize)]
+pub struct StopSessionOutcome {
+    /// Session ID
+    pub session_id: String,
+    /// State after the stop attempt
+    pub state: IOState,
+    /// Error message if the device failed to stop
+    pub error: Option<String>,
+}
+
+/// Stop every active session (app shutdown / panic button).
+/// Each device's `stop()` is awaited so ports and sockets are released before
+/// returning. Sessions stay registered and can be restarted or destroyed.
+pub async fn stop_all_sessions() -> Vec<StopSessionOutcome> {
+    let mut sessions = IO_SESSIONS.lock().await;
+    let mut outcomes = Vec::with_capacity(sessions.len());
+
+    for (session_id, session) in sessions.iter_mut() {
+        let previous = session.device.state();
+        let error = if matches!(previous, IOState::Stopped) {
+            None
+        } else {
+            session.device.stop().await.err()
+        };
+
+        let current = session.device.state();
+        if previous != current {
+            emit_state_change(&session.app, session_id, &previous, &current);
+        }
+        if let Some(ref e) = error {
+            tlog!("[reader] stop_all_sessions: failed to stop '{}': {}", session_id, e);
+        }
+
+        outcomes.push(StopSessionOutcome {
+            session_id: session_id.clone(),
+            state: current,
+            error,
+        });
+    }
+
+    tlog!("[reader] stop_all_sessions: stopped {} session(s)", outcomes.len());
+    outcomes
+}
+
 /// Transmit a payload through a session (unified)
 pub async fn session_transmit(session_id: &str, payload: &TransmitPayload) -> Result<TransmitResult, String> {
+    wait_transmit_slot(session_id).await?;
     let sessions = IO_SESSIONS.lock().await;
     let session = sessions
         .get(session_id)
@@ -2604,23 +3641,129 @@ pub async fn session_transmit(session_id: &str, payload: &TransmitPayload) -> Re
         _ => {}
     }
 
+    if !session.transmit_armed {
+        return Err(format!(
+            "Transmit not armed for session '{}'. Arm transmit before sending.",
+            session_id
+        ));
+    }
+
     // Call device transmit - this is sync and may block waiting for result
-    // For MultiSourceReader, this blocks on recv_timeout(500ms)
+    // For MultiSourceReader, this blocks for up to the session's transmit timeout
     // We call it while holding the lock, but the actual I/O happens in the
     // source reader tasks which don't need the IO_SESSIONS lock
     session.device.transmit(payload)
 }
 
+/// Wait for the session's next paced transmit slot. The session lock is only
+/// held to reserve the slot, not while waiting for it.
+async fn wait_transmit_slot(session_id: &str) -> Result<(), String> {
+    let wait = {
+        let sessions = IO_SESSIONS.lock().await;
+        sessions
+            .get(session_id)
+            .ok_or_else(|| format!("Session '{}' not found", session_id))?
+            .device
+            .reserve_transmit_slot()
+    };
+    if !wait.is_zero() {
+        tokio::time::sleep(wait).await;
+    }
+    Ok(())
+}
+
+/// Allow or block transmits on a session. Emits `transmit-armed` so every
+/// app sharing the session sees the change.
+pub async fn arm_session_transmit(session_id: &str, armed: bool) -> Result<(), String> {
+    let mut sessions = IO_SESSIONS.lock().await;
+    let session = sessions
+        .get_mut(session_id)
+        .ok_or_else(|| format!("Session '{}' not found", session_id))?;
+
+    if session.transmit_armed != armed {
+        session.transmit_armed = armed;
+        tlog!(
+            "[reader] Session '{}' transmit {}",
+            session_id,
+            if armed { "armed" } else { "disarmed" }
+        );
+        emit_to_session(&session.app, "transmit-armed", session_id, armed);
+    }
+    Ok(())
+}
+
 /// Transmit a CAN frame through a session (convenience wrapper)
 pub async fn transmit_frame(session_id: &str, frame: &CanTransmitFrame) -> Result<TransmitResult, String> {
     session_transmit(session_id, &TransmitPayload::CanFrame(frame.clone())).await
 }
 
+/// Transmit a burst of CAN frames through a session, one result per frame.
+/// The session-level checks (capability, armed) fail the whole burst.
+/// With a minimum transmit gap set, frames are sent one at a time so the
+/// session lock is released while waiting between them.
+pub async fn transmit_frames(session_id: &str, frames: &[CanTransmitFrame]) -> Result<Vec<TransmitResult>, String> {
+    let paced = {
+        let sessions = IO_SESSIONS.lock().await;
+        sessions
+            .get(session_id)
+            .ok_or_else(|| format!("Session '{}' not found", session_id))?
+            .device
+            .transmit_paced()
+    };
+    if paced {
+        let mut results = Vec::with_capacity(frames.len());
+        for (idx, frame) in frames.iter().enumerate() {
+            match transmit_frame(session_id, frame).await {
+                Ok(result) => results.push(result),
+                Err(e) if idx == 0 => return Err(e),
+                Err(e) => results.push(TransmitResult::error(e)),
+            }
+        }
+        return Ok(results);
+    }
+
+    let sessions = IO_SESSIONS.lock().await;
+    let session = sessions
+        .get(session_id)
+        .ok_or_else(|| format!("Session '{}' not found", session_id))?;
+
+    if !session.device.capabilities().can_transmit {
+        return Err("This session does not support CAN transmission".to_string());
+    }
+    if !session.transmit_armed {
+        return Err(format!(
+            "Transmit not armed for session '{}'. Arm transmit before sending.",
+            session_id
+        ));
+    }
+
+    Ok(session.device.transmit_batch(frames))
+}
+
 /// Transmit raw serial bytes through a session (convenience wrapper)
 pub async fn transmit_serial(session_id: &str, bytes: &[u8]) -> Result<TransmitResult, String> {
     session_transmit(session_id, &TransmitPayload::RawBytes(bytes.to_vec())).await
 }
 
+/// Write raw bytes straight to a session's device, bypassing frame encoding.
+/// Requires transmit to be armed like any other transmit.
+pub async fn transmit_device_command(session_id: &str, bytes: &[u8]) -> Result<TransmitResult, String> {
+    wait_transmit_slot(session_id).await?;
+    let sessions = IO_SESSIONS.lock().await;
+    let session = sessions
+        .get(session_id)
+        .ok_or_else(|| format!("Session '{}' not found", session_id))?;
+
+    if !session.transmit_armed {
+        return Err(format!(
+            "Transmit not armed for session '{}'. Arm transmit before sending.",
+            session_id
+        ));
+    }
+
+    session.device.transmit_device_command(bytes)
+}
+
 // ============================================================================
 // Listener Registration API
 // ============================================================================
@@ -2876,7 +4019,7 @@ pub async fn evict_session_listener(app: &AppHandle, session_id: &str, listener_
 pub async fn add_source_to_session(
     app: &AppHandle,
     session_id: &str,
-    new_source: SourceConfig,
+    mut new_source: SourceConfig,
 ) -> Result<IOCapabilities, String> {
     let mut sessions = IO_SESSIONS.lock().await;
     let session = sessions
@@ -2895,6 +4038,11 @@ pub async fn add_source_to_session(
         ));
     }
 
+    // Inherit the session-level timestamp source if the new source has none
+    if new_source.timestamp_source.is_none() {
+        new_source.timestamp_source = existing_configs.iter().find_map(|c| c.timestamp_source);
+    }
+
     let new_display_name = new_source.display_name.clone();
 
     // If the session is running, hot-add the source without stopping
@@ -3035,6 +4183,33 @@ pub async fn update_source_bus_mappings(
     Ok(capabilities)
 }
 
+/// Update one source's bus mappings in a multi-source session.
+/// Remapping or disabling buses takes effect in place; enabling a bus the source
+/// never opened reconnects that source.
+pub async fn update_session_bus_mapping(
+    session_id: &str,
+    source_idx: usize,
+    bus_mappings: Vec<BusMapping>,
+) -> Result<IOCapabilities, String> {
+    let mut sessions = IO_SESSIONS.lock().await;
+    let session = sessions
+        .get_mut(session_id)
+        .ok_or_else(|| format!("Session '{}' not found", session_id))?;
+
+    session.device.update_bus_mapping(source_idx, bus_mappings)?;
+    Ok(session.device.capabilities())
+}
+
+/// Get the current bus mappings of each source in a multi-source session.
+pub async fn get_session_bus_mappings(session_id: &str) -> Result<Vec<multi_source::SourceBusMappings>, String> {
+    let sessions = IO_SESSIONS.lock().await;
+    let session = sessions
+        .get(session_id)
+        .ok_or_else(|| format!("Session '{}' not found", session_id))?;
+
+    session.device.bus_mappings()
+}
+
 /// Get all listeners for a session.
 /// Useful for debugging and for the frontend to understand session state.
 pub async fn get_session_listeners(session_id: &str) -> Result<Vec<ListenerInfo>, String> {
@@ -3198,3 +4373,242 @@ pub async fn set_listener_active(session_id: &str, listener_id: &str, is_active:
         Err(format!("Listener '{}' not found in session '{}'", listener_id, session_id))
     }
 }
+
+/// Fixtures shared by the test modules across the crate
+#[cfg(test)]
+pub(crate) mod test_support {
+    use super::{CanTransmitFrame, FrameMessage};
+    use crate::buffer_db;
+
+    /// Tests that touch the global buffer database take turns on this lock
+    static DB_TEST_LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+    /// A fresh buffer database in a temp directory, held exclusively by one
+    /// test. The directory is removed when the guard is dropped.
+    pub struct TestDb {
+        dir: std::path::PathBuf,
+        _lock: std::sync::MutexGuard<'static, ()>,
+    }
+
+    impl TestDb {
+        /// `name` keeps the directories of different tests apart
+        pub fn new(name: &str) -> Self {
+            let lock = DB_TEST_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+            let dir = std::env::temp_dir().join(format!("candor_{}_{}", name, std::process::id()));
+            buffer_db::initialise(&dir, true).unwrap();
+            Self { dir, _lock: lock }
+        }
+    }
+
+    impl Drop for TestDb {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.dir);
+        }
+    }
+
+    /// A received classic CAN frame on bus 0 with a standard ID and every
+    /// optional field unset. Override the rest with struct update syntax.
+    pub fn can_frame(frame_id: u32, timestamp_us: u64, bytes: Vec<u8>) -> FrameMessage {
+        FrameMessage {
+            protocol: "can".to_string(),
+            timestamp_us,
+            frame_id,
+            bus: 0,
+            dlc: bytes.len() as u8,
+            bytes,
+            is_extended: false,
+            is_fd: false,
+            source_address: None,
+            incomplete: None,
+            direction: None,
+            dlc_raw: None,
+            is_rtr: false,
+            is_brs: false,
+            is_esi: false,
+            original_frame_id: None,
+            source_label: None,
+        }
+    }
+
+    /// `can_frame` with an empty payload, for tests that only look at IDs,
+    /// buses and timing
+    pub fn can_frame_at(frame_id: u32, timestamp_us: u64) -> FrameMessage {
+        can_frame(frame_id, timestamp_us, Vec::new())
+    }
+
+    /// A classic CAN frame to transmit on bus 0 with a standard ID
+    pub fn can_transmit(frame_id: u32, data: Vec<u8>) -> CanTransmitFrame {
+        CanTransmitFrame {
+            frame_id,
+            data,
+            bus: 0,
+            is_extended: false,
+            is_fd: false,
+            is_brs: false,
+            is_rtr: false,
+            rtr_dlc: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frame_rate_window() {
+        use std::time::Duration;
+        let start = std::time::Instant::now();
+        let mut window = FrameRateWindow::new(start);
+        assert_eq!(window.rate(start), 0.0);
+
+        // 100 frames every 100 ms for 5 s
+        for i in 0..50 {
+            window.record(start + Duration::from_millis(i * 100), 100);
+        }
+        let rate = window.rate(start + Duration::from_millis(4_999));
+        assert!((rate - 1_000.0).abs() < 60.0, "rate {}", rate);
+        assert!(window.buckets.len() <= FRAME_RATE_WINDOW_BUCKETS as usize);
+
+        // Traffic stops: the rate decays to zero once the window has passed
+        assert_eq!(window.rate(start + Duration::from_secs(8)), 0.0);
+    }
+
+    #[test]
+    fn test_session_parse_errors_ring() {
+        let session = "test_session_parse_errors_ring";
+        let long = vec![0xAA; 100];
+        record_session_parse_errors(session, 2, vec![ParseErrorEntry::new("slcan_line", "bad".to_string(), &long)]);
+        let recent = session_parse_errors(session);
+        assert_eq!(recent.len(), 1);
+        assert_eq!(recent[0].source_index, 2);
+        assert_eq!(recent[0].sample.len(), MAX_PARSE_ERROR_SAMPLE_BYTES);
+
+        let entries = (0..MAX_RECENT_PARSE_ERRORS).map(|i| ParseErrorEntry::new("gvret_resync", i.to_string(), &[]));
+        record_session_parse_errors(session, 0, entries);
+        let recent = session_parse_errors(session);
+        assert_eq!(recent.len(), MAX_RECENT_PARSE_ERRORS);
+        // The oldest entry made room for the newest
+        assert_eq!(recent[0].message, "0");
+        assert_eq!(recent.last().unwrap().message, (MAX_RECENT_PARSE_ERRORS - 1).to_string());
+
+        clear_session_parse_errors(session);
+        assert!(session_parse_errors(session).is_empty());
+    }
+
+    #[test]
+    fn test_ascii_view_marks_non_printable() {
+        assert_eq!(ascii_view(b"1FTFW1E5\x00\x7F\xFF~ "), "1FTFW1E5...~ ");
+
+        // FD-length payloads render in full
+        let vin = b"WVWZZZ1JZXW000001";
+        let mut fd = vec![0u8; 64];
+        fd[..vin.len()].copy_from_slice(vin);
+        let text = ascii_view(&fd);
+        assert_eq!(text.len(), 64);
+        assert!(text.starts_with("WVWZZZ1JZXW000001."));
+    }
+
+    #[test]
+    fn test_subscription_skips_own_tx_record() {
+        let session = "test_subscription_skips_own_tx_record";
+        let probe = vec![0x4C, 0x42, 0x00, 0x01];
+        let filter = FrameFilter {
+            frame_id: Some(0x7E0),
+            bus: Some(0),
+            is_extended: Some(false),
+            data: Some(probe.clone()),
+            mask: None,
+        };
+        let mut rx = subscribe_next_frame(session, filter);
+
+        // The session's record of sending the probe doesn't satisfy the waiter
+        let record = FrameMessage {
+            direction: Some("tx".to_string()),
+            ..test_support::can_frame(0x7E0, 1_000, probe.clone())
+        };
+        deliver_frame_subscriptions(session, &[record]);
+        assert!(rx.try_recv().is_err());
+
+        // The device's echo does
+        deliver_frame_subscriptions(session, &[test_support::can_frame(0x7E0, 1_250, probe)]);
+        assert_eq!(rx.try_recv().unwrap().timestamp_us, 1_250);
+        assert!(!FRAME_SUBSCRIPTIONS.lock().unwrap().contains_key(session));
+    }
+
+    /// Gates transmit on its state like the hardware readers, which need a live
+    /// AppHandle and so cannot be built here.
+    struct StateGatedDevice {
+        state: IOState,
+    }
+
+    #[async_trait]
+    impl IODevice for StateGatedDevice {
+        fn capabilities(&self) -> IOCapabilities {
+            IOCapabilities::realtime_can()
+        }
+
+        async fn start(&mut self) -> Result<(), String> {
+            Ok(())
+        }
+
+        async fn stop(&mut self) -> Result<(), String> {
+            Ok(())
+        }
+
+        async fn pause(&mut self) -> Result<(), String> {
+            Ok(())
+        }
+
+        async fn resume(&mut self) -> Result<(), String> {
+            Ok(())
+        }
+
+        fn set_speed(&mut self, _speed: f64) -> Result<(), String> {
+            Ok(())
+        }
+
+        fn set_time_range(&mut self, _start: Option<String>, _end: Option<String>) -> Result<(), String> {
+            Ok(())
+        }
+
+        fn transmit(&self, _payload: &TransmitPayload) -> Result<TransmitResult, String> {
+            ensure_transmit_state(&self.state)?;
+            Ok(TransmitResult::success())
+        }
+
+        fn state(&self) -> IOState {
+            self.state.clone()
+        }
+
+        fn session_id(&self) -> &str {
+            "state-gated"
+        }
+    }
+
+    #[test]
+    fn test_transmit_rejected_unless_running() {
+        let frame = test_support::can_transmit(0x123, vec![0x01]);
+        let payload = TransmitPayload::CanFrame(frame.clone());
+
+        let running = StateGatedDevice { state: IOState::Running };
+        assert!(running.transmit(&payload).unwrap().success);
+
+        for (state, expected) in [
+            (IOState::Stopped, "Cannot transmit: session is stopped"),
+            (IOState::Paused, "Cannot transmit: session is paused"),
+            (IOState::Error("unplugged".to_string()), "Cannot transmit: session is in error (unplugged)"),
+        ] {
+            let device = StateGatedDevice { state };
+            assert_eq!(device.transmit(&payload).unwrap_err(), expected);
+
+            // A burst fails every frame with the same reason
+            let results = device.transmit_batch(&[frame.clone(), frame.clone()]);
+            assert_eq!(results.len(), 2);
+            for result in results {
+                assert!(!result.success);
+                assert_eq!(result.error.as_deref(), Some(expected));
+            }
+        }
+    }
+}
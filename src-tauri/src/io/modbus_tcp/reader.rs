@@ -27,7 +27,7 @@ use tokio_modbus::prelude::*;
 
 use crate::buffer_store::{self, BufferType};
 use crate::io::{
-    emit_device_connected, emit_frames, emit_stream_ended, emit_to_session, now_us,
+    emit_device_connected, emit_first_frame, emit_frames, emit_stream_ended, emit_to_session, now_us,
     FrameMessage, IOCapabilities, IODevice, IOState, InterfaceTraits, Protocol,
     SessionDataStreams, TemporalMode,
 };
@@ -183,15 +183,18 @@ impl IODevice for ModbusTcpReader {
             self.config.polls.len()
         );
 
-        // Spawn one poll task per group
+        // Spawn one poll task per group; they share the session's first-frame flag
+        let seen_first_frame = Arc::new(AtomicBool::new(false));
         for poll in &self.config.polls {
             let handle = spawn_poll_task(
                 self.app.clone(),
                 self.session_id.clone(),
+                buffer_id.clone(),
                 self.config.unit_id,
                 poll.clone(),
                 ctx.clone(),
                 self.cancel_flag.clone(),
+                seen_first_frame.clone(),
                 self.config.max_register_errors,
             );
             self.task_handles.push(handle);
@@ -254,13 +257,16 @@ impl IODevice for ModbusTcpReader {
 // Poll Task
 // ============================================================================
 
+#[allow(clippy::too_many_arguments)]
 fn spawn_poll_task(
     app: AppHandle,
     session_id: String,
+    buffer_id: String,
     unit_id: u8,
     poll: PollGroup,
     ctx: Arc<Mutex<client::Context>>,
     cancel_flag: Arc<AtomicBool>,
+    seen_first_frame: Arc<AtomicBool>,
     max_register_errors: u32,
 ) -> tauri::async_runtime::JoinHandle<()> {
     tauri::async_runtime::spawn(async move {
@@ -360,9 +366,18 @@ fn spawn_poll_task(
                         source_address: None,
                         incomplete: None,
                         direction: Some("rx".to_string()),
+                        dlc_raw: None,
+                        is_rtr: false,
+                        is_brs: false,
+                        is_esi: false,
+                        original_frame_id: None,
+                        source_label: None,
                     };
 
-                    buffer_store::append_frames(vec![frame.clone()]);
+                    if !seen_first_frame.swap(true, Ordering::Relaxed) {
+                        emit_first_frame(&app, &session_id, &frame);
+                    }
+                    buffer_store::append_frames_to_buffer(&buffer_id, vec![frame.clone()]);
                     emit_frames(&app, &session_id, vec![frame]);
                 }
                 Err(e) => {
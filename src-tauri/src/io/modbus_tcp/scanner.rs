@@ -202,6 +202,12 @@ pub async fn modbus_scan_registers(
                         source_address: None,
                         incomplete: None,
                         direction: Some("rx".to_string()),
+                        dlc_raw: None,
+                        is_rtr: false,
+                        is_brs: false,
+                        is_esi: false,
+                        original_frame_id: None,
+                        source_label: None,
                     });
                 }
                 found_count += frames.len() as u32;
@@ -224,6 +230,12 @@ pub async fn modbus_scan_registers(
                         source_address: None,
                         incomplete: None,
                         direction: Some("rx".to_string()),
+                        dlc_raw: None,
+                        is_rtr: false,
+                        is_brs: false,
+                        is_esi: false,
+                        original_frame_id: None,
+                        source_label: None,
                     });
                 }
                 found_count += frames.len() as u32;
@@ -412,6 +424,12 @@ pub async fn modbus_scan_unit_ids(
                         source_address: None,
                         incomplete: None,
                         direction: Some("rx".to_string()),
+                        dlc_raw: None,
+                        is_rtr: false,
+                        is_brs: false,
+                        is_esi: false,
+                        original_frame_id: None,
+                        source_label: None,
                     };
                     found_count += 1;
                     let _ = app.emit("modbus-scan-frame", vec![frame]);
@@ -489,6 +507,12 @@ pub async fn modbus_scan_unit_ids(
                         source_address: None,
                         incomplete: None,
                         direction: Some("rx".to_string()),
+                        dlc_raw: None,
+                        is_rtr: false,
+                        is_brs: false,
+                        is_esi: false,
+                        original_frame_id: None,
+                        source_label: None,
                     };
                     found_count += 1;
                     let _ = app.emit("modbus-scan-frame", vec![frame]);
@@ -513,6 +537,12 @@ pub async fn modbus_scan_unit_ids(
                         source_address: None,
                         incomplete: None,
                         direction: Some("rx".to_string()),
+                        dlc_raw: None,
+                        is_rtr: false,
+                        is_brs: false,
+                        is_esi: false,
+                        original_frame_id: None,
+                        source_label: None,
                     };
                     found_count += 1;
                     let _ = app.emit("modbus-scan-frame", vec![frame]);
@@ -537,6 +567,12 @@ pub async fn modbus_scan_unit_ids(
                         source_address: None,
                         incomplete: None,
                         direction: Some("rx".to_string()),
+                        dlc_raw: None,
+                        is_rtr: false,
+                        is_brs: false,
+                        is_esi: false,
+                        original_frame_id: None,
+                        source_label: None,
                     };
                     found_count += 1;
                     let _ = app.emit("modbus-scan-frame", vec![frame]);
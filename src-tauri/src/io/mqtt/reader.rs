@@ -23,7 +23,7 @@ use std::sync::{
 use tauri::AppHandle;
 use tokio::time::Duration;
 
-use crate::io::{emit_device_connected, emit_frames, emit_stream_ended, emit_to_session, now_us, FrameMessage, IOCapabilities, IODevice, IOState};
+use crate::io::{emit_device_connected, emit_first_frame, emit_frames, emit_stream_ended, emit_to_session, idle_stop_due, now_us, FrameMessage, IOCapabilities, IODevice, IOState};
 use crate::buffer_store::{self, BufferType};
 
 // ============================================================================
@@ -45,6 +45,9 @@ pub struct MqttConfig {
     pub topic: String,
     /// Client ID (auto-generated if None)
     pub client_id: Option<String>,
+    /// End the stream once frames have been seen and none arrive for this
+    /// long (`auto_stop_after_idle_ms`, shared with the multi-source reader)
+    pub auto_stop_after_idle_ms: Option<u64>,
 }
 
 impl Default for MqttConfig {
@@ -56,6 +59,7 @@ impl Default for MqttConfig {
             password: None,
             topic: "wiretap/#".to_string(),
             client_id: None,
+            auto_stop_after_idle_ms: None,
         }
     }
 }
@@ -278,6 +282,8 @@ fn spawn_mqtt_stream(
         emit_device_connected(&app_handle, &session_id, "mqtt", &address, None);
 
         // Process incoming messages
+        let mut seen_first_frame = false;
+        let mut last_traffic: Option<std::time::Instant> = None;
         loop {
             if cancel_flag.load(Ordering::Relaxed) {
                 stream_reason = "stopped";
@@ -307,10 +313,22 @@ fn spawn_mqtt_stream(
                                     source_address: None,
                                     incomplete: None,
                                     direction: Some("rx".to_string()),
+                                    dlc_raw: None,
+                                    is_rtr: false,
+                                    is_brs: false,
+                                    is_esi: false,
+                                    original_frame_id: None,
+                                    source_label: None,
                                 };
 
+                                if !seen_first_frame {
+                                    seen_first_frame = true;
+                                    emit_first_frame(&app_handle, &session_id, &frame);
+                                }
+                                last_traffic = Some(std::time::Instant::now());
+
                                 // Buffer frame for replay
-                                buffer_store::append_frames(vec![frame.clone()]);
+                                buffer_store::append_frames_to_buffer(&buffer_id, vec![frame.clone()]);
 
                                 // Emit to frontend
                                 emit_frames(&app_handle, &session_id, vec![frame]);
@@ -340,6 +358,15 @@ fn spawn_mqtt_stream(
                     // Timeout - continue loop to check cancel flag
                 }
             }
+
+            if idle_stop_due(config.auto_stop_after_idle_ms, last_traffic, std::time::Instant::now()) {
+                tlog!(
+                    "[MQTT:{}] No traffic for {} ms, auto-stopping",
+                    session_id, config.auto_stop_after_idle_ms.unwrap_or(0)
+                );
+                stream_reason = "idle";
+                break;
+            }
         }
 
         // Disconnect cleanly
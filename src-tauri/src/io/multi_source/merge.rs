@@ -9,13 +9,14 @@ use tokio::sync::mpsc;
 
 use std::collections::HashMap;
 use std::sync::Mutex;
-use super::spawner::run_source_reader;
-use super::types::{SourceConfig, TransmitChannels};
-use super::{MergeCommand, VirtualBusCommand, VirtualBusControls, VirtualCmdTx};
+use super::spawner::{run_pre_listen, run_source_reader};
+use super::types::{DeviceInfoChannels, SharedBusMappings, SourceBusMappings, SourceConfig, TransmitChannels, WakeupStep};
+use super::{validate_transmit_for, MergeCommand, VirtualBusCommand, VirtualBusControls, VirtualCmdTx};
 use crate::settings;
-use crate::buffer_store::{self, TimestampedByte};
+use crate::buffer_store::{self, BufferType, TimestampedByte};
+use crate::obd::is_obd_id;
 use crate::io::types::{RawBytesPayload, SourceMessage};
-use crate::io::{emit_device_connected, emit_frames, emit_session_error, emit_stream_ended, emit_to_session, FrameMessage};
+use crate::io::{add_session_reordering_needed, add_session_source_buffer, emit_buffer_created, emit_bus_off, emit_bus_safety_warning, emit_can_error, emit_device_config, emit_device_connected, emit_first_frame, emit_frames, emit_frames_dropped, emit_framer_overflow, emit_parse_errors, emit_session_error, emit_source_stale, emit_stream_ended, emit_to_session, is_session_transmit_armed, record_emitted_frames, record_session_parse_errors, set_session_backpressure, transmit_frame, transmit_serial, CanTransmitFrame, BusSafetyWarningPayload, CAN_EXTENDED_ID_MASK, CAN_STANDARD_ID_MASK, FrameMessage, SourceBufferInfo, SourceStalePayload};
 
 /// Minimum pending frames before emission.
 const FRAME_BATCH_THRESHOLD: usize = 100;
@@ -23,20 +24,580 @@ const FRAME_BATCH_THRESHOLD: usize = 100;
 const BYTE_BATCH_THRESHOLD: usize = 256;
 /// Maximum time (ms) between forced emissions.
 const MERGE_EMIT_INTERVAL_MS: u64 = 50;
+/// Frames per rate window at which emission is considered backed up; frames
+/// are then buffered without being emitted to the frontend.
+const BACKPRESSURE_HIGH_WATER: usize = 20_000;
+/// Frames per rate window at or below which emission resumes after backpressure.
+const BACKPRESSURE_LOW_WATER: usize = 5_000;
+/// Length (ms) of the window the emitted frame rate is counted over.
+const BACKPRESSURE_WINDOW_MS: u64 = 1_000;
+/// Minimum time (ms) backpressure stays on after the high-water mark was last hit.
+const BACKPRESSURE_HOLD_MS: u64 = 1_000;
 /// Interval (s) between per-bus frame count log messages.
 const BUS_LOG_INTERVAL_SECS: u64 = 5;
+/// Bitrates typical of vehicle buses (250k body/J1939, 500k powertrain/diagnostics).
+const VEHICLE_BITRATES: [u32; 2] = [250_000, 500_000];
+/// How long (ms) an actively ACKing source's opening traffic is watched for OBD-II IDs.
+const BUS_SAFETY_WINDOW_MS: u64 = 2_000;
+
+/// Liveness state for a source with a `stale_after_ms` timeout.
+///
+/// A source that connects but never delivers frames (wrong bitrate, unplugged
+/// bus) is otherwise indistinguishable from a quiet one.
+struct SourceLiveness {
+    display_name: String,
+    buses: Vec<u8>,
+    stale_after: std::time::Duration,
+    last_traffic: std::time::Instant,
+    stale: bool,
+}
+
+impl SourceLiveness {
+    /// Build liveness tracking from the profile's `stale_after_ms` (absent or 0 = disabled).
+    fn from_profile(source_config: &SourceConfig, profile: &settings::IOProfile) -> Option<Self> {
+        let stale_after_ms = profile
+            .connection
+            .get("stale_after_ms")
+            .and_then(|v| v.as_u64().or_else(|| v.as_str().and_then(|s| s.parse().ok())))
+            .filter(|&ms| ms > 0)?;
+        Some(Self {
+            display_name: source_config.display_name.clone(),
+            buses: source_config
+                .bus_mappings
+                .iter()
+                .filter(|m| m.enabled)
+                .map(|m| m.output_bus)
+                .collect(),
+            stale_after: std::time::Duration::from_millis(stale_after_ms),
+            last_traffic: std::time::Instant::now(),
+            stale: false,
+        })
+    }
+
+    /// Record traffic. Returns true if the source was stale and has now recovered.
+    fn touch(&mut self, now: std::time::Instant) -> bool {
+        self.last_traffic = now;
+        std::mem::replace(&mut self.stale, false)
+    }
+
+    /// Returns true when the source has just crossed its timeout.
+    fn check(&mut self, now: std::time::Instant) -> bool {
+        if self.stale || now.duration_since(self.last_traffic) < self.stale_after {
+            return false;
+        }
+        self.stale = true;
+        true
+    }
+
+    fn payload(&self, source_index: usize, now: std::time::Instant) -> SourceStalePayload {
+        SourceStalePayload {
+            source_index,
+            display_name: self.display_name.clone(),
+            buses: self.buses.clone(),
+            idle_ms: now.duration_since(self.last_traffic).as_millis() as u64,
+            stale: self.stale,
+        }
+    }
+}
+
+/// Advisory check for a source that will ACK traffic (slcan without silent mode,
+/// gs_usb without listen-only, SocketCAN) on what looks like a vehicle bus.
+///
+/// slcan and gs_usb sources are first opened silent for a brief pre-listen
+/// (see `pre_listen_profile`), so OBD-II IDs at a vehicle bitrate are reported
+/// before the device starts ACKing. SocketCAN interfaces are already up when
+/// opened, so their opening traffic is watched instead. The user is told once
+/// and can switch to listen-only; the capture itself is never held up.
+struct BusSafetyWatch {
+    display_name: String,
+    profile_kind: String,
+    bitrate: Option<u32>,
+    /// When the source's first frames arrived
+    started: Option<std::time::Instant>,
+    done: bool,
+}
+
+impl BusSafetyWatch {
+    /// Build the check for non-silent CAN sources configured for a vehicle bitrate
+    /// (SocketCAN without a bitrate uses the interface's existing setup, so it is watched too).
+    fn from_profile(source_config: &SourceConfig, profile: &settings::IOProfile) -> Option<Self> {
+        let flag = |key: &str, default: bool| {
+            profile.connection.get(key).and_then(|v| v.as_bool()).unwrap_or(default)
+        };
+        let silent = match source_config.profile_kind.as_str() {
+            "slcan" => flag("silent_mode", false),
+            "gs_usb" => flag("listen_only", true),
+            "socketcan" => false,
+            _ => return None,
+        };
+        if silent {
+            return None;
+        }
+        let bitrate = profile
+            .connection
+            .get("bitrate")
+            .and_then(|v| v.as_u64().or_else(|| v.as_str().and_then(|s| s.parse().ok())))
+            .map(|v| v as u32)
+            .or(match source_config.profile_kind.as_str() {
+                "socketcan" => None,
+                _ => Some(500_000),
+            });
+        if bitrate.is_some_and(|b| !VEHICLE_BITRATES.contains(&b)) {
+            return None;
+        }
+        Some(Self {
+            display_name: source_config.display_name.clone(),
+            profile_kind: source_config.profile_kind.clone(),
+            bitrate,
+            started: None,
+            done: false,
+        })
+    }
+
+    /// Look at a batch of the source's frames. Returns the warning the first
+    /// time OBD-II IDs show up within the watch window.
+    fn observe(
+        &mut self,
+        source_index: usize,
+        frames: &[FrameMessage],
+        now: std::time::Instant,
+    ) -> Option<BusSafetyWarningPayload> {
+        if self.done || frames.is_empty() {
+            return None;
+        }
+        let started = *self.started.get_or_insert(now);
+        if now.duration_since(started) > std::time::Duration::from_millis(BUS_SAFETY_WINDOW_MS) {
+            self.done = true;
+            return None;
+        }
+        let mut obd_ids: Vec<u32> = frames.iter().filter(|f| is_obd_id(f)).map(|f| f.frame_id).collect();
+        if obd_ids.is_empty() {
+            return None;
+        }
+        obd_ids.sort_unstable();
+        obd_ids.dedup();
+        self.done = true;
+        Some(BusSafetyWarningPayload {
+            source_index,
+            display_name: self.display_name.clone(),
+            profile_kind: self.profile_kind.clone(),
+            bitrate: self.bitrate,
+            obd_ids,
+            message: format!(
+                "{} will ACK traffic on what looks like a vehicle bus (OBD-II traffic seen). \
+                 Consider listen-only mode to avoid interfering with the vehicle.",
+                self.display_name
+            ),
+        })
+    }
+}
+
+/// Copy of a CAN profile that opens the device silent (slcan `L`, gs_usb
+/// listen-only) for the bus safety pre-listen. None for sources that can't be
+/// opened silent: a passive slcan attach leaves the device's mode alone, and
+/// SocketCAN interfaces are set up outside the app.
+fn pre_listen_profile(profile: &settings::IOProfile) -> Option<settings::IOProfile> {
+    let passive = profile.connection.get("passive_attach").and_then(|v| v.as_bool()).unwrap_or(false);
+    let key = match profile.kind.as_str() {
+        "slcan" if !passive => "silent_mode",
+        "gs_usb" => "listen_only",
+        _ => return None,
+    };
+    let mut silent = profile.clone();
+    silent.connection.insert(key.to_string(), serde_json::Value::Bool(true));
+    Some(silent)
+}
+
+/// Reorder window (ms) from a profile's `reorder_window_ms` (absent = 0, disabled).
+fn reorder_window_from_profile(profile: &settings::IOProfile) -> u64 {
+    profile
+        .connection
+        .get("reorder_window_ms")
+        .and_then(|v| v.as_u64().or_else(|| v.as_str().and_then(|s| s.parse().ok())))
+        .unwrap_or(0)
+}
+
+/// Whether a source's channel carries CAN FD. slcan and SocketCAN open the
+/// channel in classic mode unless the profile sets `enable_fd`; other kinds
+/// check FD in their own transmit path.
+pub(super) fn fd_enabled_from_profile(profile: &settings::IOProfile) -> bool {
+    match profile.kind.as_str() {
+        "slcan" | "socketcan" => profile.connection.get("enable_fd").and_then(|v| v.as_bool()).unwrap_or(false),
+        _ => true,
+    }
+}
+
+/// Idle auto-stop (ms) from a profile's `auto_stop_after_idle_ms` (absent or 0 = disabled).
+pub(crate) fn auto_stop_idle_from_profile(profile: &settings::IOProfile) -> Option<u64> {
+    profile
+        .connection
+        .get("auto_stop_after_idle_ms")
+        .and_then(|v| v.as_u64().or_else(|| v.as_str().and_then(|s| s.parse().ok())))
+        .filter(|&ms| ms > 0)
+}
+
+/// Whether a session with an idle auto-stop should end: traffic has been seen
+/// and none has arrived for `idle_ms`. The timer only starts at the first
+/// traffic so a capture started ahead of a test waits for it.
+pub(crate) fn idle_stop_due(idle_ms: Option<u64>, last_traffic: Option<std::time::Instant>, now: std::time::Instant) -> bool {
+    match (idle_ms, last_traffic) {
+        (Some(ms), Some(last)) => now.duration_since(last) >= std::time::Duration::from_millis(ms),
+        _ => false,
+    }
+}
+
+/// Store merged frames in the session's frame buffer (sessions without framing have none).
+/// Unless `buffer_tx` is set, the session's own transmitted frames are left out.
+fn store_frames(frames_buffer_id: &Option<String>, mut frames: Vec<FrameMessage>, buffer_tx: bool) {
+    if let Some(buffer_id) = frames_buffer_id {
+        if !buffer_tx {
+            drop_tx_frames(&mut frames);
+        }
+        if !frames.is_empty() {
+            buffer_store::append_frames_to_buffer(buffer_id, frames);
+        }
+    }
+}
+
+/// Append each source's batched frames to its own buffer (per_source_buffers)
+fn store_source_frames(source_buffers: &HashMap<usize, String>, pending: &mut HashMap<usize, Vec<FrameMessage>>) {
+    for (source_idx, frames) in pending.drain() {
+        if let Some(buffer_id) = source_buffers.get(&source_idx) {
+            buffer_store::append_frames_to_buffer(buffer_id, frames);
+        }
+    }
+}
+
+/// Remove frames the session transmitted itself (direction "tx")
+fn drop_tx_frames(frames: &mut Vec<FrameMessage>) {
+    frames.retain(|f| f.direction.as_deref() != Some("tx"));
+}
+
+/// Create a frame buffer for one source's frames, kept alongside the merged
+/// session buffer. Raw serial sources produce no frames and get none.
+fn create_source_buffer(
+    app: &AppHandle,
+    session_id: &str,
+    source_idx: usize,
+    source_config: &SourceConfig,
+) -> Option<String> {
+    if source_config.profile_kind == "serial"
+        && source_config.framing_encoding.as_deref().unwrap_or("raw") == "raw"
+    {
+        return None;
+    }
+    let name = format!("{} / {}", session_id, source_config.display_name);
+    let buffer_id = buffer_store::create_buffer_inactive(BufferType::Frames, name.clone());
+    emit_buffer_created(app, session_id, &buffer_id, &name, "frames");
+    add_session_source_buffer(
+        session_id,
+        SourceBufferInfo {
+            source_index: source_idx,
+            profile_id: source_config.profile_id.clone(),
+            display_name: source_config.display_name.clone(),
+            buffer_id: buffer_id.clone(),
+        },
+    );
+    Some(buffer_id)
+}
+
+/// Pre-capture wakeup sequence from a profile's `wakeup` connection setting.
+/// Listen-only and silent profiles never transmit, and passive attach profiles
+/// write nothing on connect, so they get no steps. A sequence that doesn't parse,
+/// or has a step the profile's device can't send, is an error.
+fn wakeup_steps_from_profile(profile: &settings::IOProfile) -> Result<Vec<WakeupStep>, String> {
+    let flag = |key: &str| profile.connection.get(key).and_then(|v| v.as_bool()).unwrap_or(false);
+    if flag("listen_only") || flag("silent_mode") || flag("passive_attach") {
+        return Ok(Vec::new());
+    }
+    // Stored as a JSON array, or as JSON text when edited in the profile dialog
+    let parsed: Result<Vec<WakeupStep>, _> = match profile.connection.get("wakeup") {
+        Some(serde_json::Value::String(text)) if text.trim().is_empty() => return Ok(Vec::new()),
+        Some(serde_json::Value::String(text)) => serde_json::from_str(text),
+        Some(value) => serde_json::from_value(value.clone()),
+        None => return Ok(Vec::new()),
+    };
+    let steps = parsed.map_err(|e| format!("Invalid wakeup sequence for '{}': {}", profile.id, e))?;
+    let fd_enabled = fd_enabled_from_profile(profile);
+    for (i, step) in steps.iter().enumerate() {
+        wakeup_step_write(&profile.kind, fd_enabled, step)
+            .map_err(|e| format!("Invalid wakeup step {} for '{}': {}", i + 1, profile.id, e))?;
+    }
+    Ok(steps)
+}
+
+/// What one wakeup step sends through the session's transmit path
+#[derive(Debug)]
+enum WakeupWrite {
+    /// A CAN frame on the source's device bus
+    Frame(CanTransmitFrame),
+    /// Raw bytes for a serial source
+    Bytes(Vec<u8>),
+}
+
+/// Build the write for one wakeup step of a source of the given profile kind
+fn wakeup_step_write(profile_kind: &str, fd_enabled: bool, step: &WakeupStep) -> Result<WakeupWrite, String> {
+    if profile_kind == "serial" {
+        return step
+            .bytes
+            .clone()
+            .map(WakeupWrite::Bytes)
+            .ok_or_else(|| "serial wakeup steps need bytes".to_string());
+    }
+    let frame_id = step
+        .frame_id
+        .ok_or_else(|| "CAN wakeup steps need a frame_id".to_string())?;
+    let frame = CanTransmitFrame {
+        frame_id,
+        data: step.data.clone(),
+        bus: step.bus,
+        is_extended: step.is_extended,
+        is_fd: step.is_fd,
+        is_brs: false,
+        is_rtr: false,
+        rtr_dlc: None,
+    };
+    validate_transmit_for(profile_kind, fd_enabled, &frame).map_err(|r| r.error.unwrap_or_default())?;
+    Ok(WakeupWrite::Frame(frame))
+}
+
+/// Send a source's wakeup sequence once its transmit channel is up.
+///
+/// Steps go through the session's transmit path like any other transmit, so
+/// they need transmit armed, honour the minimum gap and skip sources whose
+/// transmit is turned off. A session that isn't armed yet skips the sequence.
+/// Runs off the merge task so step delays never hold up frame emission.
+fn run_wakeup(
+    session_id: String,
+    source_idx: usize,
+    profile_kind: String,
+    fd_enabled: bool,
+    steps: Vec<WakeupStep>,
+    bus_mappings: SharedBusMappings,
+    stop_flag: Arc<AtomicBool>,
+) {
+    tokio::spawn(async move {
+        if is_session_transmit_armed(&session_id).await != Some(true) {
+            tlog!("[MultiSourceReader] Source {} wakeup skipped: transmit not armed", source_idx);
+            return;
+        }
+        for (i, step) in steps.iter().enumerate() {
+            if step.delay_ms > 0 {
+                tokio::time::sleep(std::time::Duration::from_millis(step.delay_ms)).await;
+            }
+            if stop_flag.load(Ordering::Relaxed) {
+                return;
+            }
+            let result = match wakeup_step_write(&profile_kind, fd_enabled, step) {
+                Ok(WakeupWrite::Bytes(bytes)) => transmit_serial(&session_id, &bytes).await,
+                Ok(WakeupWrite::Frame(frame)) => match session_bus(&bus_mappings, source_idx, frame.bus) {
+                    Some(bus) => transmit_frame(&session_id, &CanTransmitFrame { bus, ..frame }).await,
+                    None => Err(format!("device bus {} is not mapped to a session bus", frame.bus)),
+                },
+                Err(e) => Err(e),
+            };
+            let result = result.and_then(|r| match r.success {
+                true => Ok(()),
+                false => Err(r.error.unwrap_or_default()),
+            });
+            if let Err(e) = result {
+                tlog!("[MultiSourceReader] Source {} wakeup step {} failed: {}", source_idx, i + 1, e);
+            }
+        }
+        tlog!("[MultiSourceReader] Source {} sent {} wakeup step(s)", source_idx, steps.len());
+    });
+}
+
+/// Session bus a source's device bus is currently delivered on
+fn session_bus(bus_mappings: &SharedBusMappings, source_idx: usize, device_bus: u8) -> Option<u8> {
+    bus_mappings
+        .read()
+        .ok()?
+        .get(&source_idx)?
+        .bus_mappings
+        .iter()
+        .find(|m| m.enabled && m.device_bus == device_bus)
+        .map(|m| m.output_bus)
+}
+
+/// Emission backpressure, driven by how many frames reach the emit step per
+/// `BACKPRESSURE_WINDOW_MS`.
+///
+/// Pending frames are drained at every emit, so their count never builds up;
+/// the rate is what tells a flooded frontend apart. Reaching the high-water
+/// mark within a window turns backpressure on. It clears at the end of a
+/// window at or below the low-water mark, once the high-water mark was last
+/// hit at least `BACKPRESSURE_HOLD_MS` ago.
+struct Backpressure {
+    active: bool,
+    window_start: std::time::Instant,
+    window_frames: usize,
+    last_high_water: std::time::Instant,
+}
+
+impl Backpressure {
+    fn new(now: std::time::Instant) -> Self {
+        Self { active: false, window_start: now, window_frames: 0, last_high_water: now }
+    }
+
+    /// Count frames at an emit. Returns the new state when it changes.
+    fn observe(&mut self, frames: usize, now: std::time::Instant) -> Option<bool> {
+        if now.duration_since(self.window_start) >= std::time::Duration::from_millis(BACKPRESSURE_WINDOW_MS) {
+            let finished = std::mem::take(&mut self.window_frames);
+            self.window_start = now;
+            if self.active
+                && finished <= BACKPRESSURE_LOW_WATER
+                && now.duration_since(self.last_high_water) >= std::time::Duration::from_millis(BACKPRESSURE_HOLD_MS)
+            {
+                self.active = false;
+                return Some(false);
+            }
+        }
+        self.window_frames += frames;
+        if self.window_frames >= BACKPRESSURE_HIGH_WATER {
+            self.last_high_water = now;
+            if !self.active {
+                self.active = true;
+                return Some(true);
+            }
+        }
+        None
+    }
+}
+
+/// Holds merged frames for a short window before emission, so frames from a
+/// source that delivers late are still emitted in timestamp order.
+///
+/// A frame is released once a frame at least `window` newer has been seen, or
+/// once it has been held for `window`, whichever comes first, so a quiet bus
+/// never stalls emission. With a zero window every frame is released at the
+/// next emit, as before. Frames that arrive after a newer frame was already
+/// emitted are counted as late.
+struct ReorderBuffer {
+    window: std::time::Duration,
+    held: Vec<(std::time::Instant, FrameMessage)>,
+    newest_us: u64,
+    last_emitted_us: u64,
+    late: u64,
+}
+
+impl ReorderBuffer {
+    fn new(window_ms: u64) -> Self {
+        Self {
+            window: std::time::Duration::from_millis(window_ms),
+            held: Vec::new(),
+            newest_us: 0,
+            last_emitted_us: 0,
+            late: 0,
+        }
+    }
+
+    fn push(&mut self, frames: Vec<FrameMessage>, now: std::time::Instant) {
+        for frame in frames {
+            self.newest_us = self.newest_us.max(frame.timestamp_us);
+            self.held.push((now, frame));
+        }
+    }
+
+    /// Take the frames that are ready for emission, in timestamp order.
+    fn release(&mut self, now: std::time::Instant) -> Vec<FrameMessage> {
+        self.held.sort_by_key(|(_, f)| f.timestamp_us);
+        let cutoff_us = self.newest_us.saturating_sub(self.window.as_micros() as u64);
+        let ready = self
+            .held
+            .iter()
+            .take_while(|(arrived, f)| f.timestamp_us <= cutoff_us || now.duration_since(*arrived) >= self.window)
+            .count();
+        self.take(ready)
+    }
+
+    /// Take every held frame, in timestamp order.
+    fn flush(&mut self) -> Vec<FrameMessage> {
+        self.held.sort_by_key(|(_, f)| f.timestamp_us);
+        self.take(self.held.len())
+    }
+
+    fn take(&mut self, count: usize) -> Vec<FrameMessage> {
+        let frames: Vec<FrameMessage> = self.held.drain(..count).map(|(_, f)| f).collect();
+        for frame in &frames {
+            if frame.timestamp_us < self.last_emitted_us {
+                self.late += 1;
+            }
+            self.last_emitted_us = self.last_emitted_us.max(frame.timestamp_us);
+        }
+        frames
+    }
+
+    /// Late frames counted since the last call.
+    fn take_late(&mut self) -> u64 {
+        std::mem::take(&mut self.late)
+    }
+}
+
+/// Move a source's frames onto its current output buses, dropping frames from
+/// buses that have since been disabled
+fn remap_source_frames(
+    bus_mappings: &SharedBusMappings,
+    source_idx: usize,
+    mut frames: Vec<FrameMessage>,
+) -> Vec<FrameMessage> {
+    let Ok(table) = bus_mappings.read() else {
+        return frames;
+    };
+    let Some(source) = table.get(&source_idx).filter(|s| s.is_remapped()) else {
+        return frames;
+    };
+    frames.retain_mut(|frame| match source.remap_bus(frame.bus) {
+        Some(bus) => {
+            frame.bus = bus;
+            true
+        }
+        None => false,
+    });
+    frames
+}
+
+/// Relabel frame IDs with a source's `id_remap`, keeping the arrival ID in
+/// `original_frame_id`. Runs after bus mapping, so buffers, the emit filter and
+/// the UI all see the relabelled ID. A CAN frame keeps its ID when the target
+/// doesn't fit its format (over 11 bits for a standard frame).
+fn remap_frame_ids(id_remap: &HashMap<u32, u32>, frames: &mut [FrameMessage]) {
+    for frame in frames {
+        if let Some(&frame_id) = id_remap.get(&frame.frame_id) {
+            let max_id = if frame.is_extended { CAN_EXTENDED_ID_MASK } else { CAN_STANDARD_ID_MASK };
+            if frame.protocol == "can" && frame_id > max_id {
+                continue;
+            }
+            frame.original_frame_id.get_or_insert(frame.frame_id);
+            frame.frame_id = frame_id;
+        }
+    }
+}
+
+/// Tag frames with the display name of the source they came from
+fn label_source_frames(label: &str, frames: &mut [FrameMessage]) {
+    for frame in frames {
+        frame.source_label = Some(label.to_string());
+    }
+}
 
 /// Main merge task that spawns sub-readers and combines their frames/bytes
+#[allow(clippy::too_many_arguments)]
 pub(super) async fn run_merge_task(
     app: AppHandle,
     session_id: String,
     sources: Vec<SourceConfig>,
     _emits_raw_bytes: bool,
+    frames_buffer_id: Option<String>,
     bytes_buffer_id: Option<String>,
+    per_source_buffers: bool,
+    buffer_tx_frames: Arc<AtomicBool>,
     stop_flag: Arc<AtomicBool>,
     mut rx: mpsc::Receiver<SourceMessage>,
     tx: mpsc::Sender<SourceMessage>,
     transmit_channels: TransmitChannels,
+    device_info_channels: DeviceInfoChannels,
+    bus_mappings: SharedBusMappings,
     virtual_bus_controls: VirtualBusControls,
     mut merge_cmd_rx: mpsc::UnboundedReceiver<MergeCommand>,
     virtual_cmd_txs: Arc<Mutex<HashMap<usize, VirtualCmdTx>>>,
@@ -56,6 +617,24 @@ pub(super) async fn run_merge_task(
     let mut next_source_idx = sources.len();
     // Per-source stop flags for hot-remove
     let mut source_stop_flags: HashMap<String, Arc<AtomicBool>> = HashMap::new();
+    // Sources with a liveness timeout, by source index
+    let mut liveness: HashMap<usize, SourceLiveness> = HashMap::new();
+    // Actively ACKing sources watched for vehicle bus traffic, by source index
+    let mut bus_safety: HashMap<usize, BusSafetyWatch> = HashMap::new();
+    // Buffers holding each source's own frames (per_source_buffers), by source index
+    let mut source_buffers: HashMap<usize, String> = HashMap::new();
+    // Frames waiting for the next emit to be appended to their source's buffer
+    let mut pending_source_frames: HashMap<usize, Vec<FrameMessage>> = HashMap::new();
+    // Frame ID relabelling, by source index
+    let mut id_remaps: HashMap<usize, HashMap<u32, u32>> = HashMap::new();
+    // Source display names stamped on frames as `source_label`, by source index
+    let mut source_labels: HashMap<usize, String> = HashMap::new();
+    // The widest reorder window any source profile asks for
+    let mut reorder_window_ms = 0u64;
+    // Idle auto-stop: the longest `auto_stop_after_idle_ms` any source profile asks for
+    let mut auto_stop_idle_ms: Option<u64> = None;
+    // Wakeup sequences (profile kind, FD enabled, steps) sent when a source's transmit channel is ready
+    let mut wakeups: HashMap<usize, (String, bool, Vec<WakeupStep>)> = HashMap::new();
     for (index, source_config) in sources.iter().enumerate() {
         let profile = match settings.io_profiles.iter().find(|p| p.id == source_config.profile_id) {
             Some(p) => p.clone(),
@@ -70,11 +649,44 @@ pub(super) async fn run_merge_task(
 
         let source_stop = Arc::new(AtomicBool::new(false));
         source_stop_flags.insert(source_config.profile_id.clone(), source_stop.clone());
+        if let Some(live) = SourceLiveness::from_profile(source_config, &profile) {
+            liveness.insert(index, live);
+        }
+        let pre_listen = BusSafetyWatch::from_profile(source_config, &profile).and_then(|watch| {
+            bus_safety.insert(index, watch);
+            pre_listen_profile(&profile)
+        });
+        if per_source_buffers {
+            if let Some(buffer_id) = create_source_buffer(&app, &session_id, index, source_config) {
+                source_buffers.insert(index, buffer_id);
+            }
+        }
+        if let Some(id_remap) = source_config.id_remap.clone().filter(|m| !m.is_empty()) {
+            id_remaps.insert(index, id_remap);
+        }
+        if !source_config.display_name.is_empty() {
+            source_labels.insert(index, source_config.display_name.clone());
+        }
+        if let Ok(mut table) = bus_mappings.write() {
+            if let Some(source) = table.get_mut(&index) {
+                source.fd_enabled = fd_enabled_from_profile(&profile);
+            }
+        }
+        reorder_window_ms = reorder_window_ms.max(reorder_window_from_profile(&profile));
+        auto_stop_idle_ms = auto_stop_idle_ms.max(auto_stop_idle_from_profile(&profile));
+        match wakeup_steps_from_profile(&profile) {
+            Ok(steps) if !steps.is_empty() => {
+                wakeups.insert(index, (source_config.profile_kind.clone(), fd_enabled_from_profile(&profile), steps));
+            }
+            Ok(_) => {}
+            Err(e) => emit_session_error(&app, &session_id, e),
+        }
 
         let handle = spawn_source(
             index,
             source_config,
             &profile,
+            pre_listen,
             source_stop,
             &app,
             &session_id,
@@ -90,8 +702,21 @@ pub(super) async fn run_merge_task(
     // Track which sources are still active
     let mut active_sources = sources.len();
     let mut pending_frames: Vec<FrameMessage> = Vec::new();
+    let mut reorder = ReorderBuffer::new(reorder_window_ms);
+    let mut total_late: u64 = 0;
+    if reorder_window_ms > 0 {
+        tlog!("[MultiSourceReader] Reordering frames within a {} ms window", reorder_window_ms);
+    }
     let mut pending_bytes: Vec<TimestampedByte> = Vec::new();
     let mut last_emit = std::time::Instant::now();
+    let mut seen_first_frame = false;
+    // Last frame or byte from any source, for the idle auto-stop
+    let mut last_traffic: Option<std::time::Instant> = None;
+    let mut idle_stopped = false;
+    let mut total_dropped: u64 = 0;
+    let mut total_overflow: u64 = 0;
+    // Buffering without emitting because the frame rate backed up
+    let mut backpressure = Backpressure::new(std::time::Instant::now());
 
     // Track frames per bus for periodic logging
     let mut frames_per_bus: std::collections::HashMap<u8, usize> = std::collections::HashMap::new();
@@ -111,33 +736,96 @@ pub(super) async fn run_merge_task(
         tokio::select! {
             msg = rx.recv() => {
                 match msg {
-                    Some(SourceMessage::Frames(_source_idx, frames)) => {
+                    Some(SourceMessage::Frames(source_idx, frames)) => {
+                        let mut frames = remap_source_frames(&bus_mappings, source_idx, frames);
+                        if let Some(id_remap) = id_remaps.get(&source_idx) {
+                            remap_frame_ids(id_remap, &mut frames);
+                        }
+                        if let Some(label) = source_labels.get(&source_idx) {
+                            label_source_frames(label, &mut frames);
+                        }
+                        if source_buffers.contains_key(&source_idx) {
+                            let mut stored = frames.clone();
+                            if !buffer_tx_frames.load(Ordering::Relaxed) {
+                                drop_tx_frames(&mut stored);
+                            }
+                            pending_source_frames.entry(source_idx).or_default().extend(stored);
+                        }
+                        if let Some(live) = liveness.get_mut(&source_idx) {
+                            let now = std::time::Instant::now();
+                            if live.touch(now) {
+                                tlog!("[MultiSourceReader] Source {} traffic resumed", source_idx);
+                                emit_source_stale(&app, &session_id, live.payload(source_idx, now));
+                            }
+                        }
+                        if let Some(watch) = bus_safety.get_mut(&source_idx) {
+                            if let Some(warning) = watch.observe(source_idx, &frames, std::time::Instant::now()) {
+                                tlog!("[MultiSourceReader] Source {} bus safety: {}", source_idx, warning.message);
+                                emit_bus_safety_warning(&app, &session_id, warning);
+                            }
+                        }
+                        if !seen_first_frame {
+                            if let Some(first) = frames.first() {
+                                seen_first_frame = true;
+                                emit_first_frame(&app, &session_id, first);
+                            }
+                        }
+                        if !frames.is_empty() {
+                            last_traffic = Some(std::time::Instant::now());
+                        }
                         for frame in &frames {
                             *frames_per_bus.entry(frame.bus).or_insert(0) += 1;
                         }
                         pending_frames.extend(frames);
                     }
-                    Some(SourceMessage::Bytes(_source_idx, raw_entries)) => {
+                    Some(SourceMessage::Bytes(source_idx, raw_entries)) => {
+                        if let Some(live) = liveness.get_mut(&source_idx) {
+                            let now = std::time::Instant::now();
+                            if live.touch(now) {
+                                tlog!("[MultiSourceReader] Source {} traffic resumed", source_idx);
+                                emit_source_stale(&app, &session_id, live.payload(source_idx, now));
+                            }
+                        }
+                        if !raw_entries.is_empty() {
+                            last_traffic = Some(std::time::Instant::now());
+                        }
+                        let table = bus_mappings.read().ok();
+                        let source = table.as_ref().and_then(|t| t.get(&source_idx)).filter(|s| s.is_remapped());
                         for entry in raw_entries {
+                            let bus = match source {
+                                Some(source) => match source.remap_bus(entry.bus) {
+                                    Some(bus) => bus,
+                                    None => continue,
+                                },
+                                None => entry.bus,
+                            };
                             pending_bytes.push(TimestampedByte {
                                 byte: entry.byte,
                                 timestamp_us: entry.timestamp_us,
-                                bus: entry.bus,
+                                bus,
                             });
                         }
                     }
                     Some(SourceMessage::Ended(source_idx, reason)) => {
                         tlog!("[MultiSourceReader] Source {} ended: {}", source_idx, reason);
+                        liveness.remove(&source_idx);
                         if let Ok(mut channels) = transmit_channels.lock() {
                             channels.remove(&source_idx);
                         }
+                        if let Ok(mut channels) = device_info_channels.lock() {
+                            channels.remove(&source_idx);
+                        }
                         active_sources = active_sources.saturating_sub(1);
                     }
                     Some(SourceMessage::Error(source_idx, error)) => {
                         tlog!("[MultiSourceReader] Source {} error: {}", source_idx, error);
+                        liveness.remove(&source_idx);
                         if let Ok(mut channels) = transmit_channels.lock() {
                             channels.remove(&source_idx);
                         }
+                        if let Ok(mut channels) = device_info_channels.lock() {
+                            channels.remove(&source_idx);
+                        }
                         emit_session_error(&app, &session_id, error);
                         active_sources = active_sources.saturating_sub(1);
                     }
@@ -146,11 +834,89 @@ pub(super) async fn run_merge_task(
                         if let Ok(mut channels) = transmit_channels.lock() {
                             channels.insert(source_idx, tx_sender);
                         }
+                        if let Some((profile_kind, fd_enabled, steps)) = wakeups.get(&source_idx) {
+                            run_wakeup(
+                                session_id.clone(),
+                                source_idx,
+                                profile_kind.clone(),
+                                *fd_enabled,
+                                steps.clone(),
+                                bus_mappings.clone(),
+                                stop_flag.clone(),
+                            );
+                        }
+                    }
+                    Some(SourceMessage::DeviceInfoReady(source_idx, info_sender)) => {
+                        if let Ok(mut channels) = device_info_channels.lock() {
+                            channels.insert(source_idx, info_sender);
+                        }
                     }
                     Some(SourceMessage::Connected(source_idx, device_type, address, bus_number)) => {
                         tlog!("[MultiSourceReader] Source {} connected: {} at {}", source_idx, device_type, address);
+                        // Connecting can take a while; start the liveness window from here
+                        if let Some(live) = liveness.get_mut(&source_idx) {
+                            live.last_traffic = std::time::Instant::now();
+                        }
                         emit_device_connected(&app, &session_id, &device_type, &address, bus_number);
                     }
+                    Some(SourceMessage::FramesDropped(source_idx, dropped)) => {
+                        total_dropped += dropped;
+                        tlog!(
+                            "[MultiSourceReader] Source {} dropped {} frames ({} total)",
+                            source_idx, dropped, total_dropped
+                        );
+                        emit_frames_dropped(&app, &session_id, dropped, total_dropped);
+                    }
+                    Some(SourceMessage::FramerOverflow(source_idx, discarded)) => {
+                        total_overflow += discarded;
+                        tlog!(
+                            "[MultiSourceReader] Source {} framer discarded {} bytes ({} total)",
+                            source_idx, discarded, total_overflow
+                        );
+                        emit_framer_overflow(&app, &session_id, discarded, total_overflow);
+                    }
+                    Some(SourceMessage::ParseErrors(source_idx, mut errors)) => {
+                        tlog!(
+                            "[MultiSourceReader] Source {} skipped {} malformed lines",
+                            source_idx, errors.count
+                        );
+                        record_session_parse_errors(&session_id, source_idx, std::mem::take(&mut errors.recent));
+                        emit_parse_errors(&app, &session_id, errors);
+                    }
+                    Some(SourceMessage::ParseFailures(source_idx, entries)) => {
+                        record_session_parse_errors(&session_id, source_idx, entries);
+                    }
+                    Some(SourceMessage::Transmitted(source_idx, mut frames)) => {
+                        // Already on session buses. Not bus traffic, so liveness
+                        // and the bus safety watch don't see them.
+                        if let Some(label) = source_labels.get(&source_idx) {
+                            label_source_frames(label, &mut frames);
+                        }
+                        if source_buffers.contains_key(&source_idx) && buffer_tx_frames.load(Ordering::Relaxed) {
+                            pending_source_frames.entry(source_idx).or_default().extend(frames.iter().cloned());
+                        }
+                        pending_frames.extend(frames);
+                    }
+                    Some(SourceMessage::DeviceConfig(source_idx, config)) => {
+                        tlog!(
+                            "[MultiSourceReader] Source {} reported config for {} bus(es)",
+                            source_idx, config.buses.len()
+                        );
+                        emit_device_config(&app, &session_id, config);
+                    }
+                    Some(SourceMessage::BusOff(source_idx, report)) => {
+                        tlog!("[MultiSourceReader] Source {} bus-off: {}", source_idx, report.message);
+                        emit_bus_off(&app, &session_id, report);
+                    }
+                    Some(SourceMessage::CanErrors(source_idx, errors)) => {
+                        for error in errors {
+                            tlog!(
+                                "[MultiSourceReader] Source {} bus {} error: {}",
+                                source_idx, error.bus, error.kinds.join(", ")
+                            );
+                            emit_can_error(&app, &session_id, error);
+                        }
+                    }
                     None => {
                         // Channel closed
                         break;
@@ -171,6 +937,37 @@ pub(super) async fn run_merge_task(
                         };
                         let source_stop = Arc::new(AtomicBool::new(false));
                         source_stop_flags.insert(source_config.profile_id.clone(), source_stop.clone());
+                        if let Some(live) = SourceLiveness::from_profile(&source_config, &profile) {
+                            liveness.insert(idx, live);
+                        }
+                        auto_stop_idle_ms = auto_stop_idle_ms.max(auto_stop_idle_from_profile(&profile));
+                        let pre_listen = BusSafetyWatch::from_profile(&source_config, &profile).and_then(|watch| {
+                            bus_safety.insert(idx, watch);
+                            pre_listen_profile(&profile)
+                        });
+                        if per_source_buffers {
+                            if let Some(buffer_id) = create_source_buffer(&app, &session_id, idx, &source_config) {
+                                source_buffers.insert(idx, buffer_id);
+                            }
+                        }
+                        if let Some(id_remap) = source_config.id_remap.clone().filter(|m| !m.is_empty()) {
+                            id_remaps.insert(idx, id_remap);
+                        }
+                        if !source_config.display_name.is_empty() {
+                            source_labels.insert(idx, source_config.display_name.clone());
+                        }
+                        match wakeup_steps_from_profile(&profile) {
+                            Ok(steps) if !steps.is_empty() => {
+                                wakeups.insert(idx, (source_config.profile_kind.clone(), fd_enabled_from_profile(&profile), steps));
+                            }
+                            Ok(_) => {}
+                            Err(e) => emit_session_error(&app, &session_id, e),
+                        }
+                        if let Ok(mut table) = bus_mappings.write() {
+                            let mut source = SourceBusMappings::new(idx, &source_config);
+                            source.fd_enabled = fd_enabled_from_profile(&profile);
+                            table.insert(idx, source);
+                        }
                         let handle = spawn_source(
                             idx,
                             &source_config,
@@ -207,6 +1004,31 @@ pub(super) async fn run_merge_task(
             }
         }
 
+        // Warn about sources that have gone quiet past their liveness timeout
+        if !liveness.is_empty() {
+            let now = std::time::Instant::now();
+            for (&source_idx, live) in liveness.iter_mut() {
+                if live.check(now) {
+                    tlog!(
+                        "[MultiSourceReader] Source {} ({}) stale: no traffic for {} ms",
+                        source_idx, live.display_name, live.stale_after.as_millis()
+                    );
+                    emit_source_stale(&app, &session_id, live.payload(source_idx, now));
+                }
+            }
+        }
+
+        // End hands-off captures once traffic has stopped (e.g. a finite test sequence finished)
+        if idle_stop_due(auto_stop_idle_ms, last_traffic, std::time::Instant::now()) {
+            tlog!(
+                "[MultiSourceReader] No traffic for {} ms, auto-stopping session '{}'",
+                auto_stop_idle_ms.unwrap_or(0), session_id
+            );
+            idle_stopped = true;
+            stop_flag.store(true, Ordering::SeqCst);
+            break;
+        }
+
         // Periodically log frames per bus (every 5 seconds)
         if last_bus_log.elapsed().as_secs() >= BUS_LOG_INTERVAL_SECS && !frames_per_bus.is_empty() {
             let mut bus_counts: Vec<_> = frames_per_bus.iter().collect();
@@ -230,19 +1052,49 @@ pub(super) async fn run_merge_task(
             || pending_bytes.len() >= BYTE_BATCH_THRESHOLD;
 
         if should_emit {
+            // Frames only leave the reorder buffer once late sources had their chance
+            let now = std::time::Instant::now();
+            reorder.push(std::mem::take(&mut pending_frames), now);
+            pending_frames = reorder.release(now);
+            let late = reorder.take_late();
+            if late > 0 {
+                total_late += late;
+                add_session_reordering_needed(&session_id, late);
+            }
+
+            store_source_frames(&source_buffers, &mut pending_source_frames);
+
+            match backpressure.observe(pending_frames.len(), now) {
+                Some(true) => {
+                    set_session_backpressure(&session_id, true);
+                    tlog!(
+                        "[MultiSourceReader] Over {} frames in {} ms, buffering without emit",
+                        BACKPRESSURE_HIGH_WATER, BACKPRESSURE_WINDOW_MS
+                    );
+                }
+                Some(false) => {
+                    set_session_backpressure(&session_id, false);
+                    tlog!("[MultiSourceReader] Backpressure cleared, resuming emit");
+                }
+                None => {}
+            }
+
             if !pending_frames.is_empty() {
-                pending_frames.sort_by_key(|f| f.timestamp_us);
-                buffer_store::append_frames(pending_frames.clone());
-                emit_frames(&app, &session_id, pending_frames);
-                pending_frames = Vec::new();
+                if backpressure.active {
+                    // Still buffered and counted, just not sent to the UI
+                    record_emitted_frames(&app, &session_id, &pending_frames);
+                    store_frames(&frames_buffer_id, std::mem::take(&mut pending_frames), buffer_tx_frames.load(Ordering::Relaxed));
+                } else {
+                    store_frames(&frames_buffer_id, pending_frames.clone(), buffer_tx_frames.load(Ordering::Relaxed));
+                    emit_frames(&app, &session_id, pending_frames);
+                    pending_frames = Vec::new();
+                }
             }
 
             if !pending_bytes.is_empty() {
                 pending_bytes.sort_by_key(|b| b.timestamp_us);
                 if let Some(ref buf_id) = bytes_buffer_id {
                     buffer_store::append_raw_bytes_to_buffer(buf_id, pending_bytes.clone());
-                } else {
-                    buffer_store::append_raw_bytes(pending_bytes.clone());
                 }
                 let payload = RawBytesPayload {
                     bytes: pending_bytes,
@@ -256,20 +1108,39 @@ pub(super) async fn run_merge_task(
         }
     }
 
+    if backpressure.active {
+        set_session_backpressure(&session_id, false);
+    }
+
     // Emit any remaining frames
+    reorder.push(std::mem::take(&mut pending_frames), std::time::Instant::now());
+    pending_frames = reorder.flush();
+    let late = reorder.take_late();
+    if late > 0 {
+        total_late += late;
+        add_session_reordering_needed(&session_id, late);
+    }
+    if total_late > 0 {
+        tlog!(
+            "[MultiSourceReader] {} frame(s) arrived after newer frames were emitted{}",
+            total_late,
+            if reorder_window_ms > 0 { "; consider a wider reorder_window_ms" } else { "" }
+        );
+    }
     if !pending_frames.is_empty() {
-        pending_frames.sort_by_key(|f| f.timestamp_us);
-        buffer_store::append_frames(pending_frames.clone());
+        store_frames(&frames_buffer_id, pending_frames.clone(), buffer_tx_frames.load(Ordering::Relaxed));
         emit_frames(&app, &session_id, pending_frames);
     }
+    store_source_frames(&source_buffers, &mut pending_source_frames);
+    for buffer_id in source_buffers.values() {
+        buffer_store::finalize_buffer_by_id(buffer_id);
+    }
 
     // Emit any remaining bytes
     if !pending_bytes.is_empty() {
         pending_bytes.sort_by_key(|b| b.timestamp_us);
         if let Some(ref buf_id) = bytes_buffer_id {
             buffer_store::append_raw_bytes_to_buffer(buf_id, pending_bytes.clone());
-        } else {
-            buffer_store::append_raw_bytes(pending_bytes.clone());
         }
         let payload = RawBytesPayload {
             bytes: pending_bytes,
@@ -284,7 +1155,9 @@ pub(super) async fn run_merge_task(
     }
 
     // Emit stream ended
-    let reason = if stop_flag.load(Ordering::SeqCst) {
+    let reason = if idle_stopped {
+        "idle"
+    } else if stop_flag.load(Ordering::SeqCst) {
         "stopped"
     } else {
         "complete"
@@ -298,6 +1171,7 @@ fn spawn_source(
     index: usize,
     source_config: &SourceConfig,
     profile: &crate::settings::IOProfile,
+    pre_listen: Option<crate::settings::IOProfile>,
     source_stop: Arc<AtomicBool>,
     app: &AppHandle,
     session_id: &str,
@@ -327,6 +1201,7 @@ fn spawn_source(
     let modbus_polls = source_config.modbus_polls.clone();
     let modbus_role = source_config.modbus_role.clone();
     let max_register_errors = source_config.max_register_errors;
+    let timestamp_source = source_config.timestamp_source;
     let virtual_bus_controls_clone = virtual_bus_controls.clone();
     let profile = profile.clone();
 
@@ -358,6 +1233,19 @@ fn spawn_source(
             }
         });
 
+        if let Some(silent_profile) = pre_listen {
+            run_pre_listen(
+                index,
+                silent_profile,
+                bus_mappings.clone(),
+                timestamp_source,
+                std::time::Duration::from_millis(BUS_SAFETY_WINDOW_MS),
+                combined_stop.clone(),
+                tx_clone.clone(),
+            )
+            .await;
+        }
+
         run_source_reader(
             app_clone,
             session_id_clone,
@@ -379,6 +1267,7 @@ fn spawn_source(
             modbus_polls,
             modbus_role,
             max_register_errors,
+            timestamp_source,
             combined_stop,
             tx_clone,
             virtual_bus_controls_clone,
@@ -389,3 +1278,257 @@ fn spawn_source(
         monitor.abort();
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::test_support::can_frame_at;
+    use std::time::{Duration, Instant};
+
+    fn liveness(stale_after_ms: u64, now: Instant) -> SourceLiveness {
+        SourceLiveness {
+            display_name: "bus".to_string(),
+            buses: vec![2],
+            stale_after: Duration::from_millis(stale_after_ms),
+            last_traffic: now,
+            stale: false,
+        }
+    }
+
+    #[test]
+    fn test_drop_tx_frames_keeps_received() {
+        let mut frames = vec![can_frame_at(0x100, 1), can_frame_at(0x100, 2), can_frame_at(0x100, 3)];
+        frames[0].direction = Some("rx".to_string());
+        frames[1].direction = Some("tx".to_string());
+        drop_tx_frames(&mut frames);
+        let timestamps: Vec<u64> = frames.iter().map(|f| f.timestamp_us).collect();
+        assert_eq!(timestamps, vec![1, 3]);
+    }
+
+    #[test]
+    fn test_remap_frame_ids_keeps_original() {
+        let mut frames = vec![can_frame_at(0x100, 1), can_frame_at(0x100, 2), can_frame_at(0x100, 3)];
+        frames[1].frame_id = 0x200;
+        frames[2].frame_id = 0x300;
+        let id_remap = HashMap::from([(0x100, 0x110), (0x300, 0x100)]);
+        remap_frame_ids(&id_remap, &mut frames);
+        let ids: Vec<(u32, Option<u32>)> = frames.iter().map(|f| (f.frame_id, f.original_frame_id)).collect();
+        // Each frame is looked up once, so 0x300 -> 0x100 isn't remapped again to 0x110
+        assert_eq!(ids, vec![(0x110, Some(0x100)), (0x200, None), (0x100, Some(0x300))]);
+
+        // A target too wide for a standard ID only relabels extended frames
+        let mut frames = vec![can_frame_at(0x100, 1), FrameMessage { is_extended: true, ..can_frame_at(0x100, 2) }];
+        remap_frame_ids(&HashMap::from([(0x100, 0x1234)]), &mut frames);
+        let ids: Vec<(u32, Option<u32>)> = frames.iter().map(|f| (f.frame_id, f.original_frame_id)).collect();
+        assert_eq!(ids, vec![(0x100, None), (0x1234, Some(0x100))]);
+    }
+
+    #[test]
+    fn test_label_source_frames() {
+        let mut frames = vec![can_frame_at(0x100, 1), can_frame_at(0x100, 2)];
+        label_source_frames("GVRET A", &mut frames);
+        assert!(frames.iter().all(|f| f.source_label.as_deref() == Some("GVRET A")));
+    }
+
+    #[test]
+    fn test_reorder_buffer_emits_late_frame_in_order() {
+        let start = Instant::now();
+        let mut reorder = ReorderBuffer::new(20);
+        let mut emitted = Vec::new();
+
+        // Fast source delivers 10, 20 and 30 ms; only 10 ms is a full window behind the newest
+        reorder.push(vec![can_frame_at(0x100, 10_000), can_frame_at(0x100, 20_000), can_frame_at(0x100, 30_000)], start);
+        emitted.extend(reorder.release(start));
+        assert_eq!(emitted.len(), 1);
+        // Slow source delivers a frame from 15 ms after the first batch was released
+        reorder.push(vec![can_frame_at(0x100, 15_000), can_frame_at(0x100, 60_000)], start + Duration::from_millis(5));
+        emitted.extend(reorder.release(start + Duration::from_millis(5)));
+        // Window elapses on a quiet bus; everything held comes out
+        emitted.extend(reorder.release(start + Duration::from_millis(30)));
+
+        let timestamps: Vec<u64> = emitted.iter().map(|f| f.timestamp_us).collect();
+        assert_eq!(timestamps, vec![10_000, 15_000, 20_000, 30_000, 60_000]);
+        assert!(timestamps.windows(2).all(|w| w[0] <= w[1]));
+        assert_eq!(reorder.take_late(), 0);
+    }
+
+    #[test]
+    fn test_reorder_buffer_counts_late_frames_without_window() {
+        let start = Instant::now();
+        let mut reorder = ReorderBuffer::new(0);
+        reorder.push(vec![can_frame_at(0x100, 10), can_frame_at(0x100, 30)], start);
+        assert_eq!(reorder.release(start).len(), 2);
+        reorder.push(vec![can_frame_at(0x100, 20)], start);
+        assert_eq!(reorder.flush().len(), 1);
+        assert_eq!(reorder.take_late(), 1);
+    }
+
+    #[test]
+    fn test_liveness_goes_stale_once() {
+        let start = Instant::now();
+        let mut live = liveness(100, start);
+        assert!(!live.check(start + Duration::from_millis(50)));
+        assert!(live.check(start + Duration::from_millis(150)));
+        // Only reported on the transition
+        assert!(!live.check(start + Duration::from_millis(300)));
+        assert!(live.payload(0, start + Duration::from_millis(300)).stale);
+    }
+
+    #[test]
+    fn test_liveness_traffic_resets_timer() {
+        let start = Instant::now();
+        let mut live = liveness(100, start);
+        assert!(!live.touch(start + Duration::from_millis(80)));
+        assert!(!live.check(start + Duration::from_millis(150)));
+        assert!(live.check(start + Duration::from_millis(200)));
+        // Traffic after going stale reports recovery
+        assert!(live.touch(start + Duration::from_millis(250)));
+        assert!(!live.payload(0, start + Duration::from_millis(250)).stale);
+    }
+
+    fn profile(kind: &str, connection: serde_json::Value) -> settings::IOProfile {
+        settings::IOProfile {
+            id: "p".to_string(),
+            name: "p".to_string(),
+            kind: kind.to_string(),
+            connection: serde_json::from_value(connection).unwrap(),
+            preferred_catalog: None,
+        }
+    }
+
+    #[test]
+    fn test_wakeup_steps_from_profile() {
+        let virtual_profile = |connection| profile("virtual", connection);
+        let wakeup = serde_json::json!([
+            { "frame_id": 0x100, "data": [0xAA], "delay_ms": 20 },
+            { "frame_id": 0x101 }
+        ]);
+
+        let steps = wakeup_steps_from_profile(&virtual_profile(serde_json::json!({ "wakeup": wakeup }))).unwrap();
+        assert_eq!(steps.len(), 2);
+        assert_eq!((steps[0].frame_id, steps[0].delay_ms), (Some(0x100), 20));
+        assert!(steps[1].data.is_empty());
+
+        let text = serde_json::json!({ "wakeup": wakeup.to_string() });
+        assert_eq!(wakeup_steps_from_profile(&virtual_profile(text)).unwrap().len(), 2);
+
+        // Sessions that never transmit get no wakeup
+        let silent = serde_json::json!({ "wakeup": wakeup, "listen_only": true });
+        assert!(wakeup_steps_from_profile(&virtual_profile(silent)).unwrap().is_empty());
+        let passive = serde_json::json!({ "wakeup": wakeup, "passive_attach": true });
+        assert!(wakeup_steps_from_profile(&virtual_profile(passive)).unwrap().is_empty());
+
+        // Sequences that don't parse or can't be sent are reported, not ignored
+        assert!(wakeup_steps_from_profile(&virtual_profile(serde_json::json!({ "wakeup": "not json" }))).is_err());
+        let no_id = serde_json::json!({ "wakeup": [{ "data": [1] }] });
+        assert!(wakeup_steps_from_profile(&virtual_profile(no_id)).is_err());
+
+        match wakeup_step_write("virtual", true, &steps[0]).unwrap() {
+            WakeupWrite::Frame(frame) => assert_eq!((frame.frame_id, frame.data), (0x100, vec![0xAA])),
+            other => panic!("unexpected {:?}", other),
+        }
+        assert!(wakeup_step_write("serial", true, &steps[0]).is_err());
+        let serial = WakeupStep { bytes: Some(vec![0x55; 4]), ..Default::default() };
+        assert!(matches!(wakeup_step_write("serial", true, &serial).unwrap(), WakeupWrite::Bytes(b) if b == vec![0x55; 4]));
+    }
+
+    #[test]
+    fn test_bus_safety_watch_profiles() {
+        let check = |kind: &str, connection: serde_json::Value| {
+            let source: SourceConfig = serde_json::from_value(serde_json::json!({
+                "profile_id": "p",
+                "profile_kind": kind,
+                "display_name": "bus",
+                "bus_mappings": [],
+            }))
+            .unwrap();
+            BusSafetyWatch::from_profile(&source, &profile(kind, connection)).is_some()
+        };
+
+        assert!(check("slcan", serde_json::json!({ "bitrate": 500_000 })));
+        assert!(!check("slcan", serde_json::json!({ "bitrate": 500_000, "silent_mode": true })));
+        assert!(!check("slcan", serde_json::json!({ "bitrate": 1_000_000 })));
+        // gs_usb defaults to listen-only
+        assert!(!check("gs_usb", serde_json::json!({ "bitrate": 500_000 })));
+        assert!(check("gs_usb", serde_json::json!({ "bitrate": "250000", "listen_only": false })));
+        assert!(check("socketcan", serde_json::json!({ "interface": "can0" })));
+        assert!(check("socketcan", serde_json::json!({ "interface": "can0", "listen_only": true })));
+        assert!(!check("gvret_tcp", serde_json::json!({ "bitrate": 500_000 })));
+    }
+
+    #[test]
+    fn test_pre_listen_profile_opens_silent() {
+        let flag = |p: Option<settings::IOProfile>, key: &str| p.and_then(|p| p.connection.get(key).and_then(|v| v.as_bool()));
+
+        assert_eq!(flag(pre_listen_profile(&profile("slcan", serde_json::json!({ "bitrate": 500_000 }))), "silent_mode"), Some(true));
+        assert_eq!(flag(pre_listen_profile(&profile("gs_usb", serde_json::json!({ "listen_only": false }))), "listen_only"), Some(true));
+        assert!(pre_listen_profile(&profile("slcan", serde_json::json!({ "passive_attach": true }))).is_none());
+        assert!(pre_listen_profile(&profile("socketcan", serde_json::json!({ "interface": "can0" }))).is_none());
+    }
+
+    #[test]
+    fn test_bus_safety_watch_warns_once_on_obd_traffic() {
+        let start = Instant::now();
+        let mut watch = BusSafetyWatch {
+            display_name: "bus".to_string(),
+            profile_kind: "slcan".to_string(),
+            bitrate: Some(500_000),
+            started: None,
+            done: false,
+        };
+        let with_id = |frame_id: u32, is_extended: bool| FrameMessage { frame_id, is_extended, ..can_frame_at(0x100, 0) };
+
+        assert!(watch.observe(0, &[with_id(0x100, false)], start).is_none());
+        let warning = watch
+            .observe(0, &[with_id(0x7E8, false), with_id(0x18DAF110, true), with_id(0x7E8, false)], start)
+            .unwrap();
+        assert_eq!(warning.obd_ids, vec![0x7E8, 0x18DAF110]);
+        assert!(watch.observe(0, &[with_id(0x7DF, false)], start).is_none());
+
+        // OBD traffic after the watch window is not reported
+        let mut late = BusSafetyWatch { done: false, started: None, ..watch };
+        assert!(late.observe(0, &[with_id(0x100, false)], start).is_none());
+        assert!(late.observe(0, &[with_id(0x7DF, false)], start + Duration::from_secs(3)).is_none());
+        assert!(!is_obd_id(&with_id(0x18DA1020, true)));
+    }
+
+    #[test]
+    fn test_backpressure_follows_frame_rate() {
+        let start = Instant::now();
+        let mut bp = Backpressure::new(start);
+        let tick = Duration::from_millis(MERGE_EMIT_INTERVAL_MS);
+
+        // A flood of 1000-frame batches every emit interval (20k frames/s)
+        let mut now = start;
+        let mut turned_on = None;
+        for i in 0..40u32 {
+            now = start + tick * i;
+            if bp.observe(1_000, now) == Some(true) {
+                turned_on = Some(i);
+                break;
+            }
+        }
+        assert_eq!(turned_on, Some(19));
+        assert!(bp.active);
+
+        // Stays on until a whole window has stayed under the low-water mark
+        now += Duration::from_millis(BACKPRESSURE_WINDOW_MS);
+        assert_eq!(bp.observe(100, now), None);
+        assert!(bp.active);
+        now += Duration::from_millis(BACKPRESSURE_WINDOW_MS);
+        assert_eq!(bp.observe(0, now), Some(false));
+        assert!(!bp.active);
+    }
+
+    #[test]
+    fn test_idle_stop_waits_for_first_traffic() {
+        let start = Instant::now();
+        let later = start + Duration::from_millis(500);
+        assert!(!idle_stop_due(Some(100), None, later));
+        assert!(!idle_stop_due(None, Some(start), later));
+        assert!(!idle_stop_due(Some(1_000), Some(start), later));
+        assert!(idle_stop_due(Some(100), Some(start), later));
+        // Traffic resets the timer
+        assert!(!idle_stop_due(Some(100), Some(later), later + Duration::from_millis(50)));
+    }
+}
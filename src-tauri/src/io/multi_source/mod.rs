@@ -7,10 +7,13 @@ mod merge;
 mod spawner;
 mod types;
 
+pub(crate) use merge::{auto_stop_idle_from_profile, idle_stop_due};
+
 use async_trait::async_trait;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
-use std::sync::{mpsc as std_mpsc, Arc, Mutex};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Duration, Instant};
 use tauri::AppHandle;
 use tokio::sync::mpsc;
 
@@ -23,10 +26,13 @@ use super::slcan::encode_transmit_frame as encode_slcan_frame;
 #[cfg(target_os = "linux")]
 use super::socketcan::{encode_frame as encode_socketcan_frame, EncodedFrame};
 use super::traits::{get_traits_for_profile_kind, validate_session_traits};
-use super::types::{SourceMessage, TransmitRequest};
+use super::types::{
+    transmit_timeout_from_ms, DeviceInfoSender, SourceMessage, TransmitPacer, TransmitRequest,
+    TransmitSender, DEFAULT_TRANSMIT_TIMEOUT,
+};
 use super::{
-    CanTransmitFrame, IOCapabilities, IODevice, IOState, InterfaceTraits, Protocol, TemporalMode,
-    TransmitPayload, TransmitResult, VirtualBusState, emit_buffer_orphaned, emit_buffer_created,
+    ensure_transmit_state, now_us, CanTransmitFrame, FrameMessage, IOCapabilities, IODevice, IOState, InterfaceTraits,
+    Protocol, TemporalMode, TransmitPayload, TransmitResult, VirtualBusState, clear_session_source_buffers, emit_buffer_orphaned, emit_buffer_created,
 };
 use crate::buffer_store::{self, BufferType};
 
@@ -34,8 +40,8 @@ use crate::buffer_store::{self, BufferType};
 use super::gs_usb::encode_frame as encode_gs_usb_frame;
 
 use merge::run_merge_task;
-pub use types::{ModbusRole, SourceConfig};
-use types::{TransmitChannels, TransmitRoute};
+pub use types::{ModbusRole, SourceBusMappings, SourceConfig};
+use types::{DeviceInfoChannels, SharedBusMappings, TransmitChannels, TransmitRoute};
 
 // ============================================================================
 // Virtual Bus Control (shared with generator tasks)
@@ -97,10 +103,14 @@ pub struct MultiSourceReader {
     rx: Option<mpsc::Receiver<SourceMessage>>,
     /// Sender for sub-readers to send messages (kept for cloning)
     tx: mpsc::Sender<SourceMessage>,
-    /// Mapping from output bus number to transmit route (source_idx, device_bus)
-    transmit_routes: HashMap<u8, TransmitRoute>,
+    /// Live bus mappings by source index; transmit routes are derived from these
+    bus_mappings: SharedBusMappings,
     /// Transmit channels by source index (populated when sources connect)
     transmit_channels: TransmitChannels,
+    /// Sources whose transmit is turned off; their channels are kept but not used
+    tx_disabled_sources: Mutex<HashSet<usize>>,
+    /// Device info query channels by source index (slcan sources)
+    device_info_channels: DeviceInfoChannels,
     /// Derived session traits from all interfaces
     session_traits: InterfaceTraits,
     /// Whether this session emits raw bytes (for serial sources without framing)
@@ -111,6 +121,14 @@ pub struct MultiSourceReader {
     merge_cmd_tx: MergeCmdTx,
     /// Command channels to virtual reader tasks for hot bus add/remove (source_idx -> sender)
     virtual_cmd_txs: Arc<Mutex<HashMap<usize, VirtualCmdTx>>>,
+    /// How long transmit waits for a source to report the write result
+    transmit_timeout: Duration,
+    /// Minimum gap between writes; callers reserve a slot and wait outside the session lock
+    tx_pacer: Mutex<TransmitPacer>,
+    /// Also keep each source's frames in a buffer of its own
+    per_source_buffers: bool,
+    /// Store the session's own transmitted frames in its buffers (they are emitted either way)
+    buffer_tx_frames: Arc<AtomicBool>,
 }
 
 impl MultiSourceReader {
@@ -164,29 +182,17 @@ impl MultiSourceReader {
 
         let (tx, rx) = mpsc::channel(SOURCE_CHANNEL_CAPACITY);
 
-        // Build transmit routing table: output_bus -> (source_idx, device_bus, kind)
-        let mut transmit_routes = HashMap::new();
-        for (source_idx, source) in sources.iter().enumerate() {
-            for mapping in &source.bus_mappings {
-                if mapping.enabled {
-                    transmit_routes.insert(
-                        mapping.output_bus,
-                        TransmitRoute {
-                            source_idx,
-                            profile_id: source.profile_id.clone(),
-                            profile_kind: source.profile_kind.clone(),
-                            device_bus: mapping.device_bus,
-                        },
-                    );
-                }
-            }
-        }
+        let bus_mappings = Arc::new(RwLock::new(initial_bus_mappings(&sources)));
 
         // Determine if this session emits raw bytes
         // Raw bytes are emitted if any serial source either:
         // 1. Has no framing (raw mode), or
         // 2. Has framing but emit_raw_bytes is explicitly true
+        // or a GVRET source has emit_raw_bytes set (profile `capture_raw_bytes`)
         let emits_raw_bytes = sources.iter().any(|source| {
+            if matches!(source.profile_kind.as_str(), "gvret_tcp" | "gvret-tcp" | "gvret_usb" | "gvret-usb") {
+                return source.emit_raw_bytes.unwrap_or(false);
+            }
             if source.profile_kind != "serial" {
                 return false;
             }
@@ -209,16 +215,29 @@ impl MultiSourceReader {
             task_handles: Vec::new(),
             rx: Some(rx),
             tx,
-            transmit_routes,
+            bus_mappings,
             transmit_channels: Arc::new(Mutex::new(HashMap::new())),
+            tx_disabled_sources: Mutex::new(HashSet::new()),
+            device_info_channels: Arc::new(Mutex::new(HashMap::new())),
             session_traits,
             emits_raw_bytes,
             virtual_bus_controls: Arc::new(Mutex::new(HashMap::new())),
             merge_cmd_tx: Arc::new(Mutex::new(None)),
             virtual_cmd_txs: Arc::new(Mutex::new(HashMap::new())),
+            transmit_timeout: DEFAULT_TRANSMIT_TIMEOUT,
+            tx_pacer: Mutex::new(TransmitPacer::default()),
+            per_source_buffers: false,
+            buffer_tx_frames: Arc::new(AtomicBool::new(true)),
         })
     }
 
+    /// Keep each source's frames in a buffer of its own in addition to the
+    /// merged session buffer (e.g. to compare two buses without filtering).
+    pub fn with_per_source_buffers(mut self, enabled: bool) -> Self {
+        self.per_source_buffers = enabled;
+        self
+    }
+
     /// Get the source configurations for this multi-source session
     #[allow(dead_code)]
     pub fn sources(&self) -> &[SourceConfig] {
@@ -235,11 +254,14 @@ impl MultiSourceReader {
 
         // Check if we have any CAN-capable sources that can transmit
         // Serial sources don't count for CAN transmit capability
-        let has_can_transmit_routes = self.transmit_routes.values().any(|route| {
-            matches!(
-                route.profile_kind.as_str(),
-                "gvret_tcp" | "gvret_usb" | "slcan" | "gs_usb" | "socketcan" | "virtual"
-            )
+        let has_can_transmit_routes = self.bus_mappings.read().is_ok_and(|table| {
+            table.values().any(|source| {
+                source.bus_mappings.iter().any(|m| m.enabled)
+                    && matches!(
+                        source.profile_kind.as_str(),
+                        "gvret_tcp" | "gvret-tcp" | "gvret_usb" | "gvret-usb" | "slcan" | "gs_usb" | "socketcan" | "virtual"
+                    )
+            })
         });
 
         IOCapabilities {
@@ -282,89 +304,153 @@ impl MultiSourceReader {
             // Emits frames if any source is non-serial, or if any serial source has framing
             self.sources.iter().any(|s| {
                 s.profile_kind != "serial"
-                    || s.framing_encoding.as_deref().map_or(false, |f| f != "raw")
+                    || s.framing_encoding.as_deref().is_some_and(|f| f != "raw")
             }),
             self.emits_raw_bytes,
         )
     }
 
+    /// Look up which source and device bus currently carry an output bus
+    fn transmit_route(&self, output_bus: u8) -> Result<TransmitRoute, String> {
+        let table = self
+            .bus_mappings
+            .read()
+            .map_err(|e| format!("Failed to lock bus mappings: {}", e))?;
+        let mut sources: Vec<&SourceBusMappings> = table.values().collect();
+        sources.sort_by_key(|s| s.source_idx);
+        if let Some(route) = sources.iter().find_map(|s| s.transmit_route(output_bus)) {
+            return Ok(route);
+        }
+        let mut available: Vec<u8> = sources
+            .iter()
+            .flat_map(|s| s.bus_mappings.iter().filter(|m| m.enabled).map(|m| m.output_bus))
+            .collect();
+        available.sort();
+        available.dedup();
+        Err(format!(
+            "No source configured for bus {} (available: {:?})",
+            output_bus, available
+        ))
+    }
+
     /// Route a CAN frame transmit to the appropriate source based on bus number
     fn transmit_can_frame(&self, frame: &CanTransmitFrame) -> Result<TransmitResult, String> {
-        let route = self.transmit_routes.get(&frame.bus).ok_or_else(|| {
-            format!(
-                "No source configured for bus {} (available: {:?})",
-                frame.bus,
-                self.transmit_routes.keys().collect::<Vec<_>>()
-            )
-        })?;
+        let route = self.transmit_route(frame.bus)?;
 
         // Create a modified frame with the device bus number (reverse the mapping)
         let mut routed_frame = frame.clone();
         routed_frame.bus = route.device_bus;
 
-        // Get the transmit channel for this source
+        let tx = self.transmit_sender(&route)?;
+
+        if let Err(result) = validate_transmit_for(&route.profile_kind, route.fd_enabled, &routed_frame) {
+            return Ok(result);
+        }
+        let data = match encode_source_frame(&route.profile_kind, &routed_frame) {
+            Ok(data) => data,
+            Err(result) => return Ok(result),
+        };
+
+        self.send_paced(&tx, data)?;
+        self.record_transmitted(route.source_idx, vec![transmitted_frame(frame, now_us())]);
+        Ok(TransmitResult::success())
+    }
+
+    /// Pass frames the session wrote to the merge task, which emits and
+    /// buffers them as direction "tx" like received traffic.
+    fn record_transmitted(&self, source_idx: usize, frames: Vec<FrameMessage>) {
+        if let Err(e) = self.tx.try_send(SourceMessage::Transmitted(source_idx, frames)) {
+            tlog!("[MultiSource:{}] Dropped transmitted frame record: {}", self.session_id, e);
+        }
+    }
+
+    /// Send one write to a source and record its confirmation for pacing.
+    /// The caller has already waited for its slot (`reserve_transmit_slot`),
+    /// so this never sleeps.
+    fn send_paced(&self, tx: &TransmitSender, data: Vec<u8>) -> Result<(), String> {
+        TransmitRequest::send(tx, data, self.transmit_timeout)?;
+        self.tx_pacer
+            .lock()
+            .map_err(|e| format!("Failed to lock transmit pacer: {}", e))?
+            .record_send(Instant::now());
+        Ok(())
+    }
+
+    /// Fail if transmit has been turned off for a source
+    fn check_source_transmit(&self, source_idx: usize, profile_id: &str) -> Result<(), String> {
+        let disabled = self
+            .tx_disabled_sources
+            .lock()
+            .map_err(|e| format!("Failed to lock transmit state: {}", e))?
+            .contains(&source_idx);
+        if disabled {
+            return Err(format!(
+                "Transmit disabled on source {} (profile '{}')",
+                source_idx, profile_id
+            ));
+        }
+        Ok(())
+    }
+
+    /// Get the transmit channel for a routed source
+    fn transmit_sender(&self, route: &TransmitRoute) -> Result<TransmitSender, String> {
+        self.check_source_transmit(route.source_idx, &route.profile_id)?;
         let channels = self
             .transmit_channels
             .lock()
             .map_err(|e| format!("Failed to lock transmit channels: {}", e))?;
 
-        let tx = channels
-            .get(&route.source_idx)
-            .ok_or_else(|| {
-                format!(
-                    "No transmit channel for source {} (profile '{}') - source may not support transmit or not yet connected",
-                    route.source_idx, route.profile_id
-                )
-            })?
-            .clone();
-        drop(channels); // Release lock before blocking
+        channels.get(&route.source_idx).cloned().ok_or_else(|| {
+            format!(
+                "No transmit channel for source {} (profile '{}') - source may not support transmit or not yet connected",
+                route.source_idx, route.profile_id
+            )
+        })
+    }
 
-        // Encode the frame based on the profile kind
-        let data = match route.profile_kind.as_str() {
-            "gvret_tcp" | "gvret_usb" => {
-                if let Err(result) = validate_gvret_frame(&routed_frame) {
-                    return Ok(result);
-                }
-                encode_gvret_frame(&routed_frame)
-            }
-            #[cfg(any(target_os = "windows", target_os = "macos"))]
-            "gs_usb" => encode_gs_usb_frame(&routed_frame, 0).to_vec(),
-            #[cfg(not(target_os = "ios"))]
-            "slcan" => encode_slcan_frame(&routed_frame),
-            #[cfg(target_os = "linux")]
-            "socketcan" => {
-                match encode_socketcan_frame(&routed_frame) {
-                    EncodedFrame::Classic(buf) => buf.to_vec(),
-                    EncodedFrame::Fd(buf) => buf.to_vec(),
-                }
-            }
-            "virtual" => {
-                // Simple binary loopback encoding: frame_id(4 LE) + bus(1) + is_extended(1) + is_fd(1) + dlc(1) + data
-                let mut buf = Vec::with_capacity(8 + routed_frame.data.len());
-                buf.extend_from_slice(&routed_frame.frame_id.to_le_bytes());
-                buf.push(routed_frame.bus);
-                buf.push(routed_frame.is_extended as u8);
-                buf.push(routed_frame.is_fd as u8);
-                buf.push(routed_frame.data.len() as u8);
-                buf.extend_from_slice(&routed_frame.data);
-                buf
+    /// Transmit a burst of CAN frames, one result per frame.
+    ///
+    /// Consecutive frames for the same GVRET source are encoded into one buffer
+    /// and written together, so a burst costs one write and one wait instead of
+    /// one per frame. Frames for other sources go out one write each, in order.
+    /// With a minimum transmit gap set, every frame is its own write; the
+    /// session-level `transmit_frames` then sends them one call at a time so
+    /// each waits for its own slot.
+    fn transmit_can_frames(&self, frames: &[CanTransmitFrame]) -> Vec<TransmitResult> {
+        let encoded = frames
+            .iter()
+            .map(|frame| -> Result<(TransmitRoute, Vec<u8>), TransmitResult> {
+                let route = self.transmit_route(frame.bus).map_err(TransmitResult::error)?;
+                let mut routed_frame = frame.clone();
+                routed_frame.bus = route.device_bus;
+                validate_transmit_for(&route.profile_kind, route.fd_enabled, &routed_frame)?;
+                let data = encode_source_frame(&route.profile_kind, &routed_frame)?;
+                Ok((route, data))
+            })
+            .collect();
+
+        let allow_batch = !self.transmit_paced();
+        let (mut results, writes) = plan_burst_writes(encoded, allow_batch);
+        for write in writes {
+            let outcome = self
+                .transmit_sender(&write.route)
+                .and_then(|tx| self.send_paced(&tx, write.data));
+            if outcome.is_ok() {
+                let sent_at = now_us();
+                let sent = write.frames.iter().map(|&idx| transmitted_frame(&frames[idx], sent_at)).collect();
+                self.record_transmitted(write.route.source_idx, sent);
             }
-            _ => {
-                return Err(format!(
-                    "Unsupported profile kind '{}' for transmission",
-                    route.profile_kind
-                ));
+            for idx in write.frames {
+                results[idx] = Some(match &outcome {
+                    Ok(()) => TransmitResult::success(),
+                    Err(e) => TransmitResult::error(e.clone()),
+                });
             }
-        };
-
-        let (result_tx, result_rx) = std_mpsc::sync_channel(1);
-        tx.try_send(TransmitRequest { data, result_tx })
-            .map_err(|e| format!("Failed to queue transmit request: {}", e))?;
-        let result = result_rx
-            .recv_timeout(std::time::Duration::from_millis(500))
-            .map_err(|e| format!("Transmit timeout or channel closed: {}", e))?;
-        result?;
-        Ok(TransmitResult::success())
+        }
+        results
+            .into_iter()
+            .map(|r| r.unwrap_or_else(|| TransmitResult::error("Frame was not sent".to_string())))
+            .collect()
     }
 
     /// Route raw bytes to the first serial source
@@ -374,10 +460,20 @@ impl MultiSourceReader {
         }
 
         let serial_route = self
-            .transmit_routes
+            .bus_mappings
+            .read()
+            .map_err(|e| format!("Failed to lock bus mappings: {}", e))?
             .values()
-            .find(|route| route.profile_kind == "serial")
+            .find(|source| source.profile_kind == "serial" && source.bus_mappings.iter().any(|m| m.enabled))
+            .map(|source| TransmitRoute {
+                source_idx: source.source_idx,
+                profile_id: source.profile_id.clone(),
+                profile_kind: source.profile_kind.clone(),
+                device_bus: 0,
+                fd_enabled: false,
+            })
             .ok_or_else(|| "No serial source configured in this session".to_string())?;
+        self.check_source_transmit(serial_route.source_idx, &serial_route.profile_id)?;
 
         let channels = self
             .transmit_channels
@@ -395,20 +491,236 @@ impl MultiSourceReader {
             .clone();
         drop(channels); // Release lock before blocking
 
-        let (result_tx, result_rx) = std_mpsc::sync_channel(1);
-        tx.try_send(TransmitRequest {
-            data: bytes.to_vec(),
-            result_tx,
-        })
-        .map_err(|e| format!("Failed to queue serial transmit request: {}", e))?;
-        let result = result_rx
-            .recv_timeout(std::time::Duration::from_millis(500))
-            .map_err(|e| format!("Serial transmit timeout or channel closed: {}", e))?;
-        result?;
+        self.send_paced(&tx, bytes.to_vec())?;
+        Ok(TransmitResult::success())
+    }
+
+    /// Write raw bytes to the session's GVRET device (lowest source index if
+    /// there are several), through the same channel as frame transmits.
+    fn transmit_gvret_command(&self, bytes: &[u8]) -> Result<TransmitResult, String> {
+        if bytes.is_empty() {
+            return Ok(TransmitResult::error("No bytes to transmit".to_string()));
+        }
+
+        let source_idx = self
+            .bus_mappings
+            .read()
+            .map_err(|e| format!("Failed to lock bus mappings: {}", e))?
+            .values()
+            .filter(|source| matches!(source.profile_kind.as_str(), "gvret_tcp" | "gvret-tcp" | "gvret_usb" | "gvret-usb"))
+            .map(|source| source.source_idx)
+            .min()
+            .ok_or_else(|| "No GVRET source configured in this session".to_string())?;
+        let profile_id = self
+            .bus_mappings
+            .read()
+            .ok()
+            .and_then(|table| table.get(&source_idx).map(|s| s.profile_id.clone()))
+            .unwrap_or_default();
+        self.check_source_transmit(source_idx, &profile_id)?;
+
+        let tx = self
+            .transmit_channels
+            .lock()
+            .map_err(|e| format!("Failed to lock transmit channels: {}", e))?
+            .get(&source_idx)
+            .ok_or_else(|| format!("No transmit channel for GVRET source {} - source may not be connected", source_idx))?
+            .clone();
+
+        tlog!("[MultiSource:{}] Raw device command to source {}: {} bytes", self.session_id, source_idx, bytes.len());
+        self.send_paced(&tx, bytes.to_vec())?;
         Ok(TransmitResult::success())
     }
 }
 
+/// One write to a source: the encoded bytes of one or more frames of a burst
+struct BurstWrite {
+    route: TransmitRoute,
+    data: Vec<u8>,
+    /// Indices of the frames in the burst carried by this write
+    frames: Vec<usize>,
+}
+
+/// Whether a source accepts several encoded frames in one write
+fn supports_batch_write(profile_kind: &str) -> bool {
+    matches!(profile_kind, "gvret_tcp" | "gvret-tcp" | "gvret_usb" | "gvret-usb")
+}
+
+/// Split a routed and encoded burst into source writes, in transmit order.
+/// Consecutive frames for the same batch-capable source share a write; every
+/// other frame gets its own. Frames that failed to route or encode keep their
+/// error result and are not written. Without `allow_batch` every frame is a
+/// write of its own.
+fn plan_burst_writes(
+    encoded: Vec<Result<(TransmitRoute, Vec<u8>), TransmitResult>>,
+    allow_batch: bool,
+) -> (Vec<Option<TransmitResult>>, Vec<BurstWrite>) {
+    let mut results = Vec::with_capacity(encoded.len());
+    let mut writes: Vec<BurstWrite> = Vec::new();
+    for (idx, item) in encoded.into_iter().enumerate() {
+        let (route, data) = match item {
+            Ok(ok) => ok,
+            Err(result) => {
+                results.push(Some(result));
+                continue;
+            }
+        };
+        results.push(None);
+        match writes.last_mut() {
+            Some(last)
+                if last.route.source_idx == route.source_idx
+                    && allow_batch
+                    && supports_batch_write(&route.profile_kind) =>
+            {
+                last.data.extend_from_slice(&data);
+                last.frames.push(idx);
+            }
+            _ => writes.push(BurstWrite { route, data, frames: vec![idx] }),
+        }
+    }
+    (results, writes)
+}
+
+/// Bus mapping table for sources spawned in order, as the merge task indexes them
+fn initial_bus_mappings(sources: &[SourceConfig]) -> HashMap<usize, SourceBusMappings> {
+    sources
+        .iter()
+        .enumerate()
+        .map(|(idx, source)| (idx, SourceBusMappings::new(idx, source)))
+        .collect()
+}
+
+/// The record of a frame the session transmitted, on its session bus.
+/// Direction "tx" marks it as host-side: readers report device echoes as
+/// received traffic, so frame waiters and the buffer filter can tell the two
+/// apart.
+fn transmitted_frame(frame: &CanTransmitFrame, timestamp_us: u64) -> FrameMessage {
+    FrameMessage {
+        protocol: "can".to_string(),
+        timestamp_us,
+        frame_id: frame.frame_id,
+        bus: frame.bus,
+        dlc: if frame.is_rtr { frame.classic_dlc() } else { frame.data.len() as u8 },
+        bytes: if frame.is_rtr { Vec::new() } else { frame.data.clone() },
+        is_extended: frame.is_extended,
+        is_fd: frame.is_fd,
+        source_address: None,
+        incomplete: None,
+        direction: Some("tx".to_string()),
+        dlc_raw: None,
+        is_rtr: frame.is_rtr,
+        is_brs: frame.is_brs,
+        is_esi: false,
+        original_frame_id: None,
+        source_label: None,
+    }
+}
+
+/// Check that a source's profile kind can carry a CAN frame.
+///
+/// Runs before encoding so every protocol rejects bad frames the same way
+/// instead of each encoder truncating or failing on its own. `frame.bus` is
+/// the device bus, after reversing the session bus mapping. `fd_enabled` is
+/// false for sources whose profile opens the channel in classic mode.
+pub(crate) fn validate_transmit_for(
+    profile_kind: &str,
+    fd_enabled: bool,
+    frame: &CanTransmitFrame,
+) -> Result<(), TransmitResult> {
+    if frame.is_brs && !frame.is_fd {
+        return Err(TransmitResult::error(
+            "Bit rate switch (BRS) is only valid on CAN FD frames".to_string(),
+        ));
+    }
+    if frame.is_rtr && frame.is_fd {
+        return Err(TransmitResult::error(
+            "CAN FD has no remote frames (RTR)".to_string(),
+        ));
+    }
+    if frame.is_fd && !fd_enabled {
+        return Err(TransmitResult::error(format!(
+            "{} source has CAN FD disabled (enable_fd)",
+            profile_kind
+        )));
+    }
+    // The GVRET binary frame and the virtual loopback encoding have no RTR
+    // flag; a remote frame would go out as a data frame
+    if frame.is_rtr && matches!(profile_kind, "gvret_tcp" | "gvret-tcp" | "gvret_usb" | "gvret-usb" | "virtual") {
+        return Err(TransmitResult::error(format!(
+            "{} sources cannot send remote frames (RTR)",
+            profile_kind
+        )));
+    }
+    if matches!(profile_kind, "gvret_tcp" | "gvret-tcp" | "gvret_usb" | "gvret-usb") {
+        return validate_gvret_frame(frame);
+    }
+
+    let max_len = if frame.is_fd { 64 } else { 8 };
+    if frame.data.len() > max_len {
+        return Err(TransmitResult::error(format!(
+            "Data length {} exceeds maximum {} bytes for {} frame",
+            frame.data.len(),
+            max_len,
+            if frame.is_fd { "FD" } else { "classic CAN" }
+        )));
+    }
+
+    // Single-bus adapters only have bus 0
+    let max_bus = match profile_kind {
+        "slcan" | "socketcan" => Some(0),
+        _ => None,
+    };
+    if let Some(max_bus) = max_bus {
+        if frame.bus > max_bus {
+            return Err(TransmitResult::error(format!(
+                "Invalid bus number: {} (valid for {}: 0-{})",
+                frame.bus, profile_kind, max_bus
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// Encode a CAN frame in the wire format of a source's profile kind.
+/// Callers validate the frame first with `validate_transmit_for`.
+pub(crate) fn encode_source_frame(
+    profile_kind: &str,
+    frame: &CanTransmitFrame,
+) -> Result<Vec<u8>, TransmitResult> {
+    match profile_kind {
+        "gvret_tcp" | "gvret-tcp" | "gvret_usb" | "gvret-usb" => Ok(encode_gvret_frame(frame)),
+        #[cfg(any(target_os = "windows", target_os = "macos"))]
+        // Channel byte is stamped by the source's transmit forwarder, which
+        // also rejects FD frames on channels initialized in classic mode
+        "gs_usb" => Ok(encode_gs_usb_frame(frame, 0)),
+        #[cfg(not(target_os = "ios"))]
+        "slcan" => Ok(encode_slcan_frame(frame)),
+        #[cfg(target_os = "linux")]
+        "socketcan" => {
+            Ok(match encode_socketcan_frame(frame) {
+                EncodedFrame::Classic(buf) => buf.to_vec(),
+                EncodedFrame::Fd(buf) => buf.to_vec(),
+            })
+        }
+        "virtual" => {
+            // Simple binary loopback encoding: frame_id(4 LE) + bus(1) + is_extended(1) + is_fd(1) + dlc(1) + data
+            let mut buf = Vec::with_capacity(8 + frame.data.len());
+            buf.extend_from_slice(&frame.frame_id.to_le_bytes());
+            buf.push(frame.bus);
+            buf.push(frame.is_extended as u8);
+            buf.push(frame.is_fd as u8);
+            buf.push(frame.data.len() as u8);
+            buf.extend_from_slice(&frame.data);
+            Ok(buf)
+        }
+        _ => Err(TransmitResult::error(format!(
+            "Unsupported profile kind '{}' for transmission",
+            profile_kind
+        ))),
+    }
+}
+
 #[async_trait]
 impl IODevice for MultiSourceReader {
     fn capabilities(&self) -> IOCapabilities {
@@ -452,6 +764,7 @@ impl IODevice for MultiSourceReader {
         // Create appropriate buffer(s) for this multi-source session
         // We may need both a Frames buffer (for CAN, framed serial) and a Bytes buffer (for raw serial)
         // Buffer names use session ID (UI prefixes with "Frames:" or "Bytes:" based on type)
+        let mut frames_buffer_id: Option<String> = None;
         let mut bytes_buffer_id: Option<String> = None;
 
         if has_framing {
@@ -460,6 +773,7 @@ impl IODevice for MultiSourceReader {
             emit_buffer_created(&self.app, &self.session_id, &buffer_id, &self.session_id, "frames");
             // Assign buffer ownership to this session
             let _ = buffer_store::set_buffer_owner(&buffer_id, &self.session_id);
+            frames_buffer_id = Some(buffer_id);
         }
 
         if self.emits_raw_bytes {
@@ -479,6 +793,7 @@ impl IODevice for MultiSourceReader {
                 emit_buffer_created(&self.app, &self.session_id, &buffer_id, &self.session_id, "bytes");
                 // Assign buffer ownership to this session
                 let _ = buffer_store::set_buffer_owner(&buffer_id, &self.session_id);
+                bytes_buffer_id = Some(buffer_id);
             }
         }
 
@@ -486,6 +801,14 @@ impl IODevice for MultiSourceReader {
         if let Ok(mut channels) = self.transmit_channels.lock() {
             channels.clear();
         }
+        if let Ok(mut channels) = self.device_info_channels.lock() {
+            channels.clear();
+        }
+        // Sources are respawned from their current configs
+        if let Ok(mut table) = self.bus_mappings.write() {
+            *table = initial_bus_mappings(&self.sources);
+        }
+        let bus_mappings = self.bus_mappings.clone();
 
         let app = self.app.clone();
         let session_id = self.session_id.clone();
@@ -493,7 +816,12 @@ impl IODevice for MultiSourceReader {
         let stop_flag = self.stop_flag.clone();
         let tx = self.tx.clone();
         let transmit_channels = self.transmit_channels.clone();
+        let device_info_channels = self.device_info_channels.clone();
         let emits_raw_bytes = self.emits_raw_bytes;
+        let per_source_buffers = self.per_source_buffers && frames_buffer_id.is_some();
+        let buffer_tx_frames = self.buffer_tx_frames.clone();
+        // Per-source buffers from a previous run stay in the store but no longer belong to this run
+        clear_session_source_buffers(&self.session_id);
 
         // Take the receiver - we'll use it in the merge task
         // This should always succeed now since we checked/recreated above
@@ -524,11 +852,16 @@ impl IODevice for MultiSourceReader {
                 session_id,
                 sources,
                 emits_raw_bytes,
+                frames_buffer_id,
                 bytes_buffer_id,
+                per_source_buffers,
+                buffer_tx_frames,
                 stop_flag,
                 rx,
                 tx,
                 transmit_channels,
+                device_info_channels,
+                bus_mappings,
                 virtual_bus_controls,
                 merge_cmd_rx,
                 virtual_cmd_txs,
@@ -588,12 +921,25 @@ impl IODevice for MultiSourceReader {
     }
 
     fn transmit(&self, payload: &TransmitPayload) -> Result<TransmitResult, String> {
+        ensure_transmit_state(&self.state)?;
         match payload {
             TransmitPayload::CanFrame(frame) => self.transmit_can_frame(frame),
             TransmitPayload::RawBytes(bytes) => self.transmit_raw_bytes(bytes),
         }
     }
 
+    fn transmit_device_command(&self, bytes: &[u8]) -> Result<TransmitResult, String> {
+        ensure_transmit_state(&self.state)?;
+        self.transmit_gvret_command(bytes)
+    }
+
+    fn transmit_batch(&self, frames: &[CanTransmitFrame]) -> Vec<TransmitResult> {
+        if let Err(e) = ensure_transmit_state(&self.state) {
+            return frames.iter().map(|_| TransmitResult::error(e.clone())).collect();
+        }
+        self.transmit_can_frames(frames)
+    }
+
     fn state(&self) -> IOState {
         self.state.clone()
     }
@@ -606,6 +952,71 @@ impl IODevice for MultiSourceReader {
         "multi_source"
     }
 
+    fn set_transmit_timeout(&mut self, timeout_ms: u64) -> Result<(), String> {
+        self.transmit_timeout = transmit_timeout_from_ms(timeout_ms)?;
+        tlog!("[MultiSource:{}] Transmit timeout set to {} ms", self.session_id, timeout_ms);
+        Ok(())
+    }
+
+    fn device_info_sender(&self) -> Result<DeviceInfoSender, String> {
+        // The first source that answers queries
+        self.device_info_channels
+            .lock()
+            .map_err(|e| format!("Failed to lock device info channels: {}", e))?
+            .iter()
+            .min_by_key(|(idx, _)| **idx)
+            .map(|(_, tx)| tx.clone())
+            .ok_or_else(|| "No source in this session answers device info queries".to_string())
+    }
+
+    fn set_buffer_tx_frames(&mut self, enabled: bool) -> Result<(), String> {
+        self.buffer_tx_frames.store(enabled, Ordering::Relaxed);
+        tlog!("[MultiSource:{}] Transmitted frames {} the buffer", self.session_id, if enabled { "kept in" } else { "left out of" });
+        Ok(())
+    }
+
+    fn set_source_transmit_enabled(&mut self, source_idx: usize, enabled: bool) -> Result<(), String> {
+        if !self
+            .bus_mappings
+            .read()
+            .map_err(|e| format!("Failed to lock bus mappings: {}", e))?
+            .contains_key(&source_idx)
+        {
+            return Err(format!("No source {} in this session", source_idx));
+        }
+        let mut disabled = self
+            .tx_disabled_sources
+            .lock()
+            .map_err(|e| format!("Failed to lock transmit state: {}", e))?;
+        if enabled {
+            disabled.remove(&source_idx);
+        } else {
+            disabled.insert(source_idx);
+        }
+        tlog!("[MultiSource:{}] Source {} transmit {}", self.session_id, source_idx, if enabled { "enabled" } else { "disabled" });
+        Ok(())
+    }
+
+    fn set_min_tx_gap(&mut self, gap_us: u64) -> Result<(), String> {
+        self.tx_pacer
+            .lock()
+            .map_err(|e| format!("Failed to lock transmit pacer: {}", e))?
+            .set_min_gap_us(gap_us)?;
+        tlog!("[MultiSource:{}] Minimum transmit gap set to {} us", self.session_id, gap_us);
+        Ok(())
+    }
+
+    fn transmit_paced(&self) -> bool {
+        self.tx_pacer.lock().is_ok_and(|p| p.is_enabled())
+    }
+
+    fn reserve_transmit_slot(&self) -> Duration {
+        self.tx_pacer
+            .lock()
+            .map(|mut p| p.reserve(Instant::now()))
+            .unwrap_or_default()
+    }
+
     fn set_traffic_enabled(&mut self, enabled: bool) -> Result<(), String> {
         let controls = self.virtual_bus_controls.lock()
             .map_err(|e| format!("Failed to lock virtual bus controls: {}", e))?;
@@ -683,9 +1094,51 @@ impl IODevice for MultiSourceReader {
             .map_err(|e| format!("Failed to send remove-source command: {}", e))?;
         // Update local configs
         self.sources.retain(|c| c.profile_id != profile_id);
+        if let Ok(mut table) = self.bus_mappings.write() {
+            table.retain(|_, source| source.profile_id != profile_id);
+        }
+        Ok(())
+    }
+
+    fn update_bus_mapping(&mut self, source_idx: usize, bus_mappings: Vec<BusMapping>) -> Result<(), String> {
+        let mut table = self.bus_mappings.write()
+            .map_err(|e| format!("Failed to lock bus mappings: {}", e))?;
+        let source = table.get_mut(&source_idx)
+            .ok_or_else(|| format!("No source {} in this session", source_idx))?;
+        let profile_id = source.profile_id.clone();
+
+        let running = matches!(self.state, IOState::Running);
+        if running && source.needs_restart(&bus_mappings) {
+            // The reader never opened these buses; reconnect just this source
+            drop(table);
+            tlog!(
+                "[MultiSource:{}] Source {} needs a new bus enabled, reconnecting it",
+                self.session_id, source_idx
+            );
+            return self.update_source_bus_mappings(&profile_id, bus_mappings);
+        }
+
+        source.bus_mappings = bus_mappings.clone();
+        if !running {
+            // Picked up by the reader on the next start
+            source.spawned = bus_mappings.clone();
+        }
+        drop(table);
+        if let Some(config) = self.sources.iter_mut().find(|c| c.profile_id == profile_id) {
+            config.bus_mappings = bus_mappings;
+        }
+        tlog!("[MultiSource:{}] Updated bus mappings for source {}", self.session_id, source_idx);
         Ok(())
     }
 
+    fn bus_mappings(&self) -> Result<Vec<SourceBusMappings>, String> {
+        let table = self.bus_mappings.read()
+            .map_err(|e| format!("Failed to lock bus mappings: {}", e))?;
+        let mut sources: Vec<SourceBusMappings> = table.values().cloned().collect();
+        sources.sort_by_key(|s| s.source_idx);
+        Ok(sources)
+    }
+
     fn update_source_bus_mappings(&mut self, profile_id: &str, bus_mappings: Vec<BusMapping>) -> Result<(), String> {
         // Find the existing source config for this profile
         let existing = self.sources.iter()
@@ -756,3 +1209,153 @@ impl IODevice for MultiSourceReader {
         Some(self.sources.clone())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::test_support::can_transmit;
+
+    fn frame(bus: u8, len: usize, is_fd: bool, is_brs: bool) -> CanTransmitFrame {
+        CanTransmitFrame { bus, is_fd, is_brs, ..can_transmit(0x123, vec![0xAA; len]) }
+    }
+
+    #[test]
+    fn test_validate_transmit_fd_capable_sources() {
+        assert!(validate_transmit_for("slcan", true, &frame(0, 64, true, true)).is_ok());
+        assert!(validate_transmit_for("slcan", true, &frame(0, 9, false, false)).is_err());
+        assert!(validate_transmit_for("socketcan", true, &frame(0, 64, true, true)).is_ok());
+        // A profile without enable_fd opens the channel in classic mode
+        assert!(validate_transmit_for("socketcan", false, &frame(0, 8, true, false)).is_err());
+        assert!(validate_transmit_for("socketcan", false, &frame(0, 8, false, false)).is_ok());
+        assert!(validate_transmit_for("gvret_tcp", true, &frame(4, 64, true, false)).is_ok());
+        assert!(validate_transmit_for("socketcan", true, &frame(0, 65, true, false)).is_err());
+        assert!(validate_transmit_for("socketcan", true, &frame(0, 9, false, false)).is_err());
+    }
+
+    #[test]
+    fn test_validate_transmit_rejects_brs_without_fd() {
+        assert!(validate_transmit_for("gvret_usb", true, &frame(0, 8, false, true)).is_err());
+    }
+
+    #[test]
+    fn test_transmitted_frame_is_tagged_tx() {
+        let sent = transmitted_frame(&frame(2, 3, false, false), 42);
+        assert_eq!(sent.direction.as_deref(), Some("tx"));
+        assert_eq!((sent.frame_id, sent.bus, sent.timestamp_us), (0x123, 2, 42));
+        assert_eq!(sent.bytes, vec![0xAA; 3]);
+
+        let rtr = CanTransmitFrame { is_rtr: true, rtr_dlc: Some(8), ..frame(0, 0, false, false) };
+        let sent = transmitted_frame(&rtr, 0);
+        assert!(sent.is_rtr && sent.bytes.is_empty());
+        assert_eq!(sent.dlc, 8);
+    }
+
+    #[test]
+    fn test_validate_transmit_rejects_gvret_remote_frames() {
+        let rtr = CanTransmitFrame { is_rtr: true, ..frame(0, 0, false, false) };
+        assert!(validate_transmit_for("gvret_tcp", true, &rtr).is_err());
+        assert!(validate_transmit_for("gvret_usb", true, &rtr).is_err());
+        assert!(validate_transmit_for("gvret-tcp", true, &rtr).is_err());
+        assert!(validate_transmit_for("virtual", true, &rtr).is_err());
+    }
+
+    #[test]
+    fn test_validate_transmit_rejects_fd_remote_frames() {
+        let rtr = CanTransmitFrame { is_rtr: true, ..frame(0, 0, true, false) };
+        assert!(validate_transmit_for("slcan", true, &rtr).is_err());
+        assert!(validate_transmit_for("socketcan", true, &rtr).is_err());
+        assert!(validate_transmit_for("slcan", true, &CanTransmitFrame { is_fd: false, ..rtr }).is_ok());
+    }
+
+    #[test]
+    fn test_validate_transmit_checks_bus_per_protocol() {
+        assert!(validate_transmit_for("slcan", true, &frame(1, 8, false, false)).is_err());
+        assert!(validate_transmit_for("socketcan", true, &frame(1, 8, false, false)).is_err());
+        assert!(validate_transmit_for("gvret_tcp", true, &frame(5, 8, false, false)).is_err());
+        assert!(validate_transmit_for("virtual", true, &frame(3, 8, false, false)).is_ok());
+    }
+
+    fn route(source_idx: usize, profile_kind: &str) -> TransmitRoute {
+        TransmitRoute {
+            source_idx,
+            profile_id: format!("p{}", source_idx),
+            profile_kind: profile_kind.to_string(),
+            device_bus: 0,
+            fd_enabled: true,
+        }
+    }
+
+    #[test]
+    fn test_burst_writes_group_consecutive_gvret_frames() {
+        let encoded = vec![
+            Ok((route(0, "gvret_tcp"), vec![1])),
+            Ok((route(0, "gvret_tcp"), vec![2])),
+            Err(TransmitResult::error("bad frame".to_string())),
+            Ok((route(0, "gvret_tcp"), vec![3])),
+            Ok((route(1, "slcan"), vec![4])),
+            Ok((route(1, "slcan"), vec![5])),
+            Ok((route(0, "gvret_tcp"), vec![6])),
+        ];
+        let (results, writes) = plan_burst_writes(encoded, true);
+
+        let grouped: Vec<(usize, Vec<u8>, Vec<usize>)> = writes
+            .into_iter()
+            .map(|w| (w.route.source_idx, w.data, w.frames))
+            .collect();
+        assert_eq!(
+            grouped,
+            vec![
+                (0, vec![1, 2, 3], vec![0, 1, 3]),
+                (1, vec![4], vec![4]),
+                (1, vec![5], vec![5]),
+                (0, vec![6], vec![6]),
+            ]
+        );
+        // Only the failed frame has a result before anything is written
+        assert!(results[2].as_ref().is_some_and(|r| !r.success));
+        assert_eq!(results.iter().filter(|r| r.is_some()).count(), 1);
+    }
+
+    fn mapping(device_bus: u8, enabled: bool, output_bus: u8) -> BusMapping {
+        BusMapping { device_bus, enabled, output_bus, ..Default::default() }
+    }
+
+    fn two_bus_source() -> SourceBusMappings {
+        let started = vec![mapping(0, true, 4), mapping(1, false, 5)];
+        SourceBusMappings {
+            source_idx: 2,
+            profile_id: "gvret".to_string(),
+            profile_kind: "gvret_tcp".to_string(),
+            bus_mappings: started.clone(),
+            spawned: started,
+            fd_enabled: true,
+        }
+    }
+
+    #[test]
+    fn test_bus_mapping_remap_in_place() {
+        let mut source = two_bus_source();
+        assert!(!source.is_remapped());
+        assert_eq!(source.remap_bus(4), Some(4));
+
+        // Move device bus 0 from output 4 to output 7
+        source.bus_mappings = vec![mapping(0, true, 7), mapping(1, false, 5)];
+        assert!(source.is_remapped());
+        assert_eq!(source.remap_bus(4), Some(7));
+        assert!(source.transmit_route(4).is_none());
+        let route = source.transmit_route(7).unwrap();
+        assert_eq!((route.source_idx, route.device_bus), (2, 0));
+
+        // Disabling it drops its frames and its transmit route
+        source.bus_mappings = vec![mapping(0, false, 7), mapping(1, false, 5)];
+        assert_eq!(source.remap_bus(4), None);
+        assert!(source.transmit_route(7).is_none());
+    }
+
+    #[test]
+    fn test_bus_mapping_enabling_unopened_bus_needs_restart() {
+        let source = two_bus_source();
+        assert!(!source.needs_restart(&[mapping(0, true, 9), mapping(1, false, 5)]));
+        assert!(source.needs_restart(&[mapping(0, true, 4), mapping(1, true, 5)]));
+    }
+}
@@ -14,13 +14,13 @@ use tokio_modbus::client::{self, tcp};
 use tokio_modbus::prelude::*;
 
 use super::types::ModbusRole;
-use crate::io::gvret::{run_gvret_tcp_source, BusMapping};
+use crate::io::gvret::{keepalive_interval_from_connection, run_gvret_tcp_source, BusMapping, TcpSocketOptions};
 #[cfg(not(target_os = "ios"))]
 use crate::io::gvret::run_gvret_usb_source;
 use crate::io::modbus_tcp::{PollGroup, RegisterType};
-use crate::io::{now_us, FrameMessage};
+use crate::io::{now_us, FrameMessage, TimestampSource};
 #[cfg(not(target_os = "ios"))]
-use crate::io::serial::{parse_profile_for_source, run_source as run_serial_source};
+use crate::io::serial::{parse_profile_for_source, run_source as run_serial_source, SerialLineControl};
 #[cfg(not(target_os = "ios"))]
 use crate::io::slcan::run_slcan_source;
 use crate::io::types::{SourceMessage, TransmitRequest};
@@ -31,7 +31,20 @@ use super::{VirtualBusCommand, VirtualBusControl, VirtualBusControls};
 use crate::io::socketcan::run_source as run_socketcan_source;
 
 #[cfg(any(target_os = "windows", target_os = "macos"))]
-use crate::io::gs_usb::run_source as run_gs_usb_source;
+use crate::io::gs_usb::{run_source as run_gs_usb_source, GsUsbReadTuning};
+
+/// Read the passive attach flag from a profile's connection settings.
+///
+/// With `passive_attach` set, the source opens the port or socket and only reads:
+/// no setup, probe or keepalive commands are written, so the device must already
+/// be configured and streaming. Supported by slcan and GVRET (TCP and USB);
+/// other drivers ignore the flag. Transmit stays available behind arming.
+fn passive_attach_from_connection(connection: &std::collections::HashMap<String, serde_json::Value>) -> bool {
+    connection
+        .get("passive_attach")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false)
+}
 
 /// Run a single source reader and send frames to the merge task
 #[allow(clippy::too_many_arguments)]
@@ -48,7 +61,8 @@ pub(super) async fn run_source_reader(
     _delimiter_override: Option<Vec<u8>>,
     _max_frame_length_override: Option<usize>,
     _min_frame_length_override: Option<usize>,
-    _emit_raw_bytes_override: Option<bool>,
+    // Raw byte capture: serial framing option, or GVRET `capture_raw_bytes`
+    emit_raw_bytes_override: Option<bool>,
     // Frame ID extraction config from session options (overrides profile settings for serial)
     _frame_id_start_byte_override: Option<i32>,
     _frame_id_bytes_override: Option<u8>,
@@ -60,18 +74,25 @@ pub(super) async fn run_source_reader(
     _modbus_polls: Option<Vec<PollGroup>>,
     _modbus_role: Option<ModbusRole>,
     _max_register_errors: Option<u32>,
+    // Session-level timestamp source (falls back to the profile setting)
+    timestamp_source_override: Option<TimestampSource>,
     stop_flag: Arc<AtomicBool>,
     tx: mpsc::Sender<SourceMessage>,
     virtual_bus_controls: VirtualBusControls,
     virtual_cmd_rx: Option<mpsc::UnboundedReceiver<VirtualBusCommand>>,
 ) {
+    let timestamp_source = timestamp_source_override
+        .unwrap_or_else(|| TimestampSource::from_connection(&profile.connection));
+
     match profile.kind.as_str() {
         "gvret_tcp" | "gvret-tcp" => {
-            run_gvret_tcp_reader(source_idx, &profile, bus_mappings, stop_flag, tx).await;
+            let capture_raw_bytes = emit_raw_bytes_override.unwrap_or(false);
+            run_gvret_tcp_reader(source_idx, &profile, capture_raw_bytes, timestamp_source, bus_mappings, stop_flag, tx).await;
         }
         #[cfg(not(target_os = "ios"))]
         "gvret_usb" | "gvret-usb" => {
-            run_gvret_usb_reader(source_idx, &profile, bus_mappings, stop_flag, tx).await;
+            let capture_raw_bytes = emit_raw_bytes_override.unwrap_or(false);
+            run_gvret_usb_reader(source_idx, &profile, capture_raw_bytes, timestamp_source, bus_mappings, stop_flag, tx).await;
         }
         #[cfg(not(target_os = "ios"))]
         "slcan" => {
@@ -79,11 +100,11 @@ pub(super) async fn run_source_reader(
         }
         #[cfg(any(target_os = "windows", target_os = "macos"))]
         "gs_usb" => {
-            run_gs_usb_reader(source_idx, &profile, bus_mappings, stop_flag, tx).await;
+            run_gs_usb_reader(source_idx, &profile, timestamp_source, bus_mappings, stop_flag, tx).await;
         }
         #[cfg(target_os = "linux")]
         "socketcan" => {
-            run_socketcan_reader(source_idx, &profile, bus_mappings, stop_flag, tx).await;
+            run_socketcan_reader(source_idx, &profile, timestamp_source, bus_mappings, stop_flag, tx).await;
         }
         #[cfg(not(target_os = "ios"))]
         "serial" => {
@@ -95,7 +116,7 @@ pub(super) async fn run_source_reader(
                 _delimiter_override,
                 _max_frame_length_override,
                 _min_frame_length_override,
-                _emit_raw_bytes_override,
+                emit_raw_bytes_override,
                 _frame_id_start_byte_override,
                 _frame_id_bytes_override,
                 _frame_id_big_endian_override,
@@ -148,6 +169,55 @@ pub(super) async fn run_source_reader(
     }
 }
 
+/// Open a CAN source with a silent profile (see `pre_listen_profile`) for
+/// `duration`, forwarding its frames so the bus safety check sees the bus
+/// before the device ACKs anything. Only frames are forwarded; connection,
+/// transmit and error messages come from the active run that follows.
+#[cfg_attr(target_os = "ios", allow(unused_variables))]
+pub(super) async fn run_pre_listen(
+    source_idx: usize,
+    profile: IOProfile,
+    bus_mappings: Vec<BusMapping>,
+    timestamp_source_override: Option<TimestampSource>,
+    duration: Duration,
+    stop_flag: Arc<AtomicBool>,
+    tx: mpsc::Sender<SourceMessage>,
+) {
+    let _timestamp_source = timestamp_source_override
+        .unwrap_or_else(|| TimestampSource::from_connection(&profile.connection));
+    let (pre_tx, mut pre_rx) = mpsc::channel::<SourceMessage>(64);
+    let pre_stop = Arc::new(AtomicBool::new(false));
+    let reader_stop = pre_stop.clone();
+    let reader = tokio::spawn(async move {
+        match profile.kind.as_str() {
+            #[cfg(not(target_os = "ios"))]
+            "slcan" => run_slcan_reader(source_idx, &profile, bus_mappings, reader_stop, pre_tx).await,
+            #[cfg(any(target_os = "windows", target_os = "macos"))]
+            "gs_usb" => {
+                run_gs_usb_reader(source_idx, &profile, _timestamp_source, bus_mappings, reader_stop, pre_tx).await
+            }
+            _ => {}
+        }
+    });
+
+    tlog!("[MultiSourceReader] Source {} pre-listening silent for {:?}", source_idx, duration);
+    let deadline = tokio::time::Instant::now() + duration;
+    while tokio::time::Instant::now() < deadline && !stop_flag.load(Ordering::Relaxed) {
+        match tokio::time::timeout(Duration::from_millis(50), pre_rx.recv()).await {
+            Ok(Some(SourceMessage::Frames(idx, frames))) => {
+                let _ = tx.send(SourceMessage::Frames(idx, frames)).await;
+            }
+            Ok(Some(_)) | Err(_) => {}
+            Ok(None) => break,
+        }
+    }
+
+    // Close the silent connection before the active run opens the device
+    pre_stop.store(true, Ordering::SeqCst);
+    drop(pre_rx);
+    let _ = reader.await;
+}
+
 // ============================================================================
 // Per-Protocol Reader Functions
 // ============================================================================
@@ -155,6 +225,8 @@ pub(super) async fn run_source_reader(
 async fn run_gvret_tcp_reader(
     source_idx: usize,
     profile: &IOProfile,
+    capture_raw_bytes: bool,
+    timestamp_source: TimestampSource,
     bus_mappings: Vec<BusMapping>,
     stop_flag: Arc<AtomicBool>,
     tx: mpsc::Sender<SourceMessage>,
@@ -176,13 +248,32 @@ async fn run_gvret_tcp_reader(
         .and_then(|v| v.as_f64().or_else(|| v.as_str().and_then(|s| s.parse().ok())))
         .unwrap_or(5.0);
 
-    run_gvret_tcp_source(source_idx, host, port, timeout_sec, bus_mappings, stop_flag, tx).await;
+    let keepalive_interval = keepalive_interval_from_connection(&profile.connection);
+    let socket_options = TcpSocketOptions::from_connection(&profile.connection);
+
+    run_gvret_tcp_source(
+        source_idx,
+        host,
+        port,
+        timeout_sec,
+        socket_options,
+        passive_attach_from_connection(&profile.connection),
+        keepalive_interval,
+        capture_raw_bytes,
+        timestamp_source,
+        bus_mappings,
+        stop_flag,
+        tx,
+    )
+    .await;
 }
 
 #[cfg(not(target_os = "ios"))]
 async fn run_gvret_usb_reader(
     source_idx: usize,
     profile: &IOProfile,
+    capture_raw_bytes: bool,
+    timestamp_source: TimestampSource,
     bus_mappings: Vec<BusMapping>,
     stop_flag: Arc<AtomicBool>,
     tx: mpsc::Sender<SourceMessage>,
@@ -205,7 +296,22 @@ async fn run_gvret_usb_reader(
         .and_then(|v| v.as_i64().or_else(|| v.as_str().and_then(|s| s.parse().ok())))
         .unwrap_or(115200) as u32;
 
-    run_gvret_usb_source(source_idx, port, baud_rate, bus_mappings, stop_flag, tx).await;
+    let keepalive_interval = keepalive_interval_from_connection(&profile.connection);
+
+    run_gvret_usb_source(
+        source_idx,
+        port,
+        baud_rate,
+        SerialLineControl::from_connection(&profile.connection),
+        passive_attach_from_connection(&profile.connection),
+        keepalive_interval,
+        capture_raw_bytes,
+        timestamp_source,
+        bus_mappings,
+        stop_flag,
+        tx,
+    )
+    .await;
 }
 
 #[cfg(not(target_os = "ios"))]
@@ -233,6 +339,23 @@ async fn run_slcan_reader(
         .get("baud_rate")
         .and_then(|v| v.as_i64().or_else(|| v.as_str().and_then(|s| s.parse().ok())))
         .unwrap_or(115200) as u32;
+    // Serial framing, 8N1 unless the profile says otherwise
+    let data_bits = profile
+        .connection
+        .get("data_bits")
+        .and_then(|v| v.as_i64().or_else(|| v.as_str().and_then(|s| s.parse().ok())))
+        .unwrap_or(8) as u8;
+    let stop_bits = profile
+        .connection
+        .get("stop_bits")
+        .and_then(|v| v.as_i64().or_else(|| v.as_str().and_then(|s| s.parse().ok())))
+        .unwrap_or(1) as u8;
+    let parity = profile
+        .connection
+        .get("parity")
+        .and_then(|v| v.as_str())
+        .unwrap_or("none")
+        .to_string();
     let bitrate = profile
         .connection
         .get("bitrate")
@@ -253,15 +376,26 @@ async fn run_slcan_reader(
         .get("data_bitrate")
         .and_then(|v| v.as_i64().or_else(|| v.as_str().and_then(|s| s.parse().ok())))
         .unwrap_or(2_000_000) as u32;
+    let strict_dlc = profile
+        .connection
+        .get("strict_dlc")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false);
 
     run_slcan_source(
         source_idx,
         port,
         baud_rate,
+        data_bits,
+        stop_bits,
+        parity,
+        SerialLineControl::from_connection(&profile.connection),
+        passive_attach_from_connection(&profile.connection),
         bitrate,
         silent_mode,
         enable_fd,
         data_bitrate,
+        strict_dlc,
         bus_mappings,
         stop_flag,
         tx,
@@ -273,6 +407,7 @@ async fn run_slcan_reader(
 async fn run_gs_usb_reader(
     source_idx: usize,
     profile: &IOProfile,
+    timestamp_source: TimestampSource,
     bus_mappings: Vec<BusMapping>,
     stop_flag: Arc<AtomicBool>,
     tx: mpsc::Sender<SourceMessage>,
@@ -327,6 +462,7 @@ async fn run_gs_usb_reader(
         .get("data_sample_point")
         .and_then(|v| v.as_f64().or_else(|| v.as_str().and_then(|s| s.parse().ok())))
         .unwrap_or(75.0) as f32;
+    let read_tuning = GsUsbReadTuning::from_connection(&profile.connection);
 
     run_gs_usb_source(
         source_idx,
@@ -340,6 +476,8 @@ async fn run_gs_usb_reader(
         enable_fd,
         data_bitrate,
         data_sample_point,
+        read_tuning,
+        timestamp_source,
         bus_mappings,
         stop_flag,
         tx,
@@ -351,6 +489,7 @@ async fn run_gs_usb_reader(
 async fn run_socketcan_reader(
     source_idx: usize,
     profile: &IOProfile,
+    timestamp_source: TimestampSource,
     bus_mappings: Vec<BusMapping>,
     stop_flag: Arc<AtomicBool>,
     tx: mpsc::Sender<SourceMessage>,
@@ -372,8 +511,8 @@ async fn run_socketcan_reader(
     let bitrate = profile
         .connection
         .get("bitrate")
-        .and_then(|v| v.as_str())
-        .and_then(|s| s.parse::<u32>().ok());
+        .and_then(|v| v.as_i64().or_else(|| v.as_str().and_then(|s| s.parse().ok())))
+        .map(|v| v as u32);
     let enable_fd = profile
         .connection
         .get("enable_fd")
@@ -384,6 +523,18 @@ async fn run_socketcan_reader(
         .get("data_bitrate")
         .and_then(|v| v.as_i64().or_else(|| v.as_str().and_then(|s| s.parse().ok())))
         .map(|v| v as u32);
+    // Optional socket receive buffer size for high-rate buses
+    let rcvbuf_bytes = profile
+        .connection
+        .get("rcvbuf_bytes")
+        .and_then(|v| v.as_i64().or_else(|| v.as_str().and_then(|s| s.parse().ok())))
+        .filter(|v| *v > 0)
+        .map(|v| v as u32);
+    let restart_on_busoff = profile
+        .connection
+        .get("restart_on_busoff")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false);
 
     run_socketcan_source(
         source_idx,
@@ -391,6 +542,9 @@ async fn run_socketcan_reader(
         bitrate,
         enable_fd,
         data_bitrate,
+        rcvbuf_bytes,
+        timestamp_source,
+        restart_on_busoff,
         bus_mappings,
         stop_flag,
         tx,
@@ -473,11 +627,14 @@ async fn run_serial_reader(
         config.data_bits,
         config.stop_bits,
         config.parity,
+        config.line_control,
         config.framing_encoding,
         frame_id_config,
         source_address_config,
         config.min_frame_length,
         config.emit_raw_bytes,
+        config.append_terminator,
+        config.expect_echo,
         bus_mappings,
         stop_flag,
         tx,
@@ -613,6 +770,12 @@ async fn run_virtual_reader(
                             source_address: None,
                             incomplete: None,
                             direction: Some("rx".to_string()),
+                            dlc_raw: None,
+                            is_rtr: false,
+                            is_brs: false,
+                            is_esi: false,
+                            original_frame_id: None,
+                            source_label: None,
                         };
                         let _ = tx_loopback
                             .send(SourceMessage::Frames(source_idx, vec![frame]))
@@ -833,6 +996,12 @@ fn spawn_bus_generator(
                         source_address: None,
                         incomplete: None,
                         direction: Some("rx".to_string()),
+                        dlc_raw: None,
+                        is_rtr: false,
+                        is_brs: false,
+                        is_esi: false,
+                        original_frame_id: None,
+                        source_label: None,
                     }
                 }
                 "modbus" => {
@@ -852,6 +1021,12 @@ fn spawn_bus_generator(
                         source_address: None,
                         incomplete: None,
                         direction: Some("rx".to_string()),
+                        dlc_raw: None,
+                        is_rtr: false,
+                        is_brs: false,
+                        is_esi: false,
+                        original_frame_id: None,
+                        source_label: None,
                     }
                 }
                 _ => {
@@ -877,6 +1052,12 @@ fn spawn_bus_generator(
                         source_address: None,
                         incomplete: None,
                         direction: Some("rx".to_string()),
+                        dlc_raw: None,
+                        is_rtr: false,
+                        is_brs: false,
+                        is_esi: false,
+                        original_frame_id: None,
+                        source_label: None,
                     }
                 }
             };
@@ -1135,6 +1316,12 @@ async fn run_modbus_poll_task(
                     source_address: None,
                     incomplete: None,
                     direction: Some("rx".to_string()),
+                    dlc_raw: None,
+                    is_rtr: false,
+                    is_brs: false,
+                    is_esi: false,
+                    original_frame_id: None,
+                    source_label: None,
                 };
 
                 let _ = tx
@@ -1321,6 +1508,12 @@ async fn handle_modbus_server_connection(
                         source_address: None,
                         incomplete: None,
                         direction: Some("rx".to_string()),
+                        dlc_raw: None,
+                        is_rtr: false,
+                        is_brs: false,
+                        is_esi: false,
+                        original_frame_id: None,
+                        source_label: None,
                     };
 
                     let _ = tx
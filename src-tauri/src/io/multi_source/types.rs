@@ -3,11 +3,12 @@
 // Type definitions for multi-source reader sessions.
 
 use std::collections::HashMap;
-use std::sync::{Arc, Mutex};
+use std::sync::{Arc, Mutex, RwLock};
 
 use crate::io::gvret::BusMapping;
 use crate::io::modbus_tcp::PollGroup;
-use crate::io::types::TransmitSender;
+use crate::io::types::{DeviceInfoSender, TransmitSender};
+use crate::io::TimestampSource;
 
 /// Modbus interface role in a multi-source session
 #[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -72,6 +73,14 @@ pub struct SourceConfig {
     /// Modbus max consecutive register errors before stopping (0 = never stop)
     #[serde(default)]
     pub max_register_errors: Option<u32>,
+    /// Frame timestamp source for the session (overrides the profile setting if provided)
+    #[serde(default)]
+    pub timestamp_source: Option<TimestampSource>,
+    /// Frame ID relabelling (arrival ID -> captured ID), applied after bus mapping
+    /// and before buffering, so buffers, the emit filter and the UI see the new ID.
+    /// Relabelled frames keep their arrival ID in `original_frame_id`.
+    #[serde(default)]
+    pub id_remap: Option<HashMap<u32, u32>>,
 }
 
 /// Transmit routing info: maps output bus to source and device bus
@@ -85,7 +94,115 @@ pub(super) struct TransmitRoute {
     pub profile_kind: String,
     /// Device bus number to use when transmitting
     pub device_bus: u8,
+    /// Whether the source's channel carries CAN FD frames
+    pub fd_enabled: bool,
+}
+
+/// One step of a source's pre-capture wakeup sequence (profile connection `wakeup`).
+///
+/// CAN sources send `frame_id`/`data`; serial sources send `bytes`. `bus` is the
+/// device bus, before any session bus mapping.
+#[derive(Clone, Debug, Default, serde::Deserialize)]
+#[serde(default)]
+pub(super) struct WakeupStep {
+    pub frame_id: Option<u32>,
+    pub data: Vec<u8>,
+    pub bus: u8,
+    pub is_extended: bool,
+    pub is_fd: bool,
+    pub bytes: Option<Vec<u8>>,
+    /// Wait before sending this step
+    pub delay_ms: u64,
 }
 
 /// Shared transmit channels by source index
 pub(super) type TransmitChannels = Arc<Mutex<HashMap<usize, TransmitSender>>>;
+
+/// Shared device info query channels by source index
+pub(super) type DeviceInfoChannels = Arc<Mutex<HashMap<usize, DeviceInfoSender>>>;
+
+/// Live bus mappings of one source in a multi-source session.
+///
+/// The source reader keeps the mappings it was started with, so it only
+/// delivers buses that were enabled then. The merge task moves those frames
+/// onto the current mappings, which can change while the session runs.
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct SourceBusMappings {
+    /// Source index, as used by the merge task and transmit routing
+    pub source_idx: usize,
+    pub profile_id: String,
+    pub profile_kind: String,
+    /// Mappings in effect now
+    pub bus_mappings: Vec<BusMapping>,
+    /// Mappings the source reader was started with
+    #[serde(skip)]
+    pub(super) spawned: Vec<BusMapping>,
+    /// Whether the source's channel carries CAN FD frames. Set by the merge
+    /// task from the source's profile (`fd_enabled_from_profile`).
+    #[serde(skip)]
+    pub(super) fd_enabled: bool,
+}
+
+impl SourceBusMappings {
+    pub(super) fn new(source_idx: usize, config: &SourceConfig) -> Self {
+        Self {
+            source_idx,
+            profile_id: config.profile_id.clone(),
+            profile_kind: config.profile_kind.clone(),
+            bus_mappings: config.bus_mappings.clone(),
+            spawned: config.bus_mappings.clone(),
+            fd_enabled: true,
+        }
+    }
+
+    /// Whether the current mappings differ from the ones the reader started with
+    pub(super) fn is_remapped(&self) -> bool {
+        let key = |m: &BusMapping| (m.device_bus, m.enabled, m.output_bus);
+        !self.spawned.iter().map(key).eq(self.bus_mappings.iter().map(key))
+    }
+
+    /// Output bus for a frame the reader delivered on `bus`, or None when its
+    /// device bus is now disabled.
+    pub(super) fn remap_bus(&self, bus: u8) -> Option<u8> {
+        let Some(device_bus) = self
+            .spawned
+            .iter()
+            .find(|m| m.enabled && m.output_bus == bus)
+            .map(|m| m.device_bus)
+        else {
+            return Some(bus);
+        };
+        match self.bus_mappings.iter().find(|m| m.device_bus == device_bus) {
+            Some(m) if m.enabled => Some(m.output_bus),
+            Some(_) => None,
+            None => Some(device_bus),
+        }
+    }
+
+    /// Whether `bus_mappings` enables a device bus the running reader never opened
+    pub(super) fn needs_restart(&self, bus_mappings: &[BusMapping]) -> bool {
+        bus_mappings.iter().filter(|m| m.enabled).any(|m| {
+            !self
+                .spawned
+                .iter()
+                .any(|s| s.enabled && s.device_bus == m.device_bus)
+        })
+    }
+
+    /// Transmit route for an output bus, if this source currently owns it
+    pub(super) fn transmit_route(&self, output_bus: u8) -> Option<TransmitRoute> {
+        self.bus_mappings
+            .iter()
+            .find(|m| m.enabled && m.output_bus == output_bus)
+            .map(|m| TransmitRoute {
+                source_idx: self.source_idx,
+                profile_id: self.profile_id.clone(),
+                profile_kind: self.profile_kind.clone(),
+                device_bus: m.device_bus,
+                fd_enabled: self.fd_enabled,
+            })
+    }
+}
+
+/// Live bus mappings by source index, shared by the reader and the merge task
+pub(super) type SharedBusMappings = Arc<RwLock<HashMap<usize, SourceBusMappings>>>;
@@ -28,7 +28,8 @@ pub enum FramingEncoding {
     Delimiter {
         /// Delimiter byte sequence (e.g., [0x0D, 0x0A] for CRLF)
         delimiter: Vec<u8>,
-        /// Max frame length before forced split
+        /// Max frame length. A longer run without a delimiter is discarded
+        /// and the framer resyncs on the next delimiter.
         max_length: usize,
         /// Whether to include delimiter in output frames
         include_delimiter: bool,
@@ -63,6 +64,26 @@ pub struct SerialFrame {
     pub crc_valid: Option<bool>,
 }
 
+/// Length of the Modbus RTU CRC trailing a validated frame
+const MODBUS_CRC_LEN: usize = 2;
+
+/// The logical payload of a frame: its bytes without a trailing checksum that
+/// the framer validated. Frame ID and source address offsets refer to this, so
+/// negative offsets count back from the last data byte rather than the CRC.
+pub fn checksum_stripped(bytes: &[u8], crc_valid: Option<bool>) -> &[u8] {
+    match crc_valid {
+        Some(true) => &bytes[..bytes.len().saturating_sub(MODBUS_CRC_LEN)],
+        _ => bytes,
+    }
+}
+
+impl SerialFrame {
+    /// Frame bytes without a validated trailing checksum (see `checksum_stripped`)
+    pub fn payload(&self) -> &[u8] {
+        checksum_stripped(&self.bytes, self.crc_valid)
+    }
+}
+
 /// Configuration for extracting frame ID from frame bytes
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FrameIdConfig {
@@ -123,6 +144,14 @@ pub fn extract_frame_id(frame: &[u8], config: &FrameIdConfig) -> Option<u32> {
 trait FramerImpl {
     fn feed(&mut self, data: &[u8]) -> Vec<FrameResult>;
     fn flush(&mut self) -> Option<FrameResult>;
+    /// Bytes discarded since the last call because a frame outgrew its limit
+    fn take_discarded(&mut self) -> usize {
+        0
+    }
+    /// Whether the framer is skipping the rest of an oversized frame
+    fn is_resyncing(&self) -> bool {
+        false
+    }
 }
 
 /// Result from internal framers (before frame_index assignment)
@@ -141,6 +170,10 @@ struct DelimiterFramer {
     delimiter: Vec<u8>,
     max_length: usize,
     include_delimiter: bool,
+    /// Skipping the rest of an oversized frame until the next delimiter
+    resyncing: bool,
+    /// Bytes discarded since the last `take_discarded`
+    discarded: usize,
 }
 
 impl DelimiterFramer {
@@ -150,6 +183,8 @@ impl DelimiterFramer {
             delimiter,
             max_length,
             include_delimiter,
+            resyncing: false,
+            discarded: 0,
         }
     }
 }
@@ -166,6 +201,14 @@ impl FramerImpl for DelimiterFramer {
                 let start = self.buffer.len() - self.delimiter.len();
                 let tail = &self.buffer[start..];
 
+                if tail == self.delimiter.as_slice() && self.resyncing {
+                    // End of an oversized frame: drop its tail and start clean
+                    self.discarded += self.buffer.len();
+                    self.buffer.clear();
+                    self.resyncing = false;
+                    continue;
+                }
+
                 if tail == self.delimiter.as_slice() {
                     let frame: Vec<u8>;
                     if self.include_delimiter {
@@ -184,21 +227,35 @@ impl FramerImpl for DelimiterFramer {
                 }
             }
 
-            // Force split on max length
-            if self.buffer.len() >= self.max_length {
-                let frame: Vec<u8> = self.buffer.drain(..).collect();
-                frames.push(FrameResult {
-                    bytes: frame,
-                    incomplete: false,
-                    crc_valid: None,
-                });
+            // No delimiter within max_length bytes (noise or a wrong delimiter):
+            // discard rather than buffer without bound, keeping only what could
+            // still be the start of a delimiter
+            if self.buffer.len() >= self.max_length + self.delimiter.len() {
+                let keep = self.delimiter.len().saturating_sub(1);
+                let dropped = self.buffer.len() - keep;
+                self.buffer.drain(..dropped);
+                self.discarded += dropped;
+                self.resyncing = true;
             }
         }
 
         frames
     }
 
+    fn take_discarded(&mut self) -> usize {
+        std::mem::take(&mut self.discarded)
+    }
+
+    fn is_resyncing(&self) -> bool {
+        self.resyncing
+    }
+
     fn flush(&mut self) -> Option<FrameResult> {
+        if self.resyncing {
+            self.discarded += self.buffer.len();
+            self.buffer.clear();
+            self.resyncing = false;
+        }
         if !self.buffer.is_empty() {
             let frame: Vec<u8> = self.buffer.drain(..).collect();
             Some(FrameResult {
@@ -526,6 +583,19 @@ impl SerialFramer {
             .collect()
     }
 
+    /// Number of bytes discarded since the last call because a frame exceeded
+    /// its maximum length without a boundary. Check after `feed` to report
+    /// overflows (the framer has already resynced).
+    pub fn take_discarded(&mut self) -> usize {
+        self.framer.take_discarded()
+    }
+
+    /// Whether the framer is discarding an oversized frame up to the next
+    /// boundary. An overflow that starts while this is false is a new one.
+    pub fn is_resyncing(&self) -> bool {
+        self.framer.is_resyncing()
+    }
+
     /// Flush any remaining buffered data as a frame.
     /// Call when stream ends.
     /// Returns a frame marked as incomplete since no delimiter was found.
@@ -672,16 +742,49 @@ mod tests {
             include_delimiter: false,
         });
 
-        let data = b"12345678"; // 8 bytes, no delimiter
-        let frames = framer.feed(data);
-
+        // A frame of exactly max_length is kept
+        let frames = framer.feed(b"12345\n");
         assert_eq!(frames.len(), 1);
         assert_eq!(frames[0].bytes, b"12345".to_vec());
+        assert_eq!(framer.take_discarded(), 0);
 
-        // Remaining 3 bytes in buffer
-        let flushed = framer.flush();
-        assert!(flushed.is_some());
-        assert_eq!(flushed.unwrap().bytes, b"678".to_vec());
+        // 8 bytes without a delimiter overflow; the tail up to the next
+        // delimiter belongs to the same bad frame and is skipped too
+        let frames = framer.feed(b"12345678\nabc\n");
+        assert_eq!(frames.len(), 1);
+        assert_eq!(frames[0].bytes, b"abc".to_vec());
+        assert_eq!(framer.take_discarded(), 9);
+        assert!(!framer.is_resyncing());
+        assert!(framer.flush().is_none());
+    }
+
+    #[test]
+    fn test_delimiter_overflow_stays_bounded() {
+        let mut framer = SerialFramer::new(FramingEncoding::Delimiter {
+            delimiter: vec![0x0D, 0x0A],
+            max_length: 64,
+            include_delimiter: false,
+        });
+
+        // 1 MB of noise with no delimiter
+        let noise = vec![0x55u8; 4096];
+        let mut discarded = 0;
+        for _ in 0..256 {
+            assert!(framer.feed(&noise).is_empty());
+            discarded += framer.take_discarded();
+        }
+        assert!(discarded >= 256 * 4096 - 65);
+        assert!(framer.is_resyncing());
+
+        // Buffer never held more than max_length + delimiter bytes
+        let flushed_or_dropped = framer.flush().map(|f| f.bytes.len()).unwrap_or(0) + framer.take_discarded();
+        assert!(flushed_or_dropped <= 64 + 2);
+        assert_eq!(discarded + flushed_or_dropped, 256 * 4096);
+
+        // Resynced: the next delimited frame comes through
+        let frames = framer.feed(b"\r\nOK\r\n");
+        assert_eq!(frames.len(), 1);
+        assert_eq!(frames[0].bytes, b"OK".to_vec());
     }
 
     #[test]
@@ -733,6 +836,19 @@ mod tests {
         assert_eq!(extract_frame_id(&frame, &config), Some(0x05));
     }
 
+    #[test]
+    fn test_frame_split_across_reads_emitted_intact() {
+        let mut framer = SerialFramer::new(FramingEncoding::Slip);
+
+        // The partial frame is held by the framer between reads, not dropped
+        assert!(framer.feed(&[SLIP_END, 0x01, 0x02]).is_empty());
+        let frames = framer.feed(&[0x03, SLIP_END]);
+
+        assert_eq!(frames.len(), 1);
+        assert_eq!(frames[0].bytes, vec![0x01, 0x02, 0x03]);
+        assert!(!frames[0].incomplete);
+    }
+
     #[test]
     fn test_flush_marks_incomplete() {
         let mut framer = SerialFramer::new(FramingEncoding::Slip);
@@ -749,4 +865,27 @@ mod tests {
         assert!(frame.incomplete);
         assert_eq!(frame.bytes, vec![0x01, 0x02, 0x03]);
     }
+
+    #[test]
+    fn test_frame_id_offsets_skip_validated_crc() {
+        // Frame ID in the last data byte, right before the CRC
+        let frame_bytes = append_modbus_crc(&[0x01, 0x03, 0x02, 0x12, 0x34]);
+        let mut framer = SerialFramer::new(FramingEncoding::ModbusRtu {
+            device_address: None,
+            validate_crc: true,
+        });
+        let frames = framer.feed(&frame_bytes);
+        assert_eq!(frames.len(), 1);
+        assert_eq!(frames[0].crc_valid, Some(true));
+        assert_eq!(frames[0].bytes, frame_bytes);
+        assert_eq!(frames[0].payload(), &frame_bytes[..5]);
+
+        let last_byte = FrameIdConfig { start_byte: -1, num_bytes: 1, big_endian: false };
+        assert_eq!(extract_frame_id(frames[0].payload(), &last_byte), Some(0x34));
+        let last_word = FrameIdConfig { start_byte: -2, num_bytes: 2, big_endian: true };
+        assert_eq!(extract_frame_id(frames[0].payload(), &last_word), Some(0x1234));
+
+        // Without validation the whole frame is the payload
+        assert_eq!(checksum_stripped(&frame_bytes, None), &frame_bytes[..]);
+    }
 }
@@ -13,10 +13,10 @@ pub mod reader; // pub for Tauri command access (list_serial_ports)
 pub(crate) mod utils;
 
 // Re-export framer types used by other modules
-pub use framer::{extract_frame_id, FrameIdConfig, FramingEncoding, SerialFramer};
+pub use framer::{checksum_stripped, extract_frame_id, FrameIdConfig, FramingEncoding, SerialFramer};
 
 // Re-export reader types used by other modules
 pub use reader::{run_source, Parity};
 
 // Re-export profile parsing for multi-source
-pub use utils::parse_profile_for_source;
+pub use utils::{open_error, parse_profile_for_source, SerialLineControl};
@@ -11,17 +11,24 @@ use std::sync::{
     atomic::{AtomicBool, Ordering},
     Arc, Mutex,
 };
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tokio::sync::mpsc;
 
 use crate::io::gvret::{apply_bus_mapping, BusMapping};
 use crate::io::types::{ByteEntry, SourceMessage, TransmitRequest};
-use crate::io::{now_us, FrameMessage};
+use crate::io::{now_us, FrameMessage, ParseErrorEntry};
 
 // Re-export Parity for external use
 pub use super::utils::Parity;
+use super::utils::{open_error, EchoFilter, LineTerminator, SerialLineControl};
 use super::framer::{extract_frame_id, FrameIdConfig, FramingEncoding, SerialFramer};
 
+/// Read timeout for the source's read loop. An idle port blocks in the driver
+/// for this long per read rather than spinning, and a read returns as soon as
+/// bytes arrive, so byte timestamps don't depend on it. It also bounds how long
+/// a queued transmit waits behind a read.
+const READ_TIMEOUT: Duration = Duration::from_millis(50);
+
 // ============================================================================
 // Types
 // ============================================================================
@@ -51,11 +58,14 @@ pub async fn run_source(
     data_bits: u8,
     stop_bits: u8,
     parity: Parity,
+    line_control: SerialLineControl,
     framing_encoding: FramingEncoding,
     frame_id_config: Option<FrameIdConfig>,
     source_address_config: Option<FrameIdConfig>,
     min_frame_length: usize,
     emit_raw_bytes: bool,
+    append_terminator: LineTerminator,
+    expect_echo: bool,
     bus_mappings: Vec<BusMapping>,
     stop_flag: Arc<AtomicBool>,
     tx: mpsc::Sender<SourceMessage>,
@@ -66,19 +76,18 @@ pub async fn run_source(
     let sp_parity = super::utils::to_serialport_parity(&parity);
 
     // Open serial port
-    let serial_port = match serialport::new(&port_path, baud_rate)
+    let builder = serialport::new(&port_path, baud_rate)
         .data_bits(sp_data_bits)
         .stop_bits(sp_stop_bits)
         .parity(sp_parity)
-        .timeout(Duration::from_millis(50))
-        .open()
-    {
+        .timeout(READ_TIMEOUT);
+    let serial_port = match line_control.open(builder) {
         Ok(p) => p,
         Err(e) => {
             let _ = tx
                 .send(SourceMessage::Error(
                     source_idx,
-                    format!("Failed to open {}: {}", port_path, e),
+                    open_error(&format!("serial({})", port_path), &port_path, &e).to_string(),
                 ))
                 .await;
             return;
@@ -102,8 +111,8 @@ pub async fn run_source(
         .unwrap_or(0);
 
     tlog!(
-        "[serial] Source {} connected to {} (baud: {}, framing: {:?}, emit_raw: {}, bus: {})",
-        source_idx, port_path, baud_rate, framing_encoding, emit_raw_bytes, output_bus
+        "[serial] Source {} connected to {} (baud: {}, framing: {:?}, emit_raw: {}, bus: {}, terminator: {:?}, echo: {})",
+        source_idx, port_path, baud_rate, framing_encoding, emit_raw_bytes, output_bus, append_terminator, expect_echo
     );
 
     // Emit device-connected event
@@ -121,14 +130,19 @@ pub async fn run_source(
 
     let blocking_handle = tokio::task::spawn_blocking(move || {
         let mut framer = SerialFramer::new(framing_encoding);
+        // Bytes discarded by the framer since the last overflow report
+        let mut overflow_discarded: u64 = 0;
         let mut buf = [0u8; 256];
+        let mut echo_filter = EchoFilter::default();
 
         while !stop_flag_clone.load(Ordering::SeqCst) {
             // Check for transmit requests (non-blocking)
             while let Ok(req) = transmit_rx.try_recv() {
+                let mut data = req.data;
+                data.extend_from_slice(append_terminator.bytes());
                 let result = match serial_port_clone.lock() {
                     Ok(mut port) => port
-                        .write_all(&req.data)
+                        .write_all(&data)
                         .and_then(|_| port.flush())
                         .map_err(|e| format!("Write error: {}", e)),
                     Err(e) => {
@@ -136,6 +150,9 @@ pub async fn run_source(
                         Err(format!("Port mutex poisoned: {}", e))
                     }
                 };
+                if expect_echo && result.is_ok() {
+                    echo_filter.expect(&data, Instant::now());
+                }
                 let _ = req.result_tx.send(result);
             }
 
@@ -155,7 +172,11 @@ pub async fn run_source(
             match read_result {
                 Ok(n) if n > 0 => {
                     let base_ts = now_us();
-                    let read_bytes = &buf[..n];
+                    let echo_len = echo_filter.strip(&buf[..n], Instant::now());
+                    let read_bytes = &buf[echo_len..n];
+                    if read_bytes.is_empty() {
+                        continue;
+                    }
 
                     // Emit raw bytes if requested
                     if emit_raw_bytes {
@@ -175,23 +196,41 @@ pub async fn run_source(
                         let mut pending_frames: Vec<FrameMessage> = Vec::new();
 
                         // Feed bytes to framer and process resulting frames
+                        let was_resyncing = framer.is_resyncing();
                         let frames = framer.feed(read_bytes);
+                        let discarded = framer.take_discarded();
+                        overflow_discarded += discarded as u64;
+                        // Report once as an overflow starts, not on every read while
+                        // the framer resyncs; bytes dropped meanwhile are carried
+                        // into the next report
+                        if discarded > 0 && !was_resyncing {
+                            let _ = tx_clone.blocking_send(SourceMessage::FramerOverflow(
+                                source_idx,
+                                std::mem::take(&mut overflow_discarded),
+                            ));
+                            let entry = ParseErrorEntry::new(
+                                "framer_overflow",
+                                format!("Framer discarded {} bytes", discarded),
+                                read_bytes,
+                            );
+                            let _ = tx_clone.blocking_send(SourceMessage::ParseFailures(source_idx, vec![entry]));
+                        }
                         for frame in frames {
                             // Skip frames that are too short
                             if frame.bytes.len() < min_frame_length {
                                 continue;
                             }
 
-                            // Extract frame ID
+                            // Extract frame ID (offsets refer to the payload without a validated CRC)
                             let frame_id = frame_id_config
                                 .as_ref()
-                                .and_then(|cfg| extract_frame_id(&frame.bytes, cfg))
+                                .and_then(|cfg| extract_frame_id(frame.payload(), cfg))
                                 .unwrap_or(0);
 
                             // Extract source address
                             let source_address = source_address_config
                                 .as_ref()
-                                .and_then(|cfg| extract_frame_id(&frame.bytes, cfg))
+                                .and_then(|cfg| extract_frame_id(frame.payload(), cfg))
                                 .map(|v| v as u16);
 
                             let mut msg = FrameMessage {
@@ -206,6 +245,12 @@ pub async fn run_source(
                                 source_address,
                                 incomplete: None,
                                 direction: None,
+                                dlc_raw: None,
+                                is_rtr: false,
+                                is_brs: false,
+                                is_esi: false,
+                                original_frame_id: None,
+                                source_label: None,
                             };
 
                             // Apply bus mapping
@@ -248,12 +293,12 @@ pub async fn run_source(
                 if frame.bytes.len() >= min_frame_length {
                     let frame_id = frame_id_config
                         .as_ref()
-                        .and_then(|cfg| extract_frame_id(&frame.bytes, cfg))
+                        .and_then(|cfg| extract_frame_id(frame.payload(), cfg))
                         .unwrap_or(0);
 
                     let source_address = source_address_config
                         .as_ref()
-                        .and_then(|cfg| extract_frame_id(&frame.bytes, cfg))
+                        .and_then(|cfg| extract_frame_id(frame.payload(), cfg))
                         .map(|v| v as u16);
 
                     let mut msg = FrameMessage {
@@ -268,6 +313,12 @@ pub async fn run_source(
                         source_address,
                         incomplete: None,
                         direction: None,
+                        dlc_raw: None,
+                        is_rtr: false,
+                        is_brs: false,
+                        is_esi: false,
+                        original_frame_id: None,
+                        source_label: None,
                     };
 
                     if apply_bus_mapping(&mut msg, &bus_mappings) {
@@ -4,9 +4,12 @@
 // Provides common types and conversion functions for the serialport crate.
 
 use serde::{Deserialize, Serialize};
-use serialport::{DataBits, Parity as SpParity, StopBits};
+use serialport::{DataBits, Parity as SpParity, SerialPort, SerialPortBuilder, StopBits};
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, Instant};
 
 use super::framer::{FrameIdConfig, FramingEncoding};
+use crate::io::error::IoError;
 use crate::settings::IOProfile;
 
 // ============================================================================
@@ -28,6 +31,218 @@ impl Default for Parity {
     }
 }
 
+/// Line ending appended to every serial transmit
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LineTerminator {
+    #[default]
+    None,
+    Cr,
+    Lf,
+    CrLf,
+}
+
+impl LineTerminator {
+    pub fn bytes(self) -> &'static [u8] {
+        match self {
+            LineTerminator::None => b"",
+            LineTerminator::Cr => b"\r",
+            LineTerminator::Lf => b"\n",
+            LineTerminator::CrLf => b"\r\n",
+        }
+    }
+}
+
+/// Port ownership and modem control lines applied when a serial port is opened.
+///
+/// Some adapters (e.g. ESP32 boards) reset or leave their bootloader on a
+/// DTR/RTS sequence, and some setups need the port shared with another tool.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SerialLineControl {
+    /// Lock the port against other openers (Unix only; Windows ports are always exclusive)
+    pub exclusive: bool,
+    /// DTR level after opening (None = leave as the driver set it)
+    pub dtr: Option<bool>,
+    /// RTS level after opening (None = leave as the driver set it)
+    pub rts: Option<bool>,
+    /// Assert DTR for this long, then release it, before applying `dtr`
+    pub dtr_pulse_ms: Option<u64>,
+}
+
+impl Default for SerialLineControl {
+    fn default() -> Self {
+        Self {
+            exclusive: true,
+            dtr: None,
+            rts: None,
+            dtr_pulse_ms: None,
+        }
+    }
+}
+
+impl SerialLineControl {
+    /// Read `exclusive`, `set_dtr`, `set_rts` and `dtr_pulse_ms` from profile connection settings.
+    /// Line levels may be booleans or "high"/"low"; anything else leaves the line alone.
+    pub fn from_connection(connection: &HashMap<String, serde_json::Value>) -> Self {
+        let line_level = |key: &str| {
+            let value = connection.get(key)?;
+            value.as_bool().or_else(|| match value.as_str()? {
+                "high" => Some(true),
+                "low" => Some(false),
+                _ => None,
+            })
+        };
+        Self {
+            exclusive: connection
+                .get("exclusive")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(true),
+            dtr: line_level("set_dtr"),
+            rts: line_level("set_rts"),
+            dtr_pulse_ms: connection
+                .get("dtr_pulse_ms")
+                .and_then(|v| v.as_u64().or_else(|| v.as_str().and_then(|s| s.parse().ok())))
+                .filter(|&ms| ms > 0),
+        }
+    }
+
+    /// Open the port described by `builder` and apply the line settings.
+    pub fn open(&self, builder: SerialPortBuilder) -> serialport::Result<Box<dyn SerialPort>> {
+        #[cfg(unix)]
+        let mut port: Box<dyn SerialPort> = {
+            let mut native = builder.open_native()?;
+            native.set_exclusive(self.exclusive)?;
+            Box::new(native)
+        };
+        #[cfg(not(unix))]
+        let mut port = {
+            if !self.exclusive {
+                tlog!("[serial] Non-exclusive open is not supported on this platform");
+            }
+            builder.open()?
+        };
+
+        if let Some(ms) = self.dtr_pulse_ms {
+            port.write_data_terminal_ready(true)?;
+            std::thread::sleep(Duration::from_millis(ms));
+            port.write_data_terminal_ready(false)?;
+        }
+        if let Some(dtr) = self.dtr {
+            port.write_data_terminal_ready(dtr)?;
+        }
+        if let Some(rts) = self.rts {
+            port.write_request_to_send(rts)?;
+        }
+        Ok(port)
+    }
+}
+
+/// Common reasons a serial port fails to open, told apart for the user
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OpenFailure {
+    /// Another application holds the port
+    InUse,
+    /// The user may not open the device node
+    PermissionDenied,
+    /// No such port
+    NotFound,
+    Other,
+}
+
+impl OpenFailure {
+    /// Classify a `serialport` open error. A busy port has no error kind of its
+    /// own: Unix reports EBUSY (kind `Unknown`, "Device or resource busy") and
+    /// Windows reports "Access is denied", since ports there are exclusive.
+    pub fn classify(err: &serialport::Error) -> Self {
+        use serialport::ErrorKind;
+        use std::io::ErrorKind as IoKind;
+
+        let description = err.description.to_lowercase();
+        if description.contains("busy")
+            || description.contains("access is denied")
+            || matches!(err.kind, ErrorKind::Io(IoKind::AddrInUse))
+        {
+            return Self::InUse;
+        }
+        match err.kind {
+            ErrorKind::Io(IoKind::PermissionDenied) => Self::PermissionDenied,
+            ErrorKind::NoDevice | ErrorKind::Io(IoKind::NotFound) => Self::NotFound,
+            _ => Self::Other,
+        }
+    }
+}
+
+/// Connection error for a serial port that failed to open, worded for the
+/// common first-time setup failures.
+pub fn open_error(device: &str, port: &str, err: &serialport::Error) -> IoError {
+    let details = match OpenFailure::classify(err) {
+        OpenFailure::InUse => format!(
+            "{} is in use by another application; close it there and try again",
+            port
+        ),
+        OpenFailure::PermissionDenied => format!(
+            "permission denied opening {} (on Linux, add your user to the dialout group and log in again)",
+            port
+        ),
+        OpenFailure::NotFound => format!(
+            "{} not found; check the device is connected and the port name is correct",
+            port
+        ),
+        OpenFailure::Other => err.to_string(),
+    };
+    IoError::connection(device, details)
+}
+
+/// How long to wait for a device to echo transmitted bytes before giving up
+const ECHO_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// Strips a device's echo of transmitted bytes from the receive stream.
+///
+/// Terminal-style devices send back every byte they receive. After `expect()`
+/// the filter drops received bytes while they match what was sent; the first
+/// mismatch or the timeout ends the expectation so real data is never lost.
+#[derive(Debug, Default)]
+pub struct EchoFilter {
+    expected: VecDeque<u8>,
+    deadline: Option<Instant>,
+}
+
+impl EchoFilter {
+    /// Record bytes that were just written and should come back as echo
+    pub fn expect(&mut self, sent: &[u8], now: Instant) {
+        self.expected.extend(sent);
+        self.deadline = Some(now + ECHO_TIMEOUT);
+    }
+
+    /// Number of leading bytes of `data` that are echo and should be dropped
+    pub fn strip(&mut self, data: &[u8], now: Instant) -> usize {
+        if self.deadline.is_none_or(|d| now > d) {
+            self.expected.clear();
+            self.deadline = None;
+            return 0;
+        }
+
+        let mut skipped = 0;
+        for &byte in data {
+            match self.expected.front() {
+                Some(&expected) if expected == byte => {
+                    self.expected.pop_front();
+                    skipped += 1;
+                }
+                Some(_) => {
+                    self.expected.clear();
+                    break;
+                }
+                None => break,
+            }
+        }
+        if self.expected.is_empty() {
+            self.deadline = None;
+        }
+        skipped
+    }
+}
+
 // ============================================================================
 // Conversion Functions
 // ============================================================================
@@ -86,6 +301,9 @@ pub struct SerialSourceConfig {
     pub source_address_config: Option<FrameIdConfig>,
     pub min_frame_length: usize,
     pub emit_raw_bytes: bool,
+    pub append_terminator: LineTerminator,
+    pub expect_echo: bool,
+    pub line_control: SerialLineControl,
 }
 
 /// Parse an IOProfile into a SerialSourceConfig, applying session-level overrides.
@@ -254,6 +472,18 @@ pub fn parse_profile_for_source(
         _ => emit_raw_bytes_override.unwrap_or(false),
     };
 
+    // Transmit line ending and echo handling (AT-command style devices)
+    let append_terminator = profile
+        .connection
+        .get("append_terminator")
+        .and_then(|v| serde_json::from_value(v.clone()).ok())
+        .unwrap_or_default();
+    let expect_echo = profile
+        .connection
+        .get("expect_echo")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false);
+
     Some(SerialSourceConfig {
         port,
         baud_rate,
@@ -265,6 +495,9 @@ pub fn parse_profile_for_source(
         source_address_config,
         min_frame_length,
         emit_raw_bytes,
+        append_terminator,
+        expect_echo,
+        line_control: SerialLineControl::from_connection(&profile.connection),
     })
 }
 
@@ -303,4 +536,80 @@ mod tests {
         assert!(matches!(to_serialport_stop_bits(2), StopBits::Two));
         assert!(matches!(to_serialport_stop_bits(0), StopBits::One)); // default
     }
+
+    #[test]
+    fn test_line_control_from_connection() {
+        let mut connection = HashMap::new();
+        assert_eq!(SerialLineControl::from_connection(&connection), SerialLineControl::default());
+
+        connection.insert("exclusive".to_string(), serde_json::json!(false));
+        connection.insert("set_dtr".to_string(), serde_json::json!(true));
+        connection.insert("set_rts".to_string(), serde_json::json!("low"));
+        connection.insert("dtr_pulse_ms".to_string(), serde_json::json!("100"));
+        assert_eq!(
+            SerialLineControl::from_connection(&connection),
+            SerialLineControl {
+                exclusive: false,
+                dtr: Some(true),
+                rts: Some(false),
+                dtr_pulse_ms: Some(100),
+            }
+        );
+    }
+
+    #[test]
+    fn test_open_errors_are_classified() {
+        use serialport::{Error, ErrorKind};
+        use std::io::ErrorKind as IoKind;
+
+        // Unix EBUSY, Windows sharing violation, EACCES and ENOENT as serialport reports them
+        let busy = Error::new(ErrorKind::Unknown, "Device or resource busy");
+        let windows_busy = Error::new(ErrorKind::NoDevice, "Access is denied.");
+        let denied = Error::new(ErrorKind::Io(IoKind::PermissionDenied), "Permission denied");
+        let missing = Error::new(ErrorKind::Io(IoKind::NotFound), "No such file or directory");
+        assert_eq!(OpenFailure::classify(&busy), OpenFailure::InUse);
+        assert_eq!(OpenFailure::classify(&windows_busy), OpenFailure::InUse);
+        assert_eq!(OpenFailure::classify(&denied), OpenFailure::PermissionDenied);
+        assert_eq!(OpenFailure::classify(&missing), OpenFailure::NotFound);
+        assert_eq!(
+            OpenFailure::classify(&Error::new(ErrorKind::InvalidInput, "Invalid baud rate")),
+            OpenFailure::Other
+        );
+
+        let message = open_error("slcan(/dev/ttyACM0)", "/dev/ttyACM0", &denied).to_string();
+        assert!(message.starts_with("[slcan(/dev/ttyACM0)] connection failed: permission denied"));
+        assert!(message.contains("dialout"));
+    }
+
+    #[test]
+    fn test_line_terminator_bytes() {
+        assert_eq!(LineTerminator::None.bytes(), b"");
+        assert_eq!(LineTerminator::CrLf.bytes(), b"\r\n");
+        let parsed: LineTerminator = serde_json::from_value(serde_json::json!("cr_lf")).unwrap();
+        assert_eq!(parsed, LineTerminator::CrLf);
+    }
+
+    #[test]
+    fn test_echo_filter_strips_echo_across_reads() {
+        let now = Instant::now();
+        let mut filter = EchoFilter::default();
+        filter.expect(b"AT\r", now);
+
+        assert_eq!(filter.strip(b"A", now), 1);
+        assert_eq!(filter.strip(b"T\rOK\r", now), 2);
+        // Echo consumed; later data passes through
+        assert_eq!(filter.strip(b"AT", now), 0);
+    }
+
+    #[test]
+    fn test_echo_filter_mismatch_and_timeout() {
+        let now = Instant::now();
+        let mut filter = EchoFilter::default();
+        filter.expect(b"AT", now);
+        assert_eq!(filter.strip(b"OK", now), 0);
+        assert_eq!(filter.strip(b"AT", now), 0);
+
+        filter.expect(b"AT", now);
+        assert_eq!(filter.strip(b"AT", now + ECHO_TIMEOUT * 2), 0);
+    }
 }
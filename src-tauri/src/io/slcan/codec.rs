@@ -68,7 +68,6 @@ impl FrameCodec for SlcanCodec {
                 ))
             }
         };
-        let _ = is_brs; // BRS is encoded in the prefix; no separate field in FrameMessage
 
         let id_len = if is_extended { 8 } else { 3 };
         let min_len = 1 + id_len + 1; // prefix + ID + DLC
@@ -90,25 +89,18 @@ impl FrameCodec for SlcanCodec {
         let frame_id = u32::from_str_radix(id_str, 16)
             .map_err(|_| IoError::protocol("slcan", format!("invalid hex ID: {}", id_str)))?;
 
-        // Parse DLC (single hex digit: 0-8 for classic, 0-F for FD)
+        // Parse DLC (single hex digit: 0-F). A classic DLC of 9-F still means
+        // 8 data bytes; the code itself is kept in dlc_raw.
         let dlc_char = bytes[1 + id_len] as char;
         let dlc_code = dlc_char.to_digit(16).ok_or_else(|| {
             IoError::protocol("slcan", format!("invalid DLC character: '{}'", dlc_char))
         })? as u8;
 
-        let max_dlc = if is_fd { 15 } else { 8 };
-        if dlc_code > max_dlc {
-            return Err(IoError::protocol(
-                "slcan",
-                format!("invalid DLC: {} (max {})", dlc_code, max_dlc),
-            ));
-        }
-
         // For FD frames, map DLC code to actual byte count via DLC_LEN table
         let data_len = if is_fd {
             DLC_LEN[dlc_code as usize]
         } else {
-            dlc_code as usize
+            (dlc_code as usize).min(8)
         };
 
         // Parse data bytes (pairs of hex characters)
@@ -150,6 +142,12 @@ impl FrameCodec for SlcanCodec {
             source_address: None,
             incomplete: None,
             direction: None,
+            dlc_raw: Some(dlc_code),
+            is_rtr,
+            is_brs,
+            is_esi: false,
+            original_frame_id: None,
+            source_label: None,
         })
     }
 
@@ -179,12 +177,15 @@ impl FrameCodec for SlcanCodec {
             } else {
                 cmd.push(if frame.is_extended { 'D' } else { 'd' });
             }
+        } else if frame.is_rtr {
+            cmd.push(if frame.is_extended { 'R' } else { 'r' });
         } else {
             cmd.push(if frame.is_extended { 'T' } else { 't' });
         }
 
+        // The format follows is_extended, not the ID's magnitude
         if frame.is_extended {
-            cmd.push_str(&format!("{:08X}", frame.frame_id));
+            cmd.push_str(&format!("{:08X}", frame.frame_id & 0x1FFF_FFFF));
         } else {
             cmd.push_str(&format!("{:03X}", frame.frame_id & 0x7FF));
         }
@@ -193,13 +194,15 @@ impl FrameCodec for SlcanCodec {
         let dlc_code = if frame.is_fd {
             len_to_fd_dlc(frame.data.len())
         } else {
-            frame.data.len().min(8) as u8
+            frame.classic_dlc()
         };
         cmd.push_str(&format!("{:X}", dlc_code));
 
-        // Data bytes
-        for byte in &frame.data {
-            cmd.push_str(&format!("{:02X}", byte));
+        // Data bytes (a remote frame carries none)
+        if !frame.is_rtr {
+            for byte in &frame.data {
+                cmd.push_str(&format!("{:02X}", byte));
+            }
         }
 
         cmd.push('\r');
@@ -235,6 +238,14 @@ mod tests {
         assert!(frame.is_extended);
     }
 
+    #[test]
+    fn test_slcan_decode_classic_dlc_above_8() {
+        let frame = SlcanCodec::decode("t123F0102030405060708").unwrap();
+        assert_eq!(frame.dlc, 8);
+        assert_eq!(frame.bytes, vec![1, 2, 3, 4, 5, 6, 7, 8]);
+        assert_eq!(frame.dlc_raw, Some(0x0F));
+    }
+
     #[test]
     fn test_slcan_decode_zero_dlc() {
         let frame = SlcanCodec::decode("t1230").unwrap();
@@ -267,6 +278,7 @@ mod tests {
             is_fd: false,
             is_brs: false,
             is_rtr: false,
+            rtr_dlc: None,
         };
 
         let encoded = SlcanCodec::encode(&frame).unwrap();
@@ -283,12 +295,37 @@ mod tests {
             is_fd: false,
             is_brs: false,
             is_rtr: false,
+            rtr_dlc: None,
         };
 
         let encoded = SlcanCodec::encode(&frame).unwrap();
         assert_eq!(encoded, b"T123456782AABB\r");
     }
 
+    #[test]
+    fn test_slcan_encode_small_id_both_formats() {
+        let mut frame = CanTransmitFrame {
+            frame_id: 0x123,
+            data: vec![0x01],
+            bus: 0,
+            is_extended: true,
+            is_fd: false,
+            is_brs: false,
+            is_rtr: false,
+            rtr_dlc: None,
+        };
+        assert_eq!(SlcanCodec::encode(&frame).unwrap(), b"T00000123101\r");
+
+        frame.is_extended = false;
+        assert_eq!(SlcanCodec::encode(&frame).unwrap(), b"t123101\r");
+
+        // Bits above the format's width are masked off
+        frame.frame_id = 0x2000_0123;
+        assert_eq!(SlcanCodec::encode(&frame).unwrap(), b"t123101\r");
+        frame.is_extended = true;
+        assert_eq!(SlcanCodec::encode(&frame).unwrap(), b"T00000123101\r");
+    }
+
     #[test]
     fn test_slcan_roundtrip() {
         let original = CanTransmitFrame {
@@ -299,6 +336,7 @@ mod tests {
             is_fd: false,
             is_brs: false,
             is_rtr: false,
+            rtr_dlc: None,
         };
 
         let encoded = SlcanCodec::encode(&original).unwrap();
@@ -386,6 +424,7 @@ mod tests {
             is_fd: true,
             is_brs: false,
             is_rtr: false,
+            rtr_dlc: None,
         };
 
         let encoded = SlcanCodec::encode(&frame).unwrap();
@@ -402,6 +441,7 @@ mod tests {
             is_fd: true,
             is_brs: true,
             is_rtr: false,
+            rtr_dlc: None,
         };
 
         let encoded = SlcanCodec::encode(&frame).unwrap();
@@ -423,6 +463,7 @@ mod tests {
             is_fd: true,
             is_brs: false,
             is_rtr: false,
+            rtr_dlc: None,
         };
 
         let encoded = SlcanCodec::encode(&original).unwrap();
@@ -9,6 +9,9 @@
 //   Standard: t<ID:3hex><DLC:1hex><DATA:2hex*DLC>\r
 //   Extended: T<ID:8hex><DLC:1hex><DATA:2hex*DLC>\r
 //   RTR:      r<ID:3hex><DLC:1hex>\r / R<ID:8hex><DLC:1hex>\r
+//
+// Other lines (V/v/N/F replies, z/Z transmit acks) are routed to the response
+// handler, so status polls and version queries can be sent mid-stream.
 
 use serde::{Deserialize, Serialize};
 use std::io::{Read, Write};
@@ -21,15 +24,29 @@ use std::time::Duration;
 use tokio::sync::mpsc;
 
 use crate::io::error::IoError;
-use crate::io::gvret::{apply_bus_mapping, BusMapping};
+use crate::io::gvret::{apply_bus_mapping, apply_bus_mappings_to_errors, BusMapping};
 use crate::io::serial::utils as serial_utils;
-use crate::io::types::{SourceMessage, TransmitRequest};
-use crate::io::{now_us, CanTransmitFrame, FrameMessage};
+use crate::io::types::{DeviceInfo, DeviceInfoRequest, SourceMessage, TransmitRequest};
+use crate::io::{normalize_frame_id, now_us, CanErrorPayload, CanTransmitFrame, FrameMessage, ParseErrorEntry, ParseErrors};
 
 // ============================================================================
 // Constants
 // ============================================================================
 
+/// Minimum interval between parse-error reports from the read loop
+const PARSE_ERROR_REPORT_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Serial read size for the source read loop. A busy bus fills this with many
+/// lines, which then go to the merge task as a single frame batch.
+const SLCAN_READ_BUF_SIZE: usize = 4096;
+
+/// Commands sent for a mid-stream device info query, one reply expected each
+const DEVICE_INFO_QUERY: &[u8] = b"V\rv\rN\r";
+const DEVICE_INFO_REPLIES: usize = 3;
+
+/// How long the read loop collects replies to a device info query
+const DEVICE_INFO_REPLY_WINDOW: Duration = Duration::from_millis(300);
+
 /// slcan bitrate commands (S0-S8)
 const SLCAN_BITRATES: [(u32, &str); 9] = [
     (10_000, "S0"),     // 10 Kbit/s
@@ -109,6 +126,16 @@ fn default_data_bitrate() -> u32 { 2_000_000 }
 // Utility Functions
 // ============================================================================
 
+/// Nominal bitrates selectable with the `S` command
+pub fn supported_bitrates() -> Vec<u32> {
+    SLCAN_BITRATES.iter().map(|(rate, _)| *rate).collect()
+}
+
+/// CAN FD data phase bitrates selectable with the `Y` command
+pub fn supported_data_bitrates() -> Vec<u32> {
+    SLCAN_DATA_BITRATES.iter().map(|(rate, _)| *rate).collect()
+}
+
 /// Find the slcan bitrate command for a given bitrate
 pub fn find_bitrate_command(bitrate: u32) -> Result<&'static str, IoError> {
     SLCAN_BITRATES
@@ -144,8 +171,15 @@ pub fn find_data_bitrate_command(bitrate: u32) -> Result<&'static str, IoError>
 /// CAN FD DLC-to-payload-length mapping (ISO 11898-2:2015).
 const DLC_LEN: [usize; 16] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 12, 16, 20, 24, 32, 48, 64];
 
+/// Longest valid slcan line: an extended FD frame (B + 8 ID digits + DLC +
+/// 64 data bytes) with the optional 4-digit timestamp.
+const MAX_SLCAN_LINE_LEN: usize = 1 + 8 + 1 + 2 * DLC_LEN[15] + 4;
+
 /// Parse a single slcan frame line (classic CAN or CAN FD).
 ///
+/// Returns `None` for lines that are not frames or are malformed; use
+/// [`parse_slcan_line`] to tell the two apart.
+///
 /// Format examples:
 ///   t1234AABBCCDD  -> Standard frame, ID=0x123, DLC=4, data=AA BB CC DD
 ///   T123456788AABBCCDD112233445566 -> Extended frame, ID=0x12345678, DLC=8
@@ -153,48 +187,76 @@ const DLC_LEN: [usize; 16] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 12, 16, 20, 24, 32, 48,
 ///   d7E09112233445566778899AABBCC -> FD frame, ID=0x7E0, 12 bytes
 ///   b7E0F...64 hex bytes... -> FD+BRS frame, ID=0x7E0, 64 bytes
 pub fn parse_slcan_frame(line: &str) -> Option<FrameMessage> {
+    parse_slcan_line(line, false).ok().flatten()
+}
+
+/// Parse a single slcan line.
+///
+/// Returns `Ok(None)` for lines that are not frames (responses like 'z'),
+/// and `Err` with a description for frame lines that are malformed
+/// (truncated, odd-length or non-hex data, invalid DLC).
+///
+/// Some adapters send classic frames with a DLC code of 9-F, which per ISO
+/// 11898-1 still carries 8 data bytes. These are accepted with 8 bytes and the
+/// code kept in `dlc_raw`, unless `strict_dlc` is set, in which case they are
+/// rejected as invalid.
+pub fn parse_slcan_line(line: &str, strict_dlc: bool) -> Result<Option<FrameMessage>, String> {
     let bytes = line.as_bytes();
     if bytes.is_empty() {
-        return None;
+        return Ok(None);
     }
 
     // Determine frame type from first character
-    let (is_extended, is_rtr, is_fd) = match bytes[0] {
-        b't' => (false, false, false),
-        b'T' => (true,  false, false),
-        b'r' => (false, true,  false),
-        b'R' => (true,  true,  false),
-        b'd' => (false, false, true),
-        b'D' => (true,  false, true),
-        b'b' => (false, false, true),
-        b'B' => (true,  false, true),
-        _ => return None, // Not a frame (could be response like 'z', '\r', etc.)
+    let (is_extended, is_rtr, is_fd, is_brs) = match bytes[0] {
+        b't' => (false, false, false, false),
+        b'T' => (true,  false, false, false),
+        b'r' => (false, true,  false, false),
+        b'R' => (true,  true,  false, false),
+        b'd' => (false, false, true,  false),
+        b'D' => (true,  false, true,  false),
+        b'b' => (false, false, true,  true),
+        b'B' => (true,  false, true,  true),
+        _ => return Ok(None), // Not a frame (could be response like 'z', '\r', etc.)
     };
 
     let id_len = if is_extended { 8 } else { 3 };
     let min_len = 1 + id_len + 1; // prefix + ID + DLC
 
     if bytes.len() < min_len {
-        return None;
+        return Err(format!("truncated frame '{}'", line));
+    }
+
+    // Everything after the prefix must be hex ASCII (ID, DLC, data, optional timestamp)
+    if let Some(pos) = bytes[1..].iter().position(|b| !b.is_ascii_hexdigit()) {
+        return Err(format!(
+            "non-hex character '{}' at position {} in '{}'",
+            bytes[1 + pos] as char,
+            1 + pos,
+            line
+        ));
     }
 
     // Parse frame ID (hex ASCII)
-    let id_str = std::str::from_utf8(&bytes[1..1 + id_len]).ok()?;
-    let frame_id = u32::from_str_radix(id_str, 16).ok()?;
+    let frame_id = u32::from_str_radix(&line[1..1 + id_len], 16)
+        .map_err(|e| format!("invalid ID in '{}': {}", line, e))?;
+    // Three hex digits reach 0xFFF and eight reach 0xFFFFFFFF. The command
+    // letter fixes the format, so an ID that doesn't fit it is malformed.
+    if normalize_frame_id(frame_id, is_extended) != Some((frame_id, is_extended)) {
+        return Err(format!("ID 0x{:X} is out of range for its frame format in '{}'", frame_id, line));
+    }
 
     // Parse DLC (single hex digit: 0-8 classic, 0-F for FD)
-    let dlc_char = bytes[1 + id_len] as char;
-    let dlc_code = dlc_char.to_digit(16)? as u8;
+    let dlc_code = (bytes[1 + id_len] as char).to_digit(16).unwrap_or(0) as u8;
 
-    let max_dlc = if is_fd { 15 } else { 8 };
+    let max_dlc = if is_fd || !strict_dlc { 15 } else { 8 };
     if dlc_code > max_dlc {
-        return None;
+        return Err(format!("invalid DLC {:X} in '{}'", dlc_code, line));
     }
 
     let data_len = if is_fd {
         DLC_LEN[dlc_code as usize]
     } else {
-        dlc_code as usize
+        (dlc_code as usize).min(8)
     };
 
     // Parse data bytes (pairs of hex characters)
@@ -204,17 +266,22 @@ pub fn parse_slcan_frame(line: &str) -> Option<FrameMessage> {
         let expected_len = data_start + (data_len * 2);
 
         if bytes.len() < expected_len {
-            return None;
+            let hex_digits = bytes.len() - data_start;
+            return Err(if hex_digits % 2 != 0 {
+                format!("odd number of data hex digits ({}) in '{}'", hex_digits, line)
+            } else {
+                format!("expected {} data bytes, got {} in '{}'", data_len, hex_digits / 2, line)
+            });
         }
 
         for i in 0..data_len {
-            let byte_str = std::str::from_utf8(&bytes[data_start + i * 2..data_start + i * 2 + 2]).ok()?;
-            let byte = u8::from_str_radix(byte_str, 16).ok()?;
-            data.push(byte);
+            let offset = data_start + i * 2;
+            // Validated as hex above, so this cannot fail
+            data.push(u8::from_str_radix(&line[offset..offset + 2], 16).unwrap_or(0));
         }
     }
 
-    Some(FrameMessage {
+    Ok(Some(FrameMessage {
         protocol: "can".to_string(),
         timestamp_us: now_us(),
         frame_id,
@@ -226,6 +293,168 @@ pub fn parse_slcan_frame(line: &str) -> Option<FrameMessage> {
         source_address: None,
         incomplete: None,
         direction: None,
+        dlc_raw: Some(dlc_code),
+        is_rtr,
+        is_brs,
+        is_esi: false,
+        original_frame_id: None,
+        source_label: None,
+    }))
+}
+
+// ============================================================================
+// Line Classification
+// ============================================================================
+
+/// A line received from an slcan device.
+#[derive(Debug)]
+pub enum SlcanLine {
+    /// Received CAN or CAN FD frame
+    Frame(FrameMessage),
+    /// Reply to a command or status poll
+    Response(SlcanResponse),
+}
+
+/// Non-frame replies an slcan device sends, kept apart from frame parsing so
+/// status polls and version queries can be issued mid-stream.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SlcanResponse {
+    /// Hardware and firmware version ('V'), e.g. "1013"
+    Version(String),
+    /// Detailed firmware version ('v')
+    FirmwareVersion(String),
+    /// Serial number ('N')
+    SerialNumber(String),
+    /// Status flags reply to an 'F' poll
+    Status(u8),
+    /// Transmit accepted ('z' standard, 'Z' extended)
+    TransmitAck,
+    /// BELL: the previous command was rejected
+    Rejected,
+    /// Any other line
+    Unknown(String),
+}
+
+// Status flag bits in the reply to an 'F' poll
+const STATUS_RX_FIFO_FULL: u8 = 0x01;
+const STATUS_TX_FIFO_FULL: u8 = 0x02;
+const STATUS_ERROR_WARNING: u8 = 0x04;
+const STATUS_DATA_OVERRUN: u8 = 0x08;
+const STATUS_ERROR_PASSIVE: u8 = 0x20;
+const STATUS_ARBITRATION_LOST: u8 = 0x40;
+const STATUS_BUS_ERROR: u8 = 0x80;
+
+/// Classify a line as a frame or a response.
+///
+/// Frame lines are parsed with [`parse_slcan_line`], so `Err` means a
+/// malformed frame. Lines that aren't frames are never an error.
+pub fn classify_slcan_line(line: &str, strict_dlc: bool) -> Result<SlcanLine, String> {
+    if let Some(frame) = parse_slcan_line(line, strict_dlc)? {
+        return Ok(SlcanLine::Frame(frame));
+    }
+
+    let rest = line.get(1..).unwrap_or("").to_string();
+    let response = match line.as_bytes().first() {
+        Some(b'V') => SlcanResponse::Version(rest),
+        Some(b'v') => SlcanResponse::FirmwareVersion(rest),
+        Some(b'N') => SlcanResponse::SerialNumber(rest),
+        Some(b'F') if rest.len() == 2 => match u8::from_str_radix(&rest, 16) {
+            Ok(flags) => SlcanResponse::Status(flags),
+            Err(_) => SlcanResponse::Unknown(line.to_string()),
+        },
+        Some(b'z' | b'Z') if rest.is_empty() => SlcanResponse::TransmitAck,
+        _ => SlcanResponse::Unknown(line.to_string()),
+    };
+    Ok(SlcanLine::Response(response))
+}
+
+/// Handle a response received mid-stream. Returns a `can-error` report when a
+/// status poll shows error conditions.
+fn handle_slcan_response(response: SlcanResponse) -> Option<CanErrorPayload> {
+    match response {
+        SlcanResponse::Status(flags) => slcan_status_error(flags),
+        SlcanResponse::Version(v) | SlcanResponse::FirmwareVersion(v) => {
+            tlog!("[slcan] Device version: {}", v);
+            None
+        }
+        SlcanResponse::SerialNumber(n) => {
+            tlog!("[slcan] Device serial number: {}", n);
+            None
+        }
+        SlcanResponse::Rejected => {
+            tlog!("[slcan] Device rejected a command (BELL)");
+            None
+        }
+        SlcanResponse::Unknown(line) => {
+            tlog!("[slcan] Ignoring unrecognised line '{}'", line);
+            None
+        }
+        SlcanResponse::TransmitAck => None,
+    }
+}
+
+/// A device info query waiting for the device's replies in the read loop
+struct PendingInfoQuery {
+    request: DeviceInfoRequest,
+    info: DeviceInfo,
+    replies: usize,
+    deadline: std::time::Instant,
+}
+
+impl PendingInfoQuery {
+    fn new(request: DeviceInfoRequest, now: std::time::Instant) -> Self {
+        Self {
+            request,
+            info: DeviceInfo::default(),
+            replies: 0,
+            deadline: now + DEVICE_INFO_REPLY_WINDOW,
+        }
+    }
+
+    /// Take a reply to one of the query's commands. BELL counts as a reply so
+    /// a device without 'v' or 'N' doesn't hold the query until its deadline.
+    fn record(&mut self, response: &SlcanResponse) {
+        match response {
+            SlcanResponse::Version(v) => self.info.version = Some(format_version(v)),
+            SlcanResponse::FirmwareVersion(v) => self.info.firmware_version = Some(v.trim().to_string()),
+            SlcanResponse::SerialNumber(n) => self.info.serial_number = Some(n.trim().to_string()),
+            SlcanResponse::Rejected => {}
+            _ => return,
+        }
+        self.replies += 1;
+    }
+
+    fn is_done(&self, now: std::time::Instant) -> bool {
+        self.replies >= DEVICE_INFO_REPLIES || now >= self.deadline
+    }
+
+    /// Answer the caller with whatever the device replied
+    fn finish(self) {
+        let _ = self.request.result_tx.send(self.info);
+    }
+}
+
+/// Decode status flags into a `can-error` report (None when no flag is set).
+fn slcan_status_error(flags: u8) -> Option<CanErrorPayload> {
+    let kinds: Vec<String> = [
+        (STATUS_RX_FIFO_FULL | STATUS_DATA_OVERRUN, "rx_overflow"),
+        (STATUS_TX_FIFO_FULL, "tx_overflow"),
+        (STATUS_ERROR_WARNING, "bus_warning"),
+        (STATUS_ERROR_PASSIVE, "bus_passive"),
+        (STATUS_ARBITRATION_LOST, "arbitration_lost"),
+        (STATUS_BUS_ERROR, "bus_error"),
+    ]
+    .iter()
+    .filter(|(mask, _)| flags & mask != 0)
+    .map(|(_, kind)| kind.to_string())
+    .collect();
+
+    (!kinds.is_empty()).then(|| CanErrorPayload {
+        bus: 0,
+        timestamp_us: now_us(),
+        kinds,
+        tx_error_count: None,
+        rx_error_count: None,
     })
 }
 
@@ -236,10 +465,11 @@ pub fn parse_slcan_frame(line: &str) -> Option<FrameMessage> {
 fn encode_slcan_frame(frame: &FrameMessage) -> String {
     let mut cmd = String::with_capacity(32);
 
-    // Frame type prefix
+    // Frame type prefix. The format follows is_extended, not the ID's
+    // magnitude: 0x123 can be sent as either t123 or T00000123.
     if frame.is_extended {
         cmd.push('T');
-        cmd.push_str(&format!("{:08X}", frame.frame_id));
+        cmd.push_str(&format!("{:08X}", frame.frame_id & 0x1FFF_FFFF));
     } else {
         cmd.push('t');
         cmd.push_str(&format!("{:03X}", frame.frame_id & 0x7FF));
@@ -272,6 +502,8 @@ pub struct SlcanProbeResult {
     pub hardware_version: Option<String>,
     /// Serial number (if available)
     pub serial_number: Option<String>,
+    /// Whether the firmware supports CAN FD (None if its version replies don't say)
+    pub supports_fd: Option<bool>,
     /// Error message (if probe failed)
     pub error: Option<String>,
 }
@@ -284,6 +516,9 @@ pub struct SlcanProbeResult {
 /// - v: Hardware version
 /// - N: Serial number
 ///
+/// Only read-only queries are sent. CAN FD support is inferred from the
+/// version replies, since the FD commands would change the device's settings.
+///
 /// CANable devices typically respond to V with something like "V1013\r"
 ///
 /// Optional serial framing parameters (defaults: 8N1):
@@ -320,7 +555,8 @@ pub fn probe_slcan_device(
                 version: None,
                 hardware_version: None,
                 serial_number: None,
-                error: Some(IoError::connection(&device, e.to_string()).to_string()),
+                supports_fd: None,
+                error: Some(serial_utils::open_error(&device, &port, &e).to_string()),
             };
         }
     };
@@ -386,6 +622,8 @@ pub fn probe_slcan_device(
         }
     }
 
+    let supports_fd = fd_support_from_versions(&[version.as_deref(), hardware_version.as_deref()]);
+
     // Close the port
     drop(serial_port);
 
@@ -395,6 +633,7 @@ pub fn probe_slcan_device(
             version,
             hardware_version,
             serial_number,
+            supports_fd,
             error: None,
         }
     } else {
@@ -403,11 +642,27 @@ pub fn probe_slcan_device(
             version: None,
             hardware_version: None,
             serial_number: None,
+            supports_fd: None,
             error: Some("No response from device".to_string()),
         }
     }
 }
 
+/// Infer CAN FD support from the version replies: FD firmware (e.g. ELMUE
+/// CANable 2.5) names FD in its version string. Classic firmware can't be told
+/// apart from FD firmware with a plain version string without sending an FD
+/// command, so anything else is unknown (None) rather than unsupported.
+fn fd_support_from_versions(versions: &[Option<&str>]) -> Option<bool> {
+    versions
+        .iter()
+        .flatten()
+        .any(|v| {
+            let v = v.to_ascii_uppercase();
+            v.contains("FD") || v.contains("ELMUE")
+        })
+        .then_some(true)
+}
+
 /// Format a version string (e.g., "1013" -> "1.0.13" or keep as-is if format unclear)
 fn format_version(s: &str) -> String {
     let s = s.trim();
@@ -470,40 +725,141 @@ fn send_and_read(port: &mut Box<dyn serialport::SerialPort>, cmd: &[u8]) -> Opti
 // Multi-Source Streaming
 // ============================================================================
 
-/// Encode a CAN transmit frame to slcan format for transmission
+/// Encode a CAN transmit frame to slcan format for transmission.
+///
+/// CAN FD frames use the ELMUE d/D (b/B with BRS) commands with the FD DLC
+/// code, the same forms `parse_slcan_line` reads; data shorter than the DLC's
+/// length is padded with zeros. Only FD-enabled channels accept these.
+/// Remote frames use r/R with the requested DLC and no data.
 pub fn encode_transmit_frame(frame: &CanTransmitFrame) -> Vec<u8> {
-    let mut cmd = String::with_capacity(32);
-
-    // Frame type prefix
+    let mut cmd = String::with_capacity(if frame.is_fd { MAX_SLCAN_LINE_LEN } else { 32 });
+
+    // Frame type prefix. The format follows is_extended, not the ID's
+    // magnitude: 0x123 can be sent as either t123 or T00000123. CAN FD has
+    // no remote frames (`validate_transmit_for` rejects them).
+    let prefix = match (frame.is_fd, frame.is_brs, frame.is_rtr) {
+        (true, true, _) => 'b',
+        (true, false, _) => 'd',
+        (false, _, true) => 'r',
+        (false, _, false) => 't',
+    };
     if frame.is_extended {
-        cmd.push('T');
-        cmd.push_str(&format!("{:08X}", frame.frame_id));
+        cmd.push(prefix.to_ascii_uppercase());
+        cmd.push_str(&format!("{:08X}", frame.frame_id & 0x1FFF_FFFF));
     } else {
-        cmd.push('t');
+        cmd.push(prefix);
         cmd.push_str(&format!("{:03X}", frame.frame_id & 0x7FF));
     }
 
-    // DLC
-    cmd.push_str(&format!("{:X}", frame.data.len().min(8)));
+    // DLC: FD uses the smallest code whose length holds the data
+    let (dlc_code, data_len) = if frame.is_fd {
+        let code = DLC_LEN.iter().position(|&l| l >= frame.data.len()).unwrap_or(15);
+        (code, DLC_LEN[code])
+    } else if frame.is_rtr {
+        (frame.classic_dlc() as usize, 0)
+    } else {
+        (frame.data.len().min(8), frame.data.len())
+    };
+    cmd.push_str(&format!("{:X}", dlc_code));
 
     // Data bytes
-    for byte in &frame.data {
-        cmd.push_str(&format!("{:02X}", byte));
+    for i in 0..data_len {
+        cmd.push_str(&format!("{:02X}", frame.data.get(i).copied().unwrap_or(0)));
     }
 
     cmd.push('\r');
     cmd.into_bytes()
 }
 
+/// Whether an encoded slcan command is a CAN FD frame (d/D/b/B)
+fn is_fd_command(data: &[u8]) -> bool {
+    matches!(data.first(), Some(b'd' | b'D' | b'b' | b'B'))
+}
+
+/// Parse the bytes from one serial read into a frame batch and error reports.
+///
+/// `line_buf` carries a partial line over to the next read. Bus mapping is
+/// applied here so disabled buses never reach the merge task. Replies to a
+/// pending device info query are passed to it as well as handled as usual.
+fn parse_slcan_read(
+    bytes: &[u8],
+    line_buf: &mut String,
+    bus_mappings: &[BusMapping],
+    strict_dlc: bool,
+    parse_errors: &mut ParseErrors,
+    info_query: &mut Option<PendingInfoQuery>,
+) -> (Vec<FrameMessage>, Vec<CanErrorPayload>) {
+    let mut frames: Vec<FrameMessage> = Vec::new();
+    let mut errors: Vec<CanErrorPayload> = Vec::new();
+
+    for &byte in bytes {
+        if byte == b'\r' || byte == b'\n' {
+            if !line_buf.is_empty() {
+                match classify_slcan_line(line_buf, strict_dlc) {
+                    Ok(SlcanLine::Frame(mut frame)) => {
+                        // Apply bus mapping
+                        if apply_bus_mapping(&mut frame, bus_mappings) {
+                            frames.push(frame);
+                        }
+                    }
+                    Ok(SlcanLine::Response(response)) => {
+                        if let Some(query) = info_query.as_mut() {
+                            query.record(&response);
+                        }
+                        errors.extend(handle_slcan_response(response));
+                    }
+                    Err(e) => parse_errors.record_entry(ParseErrorEntry::new("slcan_line", e, line_buf.as_bytes())),
+                }
+                line_buf.clear();
+            }
+        } else if byte == 0x07 {
+            // Bell = command rejected; drop any partial line
+            line_buf.clear();
+            if let Some(query) = info_query.as_mut() {
+                query.record(&SlcanResponse::Rejected);
+            }
+            handle_slcan_response(SlcanResponse::Rejected);
+        } else if byte.is_ascii() && !byte.is_ascii_control() {
+            line_buf.push(byte as char);
+            if line_buf.len() > MAX_SLCAN_LINE_LEN {
+                tlog!("[slcan] Line buffer exceeded {} bytes, discarding", MAX_SLCAN_LINE_LEN);
+                parse_errors.record_entry(ParseErrorEntry::new(
+                    "slcan_overlong_line",
+                    format!("Line exceeded {} bytes", MAX_SLCAN_LINE_LEN),
+                    line_buf.as_bytes(),
+                ));
+                line_buf.clear();
+            }
+        }
+    }
+
+    (frames, errors)
+}
+
 /// Run slcan source and send frames to merge task
+///
+/// The port is opened with the given serial framing (`parity` as "none",
+/// "odd" or "even"), like `probe_slcan_device`.
+///
+/// With `passive_attach`, nothing is written to the adapter on open (no close,
+/// bitrate, mode or open commands) and device info queries are not offered: the
+/// channel must already be open at the right bitrate, and the source just reads
+/// what the adapter emits. The channel is also left open on stop.
+#[allow(clippy::too_many_arguments)]
 pub async fn run_source(
     source_idx: usize,
     port_path: String,
     baud_rate: u32,
+    data_bits: u8,
+    stop_bits: u8,
+    parity: String,
+    line_control: serial_utils::SerialLineControl,
+    passive_attach: bool,
     bitrate: u32,
     silent_mode: bool,
     enable_fd: bool,
     data_bitrate: u32,
+    strict_dlc: bool,
     bus_mappings: Vec<BusMapping>,
     stop_flag: Arc<AtomicBool>,
     tx: mpsc::Sender<SourceMessage>,
@@ -511,16 +867,18 @@ pub async fn run_source(
     let device = format!("slcan({})", port_path);
 
     // Open serial port
-    let serial_port = match serialport::new(&port_path, baud_rate)
-        .timeout(Duration::from_millis(50))
-        .open()
-    {
+    let builder = serialport::new(&port_path, baud_rate)
+        .data_bits(serial_utils::to_serialport_data_bits(data_bits))
+        .stop_bits(serial_utils::to_serialport_stop_bits(stop_bits))
+        .parity(serial_utils::parity_str_to_serialport(&parity))
+        .timeout(Duration::from_millis(50));
+    let serial_port = match line_control.open(builder) {
         Ok(p) => p,
         Err(e) => {
             let _ = tx
                 .send(SourceMessage::Error(
                     source_idx,
-                    IoError::connection(&device, e.to_string()).to_string(),
+                    serial_utils::open_error(&device, &port_path, &e).to_string(),
                 ))
                 .await;
             return;
@@ -535,6 +893,9 @@ pub async fn run_source(
         let mut port = serial_port.lock()
             .map_err(|e| format!("Failed to lock serial port during init: {}", e))?;
         let _ = port.clear(serialport::ClearBuffer::All);
+        if passive_attach {
+            return Ok(());
+        }
 
         // Wait for device to be ready
         std::thread::sleep(Duration::from_millis(200));
@@ -588,11 +949,21 @@ pub async fn run_source(
             .await;
     }
 
-    tlog!(
-        "[slcan] Source {} connected to {} (bitrate: {}, silent: {}, fd: {}{})",
-        source_idx, port_path, bitrate, silent_mode, enable_fd,
-        if enable_fd { format!(", data_bitrate: {}", data_bitrate) } else { String::new() }
-    );
+    // Device info queries only talk to the adapter, so they work in silent mode too
+    let (info_tx, info_rx) = std::sync::mpsc::sync_channel::<DeviceInfoRequest>(4);
+    if !passive_attach {
+        let _ = tx.send(SourceMessage::DeviceInfoReady(source_idx, info_tx)).await;
+    }
+
+    if passive_attach {
+        tlog!("[slcan] Source {} attached passively to {} (no setup commands sent)", source_idx, port_path);
+    } else {
+        tlog!(
+            "[slcan] Source {} connected to {} (bitrate: {}, silent: {}, fd: {}{})",
+            source_idx, port_path, bitrate, silent_mode, enable_fd,
+            if enable_fd { format!(", data_bitrate: {}", data_bitrate) } else { String::new() }
+        );
+    }
 
     // Emit device-connected event
     let _ = tx
@@ -605,13 +976,23 @@ pub async fn run_source(
     let serial_port_clone = serial_port.clone();
 
     let blocking_handle = tokio::task::spawn_blocking(move || {
-        let mut line_buf = String::with_capacity(64);
-        let mut read_buf = [0u8; 256];
+        let mut line_buf = String::with_capacity(MAX_SLCAN_LINE_LEN);
+        let mut read_buf = [0u8; SLCAN_READ_BUF_SIZE];
+        let mut parse_errors = ParseErrors::default();
+        let mut last_parse_error_report = std::time::Instant::now();
+        let mut info_query: Option<PendingInfoQuery> = None;
 
         while !stop_flag_clone.load(Ordering::SeqCst) {
             // Check for transmit requests (non-blocking)
             if !silent_mode {
                 while let Ok(req) = transmit_rx.try_recv() {
+                    // A classic-mode channel can't put an FD frame on the bus
+                    if !enable_fd && is_fd_command(&req.data) {
+                        let _ = req.result_tx.send(Err(
+                            "Cannot transmit FD frame: slcan channel was opened in classic mode (enable FD in the profile)".to_string(),
+                        ));
+                        continue;
+                    }
                     let result = match serial_port_clone.lock() {
                         Ok(mut port) => port
                             .write_all(&req.data)
@@ -626,6 +1007,21 @@ pub async fn run_source(
                 }
             }
 
+            // Start a device info query; its replies arrive in the stream below
+            if info_query.is_none() {
+                if let Ok(request) = info_rx.try_recv() {
+                    let written = serial_port_clone.lock().is_ok_and(|mut port| {
+                        port.write_all(DEVICE_INFO_QUERY).and_then(|_| port.flush()).is_ok()
+                    });
+                    let query = PendingInfoQuery::new(request, std::time::Instant::now());
+                    if written {
+                        info_query = Some(query);
+                    } else {
+                        query.finish();
+                    }
+                }
+            }
+
             // Read data
             let read_result = match serial_port_clone.lock() {
                 Ok(mut port) => port.read(&mut read_buf),
@@ -641,35 +1037,31 @@ pub async fn run_source(
 
             match read_result {
                 Ok(n) if n > 0 => {
-                    let mut pending_frames: Vec<FrameMessage> = Vec::new();
-
-                    for &byte in &read_buf[..n] {
-                        if byte == b'\r' || byte == b'\n' {
-                            if !line_buf.is_empty() {
-                                if let Some(mut frame) = parse_slcan_frame(&line_buf) {
-                                    // Apply bus mapping
-                                    if apply_bus_mapping(&mut frame, &bus_mappings) {
-                                        pending_frames.push(frame);
-                                    }
-                                }
-                                line_buf.clear();
-                            }
-                        } else if byte == 0x07 {
-                            // Bell = error
-                            line_buf.clear();
-                        } else if byte.is_ascii() && !byte.is_ascii_control() {
-                            line_buf.push(byte as char);
-                            if line_buf.len() > 64 {
-                                tlog!("[slcan] Line buffer exceeded 64 bytes, discarding");
-                                line_buf.clear();
-                            }
-                        }
-                    }
+                    let (pending_frames, pending_errors) =
+                        parse_slcan_read(&read_buf[..n], &mut line_buf, &bus_mappings, strict_dlc, &mut parse_errors, &mut info_query);
 
                     if !pending_frames.is_empty() {
                         let _ = tx_clone
                             .blocking_send(SourceMessage::Frames(source_idx, pending_frames));
                     }
+
+                    let pending_errors = apply_bus_mappings_to_errors(pending_errors, &bus_mappings);
+                    if !pending_errors.is_empty() {
+                        let _ = tx_clone
+                            .blocking_send(SourceMessage::CanErrors(source_idx, pending_errors));
+                    }
+
+                    // Report malformed lines at most once per interval so a bad
+                    // adapter can't flood the session with events
+                    if !parse_errors.is_empty()
+                        && last_parse_error_report.elapsed() >= PARSE_ERROR_REPORT_INTERVAL
+                    {
+                        let _ = tx_clone.blocking_send(SourceMessage::ParseErrors(
+                            source_idx,
+                            std::mem::take(&mut parse_errors),
+                        ));
+                        last_parse_error_report = std::time::Instant::now();
+                    }
                 }
                 Ok(0) => {
                     std::thread::sleep(Duration::from_millis(10));
@@ -686,12 +1078,20 @@ pub async fn run_source(
                     return;
                 }
             }
+
+            if info_query.as_ref().is_some_and(|q| q.is_done(std::time::Instant::now())) {
+                if let Some(query) = info_query.take() {
+                    query.finish();
+                }
+            }
         }
 
-        // Close channel
-        if let Ok(mut port) = serial_port_clone.lock() {
-            let _ = port.write_all(b"C\r");
-            let _ = port.flush();
+        // Close channel (left open when attached passively)
+        if !passive_attach {
+            if let Ok(mut port) = serial_port_clone.lock() {
+                let _ = port.write_all(b"C\r");
+                let _ = port.flush();
+            }
         }
 
         let _ = tx_clone.blocking_send(SourceMessage::Ended(source_idx, "stopped".to_string()));
@@ -707,6 +1107,7 @@ pub async fn run_source(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::io::test_support::can_frame;
 
     #[test]
     fn test_parse_standard_frame() {
@@ -727,6 +1128,16 @@ mod tests {
         assert!(frame.is_extended);
     }
 
+    #[test]
+    fn test_parse_out_of_range_ids_rejected() {
+        // Three hex digits can exceed the 11-bit range
+        assert!(parse_slcan_frame("t9001AA").is_none());
+
+        // Eight hex digits can exceed the 29-bit range
+        assert!(parse_slcan_frame("TFFFFFFFF1AA").is_none());
+        assert_eq!(parse_slcan_frame("T1FFFFFFF1AA").unwrap().frame_id, 0x1FFF_FFFF);
+    }
+
     #[test]
     fn test_parse_standard_frame_zero_dlc() {
         let frame = parse_slcan_frame("t1230").unwrap();
@@ -768,10 +1179,26 @@ mod tests {
 
     #[test]
     fn test_parse_invalid_dlc() {
-        // DLC > 8 is invalid for classic CAN
+        // DLC > 8 is invalid for classic CAN in strict mode
+        let err = parse_slcan_line("t123F1122334455667788", true).unwrap_err();
+        assert!(err.contains("invalid DLC"), "{}", err);
+        // Lenient mode still needs the 8 data bytes
         assert!(parse_slcan_frame("t123FAABBCCDD").is_none());
     }
 
+    #[test]
+    fn test_parse_classic_dlc_above_8_lenient() {
+        for code in 9..=15u8 {
+            let line = format!("t123{:X}1122334455667788", code);
+            let frame = parse_slcan_line(&line, false).unwrap().unwrap();
+            assert_eq!(frame.dlc, 8, "{}", line);
+            assert_eq!(frame.bytes, vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+            assert_eq!(frame.dlc_raw, Some(code));
+            assert!(!frame.is_fd);
+            assert!(parse_slcan_line(&line, true).is_err());
+        }
+    }
+
     #[test]
     fn test_parse_truncated_frame() {
         // Not enough data bytes for DLC
@@ -779,56 +1206,140 @@ mod tests {
     }
 
     #[test]
-    fn test_encode_standard_frame() {
-        let frame = FrameMessage {
-            protocol: "can".to_string(),
-            timestamp_us: 0,
-            frame_id: 0x123,
-            bus: 0,
-            dlc: 3,
-            bytes: vec![0x01, 0x02, 0x03],
-            is_extended: false,
-            is_fd: false,
-            source_address: None,
-            incomplete: None,
-            direction: None,
+    fn test_parse_line_odd_length_data() {
+        let err = parse_slcan_line("t1233AABBC", false).unwrap_err();
+        assert!(err.contains("odd number"), "{}", err);
+    }
+
+    #[test]
+    fn test_parse_line_non_hex_data() {
+        let err = parse_slcan_line("t1232AGBB", false).unwrap_err();
+        assert!(err.contains("non-hex character 'G'"), "{}", err);
+        assert!(parse_slcan_line("T1234 5678", false).is_err());
+    }
+
+    #[test]
+    fn test_parse_line_not_a_frame() {
+        assert!(matches!(parse_slcan_line("z", false), Ok(None)));
+        assert!(matches!(parse_slcan_line("", false), Ok(None)));
+    }
+
+    #[test]
+    fn test_classify_routes_responses_apart_from_frames() {
+        assert!(matches!(classify_slcan_line("t1231AA", false), Ok(SlcanLine::Frame(_))));
+        assert!(classify_slcan_line("t1231A", false).is_err());
+
+        let response = |line: &str| match classify_slcan_line(line, false) {
+            Ok(SlcanLine::Response(r)) => r,
+            other => panic!("expected response for {:?}, got {:?}", line, other),
         };
+        assert_eq!(response("V1013"), SlcanResponse::Version("1013".to_string()));
+        assert_eq!(response("vSTM32-1.2"), SlcanResponse::FirmwareVersion("STM32-1.2".to_string()));
+        assert_eq!(response("NA123"), SlcanResponse::SerialNumber("A123".to_string()));
+        assert_eq!(response("F0C"), SlcanResponse::Status(0x0C));
+        assert_eq!(response("z"), SlcanResponse::TransmitAck);
+        assert_eq!(response("Z"), SlcanResponse::TransmitAck);
+        assert_eq!(response("FXY"), SlcanResponse::Unknown("FXY".to_string()));
+    }
+
+    #[test]
+    fn test_status_flags_decode_to_can_error() {
+        assert!(slcan_status_error(0).is_none());
+        let error = slcan_status_error(STATUS_DATA_OVERRUN | STATUS_ERROR_WARNING).unwrap();
+        assert_eq!(error.kinds, vec!["rx_overflow", "bus_warning"]);
+    }
+
+    #[test]
+    fn test_parse_line_accepts_trailing_timestamp() {
+        let frame = parse_slcan_line("t1232AABB1F40", false).unwrap().unwrap();
+        assert_eq!(frame.bytes, vec![0xAA, 0xBB]);
+    }
+
+    #[test]
+    fn test_encode_standard_frame() {
+        let frame = can_frame(0x123, 0, vec![0x01, 0x02, 0x03]);
         assert_eq!(encode_slcan_frame(&frame), "t1233010203\r");
     }
 
     #[test]
     fn test_encode_extended_frame() {
-        let frame = FrameMessage {
-            protocol: "can".to_string(),
-            timestamp_us: 0,
-            frame_id: 0x12345678,
+        let frame = FrameMessage { is_extended: true, ..can_frame(0x12345678, 0, vec![0xAA, 0xBB]) };
+        assert_eq!(encode_slcan_frame(&frame), "T123456782AABB\r");
+    }
+
+    #[test]
+    fn test_encode_transmit_small_id_both_formats() {
+        let mut frame = CanTransmitFrame {
+            frame_id: 0x123,
+            data: vec![0xAA],
             bus: 0,
-            dlc: 2,
-            bytes: vec![0xAA, 0xBB],
             is_extended: true,
             is_fd: false,
-            source_address: None,
-            incomplete: None,
-            direction: None,
+            is_brs: false,
+            is_rtr: false,
+            rtr_dlc: None,
         };
-        assert_eq!(encode_slcan_frame(&frame), "T123456782AABB\r");
+        assert_eq!(encode_transmit_frame(&frame), b"T000001231AA\r");
+
+        frame.is_extended = false;
+        assert_eq!(encode_transmit_frame(&frame), b"t1231AA\r");
+
+        // Remote frames send the requested DLC and no data
+        frame.is_rtr = true;
+        assert_eq!(encode_transmit_frame(&frame), b"r1231\r");
+        frame.rtr_dlc = Some(8);
+        assert_eq!(encode_transmit_frame(&frame), b"r1238\r");
+        let decoded = parse_slcan_frame("r1238").unwrap();
+        assert!(decoded.is_rtr && decoded.bytes.is_empty());
+        assert_eq!(decoded.dlc, 8);
     }
 
     #[test]
-    fn test_encode_decode_roundtrip() {
-        let original = FrameMessage {
-            protocol: "can".to_string(),
-            timestamp_us: 0,
-            frame_id: 0x7FF,
+    fn test_encode_transmit_fd_frame_round_trips() {
+        let mut frame = CanTransmitFrame {
+            frame_id: 0x7E0,
+            data: (0..10).collect(),
             bus: 0,
-            dlc: 4,
-            bytes: vec![0xDE, 0xAD, 0xBE, 0xEF],
             is_extended: false,
-            is_fd: false,
-            source_address: None,
-            incomplete: None,
-            direction: None,
+            is_fd: true,
+            is_brs: false,
+            is_rtr: false,
+            rtr_dlc: None,
         };
+        // 10 bytes go out as DLC 9 (12 bytes), zero padded
+        let encoded = encode_transmit_frame(&frame);
+        assert_eq!(encoded, b"d7E09000102030405060708090000\r");
+        assert!(is_fd_command(&encoded));
+        let decoded = parse_slcan_frame(std::str::from_utf8(&encoded[..encoded.len() - 1]).unwrap()).unwrap();
+        assert!(decoded.is_fd && !decoded.is_brs);
+        assert_eq!(decoded.bytes.len(), 12);
+        assert_eq!(&decoded.bytes[..10], &frame.data[..]);
+
+        frame.is_extended = true;
+        frame.is_brs = true;
+        frame.data = vec![0x55; 64];
+        let encoded = encode_transmit_frame(&frame);
+        assert!(encoded.starts_with(b"B000007E0F5555"));
+        // The longest FD line survives the receive path, split across reads
+        let mut line_buf = String::new();
+        let mut parse_errors = ParseErrors::default();
+        let (mid, tail) = encoded.split_at(70);
+        let (first, _) = parse_slcan_read(mid, &mut line_buf, &[], false, &mut parse_errors, &mut None);
+        let (decoded, _) = parse_slcan_read(tail, &mut line_buf, &[], false, &mut parse_errors, &mut None);
+        assert!(first.is_empty() && parse_errors.is_empty());
+        assert_eq!(decoded.len(), 1);
+        assert!(decoded[0].is_fd && decoded[0].is_brs && decoded[0].is_extended);
+        assert_eq!(decoded[0].bytes, frame.data);
+
+        frame.is_fd = false;
+        frame.is_brs = false;
+        frame.data = vec![0xAA];
+        assert!(!is_fd_command(&encode_transmit_frame(&frame)));
+    }
+
+    #[test]
+    fn test_encode_decode_roundtrip() {
+        let original = can_frame(0x7FF, 0, vec![0xDE, 0xAD, 0xBE, 0xEF]);
 
         let encoded = encode_slcan_frame(&original);
         // Remove trailing \r for parsing
@@ -848,4 +1359,101 @@ mod tests {
         assert_eq!(find_bitrate_command(10_000).unwrap(), "S0");
         assert!(find_bitrate_command(123_456).is_err());
     }
+
+    #[test]
+    fn test_fd_support_from_versions() {
+        assert_eq!(fd_support_from_versions(&[Some("1.0.13"), Some("CANable2.5 FD")]), Some(true));
+        assert_eq!(fd_support_from_versions(&[Some("ElmueSoft 25.01"), None]), Some(true));
+        assert_eq!(fd_support_from_versions(&[Some("1.0.13"), None]), None);
+        assert_eq!(fd_support_from_versions(&[None, None]), None);
+    }
+
+    #[test]
+    fn test_parse_read_batches_high_rate_stream() {
+        // 10,000 classic frames back to back, as a saturated bus would deliver them
+        let stream: String = (0..10_000u32)
+            .map(|i| format!("t{:03X}8{:016X}\r", i & 0x7FF, i as u64))
+            .collect();
+        let mut line_buf = String::new();
+        let mut parse_errors = ParseErrors::default();
+        let mut batches = 0;
+        let mut frames = Vec::new();
+        for chunk in stream.as_bytes().chunks(SLCAN_READ_BUF_SIZE) {
+            let (batch, errors) = parse_slcan_read(chunk, &mut line_buf, &[], false, &mut parse_errors, &mut None);
+            assert!(errors.is_empty());
+            batches += 1;
+            frames.extend(batch);
+        }
+
+        // One channel send per read instead of one per frame
+        assert_eq!(frames.len(), 10_000);
+        assert!(batches * 100 < frames.len(), "{} batches for {} frames", batches, frames.len());
+        assert!(parse_errors.is_empty());
+        assert!(line_buf.is_empty());
+        // Lines split across reads are reassembled in order
+        for (i, frame) in frames.iter().enumerate() {
+            assert_eq!(frame.frame_id, i as u32 & 0x7FF);
+            assert_eq!(frame.bytes, (i as u64).to_be_bytes().to_vec());
+        }
+    }
+
+    #[test]
+    fn test_parse_read_applies_bus_mapping() {
+        let mappings = vec![BusMapping { device_bus: 0, enabled: true, output_bus: 3, ..Default::default() }];
+        let mut line_buf = String::new();
+        let mut parse_errors = ParseErrors::default();
+        let (frames, _) = parse_slcan_read(b"t1232AABB\rt45", &mut line_buf, &mappings, false, &mut parse_errors, &mut None);
+        assert_eq!(frames.len(), 1);
+        assert_eq!(frames[0].bus, 3);
+        assert_eq!(line_buf, "t45");
+
+        let disabled = vec![BusMapping { device_bus: 0, enabled: false, output_bus: 0, ..Default::default() }];
+        let (frames, _) = parse_slcan_read(b"62AABB\r", &mut line_buf, &disabled, false, &mut parse_errors, &mut None);
+        assert!(frames.is_empty());
+        assert!(parse_errors.is_empty());
+    }
+
+    #[test]
+    fn test_device_info_query_collects_replies_from_stream() {
+        let (result_tx, result_rx) = std::sync::mpsc::sync_channel(1);
+        let now = std::time::Instant::now();
+        let mut query = Some(PendingInfoQuery::new(DeviceInfoRequest { result_tx }, now));
+        let mut line_buf = String::new();
+        let mut parse_errors = ParseErrors::default();
+
+        // Replies interleaved with traffic; this device has no 'v' command
+        let (frames, _) = parse_slcan_read(
+            b"t1231AA\rV1013\rt4560\r\x07NA1B2\r",
+            &mut line_buf,
+            &[],
+            false,
+            &mut parse_errors,
+            &mut query,
+        );
+        assert_eq!(frames.len(), 2);
+        let query = query.unwrap();
+        assert!(query.is_done(now));
+        query.finish();
+
+        let info = result_rx.try_recv().unwrap();
+        assert_eq!(info.version.as_deref(), Some("1.0.13"));
+        assert_eq!(info.firmware_version, None);
+        assert_eq!(info.serial_number.as_deref(), Some("A1B2"));
+    }
+
+    #[test]
+    fn test_malformed_lines_kept_as_recent_parse_errors() {
+        let mut line_buf = String::new();
+        let mut parse_errors = ParseErrors::default();
+        let mut input = b"tXYZ\rt1231AA\r".to_vec();
+        input.extend(std::iter::repeat(b'A').take(MAX_SLCAN_LINE_LEN + 1));
+        input.push(b'\r');
+
+        let (frames, _) = parse_slcan_read(&input, &mut line_buf, &[], false, &mut parse_errors, &mut None);
+        assert_eq!(frames.len(), 1);
+        assert_eq!(parse_errors.count, 2);
+        let kinds: Vec<&str> = parse_errors.recent.iter().map(|e| e.kind.as_str()).collect();
+        assert_eq!(kinds, vec!["slcan_line", "slcan_overlong_line"]);
+        assert_eq!(parse_errors.recent[0].sample, b"tXYZ".to_vec());
+    }
 }
@@ -33,6 +33,20 @@ pub mod consts {
     pub const CAN_EFF_MASK: u32 = 0x1FFF_FFFF;
     /// BRS flag for CAN FD
     pub const CANFD_BRS: u8 = 0x01;
+    /// ESI flag for CAN FD
+    pub const CANFD_ESI: u8 = 0x02;
+}
+
+/// Raw DLC code of a classic frame.
+///
+/// With CAN_CTRLMODE_CC_LEN8_DLC the kernel reports `len` as 8 and puts the wire
+/// DLC (9-15) in `len8_dlc` (byte 7 of struct can_frame); otherwise DLC == len.
+pub fn classic_dlc_raw(len: usize, len8_dlc: u8) -> u8 {
+    if len == 8 && (9..=15).contains(&len8_dlc) {
+        len8_dlc
+    } else {
+        len as u8
+    }
 }
 
 /// Encoded SocketCAN frame - either classic (16 bytes) or FD (72 bytes)
@@ -78,19 +92,26 @@ impl FrameCodec for SocketCanCodec {
 
         // Extract data
         let data = raw[8..8 + actual_len].to_vec();
+        let dlc_raw = if is_fd { None } else { Some(classic_dlc_raw(actual_len, raw[7])) };
 
         Ok(FrameMessage {
             protocol: "can".to_string(),
             timestamp_us: now_us(),
             frame_id,
             bus: 0, // SocketCAN doesn't embed bus in frame
-            dlc: data_len as u8,
+            dlc: actual_len as u8,
             bytes: data,
             is_extended,
             is_fd,
             source_address: None,
             incomplete: None,
             direction: None,
+            dlc_raw,
+            is_rtr: false,
+            is_brs: is_fd && raw[5] & consts::CANFD_BRS != 0,
+            is_esi: is_fd && raw[5] & consts::CANFD_ESI != 0,
+            original_frame_id: None,
+            source_label: None,
         })
     }
 
@@ -157,10 +178,10 @@ impl FrameCodec for SocketCanCodec {
             buf[0..4].copy_from_slice(&can_id.to_ne_bytes());
 
             // dlc
-            buf[4] = frame.data.len().min(8) as u8;
+            buf[4] = frame.classic_dlc();
 
-            // data
-            let len = frame.data.len().min(8);
+            // data (none for a remote frame)
+            let len = if frame.is_rtr { 0 } else { frame.data.len().min(8) };
             buf[8..8 + len].copy_from_slice(&frame.data[..len]);
 
             Ok(SocketCanEncodedFrame::Classic(buf))
@@ -193,6 +214,24 @@ mod tests {
         assert!(!frame.is_fd);
     }
 
+    #[test]
+    fn test_socketcan_decode_classic_len8_dlc() {
+        let mut raw = [0u8; 16];
+        raw[0..4].copy_from_slice(&0x123u32.to_ne_bytes());
+        raw[4] = 8;
+        raw[7] = 0x0F;
+        raw[8..16].copy_from_slice(&[1, 2, 3, 4, 5, 6, 7, 8]);
+
+        let frame = SocketCanCodec::decode(&raw).unwrap();
+        assert_eq!(frame.dlc, 8);
+        assert_eq!(frame.bytes.len(), 8);
+        assert_eq!(frame.dlc_raw, Some(0x0F));
+
+        raw[7] = 0;
+        let frame = SocketCanCodec::decode(&raw).unwrap();
+        assert_eq!(frame.dlc_raw, Some(8));
+    }
+
     #[test]
     fn test_socketcan_decode_fd_frame() {
         let mut raw = [0u8; 72];
@@ -222,6 +261,7 @@ mod tests {
             is_fd: false,
             is_brs: false,
             is_rtr: false,
+            rtr_dlc: None,
         };
 
         let encoded = SocketCanCodec::encode(&frame).unwrap();
@@ -248,6 +288,7 @@ mod tests {
             is_fd: true,
             is_brs: true,
             is_rtr: false,
+            rtr_dlc: None,
         };
 
         let encoded = SocketCanCodec::encode(&frame).unwrap();
@@ -272,6 +313,7 @@ mod tests {
             is_fd: false,
             is_brs: false,
             is_rtr: false,
+            rtr_dlc: None,
         };
 
         assert!(SocketCanCodec::encode(&frame).is_err());
@@ -6,15 +6,25 @@
 // Requires the interface to be configured first:
 //   sudo ip link set can0 up type can bitrate 500000
 //
+// Bus-off recovery: a controller that goes bus-off stops receiving until the
+// interface is restarted. The kernel does this itself when `restart-ms` is set
+//   sudo ip link set can0 type can restart-ms 100
+// otherwise, with `restart_on_busoff` enabled, the reader restarts it by
+// running `ip link set can0 type can restart`. That needs CAP_NET_ADMIN (run
+// as root, or `sudo setcap cap_net_admin+ep` on the binary); pkexec is not used
+// because an unattended capture can't answer a password prompt. Without the
+// capability the reader reports the bus-off and how to recover instead.
+//
 // This module is only compiled on Linux.
 
 #[cfg(target_os = "linux")]
 mod linux_impl {
     use serde::{Deserialize, Serialize};
     use socketcan::{
-        CanAnyFrame, CanDataFrame, CanFdFrame, CanFdSocket, EmbeddedFrame, ExtendedId, Frame, Id,
+        CanDataFrame, CanFdFrame, CanFdSocket, EmbeddedFrame, ExtendedId, Frame, Id,
         Socket, StandardId,
     };
+    use std::os::fd::{AsRawFd, RawFd};
     use std::sync::{
         atomic::{AtomicBool, Ordering},
         mpsc as std_mpsc,
@@ -23,10 +33,11 @@ mod linux_impl {
     use std::time::Duration;
     use tokio::sync::mpsc;
 
+    use super::codec::classic_dlc_raw;
     use crate::io::error::IoError;
     use crate::io::gvret::{apply_bus_mapping, BusMapping};
     use crate::io::types::{SourceMessage, TransmitRequest};
-    use crate::io::{now_us, CanTransmitFrame, FrameMessage};
+    use crate::io::{now_us, BusOffPayload, CanTransmitFrame, FrameMessage, TimestampSource};
 
     // ============================================================================
     // Types and Configuration
@@ -59,6 +70,14 @@ mod linux_impl {
         /// Only used when enable_fd is true.
         #[serde(default)]
         pub data_bitrate: Option<u32>,
+        /// Socket receive buffer size in bytes (`SO_RCVBUF`).
+        /// Raise this for high-rate buses; None keeps the kernel default.
+        #[serde(default)]
+        pub rcvbuf_bytes: Option<u32>,
+        /// Restart the interface when the controller goes bus-off and the
+        /// kernel's `restart-ms` is unset. Requires CAP_NET_ADMIN.
+        #[serde(default)]
+        pub restart_on_busoff: bool,
     }
 
     // ============================================================================
@@ -78,6 +97,17 @@ mod linux_impl {
     ) -> Result<(), String> {
         use std::process::Command;
 
+        if enable_fd {
+            if let Some(dbitrate) = data_bitrate {
+                if dbitrate < bitrate {
+                    return Err(format!(
+                        "CAN FD data bitrate ({} bps) must not be lower than the nominal bitrate ({} bps)",
+                        dbitrate, bitrate
+                    ));
+                }
+            }
+        }
+
         tlog!(
             "[socketcan] Configuring interface {} with bitrate {}{} using pkexec",
             interface,
@@ -144,40 +174,298 @@ mod linux_impl {
     }
 
     // ============================================================================
-    // Utility Functions
+    // Bus-off Recovery
     // ============================================================================
 
-    /// Convert a CanAnyFrame to our FrameMessage format
-    fn convert_any_frame(frame: CanAnyFrame, bus_override: Option<u8>) -> Option<FrameMessage> {
-        match frame {
-            CanAnyFrame::Normal(f) => Some(FrameMessage {
-                protocol: "can".to_string(),
-                timestamp_us: now_us(),
-                frame_id: f.raw_id() & 0x1FFF_FFFF,
-                bus: bus_override.unwrap_or(0),
-                dlc: f.len() as u8,
-                bytes: f.data().to_vec(),
-                is_extended: f.is_extended(),
-                is_fd: false,
-                source_address: None,
-                incomplete: None,
-                direction: None,
-            }),
-            CanAnyFrame::Fd(f) => Some(FrameMessage {
-                protocol: "can".to_string(),
-                timestamp_us: now_us(),
-                frame_id: f.raw_id() & 0x1FFF_FFFF,
-                bus: bus_override.unwrap_or(0),
-                dlc: f.len() as u8,
-                bytes: f.data().to_vec(),
-                is_extended: f.is_extended(),
-                is_fd: true,
-                source_address: None,
-                incomplete: None,
-                direction: None,
-            }),
-            CanAnyFrame::Remote(_) => None, // Skip remote frames
-            CanAnyFrame::Error(_) => None,  // Skip error frames
+    /// Minimum time between automatic bus-off restarts, so a bus that faults
+    /// again immediately doesn't spin on `ip link`.
+    const BUSOFF_RESTART_INTERVAL: Duration = Duration::from_secs(1);
+
+    /// CAN controller link details read from netlink (via `ip -details -json`).
+    #[derive(Debug, Default, PartialEq)]
+    struct CanLinkInfo {
+        /// Kernel automatic restart delay; 0 means restart is manual
+        restart_ms: Option<u32>,
+    }
+
+    /// Parse the output of `ip -details -json link show dev <iface>`.
+    fn parse_can_link_info(json: &str) -> Option<CanLinkInfo> {
+        let links: serde_json::Value = serde_json::from_str(json).ok()?;
+        let info_data = links.get(0)?.get("linkinfo")?.get("info_data")?;
+        Some(CanLinkInfo {
+            restart_ms: info_data.get("restart_ms").and_then(|v| v.as_u64()).map(|v| v as u32),
+        })
+    }
+
+    /// Read the `restart-ms` setting of a CAN interface.
+    /// Unprivileged; only needs iproute2.
+    fn query_can_link_info(interface: &str) -> Result<CanLinkInfo, String> {
+        use std::process::Command;
+
+        let output = Command::new("ip")
+            .args(["-details", "-json", "link", "show", "dev", interface])
+            .output()
+            .map_err(|e| format!("Failed to run ip: {}", e))?;
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+        }
+        parse_can_link_info(&String::from_utf8_lossy(&output.stdout))
+            .ok_or_else(|| format!("No CAN link details reported for {}", interface))
+    }
+
+    /// Restart a bus-off CAN controller. Requires CAP_NET_ADMIN.
+    fn restart_interface(interface: &str) -> Result<(), String> {
+        use std::process::Command;
+
+        let output = Command::new("ip")
+            .args(["link", "set", interface, "type", "can", "restart"])
+            .output()
+            .map_err(|e| format!("Failed to run ip: {}", e))?;
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            if stderr.contains("Operation not permitted") {
+                Err("restarting the interface requires CAP_NET_ADMIN".to_string())
+            } else {
+                Err(stderr.trim().to_string())
+            }
+        }
+    }
+
+    /// Respond to a bus-off error frame: leave recovery to the kernel when
+    /// `restart-ms` is set, otherwise restart the interface if enabled.
+    /// Returns None when a restart was attempted too recently to report again.
+    fn handle_bus_off(
+        interface: &str,
+        restart_on_busoff: bool,
+        last_restart: &mut Option<std::time::Instant>,
+    ) -> Option<BusOffPayload> {
+        let restart_ms = query_can_link_info(interface)
+            .map_err(|e| tlog!("[socketcan] Could not read link details of {}: {}", interface, e))
+            .ok()
+            .and_then(|info| info.restart_ms);
+
+        let (restarted, message) = match restart_ms {
+            Some(ms) if ms > 0 => (
+                false,
+                format!("{} went bus-off; the kernel will restart it after {} ms", interface, ms),
+            ),
+            _ if !restart_on_busoff => (
+                false,
+                format!(
+                    "{iface} went bus-off and no frames will be received until it is restarted. \
+                     Run `sudo ip link set {iface} type can restart`, set `restart-ms`, or enable restart on bus-off",
+                    iface = interface
+                ),
+            ),
+            _ => {
+                if last_restart.is_some_and(|t| t.elapsed() < BUSOFF_RESTART_INTERVAL) {
+                    return None;
+                }
+                *last_restart = Some(std::time::Instant::now());
+                match restart_interface(interface) {
+                    Ok(()) => (true, format!("{} went bus-off and was restarted", interface)),
+                    Err(e) => (
+                        false,
+                        format!(
+                            "{iface} went bus-off and automatic restart failed ({err}). \
+                             Run `sudo ip link set {iface} type can restart` or set `restart-ms`",
+                            iface = interface,
+                            err = e
+                        ),
+                    ),
+                }
+            }
+        };
+
+        tlog!("[socketcan] {}", message);
+        Some(BusOffPayload {
+            interface: interface.to_string(),
+            restarted,
+            message,
+        })
+    }
+
+    // ============================================================================
+    // Raw Socket Helpers (overflow detection, kernel timestamps)
+    // ============================================================================
+
+    /// Size of struct can_frame
+    const CAN_MTU: usize = 16;
+    /// Size of struct canfd_frame
+    const CANFD_MTU: usize = 72;
+
+    const CAN_EFF_FLAG: u32 = 0x8000_0000;
+    const CAN_RTR_FLAG: u32 = 0x4000_0000;
+    const CAN_ERR_FLAG: u32 = 0x2000_0000;
+    /// canfd_frame.flags bits
+    const CANFD_BRS: u8 = 0x01;
+    const CANFD_ESI: u8 = 0x02;
+
+    /// SOL_CAN_BASE + CAN_RAW
+    const SOL_CAN_RAW: libc::c_int = 101;
+    const CAN_RAW_ERR_FILTER: libc::c_int = 2;
+    /// Error frame class: controller went bus-off
+    const CAN_ERR_BUSOFF: u32 = 0x0000_0040;
+    /// Error frame class: controller restarted
+    const CAN_ERR_RESTARTED: u32 = 0x0000_0100;
+
+    /// Subscribe a raw CAN socket to the given error frame classes.
+    fn set_error_filter(fd: RawFd, mask: u32) -> std::io::Result<()> {
+        // SAFETY: `mask` outlives the call and the length matches its type.
+        let ret = unsafe {
+            libc::setsockopt(
+                fd,
+                SOL_CAN_RAW,
+                CAN_RAW_ERR_FILTER,
+                &mask as *const u32 as *const libc::c_void,
+                std::mem::size_of::<u32>() as libc::socklen_t,
+            )
+        };
+        if ret < 0 {
+            Err(std::io::Error::last_os_error())
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Error class bits of a raw error frame, or None for data/remote frames.
+    fn error_frame_class(buf: &[u8], len: usize) -> Option<u32> {
+        if len != CAN_MTU {
+            return None;
+        }
+        let can_id = u32::from_ne_bytes([buf[0], buf[1], buf[2], buf[3]]);
+        (can_id & CAN_ERR_FLAG != 0).then_some(can_id & 0x1FFF_FFFF)
+    }
+
+    /// Set an integer SOL_SOCKET option on a raw socket.
+    fn set_socket_option(fd: RawFd, name: libc::c_int, value: libc::c_int) -> std::io::Result<()> {
+        // SAFETY: `value` outlives the call and the length matches its type.
+        let ret = unsafe {
+            libc::setsockopt(
+                fd,
+                libc::SOL_SOCKET,
+                name,
+                &value as *const libc::c_int as *const libc::c_void,
+                std::mem::size_of::<libc::c_int>() as libc::socklen_t,
+            )
+        };
+        if ret < 0 {
+            Err(std::io::Error::last_os_error())
+        } else {
+            Ok(())
+        }
+    }
+
+    /// A frame received with `recvmsg` plus any ancillary data the kernel attached.
+    struct RawRecv {
+        len: usize,
+        /// Cumulative `SO_RXQ_OVFL` drop counter
+        overflow: Option<u32>,
+        /// Kernel receive time (`SO_TIMESTAMP`) in microseconds since UNIX epoch
+        timestamp_us: Option<u64>,
+    }
+
+    /// Receive one raw frame with `recvmsg`, along with the kernel's cumulative
+    /// `SO_RXQ_OVFL` drop counter and `SO_TIMESTAMP` receive time if attached.
+    fn recv_raw_frame(fd: RawFd, buf: &mut [u8; CANFD_MTU]) -> std::io::Result<RawRecv> {
+        let mut iov = libc::iovec {
+            iov_base: buf.as_mut_ptr() as *mut libc::c_void,
+            iov_len: buf.len(),
+        };
+        // u64 storage keeps the control buffer aligned for cmsghdr
+        let mut control = [0u64; 16];
+
+        // SAFETY: msghdr is plain data; all pointers reference buffers that live
+        // for the duration of the call.
+        let mut msg: libc::msghdr = unsafe { std::mem::zeroed() };
+        msg.msg_iov = &mut iov;
+        msg.msg_iovlen = 1;
+        msg.msg_control = control.as_mut_ptr() as *mut libc::c_void;
+        msg.msg_controllen = std::mem::size_of_val(&control) as _;
+
+        let n = unsafe { libc::recvmsg(fd, &mut msg, 0) };
+        if n < 0 {
+            return Err(std::io::Error::last_os_error());
+        }
+
+        let mut overflow = None;
+        let mut timestamp_us = None;
+        // SAFETY: the CMSG_* macros walk the control buffer filled in by recvmsg.
+        unsafe {
+            let mut cmsg = libc::CMSG_FIRSTHDR(&msg);
+            while !cmsg.is_null() {
+                if (*cmsg).cmsg_level == libc::SOL_SOCKET {
+                    if (*cmsg).cmsg_type == libc::SO_RXQ_OVFL {
+                        overflow = Some(std::ptr::read_unaligned(libc::CMSG_DATA(cmsg) as *const u32));
+                    } else if (*cmsg).cmsg_type == libc::SCM_TIMESTAMP {
+                        let tv = std::ptr::read_unaligned(libc::CMSG_DATA(cmsg) as *const libc::timeval);
+                        timestamp_us = Some(tv.tv_sec as u64 * 1_000_000 + tv.tv_usec as u64);
+                    }
+                }
+                cmsg = libc::CMSG_NXTHDR(&msg, cmsg);
+            }
+        }
+
+        Ok(RawRecv { len: n as usize, overflow, timestamp_us })
+    }
+
+    /// Convert a raw struct can_frame / canfd_frame to our FrameMessage format.
+    /// Remote frames keep their requested DLC and no data. Returns None for
+    /// error frames and short reads.
+    fn convert_raw_frame(buf: &[u8], len: usize) -> Option<FrameMessage> {
+        let is_fd = match len {
+            CAN_MTU => false,
+            CANFD_MTU => true,
+            _ => return None,
+        };
+
+        let can_id = u32::from_ne_bytes([buf[0], buf[1], buf[2], buf[3]]);
+        if can_id & CAN_ERR_FLAG != 0 {
+            return None;
+        }
+        let is_rtr = !is_fd && can_id & CAN_RTR_FLAG != 0;
+
+        let max_len = if is_fd { 64 } else { 8 };
+        let data_len = (buf[4] as usize).min(max_len);
+        // Classic frames carry the wire DLC in len8_dlc when it was 9-15
+        let dlc_raw = if is_fd { None } else { Some(classic_dlc_raw(data_len, buf[7])) };
+
+        Some(FrameMessage {
+            protocol: "can".to_string(),
+            timestamp_us: now_us(),
+            frame_id: can_id & 0x1FFF_FFFF,
+            bus: 0,
+            dlc: data_len as u8,
+            bytes: if is_rtr { Vec::new() } else { buf[8..8 + data_len].to_vec() },
+            is_extended: can_id & CAN_EFF_FLAG != 0,
+            is_fd,
+            source_address: None,
+            incomplete: None,
+            direction: None,
+            dlc_raw,
+            is_rtr,
+            is_brs: is_fd && buf[5] & CANFD_BRS != 0,
+            is_esi: is_fd && buf[5] & CANFD_ESI != 0,
+            original_frame_id: None,
+            source_label: None,
+        })
+    }
+
+    /// Tracks the kernel's cumulative overflow counter and yields new drops.
+    #[derive(Default)]
+    struct OverflowTracker {
+        last: Option<u32>,
+    }
+
+    impl OverflowTracker {
+        /// Record the latest counter value, returning how many frames were dropped
+        /// since the previous value. The counter is a wrapping u32.
+        fn update(&mut self, counter: u32) -> u32 {
+            let dropped = counter.wrapping_sub(self.last.unwrap_or(0));
+            self.last = Some(counter);
+            dropped
         }
     }
 
@@ -209,8 +497,9 @@ mod linux_impl {
         /// Read a frame with timeout, returns None on timeout
         pub fn read_frame_timeout(&self, _timeout: Duration) -> Result<Option<FrameMessage>, String> {
             // Note: timeout is already set in constructor, parameter kept for API compatibility
-            match self.socket.read_frame() {
-                Ok(frame) => Ok(convert_any_frame(frame, None)),
+            let mut raw = [0u8; CANFD_MTU];
+            match recv_raw_frame(self.socket.as_raw_fd(), &mut raw) {
+                Ok(RawRecv { len, .. }) => Ok(convert_raw_frame(&raw, len)),
                 Err(ref e) if e.kind() == std::io::ErrorKind::WouldBlock => Ok(None),
                 Err(ref e) if e.kind() == std::io::ErrorKind::TimedOut => Ok(None),
                 Err(e) => Err(format!("Read error: {}", e)),
@@ -331,11 +620,11 @@ mod linux_impl {
         }
 
         buf[0..4].copy_from_slice(&can_id.to_ne_bytes());
-        buf[4] = frame.data.len().min(8) as u8; // DLC
+        buf[4] = frame.classic_dlc(); // DLC
         // bytes 5-7 are padding
 
-        // Data (up to 8 bytes)
-        let len = frame.data.len().min(8);
+        // Data (up to 8 bytes; none for a remote frame)
+        let len = if frame.is_rtr { 0 } else { frame.data.len().min(8) };
         buf[8..8 + len].copy_from_slice(&frame.data[..len]);
 
         EncodedFrame::Classic(buf)
@@ -369,13 +658,21 @@ mod linux_impl {
     /// Run SocketCAN source and send frames to merge task (supports CAN FD)
     ///
     /// If `bitrate` is provided, the interface will be configured automatically
-    /// using pkexec before opening the socket.
+    /// using pkexec before opening the socket. Kernel receive-queue overflows are
+    /// detected via `SO_RXQ_OVFL` and reported as `SourceMessage::FramesDropped`.
+    /// Device timestamp sources use the kernel receive time (`SO_TIMESTAMP`).
+    /// Bus-off error frames are reported as `SourceMessage::BusOff`, restarting
+    /// the interface first when `restart_on_busoff` is set (see module docs).
+    #[allow(clippy::too_many_arguments)]
     pub async fn run_source(
         source_idx: usize,
         interface: String,
         bitrate: Option<u32>,
         enable_fd: bool,
         data_bitrate: Option<u32>,
+        rcvbuf_bytes: Option<u32>,
+        timestamp_source: TimestampSource,
+        restart_on_busoff: bool,
         bus_mappings: Vec<BusMapping>,
         stop_flag: Arc<AtomicBool>,
         tx: mpsc::Sender<SourceMessage>,
@@ -411,6 +708,36 @@ mod linux_impl {
             tlog!("[socketcan] Warning: could not set read timeout: {}", e);
         }
 
+        let fd = socket.as_raw_fd();
+
+        // Ask the kernel to attach its dropped-frame counter to each received frame
+        if let Err(e) = set_socket_option(fd, libc::SO_RXQ_OVFL, 1) {
+            tlog!("[socketcan] Warning: could not enable SO_RXQ_OVFL, drops will not be reported: {}", e);
+        }
+
+        // Kernel receive timestamps avoid the jitter of waking this thread per frame
+        let kernel_timestamps = timestamp_source.wants_device()
+            && match set_socket_option(fd, libc::SO_TIMESTAMP, 1) {
+                Ok(()) => true,
+                Err(e) => {
+                    tlog!("[socketcan] Warning: could not enable SO_TIMESTAMP, using host time: {}", e);
+                    false
+                }
+            };
+
+        // Error frames are only used to detect bus-off; convert_raw_frame drops them
+        if let Err(e) = set_error_filter(fd, CAN_ERR_BUSOFF | CAN_ERR_RESTARTED) {
+            tlog!("[socketcan] Warning: could not enable error frames, bus-off will not be detected: {}", e);
+        }
+
+        if let Some(bytes) = rcvbuf_bytes {
+            let size = bytes.min(libc::c_int::MAX as u32) as libc::c_int;
+            match set_socket_option(fd, libc::SO_RCVBUF, size) {
+                Ok(()) => tlog!("[socketcan] Set receive buffer to {} bytes on {}", bytes, interface),
+                Err(e) => tlog!("[socketcan] Warning: could not set receive buffer size: {}", e),
+            }
+        }
+
         // Create transmit channel
         let (transmit_tx, transmit_rx) = std_mpsc::sync_channel::<TransmitRequest>(32);
         let _ = tx
@@ -432,6 +759,10 @@ mod linux_impl {
         let stop_flag_clone = stop_flag.clone();
 
         let blocking_handle = tokio::task::spawn_blocking(move || {
+            let mut raw = [0u8; CANFD_MTU];
+            let mut overflow = OverflowTracker::default();
+            let mut last_restart = None;
+
             while !stop_flag_clone.load(Ordering::Relaxed) {
                 // Check for transmit requests
                 while let Ok(req) = transmit_rx.try_recv() {
@@ -439,10 +770,35 @@ mod linux_impl {
                     let _ = req.result_tx.send(result);
                 }
 
-                // Read frame (CanAnyFrame supports both classic and FD)
-                match socket.read_frame() {
-                    Ok(frame) => {
-                        if let Some(mut frame_msg) = convert_any_frame(frame, None) {
+                // Read frame (classic and FD) along with the overflow counter
+                match recv_raw_frame(fd, &mut raw) {
+                    Ok(RawRecv { len, overflow: counter, timestamp_us }) => {
+                        if let Some(counter) = counter {
+                            let dropped = overflow.update(counter);
+                            if dropped > 0 {
+                                let _ = tx_clone.blocking_send(SourceMessage::FramesDropped(
+                                    source_idx,
+                                    dropped as u64,
+                                ));
+                            }
+                        }
+                        if let Some(class) = error_frame_class(&raw, len) {
+                            if class & CAN_ERR_BUSOFF != 0 {
+                                if let Some(report) =
+                                    handle_bus_off(&interface, restart_on_busoff, &mut last_restart)
+                                {
+                                    let _ = tx_clone.blocking_send(SourceMessage::BusOff(source_idx, report));
+                                }
+                            } else if class & CAN_ERR_RESTARTED != 0 {
+                                tlog!("[socketcan] {} controller restarted", interface);
+                            }
+                        }
+                        if let Some(mut frame_msg) = convert_raw_frame(&raw, len) {
+                            if kernel_timestamps {
+                                if let Some(ts) = timestamp_us {
+                                    frame_msg.timestamp_us = ts;
+                                }
+                            }
                             if apply_bus_mapping(&mut frame_msg, &bus_mappings) {
                                 let _ = tx_clone
                                     .blocking_send(SourceMessage::Frames(source_idx, vec![frame_msg]));
@@ -527,6 +883,101 @@ mod linux_impl {
             Err("Frame data too short".to_string())
         }
     }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_convert_raw_classic_frame() {
+            let mut raw = [0u8; CANFD_MTU];
+            raw[0..4].copy_from_slice(&(0x123u32).to_ne_bytes());
+            raw[4] = 2;
+            raw[8] = 0xAA;
+            raw[9] = 0xBB;
+
+            let frame = convert_raw_frame(&raw, CAN_MTU).unwrap();
+            assert_eq!(frame.frame_id, 0x123);
+            assert_eq!(frame.bytes, vec![0xAA, 0xBB]);
+            assert!(!frame.is_extended);
+            assert!(!frame.is_fd);
+        }
+
+        #[test]
+        fn test_convert_raw_fd_extended_frame() {
+            let mut raw = [0u8; CANFD_MTU];
+            raw[0..4].copy_from_slice(&(0x1234_5678u32 | CAN_EFF_FLAG).to_ne_bytes());
+            raw[4] = 12;
+            raw[5] = CANFD_BRS;
+
+            let frame = convert_raw_frame(&raw, CANFD_MTU).unwrap();
+            assert_eq!(frame.frame_id, 0x1234_5678);
+            assert_eq!(frame.bytes.len(), 12);
+            assert!(frame.is_extended);
+            assert!(frame.is_fd);
+            assert!(frame.is_brs);
+            assert!(!frame.is_esi);
+        }
+
+        #[test]
+        fn test_convert_raw_keeps_remote_frames() {
+            let mut raw = [0u8; CANFD_MTU];
+            raw[0..4].copy_from_slice(&(0x100u32 | CAN_RTR_FLAG).to_ne_bytes());
+            raw[4] = 4;
+            raw[8] = 0xAA;
+            let frame = convert_raw_frame(&raw, CAN_MTU).unwrap();
+            assert!(frame.is_rtr);
+            assert_eq!(frame.frame_id, 0x100);
+            assert_eq!(frame.dlc, 4);
+            assert!(frame.bytes.is_empty());
+        }
+
+        #[test]
+        fn test_convert_raw_skips_error_frames_and_short_reads() {
+            let mut raw = [0u8; CANFD_MTU];
+            raw[0..4].copy_from_slice(&CAN_ERR_FLAG.to_ne_bytes());
+            assert!(convert_raw_frame(&raw, CAN_MTU).is_none());
+
+            raw[0..4].copy_from_slice(&(0x100u32).to_ne_bytes());
+            assert!(convert_raw_frame(&raw, 8).is_none());
+        }
+
+        #[test]
+        fn test_overflow_tracker() {
+            let mut tracker = OverflowTracker::default();
+            assert_eq!(tracker.update(0), 0);
+            assert_eq!(tracker.update(5), 5);
+            assert_eq!(tracker.update(5), 0);
+            assert_eq!(tracker.update(12), 7);
+            // Counter wraps
+            let mut tracker = OverflowTracker { last: Some(u32::MAX - 1) };
+            assert_eq!(tracker.update(2), 4);
+        }
+
+        #[test]
+        fn test_error_frame_class() {
+            let mut raw = [0u8; CANFD_MTU];
+            raw[0..4].copy_from_slice(&(CAN_ERR_FLAG | CAN_ERR_BUSOFF).to_ne_bytes());
+            assert_eq!(error_frame_class(&raw, CAN_MTU), Some(CAN_ERR_BUSOFF));
+
+            raw[0..4].copy_from_slice(&0x123u32.to_ne_bytes());
+            assert_eq!(error_frame_class(&raw, CAN_MTU), None);
+        }
+
+        #[test]
+        fn test_parse_can_link_info() {
+            let json = r#"[{"ifname":"can0","operstate":"UP","linkinfo":{"info_kind":"can",
+                "info_data":{"ctrlmode":[],"state":"BUS-OFF","restart_ms":0}}}]"#;
+            assert_eq!(
+                parse_can_link_info(json),
+                Some(CanLinkInfo { restart_ms: Some(0) })
+            );
+
+            // Not a CAN link
+            let json = r#"[{"ifname":"lo","operstate":"UNKNOWN"}]"#;
+            assert_eq!(parse_can_link_info(json), None);
+        }
+    }
 }
 
 // Re-export for Linux
@@ -549,7 +1000,7 @@ mod stub {
 
     use crate::io::gvret::BusMapping;
     use crate::io::types::SourceMessage;
-    use crate::io::CanTransmitFrame;
+    use crate::io::{CanTransmitFrame, TimestampSource};
 
     /// SocketCAN configuration (stub for non-Linux)
     #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -565,6 +1016,10 @@ mod stub {
         pub enable_fd: bool,
         #[serde(default)]
         pub data_bitrate: Option<u32>,
+        #[serde(default)]
+        pub rcvbuf_bytes: Option<u32>,
+        #[serde(default)]
+        pub restart_on_busoff: bool,
     }
 
     /// Encoded frame result - either classic CAN (16 bytes) or CAN FD (72 bytes)
@@ -593,12 +1048,16 @@ mod stub {
     }
 
     /// Stub run_source for non-Linux
+    #[allow(clippy::too_many_arguments)]
     pub async fn run_source(
         source_idx: usize,
         _interface: String,
         _bitrate: Option<u32>,
         _enable_fd: bool,
         _data_bitrate: Option<u32>,
+        _rcvbuf_bytes: Option<u32>,
+        _timestamp_source: TimestampSource,
+        _restart_on_busoff: bool,
         _bus_mappings: Vec<BusMapping>,
         _stop_flag: Arc<AtomicBool>,
         tx: mpsc::Sender<SourceMessage>,
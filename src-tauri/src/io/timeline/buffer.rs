@@ -5,15 +5,19 @@
 // Reads frames in chunks from SQLite instead of loading everything into memory.
 
 use async_trait::async_trait;
+use std::collections::{HashMap, HashSet};
 use std::sync::{
     atomic::{AtomicBool, AtomicI64, Ordering},
-    Arc,
+    Arc, RwLock,
 };
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tauri::AppHandle;
 
 use super::base::{TimelineControl, TimelineReaderState};
-use crate::io::{emit_frames, emit_to_session, FrameMessage, IOCapabilities, IODevice, IOState, PlaybackPosition};
+use crate::io::{
+    emit_frames, emit_snapshot_frames, emit_to_session, FrameMessage, IOCapabilities, IODevice, IOState,
+    PlaybackPosition, PlaybackRange,
+};
 use crate::{buffer_db, buffer_store};
 
 /// Sentinel value meaning "no seek requested"
@@ -21,6 +25,154 @@ const NO_SEEK: i64 = i64::MIN;
 /// Sentinel value meaning "no frame seek requested"
 const NO_SEEK_FRAME: i64 = -1;
 
+/// Buses whose frames are skipped during playback, shared with the stream task
+type DisabledBuses = Arc<RwLock<HashSet<u8>>>;
+
+/// Whether frames on `bus` are currently skipped
+fn is_bus_disabled(disabled_buses: &DisabledBuses, bus: u8) -> bool {
+    disabled_buses.read().map(|d| d.contains(&bus)).unwrap_or(false)
+}
+
+/// Collapse a batch to the latest frame per ID (bus, frame_id, extended),
+/// keeping each ID at the position it first appeared in the batch
+fn coalesce_latest_per_id(frames: Vec<FrameMessage>) -> Vec<FrameMessage> {
+    let mut slots: HashMap<(u8, u32, bool), usize> = HashMap::new();
+    let mut out: Vec<FrameMessage> = Vec::with_capacity(frames.len());
+    for frame in frames {
+        let key = (frame.bus, frame.frame_id, frame.is_extended);
+        match slots.get(&key) {
+            Some(&i) => out[i] = frame,
+            None => {
+                slots.insert(key, out.len());
+                out.push(frame);
+            }
+        }
+    }
+    out
+}
+
+/// Caps how often the stream emits frames to the frontend (`max_fps`).
+/// Frames emitted within one interval are held and coalesced to the latest
+/// frame per ID; pacing and `playback-time` are unaffected.
+struct EmitThrottle {
+    /// Minimum time between frame emits, or None when unthrottled
+    interval: Option<Duration>,
+    pending: Vec<FrameMessage>,
+    last_emit: Option<Instant>,
+}
+
+impl EmitThrottle {
+    fn new(max_fps: Option<f64>) -> Self {
+        Self {
+            interval: max_fps
+                .filter(|fps| fps.is_finite() && *fps > 0.0)
+                .map(|fps| Duration::from_secs_f64(1.0 / fps)),
+            pending: Vec::new(),
+            last_emit: None,
+        }
+    }
+
+    /// Queue frames for emission. Returns the batch to emit now, if the
+    /// interval has elapsed (or immediately when unthrottled).
+    fn push(&mut self, frames: Vec<FrameMessage>) -> Option<Vec<FrameMessage>> {
+        let Some(interval) = self.interval else {
+            return Some(frames);
+        };
+        self.pending.extend(frames);
+        if self.last_emit.is_some_and(|t| t.elapsed() < interval) {
+            return None;
+        }
+        self.take()
+    }
+
+    /// Time until held frames are due, or None if nothing is held
+    fn time_until_due(&self) -> Option<Duration> {
+        let interval = self.interval?;
+        if self.pending.is_empty() {
+            return None;
+        }
+        Some(self.last_emit.map_or(Duration::ZERO, |t| interval.saturating_sub(t.elapsed())))
+    }
+
+    /// Take the held frames, coalesced, regardless of the interval
+    fn take(&mut self) -> Option<Vec<FrameMessage>> {
+        if self.pending.is_empty() {
+            return None;
+        }
+        self.last_emit = Some(Instant::now());
+        Some(coalesce_latest_per_id(std::mem::take(&mut self.pending)))
+    }
+
+    /// Emit frames through the throttle
+    fn emit(&mut self, app: &AppHandle, session_id: &str, frames: Vec<FrameMessage>) {
+        if let Some(batch) = self.push(frames) {
+            emit_frames(app, session_id, batch);
+        }
+    }
+
+    /// Emit any held frames now (on pause, seek and end of data)
+    fn flush(&mut self, app: &AppHandle, session_id: &str) {
+        if let Some(batch) = self.take() {
+            emit_frames(app, session_id, batch);
+        }
+    }
+}
+
+/// The frame whose average period anchors reference pacing
+#[derive(Clone, Copy, Debug)]
+pub struct PacingReference {
+    pub frame_id: u32,
+    /// Only count the reference frame on this bus (None = any bus)
+    pub bus: Option<u8>,
+    pub is_extended: bool,
+}
+
+/// Alternative pacing clock that steadies replay on a reference frame ID.
+/// Capture time is remapped so consecutive reference frames play exactly one
+/// average period apart, removing capture jitter from that ID; frames between
+/// two reference frames keep their relative position in the interval. Before
+/// the first and after the last reference frame, capture time is used as is.
+struct ReferencePacing {
+    /// Capture timestamps of the reference frames, ascending and distinct
+    anchors: Vec<u64>,
+    /// Average reference period over the capture (microseconds)
+    period_us: f64,
+}
+
+impl ReferencePacing {
+    /// Returns None when the reference ID appears at fewer than two distinct times
+    fn new(mut anchors: Vec<u64>) -> Option<Self> {
+        anchors.sort_unstable();
+        anchors.dedup();
+        if anchors.len() < 2 {
+            return None;
+        }
+        let span_us = (anchors[anchors.len() - 1] - anchors[0]) as f64;
+        let period_us = span_us / (anchors.len() - 1) as f64;
+        Some(Self { anchors, period_us })
+    }
+
+    /// Pacing time (seconds) of a frame captured at `timestamp_us`
+    fn pacing_secs(&self, timestamp_us: u64) -> f64 {
+        let first = self.anchors[0];
+        let last = self.anchors[self.anchors.len() - 1];
+        if timestamp_us <= first || timestamp_us >= last {
+            return timestamp_us as f64 / 1_000_000.0;
+        }
+        // Reference interval [start, end) holding the frame
+        let k = self.anchors.partition_point(|&t| t <= timestamp_us) - 1;
+        let (start, end) = (self.anchors[k], self.anchors[k + 1]);
+        let fraction = (timestamp_us - start) as f64 / (end - start) as f64;
+        (first as f64 + (k as f64 + fraction) * self.period_us) / 1_000_000.0
+    }
+}
+
+/// Time (seconds) a frame is paced at: its capture time, or the reference
+/// pacing clock when one is selected
+fn pacing_secs(reference: Option<&ReferencePacing>, timestamp_us: u64) -> f64 {
+    reference.map_or(timestamp_us as f64 / 1_000_000.0, |r| r.pacing_secs(timestamp_us))
+}
+
 /// Buffer Reader - streams frames from the SQLite-backed buffer store
 pub struct BufferReader {
     app: AppHandle,
@@ -37,6 +189,21 @@ pub struct BufferReader {
     buffer_id: Option<String>,
     /// Available buses in this buffer (from metadata)
     buses: Vec<u8>,
+    /// Buses toggled off with `set_bus_enabled`
+    disabled_buses: DisabledBuses,
+    /// Enter `Paused` on start instead of playing
+    start_paused: bool,
+    /// Position (microseconds) to start from instead of the first frame
+    start_at_us: Option<i64>,
+    /// Cap on frame emits per second to the frontend (None = every batch)
+    max_fps: Option<f64>,
+    /// Frame whose average period anchors pacing (None = capture timing)
+    reference_pacing: Option<PacingReference>,
+    /// Report positions as original time of day using the buffer's time base
+    absolute_time: bool,
+    /// Added to buffer timestamps in `playback-time`/`playback-range`, and
+    /// subtracted from seek targets (set on start)
+    time_offset_us: i64,
 }
 
 impl BufferReader {
@@ -62,6 +229,13 @@ impl BufferReader {
             completed_flag: Arc::new(AtomicBool::new(false)),
             buffer_id,
             buses,
+            disabled_buses: Arc::new(RwLock::new(HashSet::new())),
+            start_paused: false,
+            start_at_us: None,
+            max_fps: None,
+            reference_pacing: None,
+            absolute_time: false,
+            time_offset_us: 0,
         }
     }
 
@@ -81,8 +255,50 @@ impl BufferReader {
             completed_flag: Arc::new(AtomicBool::new(false)),
             buffer_id: Some(buffer_id),
             buses,
+            disabled_buses: Arc::new(RwLock::new(HashSet::new())),
+            start_paused: false,
+            start_at_us: None,
+            max_fps: None,
+            reference_pacing: None,
+            absolute_time: false,
+            time_offset_us: 0,
         }
     }
+
+    /// Start paused and/or at a given timestamp instead of playing from the first frame.
+    /// When paused, the snapshot at the start position is emitted so the UI shows
+    /// the state of the bus there before playback begins.
+    pub fn with_start_position(mut self, start_paused: bool, start_at_us: Option<i64>) -> Self {
+        self.start_paused = start_paused;
+        self.start_at_us = start_at_us;
+        self
+    }
+
+    /// Limit frame emits to at most `max_fps` per second, for smooth display on
+    /// slower machines. Within each interval only the latest frame per ID is
+    /// emitted; playback timing is unchanged (unlike `set_speed`).
+    pub fn with_max_fps(mut self, max_fps: Option<f64>) -> Self {
+        self.max_fps = max_fps;
+        self
+    }
+
+    /// Pace playback so the `reference` frame keeps its average captured
+    /// period even where the capture had jitter. Other frames are timed
+    /// relative to the surrounding reference frames; emitted timestamps are
+    /// unchanged.
+    pub fn with_reference_pacing(mut self, reference: Option<PacingReference>) -> Self {
+        self.reference_pacing = reference;
+        self
+    }
+
+    /// Report `playback-time` and `playback-range` as the original absolute
+    /// time (microseconds since the Unix epoch) for buffers whose timestamps
+    /// were rebased, using the buffer's `time_base_us`. Seek targets are taken
+    /// on the same scale. Buffers without a time base are unaffected.
+    pub fn with_absolute_time(mut self, absolute_time: bool) -> Self {
+        self.absolute_time = absolute_time;
+        self
+    }
 }
 
 #[async_trait]
@@ -109,6 +325,26 @@ impl IODevice for BufferReader {
 
         self.reader_state.prepare_start();
 
+        self.time_offset_us = if self.absolute_time {
+            resolve_buffer_id(&self.buffer_id)
+                .and_then(|id| buffer_store::get_buffer_metadata(&id))
+                .and_then(|m| m.time_base_us)
+                .unwrap_or(0) as i64
+        } else {
+            0
+        };
+
+        // Queue the initial position before the stream task starts so it's applied
+        // (and the snapshot emitted, if paused) before any frame is played
+        if self.start_paused {
+            self.reader_state.control.pause();
+        }
+        if let Some(ts) = self.start_at_us {
+            self.seek_target_us.store((ts - self.time_offset_us).max(0), Ordering::Relaxed);
+        } else if self.start_paused {
+            self.seek_target_frame.store(0, Ordering::Relaxed);
+        }
+
         let app = self.app.clone();
         let session_id = self.reader_state.session_id.clone();
         let control = self.reader_state.control.clone();
@@ -116,9 +352,16 @@ impl IODevice for BufferReader {
         let seek_target_frame = self.seek_target_frame.clone();
         let completed_flag = self.completed_flag.clone();
         let buffer_id = self.buffer_id.clone();
+        let disabled_buses = self.disabled_buses.clone();
+        let throttle = EmitThrottle::new(self.max_fps);
+        let reference_pacing = self.reference_pacing;
+        let time_offset_us = self.time_offset_us;
 
-        let handle = spawn_buffer_stream(app, session_id, control, seek_target_us, seek_target_frame, completed_flag, buffer_id);
+        let handle = spawn_buffer_stream(app, session_id, control, seek_target_us, seek_target_frame, completed_flag, buffer_id, disabled_buses, throttle, reference_pacing, time_offset_us);
         self.reader_state.mark_running(handle);
+        if self.start_paused {
+            self.reader_state.state = IOState::Paused;
+        }
 
         Ok(())
     }
@@ -160,7 +403,7 @@ impl IODevice for BufferReader {
             "[Buffer:{}] Seek requested to {}us",
             self.reader_state.session_id, timestamp_us
         );
-        self.seek_target_us.store(timestamp_us, Ordering::Relaxed);
+        self.seek_target_us.store((timestamp_us - self.time_offset_us).max(0), Ordering::Relaxed);
         Ok(())
     }
 
@@ -183,6 +426,28 @@ impl IODevice for BufferReader {
         Ok(())
     }
 
+    fn set_bus_enabled(&mut self, bus: u8, enabled: bool) -> Result<(), String> {
+        if !self.buses.is_empty() && !self.buses.contains(&bus) {
+            return Err(format!("Bus {} is not present in this buffer", bus));
+        }
+        tlog!(
+            "[Buffer:{}] Bus {} {}",
+            self.reader_state.session_id,
+            bus,
+            if enabled { "enabled" } else { "disabled" }
+        );
+        let mut disabled = self
+            .disabled_buses
+            .write()
+            .map_err(|e| format!("Bus enable state poisoned: {}", e))?;
+        if enabled {
+            disabled.remove(&bus);
+        } else {
+            disabled.insert(bus);
+        }
+        Ok(())
+    }
+
     fn state(&self) -> IOState {
         // If stream completed naturally, report as paused (stream stays alive at end position)
         if self.completed_flag.load(Ordering::Relaxed) {
@@ -289,7 +554,62 @@ pub fn step_frame(
     }
 }
 
+/// Most frames `emit_buffer_range` sends in one call
+pub const EMIT_RANGE_MAX_FRAMES: usize = 50_000;
+/// Frames per `frame-message` batch when emitting a range
+const EMIT_RANGE_BATCH: usize = 5_000;
+
+/// Result of `emit_buffer_range`
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct EmitRangeResult {
+    /// Frames emitted
+    pub emitted: usize,
+    /// Whether the range held more frames than were emitted
+    pub truncated: bool,
+}
+
+/// Emit the frames in `[start_us, end_us]` immediately, in a few batches, to
+/// populate a view after a jump instead of waiting for paced replay.
+///
+/// Independent of the replay engine: the playback position and state are left
+/// alone, so a paused stream stays paused at its position and a playing stream
+/// carries on from where it was (its next frames follow the range's). Callers
+/// wanting a still view pause first. `max_frames` is capped at
+/// `EMIT_RANGE_MAX_FRAMES`; the earliest frames of the range are kept.
+pub fn emit_buffer_range(
+    app: &AppHandle,
+    session_id: &str,
+    start_us: u64,
+    end_us: u64,
+    max_frames: Option<usize>,
+) -> Result<EmitRangeResult, String> {
+    if end_us < start_us {
+        return Err("Range end is before its start".to_string());
+    }
+    let buf_id = buffer_store::find_frame_buffer_id()
+        .ok_or_else(|| "No frame buffer found".to_string())?;
+    let limit = max_frames.unwrap_or(EMIT_RANGE_MAX_FRAMES).min(EMIT_RANGE_MAX_FRAMES);
+
+    // One extra row tells whether the range was cut short
+    let mut frames = buffer_db::get_frames_in_range(&buf_id, start_us, end_us, limit + 1)?;
+    let truncated = frames.len() > limit;
+    frames.truncate(limit);
+    let emitted = frames.len();
+
+    tlog!(
+        "[Buffer:{}] Emitting {} frame(s) in {}..={}us{}",
+        session_id, emitted, start_us, end_us, if truncated { " (truncated)" } else { "" }
+    );
+    let mut frames = frames.into_iter().peekable();
+    while frames.peek().is_some() {
+        emit_frames(app, session_id, frames.by_ref().take(EMIT_RANGE_BATCH).collect());
+    }
+
+    Ok(EmitRangeResult { emitted, truncated })
+}
+
 /// Spawn a buffer reader task
+#[allow(clippy::too_many_arguments)]
 fn spawn_buffer_stream(
     app_handle: AppHandle,
     session_id: String,
@@ -298,9 +618,13 @@ fn spawn_buffer_stream(
     seek_target_frame: Arc<AtomicI64>,
     completed_flag: Arc<AtomicBool>,
     buffer_id: Option<String>,
+    disabled_buses: DisabledBuses,
+    throttle: EmitThrottle,
+    reference_pacing: Option<PacingReference>,
+    time_offset_us: i64,
 ) -> tauri::async_runtime::JoinHandle<()> {
     tauri::async_runtime::spawn(async move {
-        run_buffer_stream(app_handle, session_id, control, seek_target_us, seek_target_frame, completed_flag, buffer_id).await;
+        run_buffer_stream(app_handle, session_id, control, seek_target_us, seek_target_frame, completed_flag, buffer_id, disabled_buses, throttle, reference_pacing, time_offset_us).await;
     })
 }
 
@@ -335,17 +659,22 @@ fn handle_seek(
     session_id: &str,
     buf_id: &str,
     total_frames: usize,
+    playback_range: &PlaybackRange,
     seek_target_frame: &AtomicI64,
     seek_target_us: &AtomicI64,
     control: &TimelineControl,
+    disabled_buses: &DisabledBuses,
     chunk: &mut Vec<(i64, FrameMessage)>,
     chunk_idx: &mut usize,
     frame_index: &mut usize,
     last_consumed_rowid: &mut i64,
     batch_buffer: &mut Vec<FrameMessage>,
+    throttle: &mut EmitThrottle,
     playback_baseline_secs: &mut f64,
     wall_clock_baseline: &mut std::time::Instant,
     last_frame_time_secs: &mut Option<f64>,
+    reference_pacing: Option<&ReferencePacing>,
+    time_offset_us: i64,
 ) -> bool {
     // Check for frame-based seek (takes priority)
     let seek_frame = seek_target_frame.load(Ordering::Relaxed);
@@ -372,32 +701,35 @@ fn handle_seek(
 
             // Flush pending batch
             if !batch_buffer.is_empty() {
-                emit_to_session(app_handle, "frame-message", session_id, batch_buffer.clone());
+                throttle.emit(app_handle, session_id, batch_buffer.clone());
                 batch_buffer.clear();
             }
+            throttle.flush(app_handle, session_id);
 
             // Reset timing baselines
-            let seek_time_secs = frame.timestamp_us as f64 / 1_000_000.0;
+            let seek_time_secs = pacing_secs(reference_pacing, frame.timestamp_us);
             *playback_baseline_secs = seek_time_secs;
             *wall_clock_baseline = std::time::Instant::now();
             *last_frame_time_secs = None;
 
+            emit_to_session(app_handle, "playback-range", session_id, playback_range.clone());
             emit_to_session(app_handle, "playback-time", session_id, PlaybackPosition {
-                timestamp_us: frame.timestamp_us as i64,
+                timestamp_us: frame.timestamp_us as i64 + time_offset_us,
                 frame_index: target_idx,
                 frame_count: Some(total_frames),
             });
 
             // When paused, emit a snapshot of the most recent frame for each frame ID
             if is_paused {
-                let min_ts = frame.timestamp_us.saturating_sub(120_000_000);
-                if let Ok(snapshot) = buffer_db::build_snapshot(buf_id, rowid, min_ts) {
+                let min_ts = frame.timestamp_us.saturating_sub(buffer_db::SNAPSHOT_LOOKBACK_US);
+                if let Ok(mut snapshot) = buffer_db::build_snapshot(buf_id, rowid, min_ts) {
+                    snapshot.retain(|f| !is_bus_disabled(disabled_buses, f.bus));
                     if !snapshot.is_empty() {
                         tlog!(
                             "[Buffer:{}] Emitting snapshot of {} unique frames at seek position",
                             session_id, snapshot.len()
                         );
-                        emit_frames(app_handle, session_id, snapshot);
+                        emit_snapshot_frames(app_handle, session_id, snapshot);
                     }
                 }
             }
@@ -433,32 +765,35 @@ fn handle_seek(
 
             // Flush pending batch
             if !batch_buffer.is_empty() {
-                emit_to_session(app_handle, "frame-message", session_id, batch_buffer.clone());
+                throttle.emit(app_handle, session_id, batch_buffer.clone());
                 batch_buffer.clear();
             }
+            throttle.flush(app_handle, session_id);
 
             // Get frame at this rowid for timing info
             if let Some((_, ref frame)) = chunk.first() {
-                let seek_time_secs = frame.timestamp_us as f64 / 1_000_000.0;
+                let seek_time_secs = pacing_secs(reference_pacing, frame.timestamp_us);
                 *playback_baseline_secs = seek_time_secs;
                 *wall_clock_baseline = std::time::Instant::now();
                 *last_frame_time_secs = None;
 
+                emit_to_session(app_handle, "playback-range", session_id, playback_range.clone());
                 emit_to_session(app_handle, "playback-time", session_id, PlaybackPosition {
-                    timestamp_us: frame.timestamp_us as i64,
+                    timestamp_us: frame.timestamp_us as i64 + time_offset_us,
                     frame_index: target_idx,
                     frame_count: Some(total_frames),
                 });
 
                 if is_paused {
-                    let min_ts = frame.timestamp_us.saturating_sub(120_000_000);
-                    if let Ok(snapshot) = buffer_db::build_snapshot(buf_id, rowid, min_ts) {
+                    let min_ts = frame.timestamp_us.saturating_sub(buffer_db::SNAPSHOT_LOOKBACK_US);
+                    if let Ok(mut snapshot) = buffer_db::build_snapshot(buf_id, rowid, min_ts) {
+                        snapshot.retain(|f| !is_bus_disabled(disabled_buses, f.bus));
                         if !snapshot.is_empty() {
                             tlog!(
                                 "[Buffer:{}] Emitting snapshot of {} unique frames at seek position",
                                 session_id, snapshot.len()
                             );
-                            emit_frames(app_handle, session_id, snapshot);
+                            emit_snapshot_frames(app_handle, session_id, snapshot);
                         }
                     }
                 }
@@ -471,6 +806,7 @@ fn handle_seek(
     false
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn run_buffer_stream(
     app_handle: AppHandle,
     session_id: String,
@@ -479,6 +815,10 @@ async fn run_buffer_stream(
     seek_target_frame: Arc<AtomicI64>,
     completed_flag: Arc<AtomicBool>,
     buffer_id: Option<String>,
+    disabled_buses: DisabledBuses,
+    mut throttle: EmitThrottle,
+    reference: Option<PacingReference>,
+    time_offset_us: i64,
 ) {
     // Resolve which buffer to read from
     let buf_id = match resolve_buffer_id(&buffer_id) {
@@ -518,6 +858,23 @@ async fn run_buffer_stream(
         }
     };
 
+    let reference_pacing = reference.and_then(|r| {
+        let timestamps =
+            buffer_db::get_frame_timestamps(&buf_id, r.frame_id, r.bus, r.is_extended).unwrap_or_default();
+        let pacing = ReferencePacing::new(timestamps);
+        match &pacing {
+            Some(p) => tlog!(
+                "[Buffer:{}] Pacing on reference ID 0x{:X} (bus {:?}, average period {:.3}ms)",
+                session_id, r.frame_id, r.bus, p.period_us / 1000.0
+            ),
+            None => tlog!(
+                "[Buffer:{}] Reference ID 0x{:X} (bus {:?}) appears fewer than twice, using capture timing",
+                session_id, r.frame_id, r.bus
+            ),
+        }
+        pacing
+    });
+
     let metadata = buffer_store::get_metadata();
     let initial_speed = control.read_speed();
     let initial_pacing = control.is_pacing_enabled();
@@ -554,7 +911,30 @@ async fn run_buffer_stream(
     }
 
     // Get stream start time from first frame
-    let stream_start_secs = chunk[0].1.timestamp_us as f64 / 1_000_000.0;
+    let stream_start_secs = pacing_secs(reference_pacing.as_ref(), chunk[0].1.timestamp_us);
+
+    // Overall range for the UI scrubber; metadata may lack times for older buffers
+    let range_meta = buffer_store::get_buffer_metadata(&buf_id);
+    let playback_range = PlaybackRange {
+        start_us: range_meta
+            .as_ref()
+            .and_then(|m| m.start_time_us)
+            .unwrap_or(chunk[0].1.timestamp_us) as i64
+            + time_offset_us,
+        end_us: range_meta
+            .as_ref()
+            .and_then(|m| m.end_time_us)
+            .or_else(|| {
+                buffer_db::get_frame_at_index(&buf_id, total_frames - 1)
+                    .ok()
+                    .flatten()
+                    .map(|(_, f)| f.timestamp_us)
+            })
+            .unwrap_or(chunk[0].1.timestamp_us) as i64
+            + time_offset_us,
+        total_frames,
+    };
+    emit_to_session(&app_handle, "playback-range", &session_id, playback_range.clone());
 
     let mut last_frame_time_secs: Option<f64> = None;
     let mut batch_buffer: Vec<FrameMessage> = Vec::new();
@@ -584,17 +964,18 @@ async fn run_buffer_stream(
 
         // Handle seek requests (frame-based and timestamp-based)
         if handle_seek(
-            &app_handle, &session_id, &buf_id, total_frames,
-            &seek_target_frame, &seek_target_us, &control,
+            &app_handle, &session_id, &buf_id, total_frames, &playback_range,
+            &seek_target_frame, &seek_target_us, &control, &disabled_buses,
             &mut chunk, &mut chunk_idx, &mut frame_index, &mut last_consumed_rowid,
-            &mut batch_buffer, &mut playback_baseline_secs, &mut wall_clock_baseline,
-            &mut last_frame_time_secs,
+            &mut batch_buffer, &mut throttle, &mut playback_baseline_secs, &mut wall_clock_baseline,
+            &mut last_frame_time_secs, reference_pacing.as_ref(), time_offset_us,
         ) {
             continue;
         }
 
         // Check if paused (after seek check so seek works while paused)
         if control.is_paused() {
+            throttle.flush(&app_handle, &session_id);
             tokio::time::sleep(Duration::from_millis(50)).await;
             continue;
         }
@@ -669,6 +1050,12 @@ async fn run_buffer_stream(
             frame_index += 1;
         }
 
+        // Skip frames on disabled buses before pacing, so the enabled buses keep
+        // their own inter-frame timing (delays run from the last frame played)
+        if is_bus_disabled(&disabled_buses, frame.bus) {
+            continue;
+        }
+
         let is_pacing = control.is_pacing_enabled();
         let current_speed = control.read_speed();
 
@@ -698,8 +1085,10 @@ async fn run_buffer_stream(
             }
         }
 
-        let frame_time_secs = frame.timestamp_us as f64 / 1_000_000.0;
-        let playback_time_us = (frame_time_secs * 1_000_000.0) as i64;
+        // Pacing runs on frame_time_secs; playback-time reports the captured
+        // timestamp (on the absolute scale when a time offset is set)
+        let frame_time_secs = pacing_secs(reference_pacing.as_ref(), frame.timestamp_us);
+        let playback_time_us = frame.timestamp_us as i64 + time_offset_us;
 
         // When pacing is disabled, use maximum batch size
         if !is_pacing {
@@ -708,7 +1097,7 @@ async fn run_buffer_stream(
             last_frame_time_secs = Some(frame_time_secs);
 
             if batch_buffer.len() >= NO_LIMIT_BATCH_SIZE {
-                emit_frames(&app_handle, &session_id, batch_buffer.clone());
+                throttle.emit(&app_handle, &session_id, batch_buffer.clone());
                 batch_buffer.clear();
 
                 emit_to_session(&app_handle, "playback-time", &session_id, PlaybackPosition {
@@ -759,7 +1148,7 @@ async fn run_buffer_stream(
 
                 last_pacing_check = std::time::Instant::now();
 
-                emit_frames(&app_handle, &session_id, batch_buffer.clone());
+                throttle.emit(&app_handle, &session_id, batch_buffer.clone());
                 batch_buffer.clear();
 
                 emit_to_session(&app_handle, "playback-time", &session_id, PlaybackPosition {
@@ -773,7 +1162,7 @@ async fn run_buffer_stream(
         } else {
             // Normal speed: emit any pending batch first
             if !batch_buffer.is_empty() {
-                emit_frames(&app_handle, &session_id, batch_buffer.clone());
+                throttle.emit(&app_handle, &session_id, batch_buffer.clone());
                 batch_buffer.clear();
             }
 
@@ -782,7 +1171,14 @@ async fn run_buffer_stream(
             if capped_delay_ms >= 1.0 {
                 total_wait_ms += capped_delay_ms as u64;
                 wait_count += 1;
-                tokio::time::sleep(Duration::from_millis(capped_delay_ms as u64)).await;
+                let mut delay = Duration::from_millis(capped_delay_ms as u64);
+                // Don't hold throttled frames through a long gap: emit them when due
+                if let Some(due) = throttle.time_until_due().filter(|due| *due < delay) {
+                    tokio::time::sleep(due).await;
+                    throttle.flush(&app_handle, &session_id);
+                    delay -= due;
+                }
+                tokio::time::sleep(delay).await;
             }
 
             // Re-check pause after sleeping
@@ -794,7 +1190,7 @@ async fn run_buffer_stream(
             }
 
             // Emit single frame
-            emit_frames(&app_handle, &session_id, vec![frame]);
+            throttle.emit(&app_handle, &session_id, vec![frame]);
             total_emitted += 1;
 
             emit_to_session(&app_handle, "playback-time", &session_id, PlaybackPosition {
@@ -806,25 +1202,27 @@ async fn run_buffer_stream(
     }
 
     // Emit any remaining frames in batch buffer
-    if !batch_buffer.is_empty() {
-        emit_frames(&app_handle, &session_id, batch_buffer.clone());
+    if let Some(last_frame) = batch_buffer.last() {
+        let last_timestamp_us = last_frame.timestamp_us;
+        throttle.emit(&app_handle, &session_id, batch_buffer.clone());
         batch_buffer.clear();
+        throttle.flush(&app_handle, &session_id);
 
         // Emit final position so frontend highlights the last frame.
         // Forward: frame_index is one-past-end (post-increment), subtract 1.
         // Reverse: frame_index IS the last consumed position (pre-decrement), use directly.
-        if let Some(last_time) = last_frame_time_secs {
-            let is_reverse = control.is_reverse();
-            let final_index = if is_reverse { frame_index } else { frame_index.saturating_sub(1) };
-            let playback_time_us = (last_time * 1_000_000.0) as i64;
-            emit_to_session(&app_handle, "playback-time", &session_id, PlaybackPosition {
-                timestamp_us: playback_time_us,
-                frame_index: final_index,
-                frame_count: Some(total_frames),
-            });
-        }
+        let is_reverse = control.is_reverse();
+        let final_index = if is_reverse { frame_index } else { frame_index.saturating_sub(1) };
+        emit_to_session(&app_handle, "playback-time", &session_id, PlaybackPosition {
+            timestamp_us: last_timestamp_us as i64 + time_offset_us,
+            frame_index: final_index,
+            frame_count: Some(total_frames),
+        });
     }
 
+    // Release frames the throttle is still holding (e.g. the last paced frame)
+    throttle.flush(&app_handle, &session_id);
+
     // If cancelled, exit the outer loop entirely
     if control.is_cancelled() {
         break;
@@ -849,11 +1247,11 @@ async fn run_buffer_stream(
 
         // Handle seek requests while paused at end
         if handle_seek(
-            &app_handle, &session_id, &buf_id, total_frames,
-            &seek_target_frame, &seek_target_us, &control,
+            &app_handle, &session_id, &buf_id, total_frames, &playback_range,
+            &seek_target_frame, &seek_target_us, &control, &disabled_buses,
             &mut chunk, &mut chunk_idx, &mut frame_index, &mut last_consumed_rowid,
-            &mut batch_buffer, &mut playback_baseline_secs, &mut wall_clock_baseline,
-            &mut last_frame_time_secs,
+            &mut batch_buffer, &mut throttle, &mut playback_baseline_secs, &mut wall_clock_baseline,
+            &mut last_frame_time_secs, reference_pacing.as_ref(), time_offset_us,
         ) {
             continue;
         }
@@ -905,3 +1303,60 @@ async fn run_buffer_stream(
         session_id, total_emitted, total_wall_time_ms, data_duration_secs, wait_count, total_wait_ms
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::test_support::can_frame;
+
+    #[test]
+    fn test_throttle_coalesces_latest_per_id() {
+        let mut throttle = EmitThrottle::new(Some(0.001));
+
+        // First batch goes out immediately
+        let first = throttle.push(vec![can_frame(0x100, 1, vec![1])]).unwrap();
+        assert_eq!(first.len(), 1);
+
+        // Within the interval frames are held
+        assert!(throttle.push(vec![can_frame(0x100, 2, vec![2]), can_frame(0x200, 3, vec![3])]).is_none());
+        assert!(throttle.push(vec![can_frame(0x100, 4, vec![4])]).is_none());
+        assert!(throttle.time_until_due().is_some());
+
+        let held = throttle.take().unwrap();
+        let summary: Vec<(u32, u8)> = held.iter().map(|f| (f.frame_id, f.bytes[0])).collect();
+        assert_eq!(summary, vec![(0x100, 4), (0x200, 3)]);
+        assert!(throttle.time_until_due().is_none());
+    }
+
+    #[test]
+    fn test_unthrottled_passes_batches_through() {
+        let mut throttle = EmitThrottle::new(None);
+        let batch = throttle.push(vec![can_frame(0x100, 1, vec![1]), can_frame(0x100, 2, vec![2])]).unwrap();
+        assert_eq!(batch.len(), 2);
+        assert!(throttle.take().is_none());
+    }
+
+    #[test]
+    fn test_reference_pacing_steadies_reference_period() {
+        // Reference frames captured with jitter: gaps of 2ms then 1ms (average 1.5ms)
+        let pacing = ReferencePacing::new(vec![3000, 1000, 4000]).unwrap();
+        let paced_us = |t: u64| (pacing.pacing_secs(t) * 1_000_000.0).round() as u64;
+
+        // Reference frames land one average period apart
+        assert_eq!(paced_us(1000), 1000);
+        assert_eq!(paced_us(3000), 2500);
+        assert_eq!(paced_us(4000), 4000);
+
+        // Frames in between keep their relative position in the interval
+        assert_eq!(paced_us(2000), 1750);
+        assert_eq!(paced_us(3500), 3250);
+
+        // Outside the reference frames capture time is unchanged
+        assert_eq!(paced_us(500), 500);
+        assert_eq!(paced_us(6000), 6000);
+        assert_eq!(pacing_secs(None, 2000), 0.002);
+
+        // A single (or repeated) reference timestamp has no period to hold
+        assert!(ReferencePacing::new(vec![1000, 1000]).is_none());
+    }
+}
@@ -10,9 +10,10 @@ mod pacing;
 mod postgres;
 
 // Re-export public items
-pub use buffer::{step_frame, BufferReader, StepResult};
+pub use buffer::{emit_buffer_range, step_frame, BufferReader, EmitRangeResult, PacingReference, StepResult};
 pub use csv::{
-    parse_csv_file, parse_csv_with_mapping, preview_csv_file, CsvColumnMapping, CsvPreview,
-    CsvReader, CsvReaderOptions, Delimiter, SequenceGap, TimestampUnit,
+    check_frame_lengths, count_out_of_order, parse_csv_file, parse_csv_with_mapping, preview_csv_file,
+    sort_frames_by_timestamp,
+    CsvColumnMapping, CsvPreview, CsvReader, CsvReaderOptions, Delimiter, SequenceGap, TimestampUnit,
 };
 pub use postgres::{PostgresConfig, PostgresReader, PostgresReaderOptions, PostgresSourceType};
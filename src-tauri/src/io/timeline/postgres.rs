@@ -147,7 +147,7 @@ impl IODevice for PostgresReader {
         let options = self.options.clone();
         let control = self.reader_state.control.clone();
 
-        let handle = spawn_postgres_stream(app, session_id, config, options, control);
+        let handle = spawn_postgres_stream(app, session_id, buffer_id, config, options, control);
         self.reader_state.mark_running(handle);
 
         Ok(())
@@ -221,13 +221,14 @@ impl IODevice for PostgresReader {
 fn spawn_postgres_stream(
     app_handle: AppHandle,
     session_id: String,
+    buffer_id: String,
     config: PostgresConfig,
     options: PostgresReaderOptions,
     control: TimelineControl,
 ) -> tauri::async_runtime::JoinHandle<()> {
     tauri::async_runtime::spawn(async move {
         if let Err(e) =
-            run_postgres_stream(app_handle.clone(), session_id.clone(), config, options, control)
+            run_postgres_stream(app_handle.clone(), session_id.clone(), buffer_id, config, options, control)
                 .await
         {
             // run_postgres_stream emits stream-ended on error paths before returning Err,
@@ -245,6 +246,7 @@ fn spawn_postgres_stream(
 async fn run_postgres_stream(
     app_handle: AppHandle,
     session_id: String,
+    buffer_id: String,
     config: PostgresConfig,
     options: PostgresReaderOptions,
     control: TimelineControl,
@@ -509,7 +511,7 @@ async fn run_postgres_stream(
             // Emit batch when full (use larger batch for no-limit mode)
             if batch_buffer.len() >= NO_LIMIT_BATCH_SIZE {
                 // Buffer frames for replay
-                buffer_store::append_frames(batch_buffer.clone());
+                buffer_store::append_frames_to_buffer(&buffer_id, batch_buffer.clone());
 
                 emit_frames(&app_handle, &session_id, batch_buffer.clone());
                 batch_buffer.clear();
@@ -569,7 +571,7 @@ async fn run_postgres_stream(
                 last_pacing_check = std::time::Instant::now();
 
                 // Buffer frames for replay
-                buffer_store::append_frames(batch_buffer.clone());
+                buffer_store::append_frames_to_buffer(&buffer_id, batch_buffer.clone());
 
                 emit_frames(&app_handle, &session_id, batch_buffer.clone());
                 batch_buffer.clear();
@@ -594,7 +596,7 @@ async fn run_postgres_stream(
             // Normal speed: emit any pending batch first
             if !batch_buffer.is_empty() {
                 // Buffer frames for replay
-                buffer_store::append_frames(batch_buffer.clone());
+                buffer_store::append_frames_to_buffer(&buffer_id, batch_buffer.clone());
 
                 emit_frames(&app_handle, &session_id, batch_buffer.clone());
                 batch_buffer.clear();
@@ -615,7 +617,7 @@ async fn run_postgres_stream(
 
             // Emit single frame with active listener filtering
             // Buffer frames for replay
-            buffer_store::append_frames(vec![frame.clone()]);
+            buffer_store::append_frames_to_buffer(&buffer_id, vec![frame.clone()]);
 
             emit_frames(&app_handle, &session_id, vec![frame]);
             total_emitted += 1;
@@ -632,7 +634,7 @@ async fn run_postgres_stream(
     // Emit any remaining frames in batch buffer with active listener filtering
     if !batch_buffer.is_empty() {
         // Buffer frames for replay
-        buffer_store::append_frames(batch_buffer.clone());
+        buffer_store::append_frames_to_buffer(&buffer_id, batch_buffer.clone());
 
         emit_frames(&app_handle, &session_id, batch_buffer);
     }
@@ -759,6 +761,12 @@ fn parse_can_frame_row(row: &Row) -> Result<FrameMessage, Box<dyn std::error::Er
         source_address: None,
         incomplete: None,
         direction: None,
+        dlc_raw: None,
+        is_rtr: false,
+        is_brs: false,
+        is_esi: false,
+        original_frame_id: None,
+        source_label: None,
     })
 }
 
@@ -796,6 +804,12 @@ fn parse_modbus_frame_row(row: &Row) -> Result<FrameMessage, Box<dyn std::error:
         source_address: None,
         incomplete: None,
         direction: None,
+        dlc_raw: None,
+        is_rtr: false,
+        is_brs: false,
+        is_esi: false,
+        original_frame_id: None,
+        source_label: None,
     })
 }
 
@@ -830,6 +844,12 @@ fn parse_serial_frame_row(row: &Row) -> Result<FrameMessage, Box<dyn std::error:
         source_address: None, // Not extracted from PostgreSQL serial_frame table
         incomplete: None,
         direction: None,
+        dlc_raw: None,
+        is_rtr: false,
+        is_brs: false,
+        is_esi: false,
+        original_frame_id: None,
+        source_label: None,
     })
 }
 
@@ -876,6 +896,12 @@ fn parse_row_for_source_type(
                 source_address: None,
                 incomplete: None,
                 direction: None,
+                dlc_raw: None,
+                is_rtr: false,
+                is_brs: false,
+                is_esi: false,
+                original_frame_id: None,
+                source_label: None,
             })
         }
     }
@@ -0,0 +1,166 @@
+// ui/src-tauri/src/io/timestamp.rs
+//
+// Frame timestamp source selection and device clock alignment.
+// Shared by readers whose hardware reports its own receive timestamps
+// (GVRET, gs_usb, SocketCAN).
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use super::now_us;
+
+/// Period of a 32-bit microsecond counter (about 71.6 minutes).
+pub const U32_MICROS_WRAP: u64 = 1 << 32;
+
+/// Which clock stamps received frames.
+///
+/// - `Host` (default): host UNIX time when the reader saw the frame. This is
+///   absolute wall-clock time, but it includes USB/TCP/serial batching jitter
+///   (often several milliseconds), so inter-frame deltas within a burst are
+///   imprecise.
+/// - `Device`: the adapter's own receive timestamp, extended across counter
+///   wraparound. Inter-frame timing is precise, but values count from the
+///   device's own epoch (usually power-on) rather than wall-clock, so they
+///   can't be lined up with other devices or sessions.
+/// - `DeviceAlignedToHost`: device timestamps offset so the first frame lands
+///   on the host time it arrived. Keeps the device's precise relative timing
+///   with roughly correct wall-clock time, but device and host oscillators
+///   drift (tens of ppm, a few ms per minute), so absolute error grows over
+///   long captures.
+///
+/// Readers fall back to host time whenever no device timestamp is available.
+/// For SocketCAN the device clock is the kernel receive timestamp, which is
+/// already wall-clock, so both device variants use it directly.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TimestampSource {
+    #[default]
+    Host,
+    Device,
+    DeviceAlignedToHost,
+}
+
+impl TimestampSource {
+    /// Read `timestamp_source` from profile connection settings (defaults to `Host`).
+    pub fn from_connection(connection: &HashMap<String, serde_json::Value>) -> Self {
+        connection
+            .get("timestamp_source")
+            .and_then(|v| serde_json::from_value(v.clone()).ok())
+            .unwrap_or_default()
+    }
+
+    /// Whether frames should be stamped from a device clock when one exists.
+    pub fn wants_device(self) -> bool {
+        self != TimestampSource::Host
+    }
+}
+
+/// Converts raw device counter values into frame timestamps for a `TimestampSource`.
+///
+/// Keeps wraparound and host-alignment state, so use one clock per device stream.
+#[derive(Clone, Debug)]
+pub struct DeviceClock {
+    source: TimestampSource,
+    /// Counter period in microseconds
+    wrap_us: u64,
+    last_raw: Option<u64>,
+    wrap_offset: u64,
+    /// (host_us, extended device_us) of the first device-stamped frame
+    anchor: Option<(u64, u64)>,
+}
+
+impl Default for DeviceClock {
+    fn default() -> Self {
+        Self::new(TimestampSource::Host, U32_MICROS_WRAP)
+    }
+}
+
+impl DeviceClock {
+    pub fn new(source: TimestampSource, wrap_us: u64) -> Self {
+        Self {
+            source,
+            wrap_us: wrap_us.max(1),
+            last_raw: None,
+            wrap_offset: 0,
+            anchor: None,
+        }
+    }
+
+    /// Timestamp for a frame with the given raw device counter value (microseconds).
+    /// Falls back to host time when the source is `Host` or `device_raw` is `None`.
+    pub fn timestamp(&mut self, device_raw: Option<u64>) -> u64 {
+        self.timestamp_at(device_raw, now_us())
+    }
+
+    fn timestamp_at(&mut self, device_raw: Option<u64>, host_us: u64) -> u64 {
+        let raw = match (self.source, device_raw) {
+            (TimestampSource::Host, _) | (_, None) => return host_us,
+            (_, Some(raw)) => raw % self.wrap_us,
+        };
+
+        // A large backward jump is a counter wrap; a small one is just reordering
+        if let Some(last) = self.last_raw {
+            if raw < last && last - raw > self.wrap_us / 2 {
+                self.wrap_offset += self.wrap_us;
+            }
+        }
+        self.last_raw = Some(raw);
+        let device_us = self.wrap_offset + raw;
+
+        match self.source {
+            TimestampSource::DeviceAlignedToHost => {
+                let (anchor_host, anchor_device) = *self.anchor.get_or_insert((host_us, device_us));
+                (anchor_host + device_us).saturating_sub(anchor_device)
+            }
+            _ => device_us,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_host_ignores_device_timestamp() {
+        let mut clock = DeviceClock::new(TimestampSource::Host, U32_MICROS_WRAP);
+        assert_eq!(clock.timestamp_at(Some(42), 1_000), 1_000);
+    }
+
+    #[test]
+    fn test_device_falls_back_to_host() {
+        let mut clock = DeviceClock::new(TimestampSource::Device, U32_MICROS_WRAP);
+        assert_eq!(clock.timestamp_at(None, 1_000), 1_000);
+    }
+
+    #[test]
+    fn test_device_extends_across_wrap() {
+        let mut clock = DeviceClock::new(TimestampSource::Device, U32_MICROS_WRAP);
+        assert_eq!(clock.timestamp_at(Some(U32_MICROS_WRAP - 10), 0), U32_MICROS_WRAP - 10);
+        assert_eq!(clock.timestamp_at(Some(5), 0), U32_MICROS_WRAP + 5);
+        // Slight reordering is not mistaken for a wrap
+        assert_eq!(clock.timestamp_at(Some(3), 0), U32_MICROS_WRAP + 3);
+    }
+
+    #[test]
+    fn test_aligned_to_host() {
+        let mut clock = DeviceClock::new(TimestampSource::DeviceAlignedToHost, U32_MICROS_WRAP);
+        assert_eq!(clock.timestamp_at(Some(500), 1_000_000), 1_000_000);
+        // Later frames follow the device clock, not host arrival time
+        assert_eq!(clock.timestamp_at(Some(750), 1_009_000), 1_000_250);
+    }
+
+    #[test]
+    fn test_from_connection() {
+        let mut connection = HashMap::new();
+        assert_eq!(TimestampSource::from_connection(&connection), TimestampSource::Host);
+        connection.insert(
+            "timestamp_source".to_string(),
+            serde_json::json!("device_aligned_to_host"),
+        );
+        assert_eq!(
+            TimestampSource::from_connection(&connection),
+            TimestampSource::DeviceAlignedToHost
+        );
+    }
+}
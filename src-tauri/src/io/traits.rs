@@ -3,6 +3,17 @@
 // Interface trait validation and session trait inheritance.
 
 use super::{InterfaceTraits, Protocol, TemporalMode};
+use serde::Serialize;
+
+use super::gs_usb::COMMON_BITRATES;
+
+/// Standard nominal bitrates offered for adapters without a timing table of
+/// their own (the SocketCAN kernel driver and GVRET firmware accept any rate)
+const STANDARD_BITRATES: [u32; 9] = [10_000, 20_000, 50_000, 100_000, 125_000, 250_000, 500_000, 750_000, 1_000_000];
+
+/// Standard CAN FD data phase bitrates (CiA 601), offered where data phase
+/// timing is calculated from the controller clock rather than looked up
+const STANDARD_FD_DATA_RATES: [u32; 5] = [1_000_000, 2_000_000, 4_000_000, 5_000_000, 8_000_000];
 
 /// Result of validating multiple interface traits for a session
 #[derive(Clone, Debug)]
@@ -168,10 +179,79 @@ pub fn get_traits_for_profile_kind(kind: &str) -> InterfaceTraits {
     }
 }
 
+/// Bitrates a profile kind can be configured with
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct BitrateSupport {
+    /// Nominal bitrates to offer. When `arbitrary` is false these are the only
+    /// ones the device accepts.
+    pub fixed: Vec<u32>,
+    /// Whether any nominal and data bitrate can be used (timing is calculated)
+    pub arbitrary: bool,
+    /// CAN FD data phase bitrates to offer (empty when FD is not supported
+    /// or the data rate is configured on the device)
+    pub fd_data_rates: Vec<u32>,
+}
+
+/// Get the bitrates a profile kind supports, from the tables its driver uses.
+/// Returns `None` for kinds without a configurable CAN bitrate.
+pub fn bitrate_support_for_profile_kind(kind: &str) -> Option<BitrateSupport> {
+    match kind {
+        #[cfg(not(target_os = "ios"))]
+        "slcan" => Some(BitrateSupport {
+            fixed: super::slcan::reader::supported_bitrates(),
+            arbitrary: false,
+            fd_data_rates: super::slcan::reader::supported_data_bitrates(),
+        }),
+        // gs_usb calculates timing from the device clock; its table is the
+        // fallback used when the device doesn't report one. The data phase
+        // has no table and is always calculated.
+        "gs_usb" => Some(BitrateSupport {
+            fixed: COMMON_BITRATES.iter().map(|(rate, _)| *rate).collect(),
+            arbitrary: true,
+            fd_data_rates: STANDARD_FD_DATA_RATES.to_vec(),
+        }),
+        // The kernel driver calculates timing from the controller's own limits
+        "socketcan" => Some(BitrateSupport {
+            fixed: STANDARD_BITRATES.to_vec(),
+            arbitrary: true,
+            fd_data_rates: STANDARD_FD_DATA_RATES.to_vec(),
+        }),
+        // GVRET buses take any bitrate the firmware accepts
+        "gvret_tcp" | "gvret-tcp" | "gvret_usb" | "gvret-usb" => Some(BitrateSupport {
+            fixed: STANDARD_BITRATES.to_vec(),
+            arbitrary: true,
+            fd_data_rates: Vec::new(),
+        }),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    #[cfg(not(target_os = "ios"))]
+    fn test_bitrate_support_matches_driver_tables() {
+        let slcan = bitrate_support_for_profile_kind("slcan").unwrap();
+        assert!(!slcan.arbitrary);
+        for rate in &slcan.fixed {
+            assert!(crate::io::slcan::reader::find_bitrate_command(*rate).is_ok());
+        }
+        for rate in &slcan.fd_data_rates {
+            assert!(crate::io::slcan::reader::find_data_bitrate_command(*rate).is_ok());
+        }
+        assert!(!slcan.fixed.contains(&800_000));
+
+        let gs_usb = bitrate_support_for_profile_kind("gs_usb").unwrap();
+        assert!(gs_usb.arbitrary);
+        for rate in &gs_usb.fixed {
+            assert!(crate::io::gs_usb::get_bittiming_for_bitrate(*rate).is_some());
+        }
+
+        assert!(bitrate_support_for_profile_kind("mqtt").is_none());
+    }
+
     #[test]
     fn test_single_interface_valid() {
         let traits = vec![InterfaceTraits {
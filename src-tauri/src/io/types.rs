@@ -4,6 +4,7 @@
 // Used by interface implementations to communicate with the merge task.
 
 use std::sync::mpsc as std_mpsc;
+use std::time::{Duration, Instant};
 
 use crate::buffer_store::TimestampedByte;
 use serde::Serialize;
@@ -36,8 +37,37 @@ pub enum SourceMessage {
     Error(usize, String),
     /// Transmit channel is ready (source_index, transmit_sender)
     TransmitReady(usize, TransmitSender),
+    /// Source answers device info queries mid-stream (source_index, sender)
+    /// Only constructed by the slcan reader which is not available on iOS
+    #[cfg_attr(target_os = "ios", allow(dead_code))]
+    DeviceInfoReady(usize, DeviceInfoSender),
     /// Source connected successfully (source_index, device_type, address, bus_number)
     Connected(usize, String, String, Option<u8>),
+    /// Frames lost before they could be read (source_index, dropped_since_last_report)
+    /// Only constructed by the SocketCAN reader which is Linux-only
+    #[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+    FramesDropped(usize, u64),
+    /// Malformed input lines skipped since the last report (source_index, errors)
+    /// Only constructed by the slcan reader which is not available on iOS
+    #[cfg_attr(target_os = "ios", allow(dead_code))]
+    ParseErrors(usize, super::ParseErrors),
+    /// Parse failures kept for `recent_parse_errors` only, no event (source_index, entries)
+    ParseFailures(usize, Vec<super::ParseErrorEntry>),
+    /// Device reported its live bus configuration (source_index, config)
+    DeviceConfig(usize, super::DeviceConfigPayload),
+    /// Frames the session wrote through a source, on session buses and tagged
+    /// direction "tx" (source_index, frames)
+    Transmitted(usize, Vec<FrameMessage>),
+    /// CAN controller went bus-off (source_index, report)
+    /// Only constructed by the SocketCAN reader which is Linux-only
+    #[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+    BusOff(usize, super::BusOffPayload),
+    /// Device reported CAN controller errors (source_index, errors)
+    CanErrors(usize, Vec<super::CanErrorPayload>),
+    /// Serial framer discarded an oversized frame (source_index, bytes_discarded)
+    /// Only constructed by serial reader which is not available on iOS
+    #[cfg_attr(target_os = "ios", allow(dead_code))]
+    FramerOverflow(usize, u64),
 }
 
 // ============================================================================
@@ -55,6 +85,145 @@ pub struct TransmitRequest {
 /// Sender type for transmit requests (sync-safe)
 pub type TransmitSender = std_mpsc::SyncSender<TransmitRequest>;
 
+/// Default time to wait for a source to report a transmit result
+pub const DEFAULT_TRANSMIT_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// Bounds for a per-session transmit timeout (milliseconds)
+pub const MIN_TRANSMIT_TIMEOUT_MS: u64 = 10;
+pub const MAX_TRANSMIT_TIMEOUT_MS: u64 = 10_000;
+
+impl TransmitRequest {
+    /// Queue `data` on a source's transmit channel and wait up to `timeout`
+    /// for the source to report the write result.
+    ///
+    /// A write error from the source and a missing confirmation are reported
+    /// differently: on timeout the request was accepted and may still have
+    /// gone out, so callers should not treat it as a definite failure.
+    pub fn send(tx: &TransmitSender, data: Vec<u8>, timeout: Duration) -> Result<(), String> {
+        let (result_tx, result_rx) = std_mpsc::sync_channel(1);
+        tx.try_send(TransmitRequest { data, result_tx })
+            .map_err(|e| format!("Failed to queue transmit request: {}", e))?;
+        match result_rx.recv_timeout(timeout) {
+            Ok(result) => result.map_err(|e| format!("Transmit write failed: {}", e)),
+            Err(std_mpsc::RecvTimeoutError::Timeout) => Err(format!(
+                "Transmit queued but not confirmed within {}ms (frame may have been sent)",
+                timeout.as_millis()
+            )),
+            Err(std_mpsc::RecvTimeoutError::Disconnected) => {
+                Err("Transmit channel closed before the result was reported".to_string())
+            }
+        }
+    }
+}
+
+/// Validate a per-session transmit timeout in milliseconds.
+pub fn transmit_timeout_from_ms(timeout_ms: u64) -> Result<Duration, String> {
+    if !(MIN_TRANSMIT_TIMEOUT_MS..=MAX_TRANSMIT_TIMEOUT_MS).contains(&timeout_ms) {
+        return Err(format!(
+            "Transmit timeout must be between {} and {} ms",
+            MIN_TRANSMIT_TIMEOUT_MS, MAX_TRANSMIT_TIMEOUT_MS
+        ));
+    }
+    Ok(Duration::from_millis(timeout_ms))
+}
+
+/// Largest accepted minimum inter-transmit gap (microseconds)
+pub const MAX_MIN_TX_GAP_US: u64 = 10_000_000;
+
+/// Enforces a minimum gap between consecutive writes on a session.
+///
+/// Callers reserve a slot before writing and wait for it themselves, so the
+/// pacer never sleeps while a session lock is held. The gap runs from the later
+/// of the last reserved slot and the last confirmed write, so a caller that
+/// enqueues faster than the bus accepts frames still sees them spaced by at
+/// least `min_gap`.
+#[derive(Debug, Default)]
+pub struct TransmitPacer {
+    min_gap: Duration,
+    last_send: Option<Instant>,
+}
+
+impl TransmitPacer {
+    /// Set the minimum gap in microseconds (0 disables pacing).
+    pub fn set_min_gap_us(&mut self, gap_us: u64) -> Result<(), String> {
+        if gap_us > MAX_MIN_TX_GAP_US {
+            return Err(format!(
+                "Minimum transmit gap must be at most {} us",
+                MAX_MIN_TX_GAP_US
+            ));
+        }
+        self.min_gap = Duration::from_micros(gap_us);
+        Ok(())
+    }
+
+    /// Whether a minimum gap is being enforced
+    pub fn is_enabled(&self) -> bool {
+        !self.min_gap.is_zero()
+    }
+
+    /// How long a write at `now` must still wait to honour the gap
+    pub fn wait_before(&self, now: Instant) -> Duration {
+        match self.last_send {
+            Some(last) if self.is_enabled() => (last + self.min_gap).saturating_duration_since(now),
+            _ => Duration::ZERO,
+        }
+    }
+
+    /// Record that a write was confirmed at `at`
+    pub fn record_send(&mut self, at: Instant) {
+        self.last_send = Some(self.last_send.map_or(at, |last| last.max(at)));
+    }
+
+    /// Claim the next write slot at or after `now` and return how long the
+    /// caller must wait for it. Later reservations queue up behind this one.
+    pub fn reserve(&mut self, now: Instant) -> Duration {
+        let wait = self.wait_before(now);
+        if self.is_enabled() {
+            self.last_send = Some(now + wait);
+        }
+        wait
+    }
+}
+
+// ============================================================================
+// Device Info Types
+// ============================================================================
+
+/// What a device reports about itself when queried during a session
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct DeviceInfo {
+    /// Hardware and firmware version digits (slcan 'V')
+    pub version: Option<String>,
+    /// Detailed firmware version (slcan 'v')
+    pub firmware_version: Option<String>,
+    /// Serial number (slcan 'N')
+    pub serial_number: Option<String>,
+}
+
+/// Device info query sent to a running source
+pub struct DeviceInfoRequest {
+    /// Sync oneshot channel for whatever the device answered
+    pub result_tx: std_mpsc::SyncSender<DeviceInfo>,
+}
+
+/// Sender type for device info queries (sync-safe)
+pub type DeviceInfoSender = std_mpsc::SyncSender<DeviceInfoRequest>;
+
+/// How long a caller waits for a source to answer a device info query
+pub const DEVICE_INFO_TIMEOUT: Duration = Duration::from_secs(1);
+
+impl DeviceInfoRequest {
+    /// Queue a query on a source and wait for its answer.
+    pub fn send(tx: &DeviceInfoSender) -> Result<DeviceInfo, String> {
+        let (result_tx, result_rx) = std_mpsc::sync_channel(1);
+        tx.try_send(DeviceInfoRequest { result_tx })
+            .map_err(|e| format!("Failed to queue device info query: {}", e))?;
+        result_rx
+            .recv_timeout(DEVICE_INFO_TIMEOUT)
+            .map_err(|_| "Device did not answer the info query".to_string())
+    }
+}
+
 // ============================================================================
 // Byte Payload Types
 // ============================================================================
@@ -69,3 +238,60 @@ pub struct RawBytesPayload {
     /// Source identifier (e.g., port name, "multi-source")
     pub source: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transmit_pacer_waits_from_last_confirmed_send() {
+        let mut pacer = TransmitPacer::default();
+        let t0 = Instant::now();
+        pacer.record_send(t0);
+        assert_eq!(pacer.wait_before(t0), Duration::ZERO, "disabled pacer never waits");
+
+        pacer.set_min_gap_us(1_000).unwrap();
+        assert_eq!(pacer.wait_before(t0), Duration::from_micros(1_000));
+        assert_eq!(pacer.wait_before(t0 + Duration::from_micros(400)), Duration::from_micros(600));
+        assert_eq!(pacer.wait_before(t0 + Duration::from_micros(1_500)), Duration::ZERO);
+
+        assert!(pacer.set_min_gap_us(MAX_MIN_TX_GAP_US + 1).is_err());
+
+        // Each reservation pushes the next one back by a full gap
+        assert_eq!(pacer.reserve(t0), Duration::from_micros(1_000));
+        assert_eq!(pacer.reserve(t0), Duration::from_micros(2_000));
+        // A confirmation earlier than the reserved slot does not pull it forward
+        pacer.record_send(t0 + Duration::from_micros(500));
+        assert_eq!(pacer.wait_before(t0), Duration::from_micros(3_000));
+
+        pacer.set_min_gap_us(0).unwrap();
+        assert_eq!(pacer.reserve(t0), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_transmit_request_distinguishes_write_error_and_timeout() {
+        let (tx, rx) = std_mpsc::sync_channel::<TransmitRequest>(4);
+        let worker = std::thread::spawn(move || {
+            // First request fails, second is never answered
+            let req = rx.recv().unwrap();
+            let _ = req.result_tx.send(Err("bus-off".to_string()));
+            let _held = rx.recv().unwrap();
+            std::thread::sleep(Duration::from_millis(50));
+        });
+
+        let err = TransmitRequest::send(&tx, vec![1], Duration::from_millis(500)).unwrap_err();
+        assert!(err.starts_with("Transmit write failed"), "{}", err);
+
+        let err = TransmitRequest::send(&tx, vec![2], Duration::from_millis(10)).unwrap_err();
+        assert!(err.contains("not confirmed within 10ms"), "{}", err);
+
+        worker.join().unwrap();
+    }
+
+    #[test]
+    fn test_transmit_timeout_bounds() {
+        assert!(transmit_timeout_from_ms(5).is_err());
+        assert!(transmit_timeout_from_ms(20_000).is_err());
+        assert_eq!(transmit_timeout_from_ms(2000).unwrap(), Duration::from_millis(2000));
+    }
+}
@@ -23,8 +23,8 @@ use tokio::time::{interval, Duration};
 
 use crate::buffer_store::{self, BufferType, TimestampedByte};
 use crate::io::{
-    emit_device_connected, emit_frames, emit_stream_ended, emit_to_session, now_us,
-    CanTransmitFrame, FrameMessage, IOCapabilities, IODevice, IOState, InterfaceTraits, Protocol,
+    emit_device_connected, emit_first_frame, emit_frames, emit_stream_ended, emit_to_session, ensure_transmit_state,
+    now_us, CanTransmitFrame, FrameMessage, IOCapabilities, IODevice, IOState, InterfaceTraits, Protocol,
     SessionDataStreams, TemporalMode, TransmitPayload, TransmitResult, VirtualBusState,
 };
 
@@ -293,6 +293,9 @@ impl IODevice for VirtualDeviceReader {
             iface_summary.join(", ")
         );
 
+        // Generator and loopback tasks share the session's first-frame flag
+        let seen_first_frame = Arc::new(AtomicBool::new(false));
+
         // Spawn one generator task per bus interface
         for (idx, iface) in self.config.interfaces.iter().enumerate() {
             let traffic_flag = self.bus_traffic_flags[idx].clone();
@@ -300,9 +303,11 @@ impl IODevice for VirtualDeviceReader {
             let handle = spawn_bus_generator(
                 self.app.clone(),
                 self.session_id.clone(),
+                buffer_id.clone(),
                 self.config.traffic_type.clone(),
                 iface.clone(),
                 self.cancel_flag.clone(),
+                seen_first_frame.clone(),
                 traffic_flag,
                 cadence_interval,
             );
@@ -314,8 +319,10 @@ impl IODevice for VirtualDeviceReader {
             let handle = spawn_loopback_handler(
                 self.app.clone(),
                 self.session_id.clone(),
+                buffer_id.clone(),
                 self.config.traffic_type.clone(),
                 self.cancel_flag.clone(),
+                seen_first_frame.clone(),
                 rx,
             );
             self.task_handles.push(handle);
@@ -365,6 +372,7 @@ impl IODevice for VirtualDeviceReader {
     }
 
     fn transmit(&self, payload: &TransmitPayload) -> Result<TransmitResult, String> {
+        ensure_transmit_state(&self.state)?;
         if !self.config.loopback {
             return Err("Loopback is disabled on this virtual device.".to_string());
         }
@@ -479,12 +487,15 @@ pub fn canfd_patterns() -> Vec<(u32, Vec<u8>)> {
 // ============================================================================
 
 /// Spawn a background task that generates traffic for a single bus interface
+#[allow(clippy::too_many_arguments)]
 fn spawn_bus_generator(
     app: AppHandle,
     session_id: String,
+    buffer_id: String,
     traffic_type: VirtualTrafficType,
     iface: VirtualInterfaceConfig,
     cancel_flag: Arc<AtomicBool>,
+    seen_first_frame: Arc<AtomicBool>,
     traffic_enabled: Arc<AtomicBool>,
     cadence_interval_us: Arc<AtomicU64>,
 ) -> tauri::async_runtime::JoinHandle<()> {
@@ -557,9 +568,18 @@ fn spawn_bus_generator(
                         source_address: None,
                         incomplete: None,
                         direction: Some("rx".to_string()),
+                        dlc_raw: None,
+                        is_rtr: false,
+                        is_brs: false,
+                        is_esi: false,
+                        original_frame_id: None,
+                        source_label: None,
                     };
 
-                    buffer_store::append_frames(vec![frame.clone()]);
+                    if !seen_first_frame.swap(true, Ordering::Relaxed) {
+                        emit_first_frame(&app, &session_id, &frame);
+                    }
+                    buffer_store::append_frames_to_buffer(&buffer_id, vec![frame.clone()]);
                     emit_frames(&app, &session_id, vec![frame]);
                 }
                 VirtualTrafficType::CanFd => {
@@ -586,9 +606,18 @@ fn spawn_bus_generator(
                         source_address: None,
                         incomplete: None,
                         direction: Some("rx".to_string()),
+                        dlc_raw: None,
+                        is_rtr: false,
+                        is_brs: false,
+                        is_esi: false,
+                        original_frame_id: None,
+                        source_label: None,
                     };
 
-                    buffer_store::append_frames(vec![frame.clone()]);
+                    if !seen_first_frame.swap(true, Ordering::Relaxed) {
+                        emit_first_frame(&app, &session_id, &frame);
+                    }
+                    buffer_store::append_frames_to_buffer(&buffer_id, vec![frame.clone()]);
                     emit_frames(&app, &session_id, vec![frame]);
                 }
                 VirtualTrafficType::Modbus => {
@@ -609,9 +638,18 @@ fn spawn_bus_generator(
                         source_address: None,
                         incomplete: None,
                         direction: Some("rx".to_string()),
+                        dlc_raw: None,
+                        is_rtr: false,
+                        is_brs: false,
+                        is_esi: false,
+                        original_frame_id: None,
+                        source_label: None,
                     };
 
-                    buffer_store::append_frames(vec![frame.clone()]);
+                    if !seen_first_frame.swap(true, Ordering::Relaxed) {
+                        emit_first_frame(&app, &session_id, &frame);
+                    }
+                    buffer_store::append_frames_to_buffer(&buffer_id, vec![frame.clone()]);
                     emit_frames(&app, &session_id, vec![frame]);
                 }
                 VirtualTrafficType::Serial => {
@@ -624,7 +662,7 @@ fn spawn_bus_generator(
                         })
                         .collect();
 
-                    buffer_store::append_raw_bytes(entries.clone());
+                    buffer_store::append_raw_bytes_to_buffer(&buffer_id, entries.clone());
                     let payload = RawBytesPayload {
                         bytes: entries,
                         source: "virtual".to_string(),
@@ -646,8 +684,10 @@ fn spawn_bus_generator(
 fn spawn_loopback_handler(
     app: AppHandle,
     session_id: String,
+    buffer_id: String,
     traffic_type: VirtualTrafficType,
     cancel_flag: Arc<AtomicBool>,
+    seen_first_frame: Arc<AtomicBool>,
     mut loopback_rx: tokio::sync::mpsc::UnboundedReceiver<LoopbackMessage>,
 ) -> tauri::async_runtime::JoinHandle<()> {
     tauri::async_runtime::spawn(async move {
@@ -672,8 +712,17 @@ fn spawn_loopback_handler(
                         source_address: None,
                         incomplete: None,
                         direction: Some("rx".to_string()),
+                        dlc_raw: None,
+                        is_rtr: false,
+                        is_brs: is_fd && tx_frame.is_brs,
+                        is_esi: false,
+                        original_frame_id: None,
+                        source_label: None,
                     };
-                    buffer_store::append_frames(vec![frame.clone()]);
+                    if !seen_first_frame.swap(true, Ordering::Relaxed) {
+                        emit_first_frame(&app, &session_id, &frame);
+                    }
+                    buffer_store::append_frames_to_buffer(&buffer_id, vec![frame.clone()]);
                     emit_frames(&app, &session_id, vec![frame]);
                 }
                 Some(LoopbackMessage::RawBytes(bytes)) => {
@@ -687,7 +736,7 @@ fn spawn_loopback_handler(
                             bus: 0,
                         })
                         .collect();
-                    buffer_store::append_raw_bytes(entries.clone());
+                    buffer_store::append_raw_bytes_to_buffer(&buffer_id, entries.clone());
                     let payload = RawBytesPayload {
                         bytes: entries,
                         source: "virtual".to_string(),
@@ -3,21 +3,28 @@ pub(crate) mod logging;
 mod ble_common;
 mod ble_provision;
 mod buffer_db;
+mod buffer_snapshot;
 mod bufferquery;
 mod buffer_store;
 mod buffers;
+mod capture_file;
 mod catalog;
 mod checksums;
 mod credentials;
 mod dbc_export;
 mod dbc_import;
+mod dbc_stats;
 mod dbquery;
 mod device_scan;
+mod frame_export;
+mod frame_server;
 mod framing;
 mod io;
+mod obd;
 mod profile_tracker;
 mod sessions;
 mod settings;
+mod signal_probe;
 mod smp_upgrade;
 mod store_manager;
 mod transmit;
@@ -59,6 +66,7 @@ pub struct SlcanProbeResultCompat {
     pub version: Option<String>,
     pub hardware_version: Option<String>,
     pub serial_number: Option<String>,
+    pub supports_fd: Option<bool>,
     pub error: Option<String>,
 }
 
@@ -104,6 +112,7 @@ fn platform_probe_slcan_device(
             version: result.version,
             hardware_version: result.hardware_version,
             serial_number: result.serial_number,
+            supports_fd: result.supports_fd,
             error: result.error,
         }
     }
@@ -115,6 +124,7 @@ fn platform_probe_slcan_device(
             version: None,
             hardware_version: None,
             serial_number: None,
+            supports_fd: None,
             error: Some("Serial ports are not available on iOS".to_string()),
         }
     }
@@ -990,6 +1000,11 @@ pub fn run() {
             catalog::save_binary_file,
             catalog::validate_catalog,
             catalog::test_decode_frame,
+            dbc_stats::signal_statistics,
+            dbc_stats::decode_node_map,
+            signal_probe::probe_signal,
+            signal_probe::stop_signal_probe,
+            obd::detect_obd,
             catalog::list_catalogs,
             catalog::duplicate_catalog,
             catalog::rename_catalog,
@@ -1014,6 +1029,7 @@ pub fn run() {
             sessions::leave_reader_session,
             sessions::start_reader_session,
             sessions::stop_reader_session,
+            sessions::stop_all_reader_sessions,
             sessions::pause_reader_session,
             sessions::resume_reader_session,
             sessions::suspend_reader_session,
@@ -1031,13 +1047,21 @@ pub fn run() {
             sessions::seek_reader_session,
             sessions::seek_reader_session_by_frame,
             sessions::update_reader_direction,
+            sessions::set_reader_bus_enabled,
+            sessions::set_session_transmit_timeout,
+            sessions::set_min_tx_gap,
+            sessions::set_buffer_tx_frames,
+            sessions::set_source_transmit_enabled,
+            sessions::device_info,
             sessions::destroy_reader_session,
             sessions::create_buffer_reader_session,
             sessions::transition_to_buffer_reader,
             sessions::switch_session_to_buffer_replay,
             sessions::resume_session_to_live,
             sessions::step_buffer_frame,
+            sessions::emit_buffer_range_cmd,
             sessions::session_transmit_frame,
+            sessions::arm_transmit,
             // Listener registration API
             sessions::register_session_listener,
             sessions::unregister_session_listener,
@@ -1045,11 +1069,20 @@ pub fn run() {
             sessions::add_source_to_session_cmd,
             sessions::remove_source_from_session_cmd,
             sessions::update_source_bus_mappings_cmd,
+            sessions::update_bus_mapping,
+            sessions::get_bus_mappings,
             sessions::get_session_listener_list,
             sessions::reinitialize_session_if_safe_cmd,
             sessions::set_session_listener_active,
+            sessions::set_active_listeners,
+            sessions::set_session_ascii_view_cmd,
+            sessions::frame_rate,
+            sessions::recent_parse_errors,
             sessions::probe_gvret_device,
+            sessions::scan_gvret_buses,
             sessions::probe_device,
+            sessions::test_profile,
+            sessions::supported_bitrates,
             sessions::create_multi_source_session,
             sessions::list_active_sessions,
             // Profile-to-session mapping API
@@ -1067,6 +1100,7 @@ pub fn run() {
             io::check_recovery_occurred,
             // Buffer / CSV Import API
             buffers::import_csv_to_buffer,
+            buffers::import_gvret_binary,
             buffers::preview_csv,
             buffers::import_csv_with_mapping,
             buffers::import_csv_batch_with_mapping,
@@ -1085,6 +1119,16 @@ pub fn run() {
             buffers::delete_buffer,
             buffers::get_buffer_metadata_by_id,
             buffers::get_buffer_frames_by_id,
+            buffers::extract_buffer_window,
+            buffers::diff_buffers,
+            buffers::classify_periodicity,
+            buffers::buffer_overview,
+            buffers::traffic_fingerprint,
+            buffers::state_at,
+            buffers::unique_ids,
+            frame_export::export_buffer_split_by_bus,
+            buffers::transform_buffer,
+            buffers::merge_buffers,
             buffers::get_buffer_bytes_by_id,
             buffers::set_active_buffer,
             buffers::create_frame_buffer_from_frames,
@@ -1097,6 +1141,9 @@ pub fn run() {
             buffers::set_buffer_persistent,
             // Session-aware buffer API
             buffers::list_orphaned_buffers,
+            // Native buffer snapshots
+            buffers::save_buffer,
+            buffers::load_buffer,
             // Backend framing
             framing::apply_framing_to_buffer,
             // Serial port API (platform-aware: real on desktop, stub on iOS)
@@ -1107,6 +1154,7 @@ pub fn run() {
             io::gs_usb::list_gs_usb_devices,
             io::gs_usb::get_can_setup_command,
             io::gs_usb::probe_gs_usb_device,
+            io::gs_usb::compute_bit_timing,
             // Credential storage API
             credentials::store_credential,
             credentials::get_credential,
@@ -1125,6 +1173,12 @@ pub fn run() {
             // IO session-based transmit
             transmit::io_transmit_can_frame,
             transmit::io_transmit_serial,
+            transmit::transmit_raw,
+            transmit::io_transmit_serial_with_error,
+            transmit::io_transmit_and_await,
+            transmit::validate_transmit_frame,
+            transmit::encode_transmit_preview,
+            transmit::loopback_test,
             transmit::get_io_session_capabilities,
             transmit::io_start_repeat_transmit,
             transmit::io_stop_repeat_transmit,
@@ -1135,10 +1189,17 @@ pub fn run() {
             transmit::io_start_repeat_group,
             transmit::io_stop_repeat_group,
             transmit::io_stop_all_group_repeats,
+            transmit::io_start_template_transmit,
+            transmit::load_transmit_schedule,
+            transmit::set_schedule_entry_enabled,
+            transmit::stop_transmit_schedule,
             // Time-accurate frame replay
             replay::io_start_replay,
             replay::io_stop_replay,
             replay::io_stop_all_replays,
+            // Local frame server for external tools
+            frame_server::start_frame_server,
+            frame_server::stop_frame_server,
             // Transmit history (SQLite-backed)
             transmit_history::transmit_history_query,
             transmit_history::transmit_history_count,
@@ -0,0 +1,262 @@
+// ui/src-tauri/src/obd.rs
+//
+// OBD-II detection: confirm a bus is the vehicle's diagnostic/powertrain bus by
+// looking for the standard OBD-II request and response IDs in its traffic, and
+// list the ECUs that answered.
+//
+// On a live session with transmit armed, a mode 01 PID 00 request can also be
+// sent; replies are read back from the session's buffer after a short window,
+// so every ECU that answers is seen, not just the first. Only frames buffered
+// since the first scan are read for the replies.
+
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+use std::time::Duration;
+use tauri::AppHandle;
+
+use crate::buffer_store::{self, BufferType};
+use crate::io::{get_session_state, is_session_transmit_armed, CanTransmitFrame, FrameMessage};
+
+/// Functional (broadcast) request ID, 11-bit
+const OBD_FUNCTIONAL_ID: u32 = 0x7DF;
+/// Functional (broadcast) request ID, 29-bit
+const OBD_FUNCTIONAL_ID_EXT: u32 = 0x18DB_33F1;
+/// External test equipment address in 29-bit OBD IDs
+const OBD_TESTER_ADDRESS: u32 = 0xF1;
+/// How long replies to a PID 00 request are collected
+const OBD_QUERY_WINDOW: Duration = Duration::from_millis(300);
+/// Frames read from the buffer store per page while scanning
+const OBD_SCAN_PAGE_SIZE: usize = 10_000;
+
+/// Whether a frame uses an OBD-II diagnostic ID (11-bit 0x7DF/0x7E0-0x7EF, or
+/// 29-bit ISO 15765-4 addressing to or from the tester at 0xF1).
+pub(crate) fn is_obd_id(frame: &FrameMessage) -> bool {
+    if frame.is_extended {
+        let (target, source) = ((frame.frame_id >> 8) & 0xFF, frame.frame_id & 0xFF);
+        frame.frame_id == OBD_FUNCTIONAL_ID_EXT
+            || (frame.frame_id & 0x1FFF_0000 == 0x18DA_0000
+                && (target == OBD_TESTER_ADDRESS || source == OBD_TESTER_ADDRESS))
+    } else {
+        frame.frame_id == OBD_FUNCTIONAL_ID || (0x7E0..=0x7EF).contains(&frame.frame_id)
+    }
+}
+
+/// Whether a frame is an ECU's reply to the tester (0x7E8-0x7EF, or 0x18DAF1xx)
+fn is_obd_response(frame: &FrameMessage) -> bool {
+    if frame.is_extended {
+        frame.frame_id & 0x1FFF_FF00 == (0x18DA_0000 | (OBD_TESTER_ADDRESS << 8))
+    } else {
+        (0x7E8..=0x7EF).contains(&frame.frame_id)
+    }
+}
+
+/// PIDs 0x01-0x20 listed in a single-frame mode 01 PID 00 reply
+/// (`[len, 0x41, 0x00, A, B, C, D]`); bit 7 of A is PID 0x01.
+fn supported_pids(frame: &FrameMessage) -> Option<Vec<u8>> {
+    let b = &frame.bytes;
+    if b.len() < 7 || b[0] < 6 || b[1] != 0x41 || b[2] != 0x00 {
+        return None;
+    }
+    let mask = u32::from_be_bytes([b[3], b[4], b[5], b[6]]);
+    Some((1..=32u8).filter(|pid| mask & (1 << (32 - pid)) != 0).collect())
+}
+
+/// An ECU seen answering on an OBD-II response ID
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct ObdResponder {
+    pub frame_id: u32,
+    pub is_extended: bool,
+    pub frame_count: usize,
+    /// PIDs 0x01-0x20 the ECU reported in reply to mode 01 PID 00
+    /// (None if it wasn't queried or didn't reply)
+    pub supported_pids: Option<Vec<u8>>,
+}
+
+/// Result of `detect_obd`
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct ObdInfo {
+    /// OBD-II request or response IDs were seen
+    pub present: bool,
+    /// Distinct request IDs seen (functional and physical)
+    pub request_ids: Vec<u32>,
+    /// ECUs that replied, ordered by ID
+    pub responders: Vec<ObdResponder>,
+    /// A mode 01 PID 00 request was sent
+    pub queried: bool,
+}
+
+/// OBD-II traffic seen so far, added to a batch of frames at a time
+#[derive(Default)]
+struct ObdScan {
+    request_ids: BTreeSet<u32>,
+    responders: BTreeMap<(u32, bool), ObdResponder>,
+}
+
+impl ObdScan {
+    fn add_frames(&mut self, frames: &[FrameMessage]) {
+        for frame in frames.iter().filter(|f| is_obd_id(f)) {
+            if !is_obd_response(frame) {
+                self.request_ids.insert(frame.frame_id);
+                continue;
+            }
+            let responder = self
+                .responders
+                .entry((frame.frame_id, frame.is_extended))
+                .or_insert_with(|| ObdResponder {
+                    frame_id: frame.frame_id,
+                    is_extended: frame.is_extended,
+                    frame_count: 0,
+                    supported_pids: None,
+                });
+            responder.frame_count += 1;
+            if let Some(pids) = supported_pids(frame) {
+                responder.supported_pids = Some(pids);
+            }
+        }
+    }
+
+    /// Scan a frame buffer from `offset` to its end, a page at a time.
+    /// Returns the offset just past the last frame scanned.
+    fn add_buffer(&mut self, buffer_id: &str, mut offset: usize) -> usize {
+        loop {
+            let (frames, _, _) = buffer_store::get_buffer_frames_paginated(buffer_id, offset, OBD_SCAN_PAGE_SIZE);
+            self.add_frames(&frames);
+            offset += frames.len();
+            if frames.len() < OBD_SCAN_PAGE_SIZE {
+                return offset;
+            }
+        }
+    }
+
+    fn info(&self) -> ObdInfo {
+        ObdInfo {
+            present: !self.request_ids.is_empty() || !self.responders.is_empty(),
+            request_ids: self.request_ids.iter().copied().collect(),
+            responders: self.responders.values().cloned().collect(),
+            queried: false,
+        }
+    }
+}
+
+/// Mode 01 PID 00 request, sent to the 29-bit functional ID when the bus has only
+/// shown 29-bit OBD traffic
+fn pid_query_frame(info: &ObdInfo, bus: u8) -> CanTransmitFrame {
+    let extended = !info.responders.is_empty() && info.responders.iter().all(|r| r.is_extended);
+    CanTransmitFrame {
+        frame_id: if extended { OBD_FUNCTIONAL_ID_EXT } else { OBD_FUNCTIONAL_ID },
+        data: vec![0x02, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+        bus,
+        is_extended: extended,
+        is_fd: false,
+        is_brs: false,
+        is_rtr: false,
+        rtr_dlc: None,
+    }
+}
+
+// ============================================================================
+// Tauri Commands
+// ============================================================================
+
+/// Check a frame buffer or live session for OBD-II traffic and report which
+/// ECUs responded.
+///
+/// With `query_pids` on a session whose transmit is armed, a mode 01 PID 00
+/// request is sent on `bus` (default 0) and each ECU's supported PIDs are
+/// reported. The query is skipped (`queried` false) when transmit isn't armed.
+#[tauri::command(rename_all = "snake_case")]
+pub async fn detect_obd(
+    app: AppHandle,
+    buffer_id_or_session: String,
+    query_pids: Option<bool>,
+    bus: Option<u8>,
+) -> Result<ObdInfo, String> {
+    if buffer_store::get_buffer_type(&buffer_id_or_session) == Some(BufferType::Frames) {
+        let mut scan = ObdScan::default();
+        scan.add_buffer(&buffer_id_or_session, 0);
+        return Ok(scan.info());
+    }
+
+    let session_id = buffer_id_or_session;
+    if get_session_state(&session_id).await.is_none() {
+        return Err(format!("No frame buffer or session named '{}'", session_id));
+    }
+    let buffer_id = buffer_store::get_buffer_for_session(&session_id)
+        .filter(|id| buffer_store::get_buffer_type(id) == Some(BufferType::Frames))
+        .ok_or_else(|| format!("Session '{}' has no frame buffer", session_id))?;
+    let mut scan = ObdScan::default();
+    let offset = scan.add_buffer(&buffer_id, 0);
+    let info = scan.info();
+
+    if !query_pids.unwrap_or(false) {
+        return Ok(info);
+    }
+    if is_session_transmit_armed(&session_id).await != Some(true) {
+        tlog!("[obd] Session '{}' transmit not armed, skipping PID 00 query", session_id);
+        return Ok(info);
+    }
+
+    let request = pid_query_frame(&info, bus.unwrap_or(0));
+    let result = crate::transmit::io_transmit_can_frame(app, session_id.clone(), request).await?;
+    if !result.success {
+        return Err(result.error.unwrap_or_else(|| "Transmit failed".to_string()));
+    }
+    tokio::time::sleep(OBD_QUERY_WINDOW).await;
+
+    let end = scan.add_buffer(&buffer_id, offset);
+    let info = ObdInfo { queried: true, ..scan.info() };
+    tlog!(
+        "[obd] Session '{}' PID 00 query: {} responder(s), {} frame(s) since request",
+        session_id,
+        info.responders.len(),
+        end - offset
+    );
+    Ok(info)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::test_support::can_frame;
+
+    #[test]
+    fn test_scan_frames_finds_requests_and_responders() {
+        let frames = vec![
+            can_frame(0x100, 0, vec![0x00]),
+            can_frame(0x7DF, 0, vec![0x02, 0x01, 0x00]),
+            can_frame(0x7E8, 0, vec![0x06, 0x41, 0x00, 0xBE, 0x1F, 0xA8, 0x13]),
+            can_frame(0x7E9, 0, vec![0x03, 0x41, 0x0D, 0x20]),
+            can_frame(0x7E8, 0, vec![0x03, 0x41, 0x0D, 0x21]),
+            FrameMessage { is_extended: true, ..can_frame(0x18DAF110, 0, vec![0x03, 0x41, 0x05, 0x50]) },
+            FrameMessage { is_extended: true, ..can_frame(0x18DA10F1, 0, vec![0x02, 0x01, 0x05]) },
+        ];
+        // Replies scanned after the request add to what was seen before it
+        let mut scan = ObdScan::default();
+        scan.add_frames(&frames[..4]);
+        scan.add_frames(&frames[4..]);
+        let info = scan.info();
+        assert!(info.present);
+        assert_eq!(info.request_ids, vec![0x7DF, 0x18DA10F1]);
+        let ids: Vec<u32> = info.responders.iter().map(|r| r.frame_id).collect();
+        assert_eq!(ids, vec![0x7E8, 0x7E9, 0x18DAF110]);
+        assert_eq!(info.responders[0].frame_count, 2);
+        assert_eq!(info.responders[1].supported_pids, None);
+
+        let mut scan = ObdScan::default();
+        scan.add_frames(&frames[..1]);
+        assert!(!scan.info().present);
+    }
+
+    #[test]
+    fn test_supported_pids_decodes_bitmask() {
+        // 0xBE1FA813 is a typical engine ECU reply
+        let reply = can_frame(0x7E8, 0, vec![0x06, 0x41, 0x00, 0xBE, 0x1F, 0xA8, 0x13]);
+        assert_eq!(
+            supported_pids(&reply),
+            Some(vec![0x01, 0x03, 0x04, 0x05, 0x06, 0x07, 0x0C, 0x0D, 0x0E, 0x0F, 0x10, 0x11, 0x13, 0x15, 0x1C, 0x1F, 0x20])
+        );
+        // Other modes/PIDs and short frames aren't PID 00 replies
+        assert_eq!(supported_pids(&can_frame(0x7E8, 0, vec![0x03, 0x41, 0x0D, 0x20])), None);
+        assert_eq!(supported_pids(&can_frame(0x7E8, 0, vec![0x06, 0x41, 0x00])), None);
+    }
+}
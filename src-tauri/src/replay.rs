@@ -75,6 +75,19 @@ static IO_REPLAY_TASKS: Lazy<tokio::sync::Mutex<HashMap<String, ReplayTask>>> =
 // Maximum inter-frame sleep to avoid hanging on large timestamp gaps (5 seconds).
 const MAX_SLEEP_US: u64 = 5_000_000;
 
+// Frames closer together than this (after speed scaling) are sent as one burst
+// through `transmit_frames`; sleeping for less than ~1 ms isn't possible anyway.
+const BURST_GAP_US: u64 = 1_000;
+
+// Largest burst, so cancellation and progress stay responsive during dense traffic.
+const MAX_BURST_FRAMES: usize = 64;
+
+/// Delay between frame `i` and the next one, scaled by the replay speed.
+fn scaled_gap_us(frames: &[ReplayFrame], i: usize, speed: f64) -> u64 {
+    let delta_us = frames[i + 1].timestamp_us.saturating_sub(frames[i].timestamp_us);
+    ((delta_us as f64) / speed).round() as u64
+}
+
 // ============================================================================
 // Tauri Commands
 // ============================================================================
@@ -83,6 +96,8 @@ const MAX_SLEEP_US: u64 = 5_000_000;
 ///
 /// Frames are transmitted in order with delays derived from their original timestamps
 /// divided by `speed`. A speed of 1.0 is realtime; 2.0 is twice as fast.
+/// Frames less than a millisecond apart go out as one burst, which GVRET
+/// devices write in a single transfer.
 ///
 /// History events are emitted as `transmit-history` (one per frame). A `repeat-stopped`
 /// event is emitted when the replay finishes or is cancelled.
@@ -128,29 +143,70 @@ pub async fn io_start_replay(
         let mut pass: u64 = 1;
 
         'outer: loop {
-            for i in 0..frames.len() {
+            let mut i = 0;
+            while i < frames.len() {
                 if cancel_flag_clone.load(Ordering::Relaxed) {
                     cancelled = true;
                     break 'outer;
                 }
 
-                let frame = &frames[i].frame;
+                // Frames closer together than the sleep granularity go out as one burst
+                let mut end = i + 1;
+                while end < frames.len()
+                    && end - i < MAX_BURST_FRAMES
+                    && scaled_gap_us(&frames, end - 1, speed) < BURST_GAP_US
+                {
+                    end += 1;
+                }
+                let burst: Vec<CanTransmitFrame> = frames[i..end].iter().map(|f| f.frame.clone()).collect();
 
-                // Transmit the frame. Writing to SQLite per frame is safe here because
+                // Transmit the burst. Writing to SQLite per frame is safe here because
                 // the write_entry mutex lock is held only for the INSERT (~microseconds).
-                let result = io::transmit_frame(&session_id_clone, frame).await;
-
-                // Stop on permanent device errors
-                let is_permanent = match &result {
-                    Ok(r) => r.error.as_deref().map(crate::transmit::is_permanent_error_pub).unwrap_or(false) && !r.success,
-                    Err(e) => crate::transmit::is_permanent_error_pub(e),
+                let results: Vec<Result<io::TransmitResult, String>> = if burst.len() == 1 {
+                    vec![io::transmit_frame(&session_id_clone, &burst[0]).await]
+                } else {
+                    match io::transmit_frames(&session_id_clone, &burst).await {
+                        Ok(results) => results.into_iter().map(Ok).collect(),
+                        Err(e) => burst.iter().map(|_| Err(e.clone())).collect(),
+                    }
                 };
-                if is_permanent {
-                    let err_msg = match &result {
-                        Ok(r) => r.error.clone().unwrap_or_else(|| "Device error".to_string()),
-                        Err(e) => e.clone(),
+
+                for (frame, result) in burst.iter().zip(results) {
+                    // Stop on permanent device errors
+                    let is_permanent = match &result {
+                        Ok(r) => r.error.as_deref().map(crate::transmit::is_permanent_error_pub).unwrap_or(false) && !r.success,
+                        Err(e) => crate::transmit::is_permanent_error_pub(e),
+                    };
+                    if is_permanent {
+                        let err_msg = match &result {
+                            Ok(r) => r.error.clone().unwrap_or_else(|| "Device error".to_string()),
+                            Err(e) => e.clone(),
+                        };
+                        // Write the failed frame to history before stopping
+                        crate::transmit_history::write_entry(
+                            &session_id_clone, "can",
+                            Some(frame.frame_id as i64),
+                            Some(frame.data.len() as i64),
+                            &frame.data,
+                            frame.bus as i64,
+                            frame.is_extended,
+                            frame.is_fd,
+                            false,
+                            Some(&err_msg),
+                        );
+                        let _ = app.emit("transmit-history-updated", ());
+                        tlog!("[replay] Stopping replay '{}' due to permanent error: {}", replay_id_for_task, err_msg);
+                        let _ = app.emit("repeat-stopped", RepeatStoppedEvent {
+                            queue_id: replay_id_for_task.clone(),
+                            reason: err_msg,
+                        });
+                        return;
+                    }
+
+                    let (r_success, r_error) = match &result {
+                        Ok(r) => (r.success, r.error.clone()),
+                        Err(e) => (false, Some(e.clone())),
                     };
-                    // Write the failed frame to history before stopping
                     crate::transmit_history::write_entry(
                         &session_id_clone, "can",
                         Some(frame.frame_id as i64),
@@ -159,37 +215,14 @@ pub async fn io_start_replay(
                         frame.bus as i64,
                         frame.is_extended,
                         frame.is_fd,
-                        false,
-                        Some(&err_msg),
+                        r_success,
+                        r_error.as_deref(),
                     );
-                    let _ = app.emit("transmit-history-updated", ());
-                    tlog!("[replay] Stopping replay '{}' due to permanent error: {}", replay_id_for_task, err_msg);
-                    let _ = app.emit("repeat-stopped", RepeatStoppedEvent {
-                        queue_id: replay_id_for_task.clone(),
-                        reason: err_msg,
-                    });
-                    return;
-                }
 
-                let (r_success, r_error) = match &result {
-                    Ok(r) => (r.success, r.error.clone()),
-                    Err(e) => (false, Some(e.clone())),
-                };
-                crate::transmit_history::write_entry(
-                    &session_id_clone, "can",
-                    Some(frame.frame_id as i64),
-                    Some(frame.data.len() as i64),
-                    &frame.data,
-                    frame.bus as i64,
-                    frame.is_extended,
-                    frame.is_fd,
-                    r_success,
-                    r_error.as_deref(),
-                );
-
-                match result {
-                    Ok(r) if r.success => frames_sent += 1,
-                    _ => frames_failed += 1,
+                    match result {
+                        Ok(r) if r.success => frames_sent += 1,
+                        _ => frames_failed += 1,
+                    }
                 }
 
                 // Throttled progress + history update (~250 ms)
@@ -204,16 +237,13 @@ pub async fn io_start_replay(
                 }
 
                 // Sleep until the next frame's timestamp (scaled by speed)
-                if i + 1 < frames.len() {
-                    let next_ts = frames[i + 1].timestamp_us;
-                    let curr_ts = frames[i].timestamp_us;
-                    let delta_us = next_ts.saturating_sub(curr_ts);
-                    let sleep_us = ((delta_us as f64) / speed).round() as u64;
-                    let capped_us = sleep_us.min(MAX_SLEEP_US);
+                if end < frames.len() {
+                    let capped_us = scaled_gap_us(&frames, end - 1, speed).min(MAX_SLEEP_US);
                     if capped_us > 0 {
                         tokio::time::sleep(tokio::time::Duration::from_micros(capped_us)).await;
                     }
                 }
+                i = end;
             }
 
             if !loop_replay {
@@ -5,34 +5,37 @@
 
 use crate::{
     buffer_store,
+    capture_file::{self, CaptureFormat},
     credentials,
     io::{
-        create_session, destroy_session, get_session_capabilities, get_session_joiner_count, get_session_state,
-        get_session_listeners, join_session, leave_session, list_sessions, pause_session,
+        arm_session_transmit, create_session, destroy_session, get_session_capabilities, get_session_joiner_count, get_session_state,
+        get_session_listeners, join_session, leave_session, list_sessions, pause_session, stop_all_sessions,
         reconfigure_session, register_listener, reinitialize_session_if_safe, resume_session,
         resume_session_fresh, seek_session, seek_session_by_frame, set_listener_active, start_session, stop_session,
         suspend_session, switch_to_buffer_replay, resume_to_live_session, transmit_frame, unregister_listener,
         evict_session_listener, add_source_to_session, remove_source_from_session, update_source_bus_mappings, get_session_source_count,
-        update_session_direction, update_session_speed, update_session_time_range, ActiveSessionInfo, IOCapabilities, IODevice, IOState,
-        JoinSessionResult, ListenerInfo, RegisterListenerResult, ReinitializeResult, BufferReader, step_frame, StepResult,
-        BusMapping, InterfaceTraits, Protocol, TemporalMode,
+        update_session_bus_mapping, get_session_bus_mappings, SourceBusMappings,
+        update_session_bus_enabled, update_session_direction, update_session_transmit_timeout, update_session_min_tx_gap, update_session_buffer_tx_frames, update_session_source_transmit_enabled, session_device_info, DeviceInfo, update_session_speed, update_session_time_range, ActiveSessionInfo, IOCapabilities, IODevice, IOState,
+        JoinSessionResult, ListenerInfo, RegisterListenerResult, ReinitializeResult, StopSessionOutcome, BufferReader, PacingReference, emit_buffer_range, step_frame, EmitRangeResult, StepResult,
+        BusMapping, InterfaceTraits, Protocol, TemporalMode, CAN_EXTENDED_ID_MASK, CAN_STANDARD_ID_MASK,
         CsvReader, CsvReaderOptions,
-        GvretDeviceInfo, probe_gvret_tcp,
+        BusScanResult, GvretDeviceInfo, probe_gvret_tcp, scan_gvret_tcp_buses,
         ModbusTcpConfig, ModbusTcpReader,
         ModbusScanConfig, ScanCompletePayload, UnitIdScanConfig,
         MqttConfig, MqttReader,
         VirtualDeviceConfig, VirtualDeviceReader, VirtualInterfaceConfig, VirtualTrafficType,
-        ModbusRole, MultiSourceReader, SourceConfig,
+        ModbusRole, MultiSourceReader, SourceConfig, TimestampSource,
         PostgresConfig, PostgresReader, PostgresReaderOptions, PostgresSourceType,
         CanTransmitFrame, TransmitResult,
         emit_device_probe, DeviceProbePayload,
-        set_wake_settings as io_set_wake_settings,
+        set_wake_settings as io_set_wake_settings, set_session_emit_filter, set_session_ascii_view, session_frame_rate, session_parse_errors, ParseErrorEntry,
     },
     profile_tracker,
     settings::{self, AppSettings, IOProfile},
 };
+use crate::io::gvret::capture_raw_bytes_from_connection;
 #[cfg(not(target_os = "ios"))]
-use crate::io::probe_gvret_usb;
+use crate::io::{probe_gvret_usb, scan_gvret_usb_buses};
 use once_cell::sync::Lazy;
 use std::collections::HashMap;
 use std::sync::{
@@ -246,6 +249,44 @@ fn is_realtime_device(kind: &str) -> bool {
     )
 }
 
+/// Raw byte capture setting of a GVRET profile (`capture_raw_bytes`), or None for
+/// other kinds. GVRET sources carry it in `SourceConfig::emit_raw_bytes`.
+fn gvret_raw_bytes_capture(profile: &IOProfile) -> Option<bool> {
+    matches!(profile.kind.as_str(), "gvret_tcp" | "gvret-tcp" | "gvret_usb" | "gvret-usb")
+        .then(|| capture_raw_bytes_from_connection(&profile.connection))
+}
+
+/// Frame ID relabelling from a profile's `id_remap` connection setting: an
+/// object of arrival ID -> captured ID, written as decimal or 0x-prefixed hex.
+/// Entries that don't parse or are wider than 29 bits are left out.
+fn id_remap_from_profile(profile: &IOProfile) -> Option<HashMap<u32, u32>> {
+    let parse_str = |s: &str| -> Option<u32> {
+        let s = s.trim();
+        match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+            Some(hex) => u32::from_str_radix(hex, 16).ok(),
+            None => s.parse().ok(),
+        }
+    };
+    let parse = |v: &serde_json::Value| -> Option<u32> {
+        match v {
+            serde_json::Value::Number(n) => n.as_u64().and_then(|n| u32::try_from(n).ok()),
+            serde_json::Value::String(s) => parse_str(s),
+            _ => None,
+        }
+    };
+    let entries = profile.connection.get("id_remap")?.as_object()?;
+    let mut id_remap = HashMap::new();
+    for (from, to) in entries {
+        match (parse_str(from), parse(to)) {
+            (Some(from), Some(to)) if from <= CAN_EXTENDED_ID_MASK && to <= CAN_EXTENDED_ID_MASK => {
+                id_remap.insert(from, to);
+            }
+            _ => tlog!("[id_remap_from_profile] Profile '{}': skipping invalid entry {} -> {}", profile.id, from, to),
+        }
+    }
+    (!id_remap.is_empty()).then_some(id_remap)
+}
+
 /// Create a SourceConfig from an IOProfile for use with MultiSourceReader.
 /// This extracts the common device configuration logic used by both single-device
 /// and multi-device session creation.
@@ -280,7 +321,7 @@ fn create_source_config_from_profile(
         delimiter: None,
         max_frame_length: None,
         min_frame_length: None,
-        emit_raw_bytes: None,
+        emit_raw_bytes: gvret_raw_bytes_capture(profile),
         // Frame ID extraction - not passed for single-source (uses profile settings)
         frame_id_start_byte: None,
         frame_id_bytes: None,
@@ -292,6 +333,9 @@ fn create_source_config_from_profile(
         modbus_polls: None,
         modbus_role: None,
         max_register_errors: None,
+        // Single-source sessions use the profile's timestamp_source setting
+        timestamp_source: None,
+        id_remap: id_remap_from_profile(profile),
     })
 }
 
@@ -410,6 +454,9 @@ pub async fn create_reader_session(
     app_name: Option<String>,
     // Modbus TCP poll groups (JSON-serialised from frontend catalog)
     modbus_polls: Option<String>,
+    // Tee every emitted batch to this file while the session streams
+    capture_file: Option<String>,
+    capture_format: Option<CaptureFormat>,
 ) -> Result<IOCapabilities, String> {
     let settings = settings::load_settings(app.clone())
         .await
@@ -661,6 +708,7 @@ pub async fn create_reader_session(
                 password,
                 topic,
                 client_id: None,
+                auto_stop_after_idle_ms: crate::io::auto_stop_idle_from_profile(&profile),
             };
 
             Box::new(MqttReader::new(app.clone(), session_id.clone(), config))
@@ -793,6 +841,10 @@ pub async fn create_reader_session(
 
     let result = create_session(app, session_id.clone(), reader, listener_id, app_name, None).await;
 
+    if result.is_new {
+        start_session_capture(&session_id, capture_file.as_deref(), capture_format).await?;
+    }
+
     // Auto-start the session after creation (only for real-time devices)
     // Playback sources (postgres, csv) should NOT auto-start because frames would be emitted
     // before the frontend has registered its listener and set up event handlers.
@@ -814,6 +866,24 @@ pub async fn create_reader_session(
     Ok(result.capabilities)
 }
 
+/// Open the capture file for a newly created session, before it starts streaming.
+/// A file that can't be created fails the whole session rather than leaving a
+/// capture the user asked for silently not running.
+async fn start_session_capture(
+    session_id: &str,
+    path: Option<&str>,
+    format: Option<CaptureFormat>,
+) -> Result<(), String> {
+    let Some(path) = path else {
+        return Ok(());
+    };
+    if let Err(e) = capture_file::start_capture(session_id, path, format.unwrap_or_default()) {
+        let _ = destroy_reader_session(session_id.to_string()).await;
+        return Err(e);
+    }
+    Ok(())
+}
+
 /// Get the state of a reader session
 #[tauri::command(rename_all = "snake_case")]
 pub async fn get_reader_session_state(session_id: String) -> Result<Option<IOState>, String> {
@@ -870,6 +940,13 @@ pub async fn stop_reader_session(session_id: String) -> Result<IOState, String>
     stop_session(&session_id).await
 }
 
+/// Stop all active reader sessions (app shutdown / panic button).
+/// Sessions stay alive; returns the resulting state of each.
+#[tauri::command(rename_all = "snake_case")]
+pub async fn stop_all_reader_sessions() -> Vec<StopSessionOutcome> {
+    stop_all_sessions().await
+}
+
 /// Pause a reader session
 /// Returns the confirmed state after the operation.
 #[tauri::command(rename_all = "snake_case")]
@@ -1018,6 +1095,54 @@ pub async fn update_reader_direction(session_id: String, reverse: bool) -> Resul
     update_session_direction(&session_id, reverse).await
 }
 
+/// Enable or disable playback of one bus in a buffer reader session.
+/// Frames on disabled buses are skipped; the remaining buses keep their timing.
+#[tauri::command(rename_all = "snake_case")]
+pub async fn set_reader_bus_enabled(session_id: String, bus: u8, enabled: bool) -> Result<(), String> {
+    update_session_bus_enabled(&session_id, bus, enabled).await
+}
+
+/// Set how long transmits on a session wait for the device to confirm a write.
+/// Raise it for slow USB adapters or buses with no other node to ACK.
+#[tauri::command(rename_all = "snake_case")]
+pub async fn set_session_transmit_timeout(session_id: String, timeout_ms: u64) -> Result<(), String> {
+    update_session_transmit_timeout(&session_id, timeout_ms).await
+}
+
+/// Enforce a minimum gap between writes on a session, in microseconds (0 disables).
+/// Writes are spaced from the time the previous one was confirmed, however fast
+/// callers queue them.
+#[tauri::command(rename_all = "snake_case")]
+pub async fn set_min_tx_gap(session_id: String, gap_us: u64) -> Result<(), String> {
+    update_session_min_tx_gap(&session_id, gap_us).await
+}
+
+/// Keep the session's own transmitted frames (direction "tx") out of its buffer
+/// when `enabled` is false (default true). Every frame a multi-source session
+/// sends is recorded as a "tx" frame; with this off they still reach the live
+/// UI, but a later export or replay of the buffer won't contain them.
+#[tauri::command(rename_all = "snake_case")]
+pub async fn set_buffer_tx_frames(session_id: String, enabled: bool) -> Result<(), String> {
+    update_session_buffer_tx_frames(&session_id, enabled).await
+}
+
+/// Turn transmit through one source of a multi-source session off or on
+/// without disconnecting it: its frames keep arriving, but transmits routed to
+/// its buses fail with "transmit disabled". `source_idx` is the index used in
+/// the session's bus mappings. A source re-added by a hot swap gets a new
+/// index with transmit enabled.
+#[tauri::command(rename_all = "snake_case")]
+pub async fn set_source_transmit_enabled(session_id: String, source_idx: usize, enabled: bool) -> Result<(), String> {
+    update_session_source_transmit_enabled(&session_id, source_idx, enabled).await
+}
+
+/// Ask a running session's adapter for its firmware versions and serial number.
+/// slcan sources answer without interrupting the frame stream.
+#[tauri::command(rename_all = "snake_case")]
+pub async fn device_info(session_id: String) -> Result<DeviceInfo, String> {
+    session_device_info(&session_id).await
+}
+
 /// Destroy a reader session
 #[tauri::command(rename_all = "snake_case")]
 pub async fn destroy_reader_session(session_id: String) -> Result<(), String> {
@@ -1042,6 +1167,13 @@ pub async fn create_buffer_reader_session(
     session_id: String,
     buffer_id: Option<String>,
     speed: Option<f64>,
+    start_paused: Option<bool>,
+    start_at_us: Option<i64>,
+    max_fps: Option<f64>,
+    reference_pacing_id: Option<u32>,
+    reference_pacing_bus: Option<u8>,
+    reference_pacing_extended: Option<bool>,
+    absolute_time: Option<bool>,
 ) -> Result<IOCapabilities, String> {
     if !buffer_store::has_data() {
         return Err("No data in buffer. Please import a CSV file first.".to_string());
@@ -1064,7 +1196,15 @@ pub async fn create_buffer_reader_session(
             session_id.clone(),
             speed.unwrap_or(0.0),
         ),
-    };
+    }
+    .with_start_position(start_paused.unwrap_or(false), start_at_us)
+    .with_max_fps(max_fps)
+    .with_reference_pacing(reference_pacing_id.map(|frame_id| PacingReference {
+        frame_id,
+        bus: reference_pacing_bus,
+        is_extended: reference_pacing_extended.unwrap_or(frame_id > CAN_STANDARD_ID_MASK),
+    }))
+    .with_absolute_time(absolute_time.unwrap_or(false));
 
     let result = create_session(app, session_id, Box::new(reader), None, None, None).await;
     Ok(result.capabilities)
@@ -1201,10 +1341,31 @@ pub async fn step_buffer_frame(
     step_frame(&app, &session_id, current_frame_index, current_timestamp_us, backward, filter_frame_ids.as_deref())
 }
 
+/// Emit the buffer frames in `[start_us, end_us]` at once (no pacing), e.g. to
+/// fill the visible window after a jump. Leaves playback position and state
+/// unchanged; see `emit_buffer_range` for how it interacts with playback.
+#[tauri::command(rename_all = "snake_case")]
+pub async fn emit_buffer_range_cmd(
+    app: tauri::AppHandle,
+    session_id: String,
+    start_us: u64,
+    end_us: u64,
+    max_frames: Option<usize>,
+) -> Result<EmitRangeResult, String> {
+    emit_buffer_range(&app, &session_id, start_us, end_us, max_frames)
+}
+
 // Legacy heartbeat commands removed - use register_session_listener/unregister_session_listener instead
 
+/// Arm or disarm transmit on a session. Sessions start disarmed and reject
+/// every transmit until armed.
+#[tauri::command(rename_all = "snake_case")]
+pub async fn arm_transmit(session_id: String, armed: bool) -> Result<(), String> {
+    arm_session_transmit(&session_id, armed).await
+}
+
 /// Transmit a CAN frame through a session.
-/// The session must be connected and support transmission.
+/// The session must be connected, support transmission and be armed.
 #[tauri::command(rename_all = "snake_case")]
 pub async fn session_transmit_frame(
     session_id: String,
@@ -1342,6 +1503,24 @@ pub async fn update_source_bus_mappings_cmd(
     update_source_bus_mappings(&session_id, &profile_id, bus_mappings).await
 }
 
+/// Change one source's bus mappings in a running multi-source session.
+/// Remaps and disabled buses take effect without reconnecting; enabling a bus
+/// the source never opened reconnects that source only.
+#[tauri::command(rename_all = "snake_case")]
+pub async fn update_bus_mapping(
+    session_id: String,
+    source_idx: usize,
+    mappings: Vec<BusMapping>,
+) -> Result<IOCapabilities, String> {
+    update_session_bus_mapping(&session_id, source_idx, mappings).await
+}
+
+/// Get the current bus mappings of each source in a multi-source session.
+#[tauri::command(rename_all = "snake_case")]
+pub async fn get_bus_mappings(session_id: String) -> Result<Vec<SourceBusMappings>, String> {
+    get_session_bus_mappings(&session_id).await
+}
+
 /// Check if it's safe to reinitialize a session and do so if safe.
 /// Reinitialize is only safe if the requesting listener is the only listener.
 /// This is an atomic check-and-act operation to prevent race conditions.
@@ -1356,6 +1535,41 @@ pub async fn reinitialize_session_if_safe_cmd(
     reinitialize_session_if_safe(&session_id, &listener_id).await
 }
 
+/// Restrict the frame IDs a session emits to the frontend (None = all IDs).
+/// Frames are still fully buffered; this only reduces `frame-message` IPC volume
+/// for focused views that render a handful of IDs.
+#[tauri::command(rename_all = "snake_case")]
+pub async fn set_active_listeners(
+    session_id: String,
+    ids: Option<Vec<u32>>,
+) -> Result<(), String> {
+    set_session_emit_filter(&session_id, ids);
+    Ok(())
+}
+
+/// Enable or disable the ASCII view for a session: emitted frames carry an
+/// `ascii` field with their data bytes rendered as printable ASCII ('.' for
+/// anything else). Off by default.
+#[tauri::command(rename_all = "snake_case")]
+pub async fn set_session_ascii_view_cmd(session_id: String, enabled: bool) -> Result<(), String> {
+    set_session_ascii_view(&session_id, enabled);
+    Ok(())
+}
+
+/// Frames per second a session has emitted over the last couple of seconds,
+/// for a quick rate readout. Works for every reader type.
+#[tauri::command(rename_all = "snake_case")]
+pub async fn frame_rate(session_id: String) -> f64 {
+    session_frame_rate(&session_id)
+}
+
+/// A session's most recent parse failures (malformed slcan lines, GVRET resyncs,
+/// serial framer overflows), oldest first, with a sample of the offending bytes.
+#[tauri::command(rename_all = "snake_case")]
+pub fn recent_parse_errors(session_id: String) -> Vec<ParseErrorEntry> {
+    session_parse_errors(&session_id)
+}
+
 /// Set whether a listener is active (receiving frames).
 /// When a listener detaches, set is_active to false to stop receiving frames.
 /// When they rejoin, set is_active to true to resume receiving frames.
@@ -1442,6 +1656,84 @@ pub async fn probe_gvret_device(
     }
 }
 
+/// Listen to a GVRET device for `duration_ms` and report per-bus traffic, so
+/// bus mappings can be set up for the buses that are actually wired.
+///
+/// Like `probe_gvret_device`, the connection comes from the profile and is
+/// closed afterwards. Silent buses are omitted from the result.
+#[tauri::command(rename_all = "snake_case")]
+pub async fn scan_gvret_buses(
+    app: tauri::AppHandle,
+    profile_id: String,
+    duration_ms: u64,
+) -> Result<Vec<BusScanResult>, String> {
+    let settings = settings::load_settings(app.clone())
+        .await
+        .map_err(|e| format!("Failed to load settings: {}", e))?;
+
+    let profile = settings
+        .io_profiles
+        .iter()
+        .find(|p| p.id == profile_id)
+        .ok_or_else(|| format!("Profile '{}' not found", profile_id))?;
+
+    match profile.kind.as_str() {
+        "gvret_tcp" | "gvret-tcp" => {
+            let host = profile
+                .connection
+                .get("host")
+                .and_then(|v| v.as_str())
+                .unwrap_or("127.0.0.1");
+            let port = profile
+                .connection
+                .get("port")
+                .and_then(|v| v.as_i64().or_else(|| v.as_str().and_then(|s| s.parse().ok())))
+                .unwrap_or(23) as u16;
+            let timeout_sec = profile
+                .connection
+                .get("timeout")
+                .and_then(|v| v.as_f64().or_else(|| v.as_str().and_then(|s| s.parse().ok())))
+                .unwrap_or(5.0);
+
+            scan_gvret_tcp_buses(host, port, timeout_sec, duration_ms)
+                .await
+                .map_err(String::from)
+        }
+        #[cfg(not(target_os = "ios"))]
+        "gvret_usb" | "gvret-usb" => {
+            let port = profile
+                .connection
+                .get("port")
+                .and_then(|v| v.as_str())
+                .ok_or_else(|| "Serial port is required for GVRET USB".to_string())?;
+            let baud_rate = profile
+                .connection
+                .get("baud_rate")
+                .and_then(|v| v.as_i64().or_else(|| v.as_str().and_then(|s| s.parse().ok())))
+                .unwrap_or(115200) as u32;
+
+            // Same port ownership and DTR/RTS handling as a streaming session
+            let line_control = crate::io::serial::SerialLineControl::from_connection(&profile.connection);
+
+            // Run blocking serial scan in a dedicated thread
+            let port_owned = port.to_string();
+            tokio::task::spawn_blocking(move || {
+                scan_gvret_usb_buses(&port_owned, baud_rate, line_control, duration_ms).map_err(String::from)
+            })
+                .await
+                .map_err(|e| format!("Scan task failed: {}", e))?
+        }
+        #[cfg(target_os = "ios")]
+        "gvret_usb" | "gvret-usb" => {
+            Err("GVRET USB is not available on iOS".to_string())
+        }
+        _ => Err(format!(
+            "Profile '{}' is not a GVRET device (kind: {})",
+            profile_id, profile.kind
+        )),
+    }
+}
+
 // ============================================================================
 // Unified Device Probe API
 // ============================================================================
@@ -1462,7 +1754,7 @@ pub struct DeviceProbeResult {
     pub primary_info: Option<String>,
     /// Secondary info line (hardware version, channel count, etc.)
     pub secondary_info: Option<String>,
-    /// Whether device supports CAN FD (gs_usb devices only, None for others)
+    /// Whether device supports CAN FD (gs_usb, GVRET, slcan, SocketCAN; None if unknown)
     pub supports_fd: Option<bool>,
     /// Error message if probe failed
     pub error: Option<String>,
@@ -1488,9 +1780,6 @@ pub async fn probe_device(
     app: tauri::AppHandle,
     profile_id: String,
 ) -> Result<DeviceProbeResult, String> {
-    #[cfg(not(target_os = "ios"))]
-    use crate::io::slcan::reader::probe_slcan_device;
-
     // Buffer IDs — metadata already in memory, no profile lookup needed
     if profile_id.starts_with("buf_") || profile_id == "__imported_buffer__" {
         if let Some(meta) = buffer_store::get_buffer_metadata(&profile_id) {
@@ -1547,7 +1836,33 @@ pub async fn probe_device(
         .find(|p| p.id == profile_id)
         .ok_or_else(|| format!("Profile '{}' not found", profile_id))?;
 
-    let result = match profile.kind.as_str() {
+    let result = probe_profile(profile).await;
+
+    // Emit probe result event (fresh probe, not cached)
+    if let Ok(ref probe_result) = result {
+        emit_device_probe(&app, DeviceProbePayload {
+            profile_id: profile_id.clone(),
+            device_type: probe_result.device_type.clone(),
+            address: probe_result.secondary_info.clone().unwrap_or_default(),
+            success: probe_result.success,
+            cached: false,
+            bus_count: probe_result.bus_count,
+            error: probe_result.error.clone(),
+        });
+        // Cache successful probe results for future use
+        cache_probe_result(&profile_id, probe_result);
+    }
+
+    result
+}
+
+/// Probe the device a profile points at, keyed on `profile.kind`.
+/// Shared by `probe_device` (saved profiles, cached) and `test_profile`.
+async fn probe_profile(profile: &IOProfile) -> Result<DeviceProbeResult, String> {
+    #[cfg(not(target_os = "ios"))]
+    use crate::io::slcan::reader::probe_slcan_device;
+
+    match profile.kind.as_str() {
         // GVRET devices - multi-bus
         "gvret_tcp" | "gvret-tcp" => {
             let host = profile.connection.get("host")
@@ -1568,7 +1883,7 @@ pub async fn probe_device(
                     bus_count: info.bus_count,
                     primary_info: Some(format!("{} buses available", info.bus_count)),
                     secondary_info: Some(format!("{}:{}", host, port)),
-                    supports_fd: None,
+                    supports_fd: info.supports_fd,
                     error: None,
                 }),
                 Err(e) => Ok(DeviceProbeResult {
@@ -1602,7 +1917,7 @@ pub async fn probe_device(
                     bus_count: info.bus_count,
                     primary_info: Some(format!("{} buses available", info.bus_count)),
                     secondary_info: Some(port.to_string()),
-                    supports_fd: None,
+                    supports_fd: info.supports_fd,
                     error: None,
                 }),
                 Ok(Err(e)) => Ok(DeviceProbeResult {
@@ -1672,7 +1987,7 @@ pub async fn probe_device(
                 bus_count: if result.success { 1 } else { 0 },
                 primary_info: result.version,
                 secondary_info: result.hardware_version,
-                supports_fd: None,
+                supports_fd: result.supports_fd,
                 error: result.error,
             })
         }
@@ -1744,14 +2059,24 @@ pub async fn probe_device(
             // Check if the interface exists by reading from /sys/class/net
             let path = format!("/sys/class/net/{}", interface);
             if std::path::Path::new(&path).exists() {
+                // An interface in FD mode reports the canfd_frame MTU (72); classic is 16.
+                // This reflects the current configuration rather than the controller's limits.
+                let supports_fd = std::fs::read_to_string(format!("{}/mtu", path))
+                    .ok()
+                    .and_then(|mtu| mtu.trim().parse::<u32>().ok())
+                    .map(|mtu| mtu >= 72);
                 Ok(DeviceProbeResult {
                     success: true,
                     device_type: "socketcan".to_string(),
                     is_multi_bus: false,
                     bus_count: 1,
                     primary_info: Some(format!("Interface: {}", interface)),
-                    secondary_info: None,
-                    supports_fd: None,
+                    secondary_info: if supports_fd == Some(true) {
+                        Some("CAN FD enabled".to_string())
+                    } else {
+                        None
+                    },
+                    supports_fd,
                     error: None,
                 })
             } else {
@@ -1913,26 +2238,119 @@ pub async fn probe_device(
         // Recorded sources or unsupported types
         _ => Err(format!(
             "Profile '{}' is not a real-time device (kind: {})",
-            profile_id, profile.kind
+            profile.id, profile.kind
         )),
+    }
+}
+
+/// Result of testing a profile with `test_profile`
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct ProfileTestResult {
+    /// Probe outcome and device info
+    #[serde(flatten)]
+    pub probe: DeviceProbeResult,
+    /// Settings that look wrong even though the device answered,
+    /// such as a bitrate the adapter can't run at
+    pub warnings: Vec<String>,
+}
+
+/// Bitrates the profile editor can offer for a profile kind, taken from the
+/// same tables the device code uses at connect time.
+#[tauri::command(rename_all = "snake_case")]
+pub async fn supported_bitrates(profile_kind: String) -> Result<crate::io::traits::BitrateSupport, String> {
+    crate::io::traits::bitrate_support_for_profile_kind(&profile_kind)
+        .ok_or_else(|| format!("Profile kind '{}' has no configurable CAN bitrate", profile_kind))
+}
+
+/// Check a CAN profile's bitrate settings against what its adapter supports.
+fn profile_bitrate_warnings(profile: &IOProfile) -> Vec<String> {
+    let get_u32 = |key: &str| {
+        profile.connection.get(key)
+            .and_then(|v| v.as_i64().or_else(|| v.as_str().and_then(|s| s.parse().ok())))
+            .map(|v| v as u32)
     };
+    let mut warnings = Vec::new();
 
-    // Emit probe result event (fresh probe, not cached)
-    if let Ok(ref probe_result) = result {
-        emit_device_probe(&app, DeviceProbePayload {
-            profile_id: profile_id.clone(),
-            device_type: probe_result.device_type.clone(),
-            address: probe_result.secondary_info.clone().unwrap_or_default(),
-            success: probe_result.success,
-            cached: false,
-            bus_count: probe_result.bus_count,
-            error: probe_result.error.clone(),
-        });
-        // Cache successful probe results for future use
-        cache_probe_result(&profile_id, probe_result);
+    if !matches!(profile.kind.as_str(), "slcan" | "gs_usb" | "socketcan") {
+        return warnings;
+    }
+    let Some(support) = crate::io::traits::bitrate_support_for_profile_kind(&profile.kind) else {
+        return warnings;
+    };
+    let Some(bitrate) = get_u32("bitrate") else {
+        return warnings;
+    };
+    let enable_fd = profile.connection.get("enable_fd")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false);
+    let data_bitrate = get_u32("data_bitrate").unwrap_or(2_000_000);
+
+    let list = |rates: &[u32]| rates.iter().map(|r| r.to_string()).collect::<Vec<_>>().join(", ");
+
+    // Adapters with fixed tables only run at the rates listed
+    if !support.arbitrary {
+        if !support.fixed.contains(&bitrate) {
+            warnings.push(format!(
+                "Bitrate {} bps is not supported. Valid bitrates: {}",
+                bitrate,
+                list(&support.fixed)
+            ));
+        }
+        if enable_fd && !support.fd_data_rates.contains(&data_bitrate) {
+            warnings.push(format!(
+                "Data bitrate {} bps is not supported. Valid data bitrates: {}",
+                data_bitrate,
+                list(&support.fd_data_rates)
+            ));
+        }
+        return warnings;
     }
 
-    result
+    // Classic CAN nominal bitrates run from 5 kbit/s (ISO 11898-3) to 1 Mbit/s
+    if !(5_000..=1_000_000).contains(&bitrate) {
+        warnings.push(format!(
+            "Bitrate {} bps is outside the CAN range (5000-1000000 bps)",
+            bitrate
+        ));
+    }
+    if enable_fd {
+        if let Err(e) = crate::io::gs_usb::validate_fd_bitrates(bitrate, data_bitrate) {
+            warnings.push(e);
+        }
+    }
+    warnings
+}
+
+/// Test a profile's connectivity without starting a session.
+///
+/// Takes the profile itself rather than an ID, so unsaved settings can be
+/// tested from the profile dialog. Runs the probe for `profile.kind` (the same
+/// one `probe_device` uses), briefly opens SocketCAN interfaces, and checks
+/// the bitrate settings. Nothing is cached and no session or buffer is created.
+#[tauri::command(rename_all = "snake_case")]
+pub async fn test_profile(profile: IOProfile) -> Result<ProfileTestResult, String> {
+    #[allow(unused_mut)]
+    let mut probe = probe_profile(&profile).await?;
+
+    // The sysfs check only shows the interface exists; opening it proves it's usable
+    #[cfg(target_os = "linux")]
+    if probe.success && profile.kind == "socketcan" {
+        let interface = profile.connection.get("interface")
+            .and_then(|v| v.as_str())
+            .unwrap_or("can0");
+        if let Err(e) = crate::io::SocketCanReader::new(interface) {
+            probe.success = false;
+            probe.bus_count = 0;
+            probe.error = Some(e);
+        }
+    }
+
+    let warnings = profile_bitrate_warnings(&profile);
+    tlog!(
+        "[test_profile] '{}' ({}): success={}, warnings={}",
+        profile.name, profile.kind, probe.success, warnings.len()
+    );
+    Ok(ProfileTestResult { probe, warnings })
 }
 
 // ============================================================================
@@ -1984,6 +2402,9 @@ pub struct MultiSourceInput {
     /// Modbus interface role (client or server)
     #[serde(default)]
     pub modbus_role: Option<ModbusRole>,
+    /// Frame ID relabelling (arrival ID -> captured ID)
+    #[serde(default)]
+    pub id_remap: Option<HashMap<u32, u32>>,
 }
 
 /// Convert a MultiSourceInput to a SourceConfig, resolving profile name and kind from settings.
@@ -2045,7 +2466,7 @@ fn resolve_source_config(
         delimiter: input.delimiter,
         max_frame_length: input.max_frame_length,
         min_frame_length: input.min_frame_length,
-        emit_raw_bytes: input.emit_raw_bytes,
+        emit_raw_bytes: input.emit_raw_bytes.or_else(|| gvret_raw_bytes_capture(profile)),
         frame_id_start_byte: input.frame_id_start_byte,
         frame_id_bytes: input.frame_id_bytes,
         frame_id_big_endian: input.frame_id_big_endian,
@@ -2055,6 +2476,8 @@ fn resolve_source_config(
         modbus_polls: None,    // Injected by create_multi_source_session
         modbus_role: input.modbus_role,
         max_register_errors: None, // Injected by create_multi_source_session
+        timestamp_source: None,    // Injected by create_multi_source_session
+        id_remap: input.id_remap.or_else(|| id_remap_from_profile(profile)),
     })
 }
 
@@ -2066,6 +2489,8 @@ fn resolve_source_config(
 /// - Remap device bus numbers to different output bus numbers
 ///
 /// The merged frames are sorted by timestamp and emitted as a single stream.
+/// With `per_source_buffers`, each source's frames are also kept in a buffer of
+/// their own (listed as `source_buffers` in the session info).
 #[tauri::command(rename_all = "snake_case")]
 pub async fn create_multi_source_session(
     app: tauri::AppHandle,
@@ -2074,6 +2499,10 @@ pub async fn create_multi_source_session(
     listener_id: Option<String>,
     app_name: Option<String>,
     modbus_polls: Option<String>,
+    timestamp_source: Option<TimestampSource>,
+    capture_file: Option<String>,
+    capture_format: Option<CaptureFormat>,
+    per_source_buffers: Option<bool>,
 ) -> Result<IOCapabilities, String> {
     if sources.is_empty() {
         return Err("At least one source is required".to_string());
@@ -2108,6 +2537,13 @@ pub async fn create_multi_source_session(
         }
     }
 
+    // Apply the session-level timestamp source to every source
+    if timestamp_source.is_some() {
+        for config in &mut source_configs {
+            config.timestamp_source = timestamp_source;
+        }
+    }
+
     // Validate all profiles are real-time devices supported by MultiSourceReader
     for config in &source_configs {
         if !is_realtime_device(&config.profile_kind) {
@@ -2155,7 +2591,8 @@ pub async fn create_multi_source_session(
     let source_display_names: Vec<String> = source_configs.iter()
         .map(|c| c.display_name.clone())
         .collect();
-    let reader = MultiSourceReader::new(app.clone(), session_id.clone(), source_configs)?;
+    let reader = MultiSourceReader::new(app.clone(), session_id.clone(), source_configs)?
+        .with_per_source_buffers(per_source_buffers.unwrap_or(false));
 
     // Register profile usage BEFORE create_session so lifecycle event has profile IDs
     for profile_id in &profile_ids {
@@ -2166,6 +2603,10 @@ pub async fn create_multi_source_session(
 
     let result = create_session(app, session_id.clone(), Box::new(reader), listener_id, app_name, Some(source_display_names)).await;
 
+    if result.is_new {
+        start_session_capture(&session_id, capture_file.as_deref(), capture_format).await?;
+    }
+
     // Auto-start the session if it's new OR if it exists but is stopped
     let should_start = if result.is_new {
         true
@@ -0,0 +1,278 @@
+// ui/src-tauri/src/signal_probe.rs
+//
+// Ad-hoc signal probe: decode one bit-field of a frame as a number without a
+// DBC, the manual equivalent of DBC decoding used to reverse-engineer an
+// unknown signal.
+//
+// Over a frame buffer the whole time series is returned at once. On a live
+// session the probe stays registered and the samples from each emitted batch
+// are sent as a `signal-probe` event until the probe is stopped or the
+// session is destroyed. Probes see batches from `emit_frames` before the
+// session emit filter, so filtering the view doesn't starve a probe.
+
+use can_dbc::ByteOrder;
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use tauri::AppHandle;
+
+use crate::buffer_store::{self, BufferType};
+use crate::dbc_stats::{extract_raw, raw_to_physical};
+use crate::io::{emit_to_session, get_session_state, FrameMessage, CAN_STANDARD_ID_MASK};
+
+/// Highest start bit a probe can use (last bit of a 64-byte CAN FD payload)
+const MAX_START_BIT: u64 = 64 * 8 - 1;
+
+/// Bit numbering of a probed field, as in DBC files
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Endianness {
+    /// Little-endian; `start_bit` is the LSB
+    Intel,
+    /// Big-endian; `start_bit` is the MSB
+    Motorola,
+}
+
+/// One bit-field of one frame, decoded to a physical value. Frames are
+/// matched on ID and ID format, and on bus when one is given.
+struct SignalProbe {
+    frame_id: u32,
+    is_extended: bool,
+    bus: Option<u8>,
+    start_bit: u64,
+    length: u64,
+    byte_order: ByteOrder,
+    signed: bool,
+    scale: f64,
+    offset: f64,
+}
+
+impl SignalProbe {
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        frame_id: u32,
+        is_extended: bool,
+        bus: Option<u8>,
+        start_bit: u64,
+        length: u64,
+        endianness: Endianness,
+        signed: bool,
+        scale: f64,
+        offset: f64,
+    ) -> Result<Self, String> {
+        if !(1..=64).contains(&length) {
+            return Err(format!("Bit length must be between 1 and 64 (got {})", length));
+        }
+        if start_bit > MAX_START_BIT {
+            return Err(format!("Start bit must be at most {} (got {})", MAX_START_BIT, start_bit));
+        }
+        let byte_order = match endianness {
+            Endianness::Intel => ByteOrder::LittleEndian,
+            Endianness::Motorola => ByteOrder::BigEndian,
+        };
+        Ok(Self { frame_id, is_extended, bus, start_bit, length, byte_order, signed, scale, offset })
+    }
+
+    /// Physical value of the field in `frame`, or None for other frames and
+    /// frames too short to hold it
+    fn decode(&self, frame: &FrameMessage) -> Option<f64> {
+        if frame.frame_id != self.frame_id
+            || frame.is_extended != self.is_extended
+            || self.bus.is_some_and(|bus| bus != frame.bus)
+        {
+            return None;
+        }
+        let raw = extract_raw(&frame.bytes, self.start_bit, self.length, &self.byte_order)?;
+        Some(raw_to_physical(raw, self.length, self.signed, self.scale, self.offset))
+    }
+
+    /// (timestamp_us, value) for every frame that carries the field
+    fn sample(&self, frames: &[FrameMessage]) -> Vec<(u64, f64)> {
+        frames
+            .iter()
+            .filter_map(|f| self.decode(f).map(|v| (f.timestamp_us, v)))
+            .collect()
+    }
+}
+
+/// Result of `probe_signal`
+#[derive(Clone, Debug, Serialize)]
+pub struct SignalProbeResult {
+    /// Set for a live session probe; samples then arrive as `signal-probe` events
+    pub probe_id: Option<String>,
+    /// Time series decoded from a buffer (empty for a live probe)
+    pub samples: Vec<(u64, f64)>,
+}
+
+/// Payload for the signal-probe event
+#[derive(Clone, Serialize)]
+struct SignalProbePayload {
+    probe_id: String,
+    samples: Vec<(u64, f64)>,
+}
+
+/// Live probes by session ID
+static SESSION_PROBES: Lazy<Mutex<HashMap<String, Vec<(String, SignalProbe)>>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+static NEXT_PROBE_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Decode a batch for the session's live probes and emit their samples.
+/// Does nothing for sessions without probes.
+pub fn deliver_frames(app: &AppHandle, session_id: &str, frames: &[FrameMessage]) {
+    let payloads: Vec<SignalProbePayload> = {
+        let Ok(probes) = SESSION_PROBES.lock() else {
+            return;
+        };
+        let Some(probes) = probes.get(session_id) else {
+            return;
+        };
+        probes
+            .iter()
+            .map(|(probe_id, probe)| SignalProbePayload {
+                probe_id: probe_id.clone(),
+                samples: probe.sample(frames),
+            })
+            .filter(|p| !p.samples.is_empty())
+            .collect()
+    };
+    for payload in payloads {
+        emit_to_session(app, "signal-probe", session_id, payload);
+    }
+}
+
+/// Drop all live probes for a session (called on session destroy)
+pub fn clear_session_probes(session_id: &str) {
+    if let Ok(mut probes) = SESSION_PROBES.lock() {
+        probes.remove(session_id);
+    }
+}
+
+// ============================================================================
+// Tauri Commands
+// ============================================================================
+
+/// Decode a bit-field of `frame_id` as a number.
+///
+/// `is_extended` defaults to whether the ID needs more than 11 bits; `bus`
+/// limits the probe to one bus (default: every bus). For a frame buffer, returns the time series from every matching frame. For
+/// a session, registers a live probe and returns its ID; samples are emitted
+/// as `signal-probe` events until `stop_signal_probe` or session destroy.
+#[allow(clippy::too_many_arguments)]
+#[tauri::command(rename_all = "snake_case")]
+pub async fn probe_signal(
+    buffer_id_or_session: String,
+    frame_id: u32,
+    is_extended: Option<bool>,
+    bus: Option<u8>,
+    start_bit: u64,
+    length: u64,
+    endianness: Endianness,
+    signed: bool,
+    scale: Option<f64>,
+    offset: Option<f64>,
+) -> Result<SignalProbeResult, String> {
+    let probe = SignalProbe::new(
+        frame_id,
+        is_extended.unwrap_or(frame_id > CAN_STANDARD_ID_MASK),
+        bus,
+        start_bit,
+        length,
+        endianness,
+        signed,
+        scale.unwrap_or(1.0),
+        offset.unwrap_or(0.0),
+    )?;
+
+    if buffer_store::get_buffer_type(&buffer_id_or_session) == Some(BufferType::Frames) {
+        let frames = buffer_store::get_buffer_frames(&buffer_id_or_session)
+            .ok_or_else(|| format!("Buffer '{}' not found", buffer_id_or_session))?;
+        return Ok(SignalProbeResult { probe_id: None, samples: probe.sample(&frames) });
+    }
+
+    if get_session_state(&buffer_id_or_session).await.is_none() {
+        return Err(format!(
+            "No frame buffer or session named '{}'",
+            buffer_id_or_session
+        ));
+    }
+    let probe_id = format!("probe_{}", NEXT_PROBE_ID.fetch_add(1, Ordering::Relaxed));
+    SESSION_PROBES
+        .lock()
+        .map_err(|_| "Signal probe registry poisoned".to_string())?
+        .entry(buffer_id_or_session.clone())
+        .or_default()
+        .push((probe_id.clone(), probe));
+    tlog!("[signal_probe] Session '{}' probing frame 0x{:X} as '{}'", buffer_id_or_session, frame_id, probe_id);
+    Ok(SignalProbeResult { probe_id: Some(probe_id), samples: Vec::new() })
+}
+
+/// Stop a live probe started by `probe_signal`
+#[tauri::command(rename_all = "snake_case")]
+pub fn stop_signal_probe(session_id: String, probe_id: String) -> Result<(), String> {
+    let mut probes = SESSION_PROBES
+        .lock()
+        .map_err(|_| "Signal probe registry poisoned".to_string())?;
+    let Some(session_probes) = probes.get_mut(&session_id) else {
+        return Err(format!("Session '{}' has no signal probes", session_id));
+    };
+    let before = session_probes.len();
+    session_probes.retain(|(id, _)| *id != probe_id);
+    if session_probes.len() == before {
+        return Err(format!("Signal probe '{}' not found", probe_id));
+    }
+    if session_probes.is_empty() {
+        probes.remove(&session_id);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::test_support::can_frame;
+
+    #[test]
+    fn test_probe_intel_and_motorola_fields() {
+        let data = vec![0x34, 0x12, 0xAB, 0xCD, 0xF0, 0x00, 0x00, 0x00];
+
+        // Intel: 16 bits from bit 0 (LSB first) = 0x1234
+        let intel = SignalProbe::new(0x100, false, None, 0, 16, Endianness::Intel, false, 1.0, 0.0).unwrap();
+        assert_eq!(intel.decode(&can_frame(0x100, 0, data.clone())), Some(0x1234 as f64));
+
+        // Motorola: 16 bits with MSB at bit 23 (byte 2, bit 7) = 0xABCD
+        let motorola = SignalProbe::new(0x100, false, None, 23, 16, Endianness::Motorola, false, 1.0, 0.0).unwrap();
+        assert_eq!(motorola.decode(&can_frame(0x100, 0, data.clone())), Some(0xABCD as f64));
+
+        // Signed nibble 0xF (bits 36..40) is -1, then scaled and offset
+        let signed = SignalProbe::new(0x100, false, None, 36, 4, Endianness::Intel, true, 0.5, 10.0).unwrap();
+        assert_eq!(signed.decode(&can_frame(0x100, 0, data.clone())), Some(9.5));
+
+        // Other IDs and frames too short for the field are skipped
+        assert_eq!(intel.decode(&can_frame(0x101, 0, data.clone())), None);
+        assert_eq!(motorola.decode(&can_frame(0x100, 0, vec![0x34, 0x12])), None);
+
+        // The same ID in the other format, or on another bus, is another frame
+        let extended = FrameMessage { is_extended: true, ..can_frame(0x100, 0, data.clone()) };
+        assert_eq!(intel.decode(&extended), None);
+        let bus_1 = SignalProbe::new(0x100, false, Some(1), 0, 16, Endianness::Intel, false, 1.0, 0.0).unwrap();
+        assert_eq!(bus_1.decode(&can_frame(0x100, 0, data.clone())), None);
+        assert_eq!(bus_1.decode(&FrameMessage { bus: 1, ..can_frame(0x100, 0, data.clone()) }), Some(0x1234 as f64));
+    }
+
+    #[test]
+    fn test_probe_samples_time_series() {
+        let probe = SignalProbe::new(0x200, false, None, 8, 8, Endianness::Intel, false, 2.0, 0.0).unwrap();
+        let frames = vec![
+            can_frame(0x200, 10, vec![0x00, 0x01]),
+            can_frame(0x300, 20, vec![0x00, 0x02]),
+            can_frame(0x200, 30, vec![0x00, 0x03]),
+        ];
+        assert_eq!(probe.sample(&frames), vec![(10, 2.0), (30, 6.0)]);
+
+        assert!(SignalProbe::new(0x200, false, None, 0, 0, Endianness::Intel, false, 1.0, 0.0).is_err());
+        assert!(SignalProbe::new(0x200, false, None, 512, 8, Endianness::Intel, false, 1.0, 0.0).is_err());
+    }
+}
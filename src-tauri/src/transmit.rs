@@ -56,9 +56,11 @@ pub struct RepeatStoppedEvent {
 
 /// Kinds that support CAN transmit (platform-dependent)
 #[cfg(not(target_os = "ios"))]
-const CAN_TRANSMIT_KINDS: [&str; 6] = ["slcan", "gvret_tcp", "gvret_usb", "socketcan", "gs_usb", "virtual"];
+const CAN_TRANSMIT_KINDS: [&str; 8] = [
+    "slcan", "gvret_tcp", "gvret-tcp", "gvret_usb", "gvret-usb", "socketcan", "gs_usb", "virtual",
+];
 #[cfg(target_os = "ios")]
-const CAN_TRANSMIT_KINDS: [&str; 2] = ["gvret_tcp", "virtual"];
+const CAN_TRANSMIT_KINDS: [&str; 3] = ["gvret_tcp", "gvret-tcp", "virtual"];
 
 /// Kinds that support serial transmit (not available on iOS)
 #[cfg(not(target_os = "ios"))]
@@ -117,7 +119,7 @@ fn get_capabilities_for_kind(kind: &str, profile: &IOProfile) -> WriterCapabilit
                 }
             }
         }
-        "gvret_tcp" | "gvret_usb" => WriterCapabilities {
+        "gvret_tcp" | "gvret-tcp" | "gvret_usb" | "gvret-usb" => WriterCapabilities {
             can_transmit_can: true,
             can_transmit_serial: false,
             supports_canfd: true,
@@ -231,6 +233,206 @@ pub async fn io_transmit_can_frame(
     Ok(result)
 }
 
+/// Transmit a CAN frame and wait for the first received frame matching
+/// `response_filter`, for request/response exchanges such as UDS.
+///
+/// The waiter is registered before the frame is sent so a fast reply is not
+/// missed. Returns `Ok(None)` if nothing matches within `timeout_ms`, and an
+/// error if the transmit itself fails.
+#[tauri::command]
+pub async fn io_transmit_and_await(
+    app: AppHandle,
+    session_id: String,
+    frame: CanTransmitFrame,
+    response_filter: io::FrameFilter,
+    timeout_ms: u64,
+) -> Result<Option<io::FrameMessage>, String> {
+    let response_rx = io::subscribe_next_frame(&session_id, response_filter);
+
+    let result = io_transmit_can_frame(app, session_id, frame).await?;
+    if !result.success {
+        return Err(result.error.unwrap_or_else(|| "Transmit failed".to_string()));
+    }
+
+    match tokio::time::timeout(std::time::Duration::from_millis(timeout_ms), response_rx).await {
+        Ok(Ok(frame)) => Ok(Some(frame)),
+        // Timed out, or the session was destroyed while waiting
+        _ => Ok(None),
+    }
+}
+
+/// Check a CAN frame against what a profile kind can transmit (data length
+/// for classic or FD, BRS only on FD, bus range, FD support) without sending
+/// anything, so the UI can flag an invalid frame before send. These are the
+/// checks a session runs before encoding.
+#[tauri::command]
+pub fn validate_transmit_frame(profile_kind: String, frame: CanTransmitFrame) -> Result<(), String> {
+    if !supports_can_transmit(&profile_kind) {
+        return Err(format!("{} profiles cannot transmit CAN frames", profile_kind));
+    }
+    // No profile to read `enable_fd` from; a running session checks it per source
+    io::validate_transmit_for(&profile_kind, true, &frame)
+        .map_err(|result| result.error.unwrap_or_else(|| "Invalid frame".to_string()))
+}
+
+/// Bytes a profile kind would put on the wire for a frame
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct TransmitPreview {
+    pub bytes: Vec<u8>,
+    /// Space-separated uppercase hex of `bytes`
+    pub hex: String,
+}
+
+/// Dry-run transmit: validate a frame like `validate_transmit_frame`, then run
+/// the profile kind's encoder and return the bytes it would send, without
+/// opening a device. For gs_usb the channel byte is 0; a running source stamps
+/// its own channel.
+#[tauri::command]
+pub fn encode_transmit_preview(profile_kind: String, frame: CanTransmitFrame) -> Result<TransmitPreview, String> {
+    validate_transmit_frame(profile_kind.clone(), frame.clone())?;
+    let bytes = io::encode_source_frame(&profile_kind, &frame)
+        .map_err(|result| result.error.unwrap_or_else(|| "Encode failed".to_string()))?;
+    let hex = bytes.iter().map(|b| format!("{:02X}", b)).collect::<Vec<_>>().join(" ");
+    tlog!("[transmit] {} preview of 0x{:X}: {}", profile_kind, frame.frame_id, hex);
+    Ok(TransmitPreview { bytes, hex })
+}
+
+// ============================================================================
+// Loopback Latency Test
+// ============================================================================
+
+/// Frame ID used for loopback probes when none is given
+const LOOPBACK_DEFAULT_FRAME_ID: u32 = 0x7FF;
+/// How long to wait for each probe to come back when no timeout is given
+const LOOPBACK_DEFAULT_TIMEOUT_MS: u64 = 200;
+/// Upper bound on iterations per run
+const LOOPBACK_MAX_ITERATIONS: u32 = 10_000;
+
+/// Round-trip latency distribution from `loopback_test`
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct LoopbackStats {
+    /// Probes sent
+    pub iterations: u32,
+    /// Probes that came back
+    pub received: u32,
+    /// Probes that did not come back within the timeout
+    pub lost: u32,
+    /// Fastest round trip (0 if nothing came back)
+    pub min_us: u64,
+    /// Slowest round trip (0 if nothing came back)
+    pub max_us: u64,
+    /// Mean round trip (0 if nothing came back)
+    pub mean_us: u64,
+}
+
+impl LoopbackStats {
+    fn from_samples(samples: &[u64], iterations: u32) -> Self {
+        let received = samples.len() as u32;
+        let mut stats = LoopbackStats {
+            iterations,
+            received,
+            lost: iterations.saturating_sub(received),
+            ..Default::default()
+        };
+        if !samples.is_empty() {
+            stats.min_us = samples.iter().copied().min().unwrap_or(0);
+            stats.max_us = samples.iter().copied().max().unwrap_or(0);
+            stats.mean_us = samples.iter().sum::<u64>() / samples.len() as u64;
+        }
+        stats
+    }
+}
+
+/// Payload of a loopback probe: "LB", a per-run tag, then the iteration number
+fn loopback_payload(run_tag: u16, iteration: u32) -> Vec<u8> {
+    let mut data = vec![b'L', b'B'];
+    data.extend_from_slice(&run_tag.to_be_bytes());
+    data.extend_from_slice(&iteration.to_be_bytes());
+    data
+}
+
+/// Round trip of one probe, from the echo's timestamp when it is on the host
+/// clock, otherwise from when the echo arrived.
+fn loopback_latency_us(sent_us: u64, echo_timestamp_us: u64, arrived_after: std::time::Duration) -> u64 {
+    let arrived_us = arrived_after.as_micros() as u64;
+    match echo_timestamp_us.checked_sub(sent_us) {
+        Some(latency) if latency <= arrived_us => latency,
+        _ => arrived_us,
+    }
+}
+
+/// Measure round-trip latency by transmitting `iterations` unique probe
+/// frames and timing each until it (or the device's echo of it) is seen on
+/// the session.
+///
+/// Each probe carries a run tag and iteration number so stale or foreign
+/// frames are never matched. Latency runs from queuing the transmit to the
+/// echo's `timestamp_us`, so batching on the session's emit path doesn't
+/// inflate it. Sessions stamping frames with a raw device clock fall back to
+/// the time the echo reached the session. Probes not seen within `timeout_ms`
+/// count as lost. Useful for comparing adapters on a loopback bus or with
+/// echo enabled.
+#[tauri::command]
+pub async fn loopback_test(
+    session_id: String,
+    iterations: u32,
+    frame_id: Option<u32>,
+    bus: Option<u8>,
+    timeout_ms: Option<u64>,
+) -> Result<LoopbackStats, String> {
+    if iterations == 0 || iterations > LOOPBACK_MAX_ITERATIONS {
+        return Err(format!(
+            "Iterations must be between 1 and {}",
+            LOOPBACK_MAX_ITERATIONS
+        ));
+    }
+    let frame_id = frame_id.unwrap_or(LOOPBACK_DEFAULT_FRAME_ID);
+    let bus = bus.unwrap_or(0);
+    let is_extended = frame_id > 0x7FF;
+    let timeout = std::time::Duration::from_millis(timeout_ms.unwrap_or(LOOPBACK_DEFAULT_TIMEOUT_MS));
+    let run_tag = io::now_us() as u16;
+
+    let mut samples = Vec::with_capacity(iterations as usize);
+    for iteration in 0..iterations {
+        let data = loopback_payload(run_tag, iteration);
+        let filter = io::FrameFilter {
+            frame_id: Some(frame_id),
+            bus: Some(bus),
+            is_extended: Some(is_extended),
+            data: Some(data.clone()),
+            mask: None,
+        };
+        let frame = CanTransmitFrame {
+            frame_id,
+            data,
+            bus,
+            is_extended,
+            is_fd: false,
+            is_brs: false,
+            is_rtr: false,
+            rtr_dlc: None,
+        };
+
+        let echo_rx = io::subscribe_next_frame(&session_id, filter);
+        let started = std::time::Instant::now();
+        let sent_us = io::now_us();
+        let result = io::transmit_frame(&session_id, &frame).await?;
+        if !result.success {
+            return Err(result.error.unwrap_or_else(|| "Transmit failed".to_string()));
+        }
+        if let Ok(Ok(echo)) = tokio::time::timeout(timeout.saturating_sub(started.elapsed()), echo_rx).await {
+            samples.push(loopback_latency_us(sent_us, echo.timestamp_us, started.elapsed()));
+        }
+    }
+
+    let stats = LoopbackStats::from_samples(&samples, iterations);
+    tlog!(
+        "[transmit] Loopback on '{}': {}/{} received, min {} us, mean {} us, max {} us",
+        session_id, stats.received, stats.iterations, stats.min_us, stats.mean_us, stats.max_us
+    );
+    Ok(stats)
+}
+
 /// Transmit raw serial bytes through an IO session
 #[tauri::command]
 pub async fn io_transmit_serial(
@@ -251,6 +453,135 @@ pub async fn io_transmit_serial(
     Ok(result)
 }
 
+/// Write raw bytes straight to a GVRET device (e.g. a device-specific control
+/// command), bypassing frame encoding. Requires transmit to be armed.
+///
+/// The bytes are written as-is: a malformed command can desynchronise the
+/// device's binary stream until the session is restarted.
+#[tauri::command]
+pub async fn transmit_raw(
+    app: AppHandle,
+    session_id: String,
+    bytes: Vec<u8>,
+) -> Result<crate::io::TransmitResult, String> {
+    let result = io::transmit_device_command(&session_id, &bytes).await?;
+    crate::transmit_history::write_entry(
+        &session_id, "raw",
+        None, None,
+        &bytes,
+        0, false, false,
+        result.success,
+        result.error.as_deref(),
+    );
+    let _ = app.emit("transmit-history-updated", ());
+    Ok(result)
+}
+
+// ============================================================================
+// Serial Error Injection (device error-handling tests)
+// ============================================================================
+
+/// Deliberate corruption applied to a serial frame before it is sent.
+/// Used to verify that a device rejects malformed frames.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum SerialErrorInjection {
+    /// Invert every bit of the checksum field so it can never validate.
+    /// `byte_index` supports negative indexing (-1 = last byte).
+    CorruptChecksum { byte_index: i32, length: usize },
+    /// XOR the byte at `byte_index` with `mask` (negative index = from end)
+    FlipBits { byte_index: i32, mask: u8 },
+}
+
+/// Resolve an injection's byte index against the frame length. Unlike
+/// `checksums::resolve_byte_index`, a negative index reaching before the first
+/// byte is an error rather than being clamped to byte 0.
+fn resolve_injection_index(byte_index: i32, frame_length: usize) -> Result<usize, String> {
+    if byte_index >= 0 {
+        return Ok(byte_index as usize);
+    }
+    frame_length
+        .checked_sub(byte_index.unsigned_abs() as usize)
+        .ok_or_else(|| format!("Byte index {} is before the start of the {}-byte frame", byte_index, frame_length))
+}
+
+/// Apply an error injection to a copy of `bytes`.
+/// Returns an error if the targeted bytes fall outside the frame or the
+/// injection would leave the frame unchanged.
+pub fn apply_serial_error_injection(
+    bytes: &[u8],
+    injection: &SerialErrorInjection,
+) -> Result<Vec<u8>, String> {
+    let mut corrupted = bytes.to_vec();
+    match *injection {
+        SerialErrorInjection::CorruptChecksum { byte_index, length } => {
+            let start = resolve_injection_index(byte_index, bytes.len())?;
+            if length == 0 || start + length > bytes.len() {
+                return Err(format!(
+                    "Checksum range {}..{} is outside the {}-byte frame",
+                    start,
+                    start + length,
+                    bytes.len()
+                ));
+            }
+            for b in &mut corrupted[start..start + length] {
+                *b = !*b;
+            }
+        }
+        SerialErrorInjection::FlipBits { byte_index, mask } => {
+            let index = resolve_injection_index(byte_index, bytes.len())?;
+            if index >= bytes.len() {
+                return Err(format!(
+                    "Byte index {} is outside the {}-byte frame",
+                    byte_index,
+                    bytes.len()
+                ));
+            }
+            if mask == 0 {
+                return Err("Bit mask must flip at least one bit".to_string());
+            }
+            corrupted[index] ^= mask;
+        }
+    }
+    Ok(corrupted)
+}
+
+/// Transmit a deliberately corrupted serial frame through an IO session.
+///
+/// Kept separate from `io_transmit_serial` so malformed frames can't be sent
+/// by accident: the caller must also pass `inject_error = true`.
+/// Returns the transmit result and the bytes that were actually sent.
+#[tauri::command]
+pub async fn io_transmit_serial_with_error(
+    app: AppHandle,
+    session_id: String,
+    bytes: Vec<u8>,
+    injection: SerialErrorInjection,
+    inject_error: bool,
+) -> Result<(crate::io::TransmitResult, Vec<u8>), String> {
+    if !inject_error {
+        return Err("Error injection requires inject_error = true".to_string());
+    }
+
+    let corrupted = apply_serial_error_injection(&bytes, &injection)?;
+    tlog!(
+        "[transmit] Injecting serial error on session '{}': {:?}",
+        session_id, injection
+    );
+
+    let result = io::transmit_serial(&session_id, &corrupted).await?;
+    crate::transmit_history::write_entry(
+        &session_id, "serial",
+        None, None,
+        &corrupted,
+        0, false, false,
+        result.success,
+        result.error.as_deref(),
+    );
+    let _ = app.emit("transmit-history-updated", ());
+    Ok((result, corrupted))
+}
+
 /// Get IO session capabilities (includes transmit capabilities)
 #[tauri::command]
 pub async fn get_io_session_capabilities(session_id: String) -> Result<Option<IOCapabilities>, String> {
@@ -276,8 +607,9 @@ pub fn is_permanent_error_pub(error: &str) -> bool {
 /// Check if an error is permanent (should stop repeat) vs transient (can continue)
 fn is_permanent_error(error: &str) -> bool {
     let error_lower = error.to_lowercase();
-    // Permanent errors - device is gone or session invalid
+    // Permanent errors - device is gone, session invalid or transmit disarmed
     error_lower.contains("not found")
+        || error_lower.contains("not armed")
         || error_lower.contains("disconnected")
         || error_lower.contains("does not support")
         || error_lower.contains("no device")
@@ -307,6 +639,30 @@ async fn do_transmit(
     }
 }
 
+/// Transmit a group of frames as one burst - no retry logic.
+/// Returns one (result, should_stop) per frame, in order.
+async fn do_transmit_group(
+    session_id: &str,
+    frames: &[CanTransmitFrame],
+) -> Vec<(Result<crate::io::TransmitResult, String>, bool)> {
+    let results: Vec<Result<crate::io::TransmitResult, String>> = match io::transmit_frames(session_id, frames).await {
+        Ok(results) => results.into_iter().map(Ok).collect(),
+        Err(e) => frames.iter().map(|_| Err(e.clone())).collect(),
+    };
+
+    results
+        .into_iter()
+        .map(|result| {
+            let should_stop = match &result {
+                Ok(r) if r.success => false,
+                Ok(r) => is_permanent_error(r.error.as_deref().unwrap_or("Unknown error")),
+                Err(e) => is_permanent_error(e),
+            };
+            (result, should_stop)
+        })
+        .collect()
+}
+
 /// Simple serial transmit - no retry logic.
 async fn do_serial_transmit(
     session_id: &str,
@@ -341,6 +697,91 @@ struct IoRepeatTask {
 static IO_REPEAT_TASKS: Lazy<tokio::sync::Mutex<HashMap<String, IoRepeatTask>>> =
     Lazy::new(|| tokio::sync::Mutex::new(HashMap::new()));
 
+/// How a `run_can_repeat` run ended
+enum RepeatEnd {
+    /// Every frame was sent
+    Complete,
+    /// The cancel flag was set
+    Cancelled,
+    /// A permanent error stopped the run (`repeat-stopped` already emitted)
+    Failed,
+}
+
+/// Transmit `frames` through an IO session, one every `interval_ms`, writing
+/// each result to transmit history. The first frame goes out immediately.
+/// Returns how the run ended and the number of frames sent.
+async fn run_can_repeat(
+    app: &AppHandle,
+    session_id: &str,
+    queue_id: &str,
+    frames: impl Iterator<Item = CanTransmitFrame>,
+    interval_ms: u64,
+    cancel_flag: &std::sync::atomic::AtomicBool,
+) -> (RepeatEnd, u64) {
+    const NOTIFY_INTERVAL: std::time::Duration = std::time::Duration::from_millis(250);
+    let mut last_notify: Option<std::time::Instant> = None;
+    let mut interval_timer: Option<tokio::time::Interval> = None;
+    let mut frames_sent: u64 = 0;
+
+    for frame in frames {
+        if let Some(timer) = interval_timer.as_mut() {
+            timer.tick().await;
+        }
+        if cancel_flag.load(Ordering::Relaxed) {
+            return (RepeatEnd::Cancelled, frames_sent);
+        }
+
+        let (result, should_stop) = do_transmit(session_id, &frame).await;
+        let (success, error) = match &result {
+            Ok(r) => (r.success, r.error.clone()),
+            Err(e) => (false, Some(e.clone())),
+        };
+        crate::transmit_history::write_entry(
+            session_id, "can",
+            Some(frame.frame_id as i64),
+            Some(frame.data.len() as i64),
+            &frame.data,
+            frame.bus as i64,
+            frame.is_extended,
+            frame.is_fd,
+            success,
+            error.as_deref(),
+        );
+        if last_notify.as_ref().is_none_or(|t| t.elapsed() >= NOTIFY_INTERVAL) {
+            let _ = app.emit("transmit-history-updated", ());
+            last_notify = Some(std::time::Instant::now());
+        }
+        frames_sent += 1;
+
+        // Stop on permanent errors (device gone, session invalid)
+        if should_stop {
+            let reason = error.unwrap_or_else(|| "Permanent error".to_string());
+            tlog!(
+                "[io_transmit] Stopping repeat for '{}' due to permanent error: {}",
+                queue_id, reason
+            );
+            // Notify frontend that repeat has stopped
+            let _ = app.emit("repeat-stopped", RepeatStoppedEvent {
+                queue_id: queue_id.to_string(),
+                reason,
+            });
+            let _ = app.emit("transmit-history-updated", ());
+            return (RepeatEnd::Failed, frames_sent);
+        }
+
+        if interval_timer.is_none() {
+            // Start the timer after the first transmit so startup delays
+            // don't affect the regular interval timing
+            let mut timer = tokio::time::interval(tokio::time::Duration::from_millis(interval_ms));
+            // Skip the first tick which fires immediately
+            timer.tick().await;
+            interval_timer = Some(timer);
+        }
+    }
+
+    (RepeatEnd::Complete, frames_sent)
+}
+
 /// Start repeat transmission for a CAN frame through an IO session
 #[tauri::command]
 pub async fn io_start_repeat_transmit(
@@ -359,95 +800,18 @@ pub async fn io_start_repeat_transmit(
 
     let cancel_flag = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
     let cancel_flag_clone = cancel_flag.clone();
-    let session_id_clone = session_id.clone();
     let queue_id_for_task = queue_id.clone();
 
     let handle = tauri::async_runtime::spawn(async move {
-        const NOTIFY_INTERVAL: std::time::Duration = std::time::Duration::from_millis(250);
-        let mut last_notify: Option<std::time::Instant> = None;
-
-        // Write this frame's transmit result to SQLite and rate-limit the UI notification.
-        let write_and_notify = |app: &AppHandle, result: &Result<crate::io::TransmitResult, String>, last_notify: &mut Option<std::time::Instant>| -> (bool, Option<String>) {
-            let (success, error) = match result {
-                Ok(r) => (r.success, r.error.clone()),
-                Err(e) => (false, Some(e.clone())),
-            };
-            crate::transmit_history::write_entry(
-                &session_id_clone, "can",
-                Some(frame.frame_id as i64),
-                Some(frame.data.len() as i64),
-                &frame.data,
-                frame.bus as i64,
-                frame.is_extended,
-                frame.is_fd,
-                success,
-                error.as_deref(),
-            );
-            let should_emit = last_notify.as_ref().map_or(true, |t| t.elapsed() >= NOTIFY_INTERVAL);
-            if should_emit {
-                let _ = app.emit("transmit-history-updated", ());
-                *last_notify = Some(std::time::Instant::now());
-            }
-            (success, error)
-        };
-
-        // First transmit - do this before starting interval timer so startup delays
-        // don't affect the regular interval timing
-        if cancel_flag_clone.load(Ordering::Relaxed) {
-            return;
-        }
-
-        let (result, should_stop) = do_transmit(&session_id_clone, &frame).await;
-        let (_, error) = write_and_notify(&app, &result, &mut last_notify);
-
-        if should_stop {
-            let reason = error.unwrap_or_else(|| "Permanent error".to_string());
-            tlog!(
-                "[io_transmit] Stopping repeat for '{}' due to permanent error: {}",
-                queue_id_for_task, reason
-            );
-            let _ = app.emit("repeat-stopped", RepeatStoppedEvent {
-                queue_id: queue_id_for_task.clone(),
-                reason,
-            });
-            let _ = app.emit("transmit-history-updated", ());
-            return;
-        }
-
-        // Now start the interval timer for subsequent transmits
-        let mut interval_timer = tokio::time::interval(tokio::time::Duration::from_millis(interval_ms));
-        // Skip the first tick which fires immediately
-        interval_timer.tick().await;
-
-        loop {
-            // Wait for next interval tick first
-            interval_timer.tick().await;
-
-            // Check cancel flag
-            if cancel_flag_clone.load(Ordering::Relaxed) {
-                break;
-            }
-
-            // Transmit with retry for transient errors
-            let (result, should_stop) = do_transmit(&session_id_clone, &frame).await;
-            let (_, error) = write_and_notify(&app, &result, &mut last_notify);
-
-            // Stop on permanent errors (device gone, session invalid)
-            if should_stop {
-                let reason = error.unwrap_or_else(|| "Permanent error".to_string());
-                tlog!(
-                    "[io_transmit] Stopping repeat for '{}' due to permanent error: {}",
-                    queue_id_for_task, reason
-                );
-                // Notify frontend that repeat has stopped
-                let _ = app.emit("repeat-stopped", RepeatStoppedEvent {
-                    queue_id: queue_id_for_task.clone(),
-                    reason,
-                });
-                let _ = app.emit("transmit-history-updated", ());
-                break;
-            }
-        }
+        run_can_repeat(
+            &app,
+            &session_id,
+            &queue_id_for_task,
+            std::iter::repeat(frame),
+            interval_ms,
+            &cancel_flag_clone,
+        )
+        .await;
     });
 
     // Store the task
@@ -540,7 +904,7 @@ pub async fn io_start_serial_repeat_transmit(
                 success,
                 error.as_deref(),
             );
-            let should_emit = last_notify.as_ref().map_or(true, |t| t.elapsed() >= NOTIFY_INTERVAL);
+            let should_emit = last_notify.as_ref().is_none_or(|t| t.elapsed() >= NOTIFY_INTERVAL);
             if should_emit {
                 let _ = app.emit("transmit-history-updated", ());
                 *last_notify = Some(std::time::Instant::now());
@@ -627,6 +991,7 @@ pub async fn io_start_serial_repeat_transmit(
 // Group repeat transmits multiple frames in sequence within a single loop.
 // All frames in the group are sent one after another (no delay between them),
 // then the system waits for the interval before repeating the sequence.
+// Each cycle goes out as one burst, so GVRET devices get a single write.
 
 /// Map of group_id -> IoRepeatTask for active group repeat transmissions
 static IO_REPEAT_GROUPS: Lazy<tokio::sync::Mutex<HashMap<String, IoRepeatTask>>> =
@@ -686,7 +1051,7 @@ pub async fn io_start_repeat_group(
                 success,
                 error.as_deref(),
             );
-            let should_emit = last_notify.as_ref().map_or(true, |t| t.elapsed() >= NOTIFY_INTERVAL);
+            let should_emit = last_notify.as_ref().is_none_or(|t| t.elapsed() >= NOTIFY_INTERVAL);
             if should_emit {
                 let _ = app.emit("transmit-history-updated", ());
                 *last_notify = Some(std::time::Instant::now());
@@ -700,8 +1065,7 @@ pub async fn io_start_repeat_group(
             return;
         }
 
-        for frame in &frames {
-            let (result, should_stop) = do_transmit(&session_id_clone, frame).await;
+        for (frame, (result, should_stop)) in frames.iter().zip(do_transmit_group(&session_id_clone, &frames).await) {
             let error = write_frame(frame, &result, &mut last_notify);
 
             if should_stop {
@@ -733,10 +1097,8 @@ pub async fn io_start_repeat_group(
                 break;
             }
 
-            // Send all frames in sequence (no delays between them)
-            for frame in &frames {
-                // Transmit with retry for transient errors
-                let (result, should_stop) = do_transmit(&session_id_clone, frame).await;
+            // Send all frames in sequence (no delays between them), as one burst
+            for (frame, (result, should_stop)) in frames.iter().zip(do_transmit_group(&session_id_clone, &frames).await) {
                 let error = write_frame(frame, &result, &mut last_notify);
 
                 // Stop on permanent errors (device gone, session invalid)
@@ -801,3 +1163,629 @@ pub async fn io_stop_all_group_repeats() -> Result<(), String> {
 
     Ok(())
 }
+
+// ============================================================================
+// Templated Transmit
+// ============================================================================
+//
+// A template is a CAN frame whose payload has wildcard bytes. It expands into
+// a run of concrete frames, so scripted sweeps over command bytes don't need
+// the frontend to build and send every combination.
+
+/// How wildcard bytes in a `FrameTemplate` are filled.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum TemplateFill {
+    /// Wildcards form one big-endian counter (first wildcard most significant)
+    /// starting at `start`, so a full run covers every combination once.
+    Counter {
+        #[serde(default)]
+        start: u64,
+    },
+    /// Independent random bytes; a fixed `seed` makes the run repeatable
+    Random {
+        #[serde(default)]
+        seed: Option<u64>,
+    },
+    /// Every wildcard set to `value`
+    Fixed { value: u8 },
+}
+
+/// CAN frame with wildcard payload bytes (`null` in `data`).
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct FrameTemplate {
+    pub frame_id: u32,
+    pub data: Vec<Option<u8>>,
+    #[serde(default)]
+    pub bus: u8,
+    #[serde(default)]
+    pub is_extended: bool,
+    #[serde(default)]
+    pub is_fd: bool,
+    #[serde(default)]
+    pub is_brs: bool,
+    pub fill: TemplateFill,
+}
+
+/// SplitMix64 step; enough for fuzzing payloads without pulling in an RNG crate.
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Expand a template into `count` concrete frames, generated lazily in order.
+///
+/// Errors if the payload is too long for the frame type, `count` is zero, or a
+/// counter run would go past the last combination of the wildcard bytes.
+pub fn expand_frame_template(
+    template: FrameTemplate,
+    count: u64,
+) -> Result<impl Iterator<Item = CanTransmitFrame> + Send, String> {
+    let max_len = if template.is_fd { 64 } else { 8 };
+    if template.data.len() > max_len {
+        return Err(format!(
+            "Template has {} bytes; at most {} allowed for this frame type",
+            template.data.len(),
+            max_len
+        ));
+    }
+    if count == 0 {
+        return Err("Count must be at least 1".to_string());
+    }
+
+    let wildcards: Vec<usize> = template
+        .data
+        .iter()
+        .enumerate()
+        .filter(|(_, b)| b.is_none())
+        .map(|(i, _)| i)
+        .collect();
+
+    if let TemplateFill::Counter { start } = template.fill {
+        let Some(last) = start.checked_add(count - 1) else {
+            return Err(format!("Counter from {} for {} frames overflows a 64-bit counter", start, count));
+        };
+        // 256^n combinations; eight or more wildcards hold any u64 value
+        if let Some(combinations) = 1u64.checked_shl(8 * wildcards.len() as u32) {
+            if last >= combinations {
+                return Err(format!(
+                    "Counter from {} for {} frames exceeds the {} combinations of {} wildcard byte(s)",
+                    start,
+                    count,
+                    combinations,
+                    wildcards.len()
+                ));
+            }
+        }
+    }
+
+    let mut rng = match template.fill {
+        TemplateFill::Random { seed } => seed.unwrap_or_else(io::now_us),
+        _ => 0,
+    };
+
+    Ok((0..count).map(move |i| {
+        let mut data: Vec<u8> = template.data.iter().map(|b| b.unwrap_or(0)).collect();
+        match template.fill {
+            TemplateFill::Counter { start } => {
+                let value = start + i;
+                for (k, &pos) in wildcards.iter().rev().enumerate() {
+                    data[pos] = value.checked_shr(8 * k as u32).unwrap_or(0) as u8;
+                }
+            }
+            TemplateFill::Random { .. } => {
+                for &pos in &wildcards {
+                    data[pos] = (splitmix64(&mut rng) >> 56) as u8;
+                }
+            }
+            TemplateFill::Fixed { value } => {
+                for &pos in &wildcards {
+                    data[pos] = value;
+                }
+            }
+        }
+        CanTransmitFrame {
+            frame_id: template.frame_id,
+            data,
+            bus: template.bus,
+            is_extended: template.is_extended,
+            is_fd: template.is_fd,
+            is_brs: template.is_brs,
+            is_rtr: false,
+            rtr_dlc: None,
+        }
+    }))
+}
+
+/// Transmit `count` frames expanded from a template, one every `interval_ms`.
+///
+/// Runs once (no looping) as a repeat task under `queue_id`, so
+/// `io_stop_repeat_transmit` cancels it. A `repeat-stopped` event fires when
+/// the run completes, is cancelled, or hits a permanent error.
+#[tauri::command]
+pub async fn io_start_template_transmit(
+    app: AppHandle,
+    session_id: String,
+    queue_id: String,
+    template: FrameTemplate,
+    count: u64,
+    interval_ms: u64,
+) -> Result<(), String> {
+    if interval_ms < 1 {
+        return Err("Interval must be at least 1ms".to_string());
+    }
+    let frames = expand_frame_template(template, count)?;
+
+    // Stop any existing repeat for this queue_id
+    io_stop_repeat_transmit(queue_id.clone()).await?;
+
+    let cancel_flag = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+    let cancel_flag_clone = cancel_flag.clone();
+    let queue_id_for_task = queue_id.clone();
+
+    tlog!(
+        "[io_transmit] Starting template transmit '{}' on session '{}', {} frames, interval {}ms",
+        queue_id, session_id, count, interval_ms
+    );
+
+    // Held across the spawn so a run that ends at once can't remove its
+    // entry before it is inserted
+    let mut tasks = IO_REPEAT_TASKS.lock().await;
+    let handle = tauri::async_runtime::spawn(async move {
+        let (end, frames_sent) = run_can_repeat(
+            &app,
+            &session_id,
+            &queue_id_for_task,
+            frames,
+            interval_ms,
+            &cancel_flag_clone,
+        )
+        .await;
+        let reason = match end {
+            RepeatEnd::Complete => Some(format!("Template complete ({} frames)", frames_sent)),
+            RepeatEnd::Cancelled => Some(format!("Template stopped ({} frames)", frames_sent)),
+            RepeatEnd::Failed => None,
+        };
+        if let Some(reason) = reason {
+            let _ = app.emit("transmit-history-updated", ());
+            let _ = app.emit("repeat-stopped", RepeatStoppedEvent {
+                queue_id: queue_id_for_task.clone(),
+                reason,
+            });
+        }
+
+        // A cancelled task was already removed (and may have been replaced)
+        if !cancel_flag_clone.load(Ordering::Relaxed) {
+            IO_REPEAT_TASKS.lock().await.remove(&queue_id_for_task);
+        }
+    });
+    tasks.insert(
+        queue_id,
+        IoRepeatTask {
+            cancel_flag,
+            handle,
+        },
+    );
+
+    Ok(())
+}
+
+// ============================================================================
+// Transmit Schedule
+// ============================================================================
+//
+// A schedule is a table of periodic frames (like a CANoe interaction layer),
+// each with its own period, sent by one background task per session. Entries
+// can be enabled and disabled while the schedule runs. Frames due at the same
+// time go out as one burst.
+
+/// One row of a transmit schedule
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ScheduleEntry {
+    pub frame: CanTransmitFrame,
+    pub period_ms: u64,
+    pub enabled: bool,
+}
+
+/// Entries plus when each enabled one is next due (None while disabled)
+struct ScheduleState {
+    entries: Vec<ScheduleEntry>,
+    next_due: Vec<Option<std::time::Instant>>,
+}
+
+impl ScheduleState {
+    /// Enabled entries are due immediately.
+    fn new(entries: Vec<ScheduleEntry>, now: std::time::Instant) -> Result<Self, String> {
+        if let Some(i) = entries.iter().position(|e| e.period_ms < 1) {
+            return Err(format!("Entry {}: period must be at least 1ms", i));
+        }
+        let next_due = entries.iter().map(|e| e.enabled.then_some(now)).collect();
+        Ok(Self { entries, next_due })
+    }
+
+    /// Indices of entries due at `now`, in table order, moving each to its
+    /// next period. An entry that fell more than a period behind skips the
+    /// missed sends rather than bursting to catch up.
+    fn take_due(&mut self, now: std::time::Instant) -> Vec<usize> {
+        let mut due = Vec::new();
+        for (i, (entry, next)) in self.entries.iter().zip(self.next_due.iter_mut()).enumerate() {
+            let Some(deadline) = *next else { continue };
+            if deadline > now {
+                continue;
+            }
+            due.push(i);
+            let period = std::time::Duration::from_millis(entry.period_ms);
+            let following = deadline + period;
+            *next = Some(if following <= now { now + period } else { following });
+        }
+        due
+    }
+
+    /// Earliest time an enabled entry is due
+    fn next_deadline(&self) -> Option<std::time::Instant> {
+        self.next_due.iter().flatten().min().copied()
+    }
+
+    /// Enable (due at `now`) or disable an entry
+    fn set_enabled(&mut self, index: usize, enabled: bool, now: std::time::Instant) -> Result<(), String> {
+        let entry = self
+            .entries
+            .get_mut(index)
+            .ok_or_else(|| format!("Schedule has no entry {}", index))?;
+        if entry.enabled != enabled {
+            entry.enabled = enabled;
+            self.next_due[index] = enabled.then_some(now);
+        }
+        Ok(())
+    }
+}
+
+/// Running schedule for a session
+struct TransmitSchedule {
+    state: std::sync::Arc<std::sync::Mutex<ScheduleState>>,
+    /// Wakes the task early when entries are enabled or disabled
+    wake: std::sync::Arc<tokio::sync::Notify>,
+    cancel_flag: std::sync::Arc<std::sync::atomic::AtomicBool>,
+}
+
+/// Map of session_id -> running transmit schedule
+static TRANSMIT_SCHEDULES: Lazy<tokio::sync::Mutex<HashMap<String, TransmitSchedule>>> =
+    Lazy::new(|| tokio::sync::Mutex::new(HashMap::new()));
+
+/// Load a transmit schedule for a session and start sending its enabled
+/// entries, each at its own period. Replaces any schedule the session already
+/// has. A `repeat-stopped` event (queue_id `schedule:<session_id>`) fires if a
+/// permanent error stops it.
+#[tauri::command]
+pub async fn load_transmit_schedule(
+    app: AppHandle,
+    session_id: String,
+    entries: Vec<ScheduleEntry>,
+) -> Result<(), String> {
+    if entries.is_empty() {
+        return Err("Schedule must contain at least one entry".to_string());
+    }
+    let state = std::sync::Arc::new(std::sync::Mutex::new(ScheduleState::new(
+        entries,
+        std::time::Instant::now(),
+    )?));
+
+    stop_transmit_schedule(session_id.clone()).await?;
+
+    let wake = std::sync::Arc::new(tokio::sync::Notify::new());
+    let cancel_flag = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+    let (state_task, wake_task, cancel_task) = (state.clone(), wake.clone(), cancel_flag.clone());
+    let session_id_task = session_id.clone();
+
+    tlog!(
+        "[io_transmit] Starting transmit schedule on session '{}', {} entries",
+        session_id,
+        state.lock().map(|s| s.entries.len()).unwrap_or(0)
+    );
+
+    // Registered before the task starts, so a permanent error on the first
+    // burst removes this schedule rather than leaving it behind
+    TRANSMIT_SCHEDULES
+        .lock()
+        .await
+        .insert(session_id, TransmitSchedule { state, wake, cancel_flag });
+
+    tauri::async_runtime::spawn(async move {
+        const NOTIFY_INTERVAL: std::time::Duration = std::time::Duration::from_millis(250);
+        const IDLE_WAIT: std::time::Duration = std::time::Duration::from_secs(1);
+        let mut last_notify: Option<std::time::Instant> = None;
+
+        loop {
+            if cancel_task.load(Ordering::Relaxed) {
+                break;
+            }
+
+            let now = std::time::Instant::now();
+            let (frames, deadline) = {
+                let Ok(mut state) = state_task.lock() else { break };
+                let frames: Vec<CanTransmitFrame> = state
+                    .take_due(now)
+                    .into_iter()
+                    .map(|i| state.entries[i].frame.clone())
+                    .collect();
+                (frames, state.next_deadline())
+            };
+
+            if !frames.is_empty() {
+                let mut stop_reason = None;
+                for (frame, (result, should_stop)) in frames.iter().zip(do_transmit_group(&session_id_task, &frames).await) {
+                    let (success, error) = match &result {
+                        Ok(r) => (r.success, r.error.clone()),
+                        Err(e) => (false, Some(e.clone())),
+                    };
+                    crate::transmit_history::write_entry(
+                        &session_id_task, "can",
+                        Some(frame.frame_id as i64),
+                        Some(frame.data.len() as i64),
+                        &frame.data,
+                        frame.bus as i64,
+                        frame.is_extended,
+                        frame.is_fd,
+                        success,
+                        error.as_deref(),
+                    );
+                    if should_stop && stop_reason.is_none() {
+                        stop_reason = Some(error.unwrap_or_else(|| "Permanent error".to_string()));
+                    }
+                }
+                if last_notify.as_ref().is_none_or(|t| t.elapsed() >= NOTIFY_INTERVAL) {
+                    let _ = app.emit("transmit-history-updated", ());
+                    last_notify = Some(std::time::Instant::now());
+                }
+
+                // Stop on permanent errors (device gone, session invalid)
+                if let Some(reason) = stop_reason {
+                    tlog!(
+                        "[io_transmit] Stopping transmit schedule on '{}' due to permanent error: {}",
+                        session_id_task, reason
+                    );
+                    let _ = app.emit("repeat-stopped", RepeatStoppedEvent {
+                        queue_id: format!("schedule:{}", session_id_task),
+                        reason,
+                    });
+                    let _ = app.emit("transmit-history-updated", ());
+                    if !cancel_task.load(Ordering::Relaxed) {
+                        TRANSMIT_SCHEDULES.lock().await.remove(&session_id_task);
+                    }
+                    break;
+                }
+            }
+
+            // Sleep until the next entry is due, or until an entry is toggled
+            let deadline = deadline.unwrap_or(now + IDLE_WAIT);
+            tokio::select! {
+                _ = tokio::time::sleep_until(tokio::time::Instant::from_std(deadline)) => {}
+                _ = wake_task.notified() => {}
+            }
+        }
+    });
+
+    Ok(())
+}
+
+/// Enable or disable one entry (by index) of a session's running schedule.
+/// An enabled entry sends right away, then at its period.
+#[tauri::command]
+pub async fn set_schedule_entry_enabled(session_id: String, index: usize, enabled: bool) -> Result<(), String> {
+    let schedules = TRANSMIT_SCHEDULES.lock().await;
+    let schedule = schedules
+        .get(&session_id)
+        .ok_or_else(|| format!("Session '{}' has no transmit schedule", session_id))?;
+    schedule
+        .state
+        .lock()
+        .map_err(|_| "Transmit schedule poisoned".to_string())?
+        .set_enabled(index, enabled, std::time::Instant::now())?;
+    schedule.wake.notify_one();
+    Ok(())
+}
+
+/// Stop a session's transmit schedule, if it has one
+#[tauri::command]
+pub async fn stop_transmit_schedule(session_id: String) -> Result<(), String> {
+    if let Some(schedule) = TRANSMIT_SCHEDULES.lock().await.remove(&session_id) {
+        tlog!("[io_transmit] Stopping transmit schedule on session '{}'", session_id);
+        schedule.cancel_flag.store(true, Ordering::Relaxed);
+        schedule.wake.notify_one();
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::test_support::can_transmit;
+
+    #[test]
+    fn test_loopback_stats_from_samples() {
+        let stats = LoopbackStats::from_samples(&[300, 100, 200], 5);
+        assert_eq!(
+            stats,
+            LoopbackStats { iterations: 5, received: 3, lost: 2, min_us: 100, max_us: 300, mean_us: 200 }
+        );
+        assert_eq!(LoopbackStats::from_samples(&[], 4).lost, 4);
+        assert_ne!(loopback_payload(1, 7), loopback_payload(2, 7));
+    }
+
+    #[test]
+    fn test_loopback_latency_uses_echo_timestamp() {
+        let arrived = std::time::Duration::from_micros(900);
+        assert_eq!(loopback_latency_us(1_000_000, 1_000_250, arrived), 250);
+        // A raw device clock isn't comparable with host time
+        assert_eq!(loopback_latency_us(1_000_000, 42, arrived), 900);
+    }
+
+    #[test]
+    fn test_corrupt_checksum_inverts_trailing_bytes() {
+        let frame = [0x01, 0x03, 0x00, 0x10, 0xC5, 0xCD];
+        let injection = SerialErrorInjection::CorruptChecksum { byte_index: -2, length: 2 };
+        let out = apply_serial_error_injection(&frame, &injection).unwrap();
+        assert_eq!(out, vec![0x01, 0x03, 0x00, 0x10, 0x3A, 0x32]);
+    }
+
+    #[test]
+    fn test_flip_bits_at_position() {
+        let frame = [0xAA, 0x55];
+        let injection = SerialErrorInjection::FlipBits { byte_index: 1, mask: 0x01 };
+        let out = apply_serial_error_injection(&frame, &injection).unwrap();
+        assert_eq!(out, vec![0xAA, 0x54]);
+    }
+
+    #[test]
+    fn test_injection_out_of_range() {
+        let frame = [0x01, 0x02];
+        let flip = SerialErrorInjection::FlipBits { byte_index: 5, mask: 0xFF };
+        assert!(apply_serial_error_injection(&frame, &flip).is_err());
+        let crc = SerialErrorInjection::CorruptChecksum { byte_index: 1, length: 2 };
+        assert!(apply_serial_error_injection(&frame, &crc).is_err());
+        let noop = SerialErrorInjection::FlipBits { byte_index: 0, mask: 0 };
+        assert!(apply_serial_error_injection(&frame, &noop).is_err());
+        // Negative indices must still land inside the frame
+        let before_start = SerialErrorInjection::FlipBits { byte_index: -3, mask: 0x01 };
+        assert!(apply_serial_error_injection(&frame, &before_start).is_err());
+        let crc = SerialErrorInjection::CorruptChecksum { byte_index: i32::MIN, length: 1 };
+        assert!(apply_serial_error_injection(&frame, &crc).is_err());
+    }
+
+    fn template(data: Vec<Option<u8>>, fill: TemplateFill) -> FrameTemplate {
+        FrameTemplate {
+            frame_id: 0x7E0,
+            data,
+            bus: 0,
+            is_extended: false,
+            is_fd: false,
+            is_brs: false,
+            fill,
+        }
+    }
+
+    #[test]
+    fn test_template_counter_covers_combinations() {
+        let t = template(vec![Some(0x10), None, Some(0xAA), None], TemplateFill::Counter { start: 0xFE });
+        let frames: Vec<Vec<u8>> = expand_frame_template(t, 3).unwrap().map(|f| f.data).collect();
+        assert_eq!(
+            frames,
+            vec![
+                vec![0x10, 0x00, 0xAA, 0xFE],
+                vec![0x10, 0x00, 0xAA, 0xFF],
+                vec![0x10, 0x01, 0xAA, 0x00],
+            ]
+        );
+
+        // One wildcard byte has 256 combinations
+        let t = template(vec![Some(0x22), None], TemplateFill::Counter { start: 0 });
+        assert_eq!(expand_frame_template(t.clone(), 256).unwrap().count(), 256);
+        assert!(expand_frame_template(t, 257).is_err());
+
+        // Nine FD wildcard bytes hold any counter value, but the counter itself
+        // must not overflow
+        let t = FrameTemplate {
+            is_fd: true,
+            ..template(vec![None; 9], TemplateFill::Counter { start: u64::MAX - 1 })
+        };
+        let last = expand_frame_template(t.clone(), 2).unwrap().last().unwrap();
+        assert_eq!(last.data, [vec![0x00], vec![0xFF; 8]].concat());
+        assert!(expand_frame_template(t, 3).is_err());
+    }
+
+    #[test]
+    fn test_template_fixed_and_random() {
+        let t = template(vec![None, Some(0x01), None], TemplateFill::Fixed { value: 0x55 });
+        let frame = expand_frame_template(t, 1).unwrap().next().unwrap();
+        assert_eq!(frame.data, vec![0x55, 0x01, 0x55]);
+        assert_eq!(frame.frame_id, 0x7E0);
+
+        // Seeded runs repeat exactly and leave fixed bytes alone
+        let t = template(vec![None, Some(0x01), None], TemplateFill::Random { seed: Some(42) });
+        let a: Vec<_> = expand_frame_template(t.clone(), 16).unwrap().map(|f| f.data).collect();
+        let b: Vec<_> = expand_frame_template(t, 16).unwrap().map(|f| f.data).collect();
+        assert_eq!(a, b);
+        assert!(a.iter().all(|d| d[1] == 0x01));
+        assert!(a.windows(2).any(|w| w[0] != w[1]));
+    }
+
+    #[test]
+    fn test_template_rejects_invalid() {
+        let long = template(vec![None; 9], TemplateFill::Fixed { value: 0 });
+        assert!(expand_frame_template(long, 1).is_err());
+        let t = template(vec![None], TemplateFill::Fixed { value: 0 });
+        assert!(expand_frame_template(t, 0).is_err());
+    }
+
+    fn schedule_entry(frame_id: u32, period_ms: u64, enabled: bool) -> ScheduleEntry {
+        ScheduleEntry {
+            frame: can_transmit(frame_id, vec![0x00]),
+            period_ms,
+            enabled,
+        }
+    }
+
+    #[test]
+    fn test_validate_transmit_frame() {
+        let fd = |bus: u8, len: usize| CanTransmitFrame { bus, is_fd: true, ..can_transmit(0x100, vec![0; len]) };
+        assert!(validate_transmit_frame("gvret_tcp".to_string(), fd(4, 64)).is_ok());
+        assert!(validate_transmit_frame("gvret_tcp".to_string(), fd(5, 8)).is_err());
+        // Hyphenated kind aliases validate and encode like their canonical kind
+        assert!(validate_transmit_frame("gvret-usb".to_string(), fd(5, 8)).is_err());
+        assert!(encode_transmit_preview("gvret-tcp".to_string(), fd(4, 8)).is_ok());
+        assert!(validate_transmit_frame("virtual".to_string(), fd(0, 65)).is_err());
+        let classic = CanTransmitFrame { is_fd: false, ..fd(0, 9) };
+        assert!(validate_transmit_frame("virtual".to_string(), classic).is_err());
+        // Profiles that can't send CAN at all are rejected up front
+        assert!(validate_transmit_frame("serial".to_string(), fd(0, 8)).is_err());
+    }
+
+    #[test]
+    fn test_encode_transmit_preview() {
+        let frame = CanTransmitFrame { bus: 1, is_extended: true, ..can_transmit(0x123, vec![0xAA, 0xBB]) };
+        let preview = encode_transmit_preview("gvret_tcp".to_string(), frame.clone()).unwrap();
+        assert_eq!(preview.bytes, vec![0xF1, 0x00, 0x23, 0x01, 0x00, 0x80, 0x01, 0x02, 0xAA, 0xBB]);
+        assert_eq!(preview.hex, "F1 00 23 01 00 80 01 02 AA BB");
+        // Invalid frames are rejected before encoding, including remote
+        // frames GVRET can't express
+        let rtr = CanTransmitFrame { is_rtr: true, ..frame.clone() };
+        assert!(encode_transmit_preview("gvret_tcp".to_string(), rtr).is_err());
+        let too_long = CanTransmitFrame { data: vec![0; 9], ..frame };
+        assert!(encode_transmit_preview("gvret_tcp".to_string(), too_long).is_err());
+    }
+
+    #[test]
+    fn test_disarmed_session_is_permanent_error() {
+        assert!(is_permanent_error("Transmit not armed for session 's1'. Arm transmit before sending."));
+        assert!(is_permanent_error("Session 's1' not found"));
+        assert!(!is_permanent_error("Device or resource busy"));
+    }
+
+    #[test]
+    fn test_schedule_sends_entries_at_their_periods() {
+        let start = std::time::Instant::now();
+        let ms = |n: u64| start + std::time::Duration::from_millis(n);
+        let entries = vec![schedule_entry(0x100, 10, true), schedule_entry(0x200, 25, true), schedule_entry(0x300, 5, false)];
+        let mut state = ScheduleState::new(entries, start).unwrap();
+
+        assert_eq!(state.take_due(start), vec![0, 1]);
+        assert_eq!(state.next_deadline(), Some(ms(10)));
+        assert!(state.take_due(ms(9)).is_empty());
+        assert_eq!(state.take_due(ms(10)), vec![0]);
+        assert_eq!(state.take_due(ms(25)), vec![0, 1]);
+        // Missed periods are skipped, not sent in a burst
+        assert_eq!(state.take_due(ms(100)), vec![0, 1]);
+        assert_eq!(state.next_deadline(), Some(ms(110)));
+
+        state.set_enabled(2, true, ms(101)).unwrap();
+        state.set_enabled(0, false, ms(101)).unwrap();
+        assert_eq!(state.take_due(ms(101)), vec![2]);
+        assert_eq!(state.take_due(ms(125)), vec![1, 2]);
+        assert!(state.set_enabled(3, true, ms(125)).is_err());
+
+        assert!(ScheduleState::new(vec![schedule_entry(0x100, 0, true)], start).is_err());
+    }
+}
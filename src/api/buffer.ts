@@ -41,6 +41,11 @@ export interface BufferMetadata {
   persistent: boolean;
   /** Distinct bus numbers present in this buffer's data (sorted) */
   buses: number[];
+  /**
+   * Absolute time (microseconds since the Unix epoch) that timestamp 0
+   * corresponds to, when timestamps were rebased (null = already absolute)
+   */
+  time_base_us: number | null;
 }
 
 /**
@@ -48,10 +53,42 @@ export interface BufferMetadata {
  * The buffer can then be used by any app (Discovery, Decoder).
  *
  * @param filePath - Full path to the CSV file
- * @returns Metadata about the imported data
+ * @param sortByTimestamp - Stably sort frames by timestamp (for unsorted logs)
+ * @param dropInvalidFrames - Drop frames with impossible data lengths instead of flagging them incomplete
+ * @returns Metadata, skipped rows and the out-of-order frame count
  */
-export async function importCsvToBuffer(filePath: string): Promise<BufferMetadata> {
-  return invoke("import_csv_to_buffer", { file_path: filePath });
+export async function importCsvToBuffer(
+  filePath: string,
+  sortByTimestamp = false,
+  dropInvalidFrames = false
+): Promise<CsvImportResult> {
+  return invoke("import_csv_to_buffer", {
+    file_path: filePath,
+    sort_by_timestamp: sortByTimestamp,
+    drop_invalid_frames: dropInvalidFrames,
+  });
+}
+
+/**
+ * Import a SavvyCAN/GVRET binary capture file into the shared buffer.
+ * Frames keep the device's microsecond timestamps.
+ *
+ * @param filePath - Full path to the capture file
+ * @param sortByTimestamp - Stably sort frames by timestamp (for unsorted captures)
+ * @returns Metadata and the out-of-order frame count
+ */
+export async function importGvretBinary(
+  filePath: string,
+  sortByTimestamp = false
+): Promise<GvretImportResult> {
+  return invoke("import_gvret_binary", { file_path: filePath, sort_by_timestamp: sortByTimestamp });
+}
+
+/** Result of a GVRET binary capture import */
+export interface GvretImportResult {
+  metadata: BufferMetadata;
+  /** Frames that were out of timestamp order (sorted if sortByTimestamp was set) */
+  out_of_order_frames: number;
 }
 
 // ============================================================================
@@ -72,6 +109,12 @@ export interface SequenceGap {
   filename?: string;
 }
 
+/** Malformed input skipped while parsing, with the first few error messages */
+export interface ParseErrors {
+  count: number;
+  samples: string[];
+}
+
 /** Result of a CSV import, including buffer metadata and sequence diagnostics */
 export interface CsvImportResult {
   metadata: BufferMetadata;
@@ -80,6 +123,12 @@ export interface CsvImportResult {
   total_dropped: number;
   /** Detected sequence wraparound points (raw sequence value at each wrap) */
   wrap_points: number[];
+  /** Rows skipped because of malformed IDs or data */
+  parse_errors: ParseErrors;
+  /** Frames that were out of timestamp order (sorted if sortByTimestamp was set) */
+  out_of_order_frames: number;
+  /** Frames with a data length impossible for their type (flagged incomplete, or dropped if dropInvalidFrames was set) */
+  invalid_length_frames: number;
 }
 
 /**
@@ -101,7 +150,8 @@ export type CsvColumnRole =
   | "bus"
   | "direction"
   | "frame_id_data"
-  | "sequence";
+  | "sequence"
+  | "rtr";
 
 /**
  * A single column mapping: column index to role
@@ -169,6 +219,8 @@ export async function previewCsv(
  * @param mappings - Column role assignments
  * @param skipFirstRow - Whether to skip the first row (header)
  * @param delimiter - Column delimiter
+ * @param sortByTimestamp - Stably sort frames by timestamp (ignored when a sequence column is mapped)
+ * @param dropInvalidFrames - Drop frames with impossible data lengths instead of flagging them incomplete
  * @returns Buffer metadata for the imported data
  */
 export async function importCsvWithMapping(
@@ -177,7 +229,9 @@ export async function importCsvWithMapping(
   skipFirstRow: boolean,
   timestampUnit: TimestampUnit,
   negateTimestamps: boolean,
-  delimiter: Delimiter
+  delimiter: Delimiter,
+  sortByTimestamp = false,
+  dropInvalidFrames = false
 ): Promise<CsvImportResult> {
   return invoke("import_csv_with_mapping", {
     file_path: filePath,
@@ -186,6 +240,8 @@ export async function importCsvWithMapping(
     timestamp_unit: timestampUnit,
     negate_timestamps: negateTimestamps,
     delimiter,
+    sort_by_timestamp: sortByTimestamp,
+    drop_invalid_frames: dropInvalidFrames,
   });
 }
 
@@ -199,6 +255,8 @@ export async function importCsvWithMapping(
  * @param timestampUnit - Timestamp unit for all files
  * @param negateTimestamps - Whether to negate timestamps
  * @param delimiter - Column delimiter
+ * @param sortByTimestamp - Stably sort the concatenated frames by timestamp
+ * @param dropInvalidFrames - Drop frames with impossible data lengths instead of flagging them incomplete
  * @returns Buffer metadata for the merged data
  */
 export async function importCsvBatchWithMapping(
@@ -207,7 +265,9 @@ export async function importCsvBatchWithMapping(
   skipFirstRowPerFile: boolean[],
   timestampUnit: TimestampUnit,
   negateTimestamps: boolean,
-  delimiter: Delimiter
+  delimiter: Delimiter,
+  sortByTimestamp = false,
+  dropInvalidFrames = false
 ): Promise<CsvImportResult> {
   return invoke("import_csv_batch_with_mapping", {
     file_paths: filePaths,
@@ -216,6 +276,8 @@ export async function importCsvBatchWithMapping(
     timestamp_unit: timestampUnit,
     negate_timestamps: negateTimestamps,
     delimiter,
+    sort_by_timestamp: sortByTimestamp,
+    drop_invalid_frames: dropInvalidFrames,
   });
 }
 
@@ -246,6 +308,8 @@ export interface BufferFrame {
   bytes: number[];
   is_extended?: boolean;
   is_fd?: boolean;
+  /** Remote transmission request: no data, `dlc` is the requested length */
+  is_rtr?: boolean;
   /** Source address (for protocols like J1939, TWC that embed sender ID in frame) */
   source_address?: number;
 }
@@ -396,15 +460,36 @@ export async function findBufferOffsetForTimestamp(
  *
  * @param sessionId - Unique session ID (e.g., "discovery", "decoder")
  * @param speed - Playback speed (0 = no limit, 1 = realtime)
+ * @param startPaused - Start paused, showing the snapshot at the start position
+ * @param startAtUs - Start position in microseconds (default: first frame)
+ * @param maxFps - Cap frame updates to this many per second (latest frame per ID kept)
+ * @param referencePacingId - Pace playback so this frame ID keeps its average captured period
+ * @param absoluteTime - Report playback-time as original time of day (rebased buffers only)
+ * @param referencePacingBus - Only count the reference pacing frame on this bus (default: any bus)
+ * @param referencePacingExtended - The reference pacing ID is extended (default: IDs above 0x7FF)
  * @returns Reader capabilities
  */
 export async function createBufferReaderSession(
   sessionId: string,
-  speed?: number
+  speed?: number,
+  startPaused?: boolean,
+  startAtUs?: number,
+  maxFps?: number,
+  referencePacingId?: number,
+  absoluteTime?: boolean,
+  referencePacingBus?: number,
+  referencePacingExtended?: boolean
 ): Promise<IOCapabilities> {
   return invoke("create_buffer_reader_session", {
     session_id: sessionId,
     speed,
+    start_paused: startPaused,
+    start_at_us: startAtUs,
+    max_fps: maxFps,
+    reference_pacing_id: referencePacingId,
+    reference_pacing_bus: referencePacingBus,
+    reference_pacing_extended: referencePacingExtended,
+    absolute_time: absoluteTime,
   });
 }
 
@@ -482,6 +567,337 @@ export async function getBufferFramesById(bufferId: string): Promise<BufferFrame
   return invoke("get_buffer_frames_by_id", { buffer_id: bufferId });
 }
 
+/**
+ * Match criteria for locating a trigger frame. Unset fields match any frame.
+ */
+export interface FrameFilter {
+  frame_id?: number;
+  bus?: number;
+  is_extended?: boolean;
+  /** Expected payload bytes, compared under `mask` (missing mask bytes = 0xFF) */
+  data?: number[];
+  mask?: number[];
+}
+
+/**
+ * Extract the frames within [match - beforeUs, match + afterUs] around the first
+ * (or every) frame in a buffer matching the filter. Window edges are inclusive.
+ *
+ * @param bufferId - The frame buffer to scan
+ * @param filter - Trigger frame criteria
+ * @param beforeUs - Microseconds to include before each match
+ * @param afterUs - Microseconds to include after each match
+ * @param allMatches - Use every match (overlapping windows are merged) instead of the first
+ */
+export async function extractBufferWindow(
+  bufferId: string,
+  filter: FrameFilter,
+  beforeUs: number,
+  afterUs: number,
+  allMatches = false
+): Promise<BufferFrame[]> {
+  return invoke("extract_buffer_window", {
+    buffer_id: bufferId,
+    filter,
+    before_us: beforeUs,
+    after_us: afterUs,
+    all_matches: allMatches,
+  });
+}
+
+/** A frame ID on one bus; standard and extended IDs with the same value are distinct */
+export interface FrameIdKey {
+  bus: number;
+  frame_id: number;
+  is_extended: boolean;
+}
+
+/** A frame ID present in both buffers whose payload bytes differ */
+export interface ChangedFrameId {
+  bus: number;
+  frame_id: number;
+  is_extended: boolean;
+  /** Byte positions where the set of values seen differs between the buffers */
+  byte_positions: number[];
+}
+
+/** Difference between two frame buffers, keyed by bus, frame ID and ID format */
+export interface BufferDiff {
+  only_in_a: FrameIdKey[];
+  only_in_b: FrameIdKey[];
+  changed: ChangedFrameId[];
+}
+
+/**
+ * Compare two frame buffers (e.g. a baseline capture against one where a button was pressed).
+ *
+ * @param bufferA - Baseline buffer ID
+ * @param bufferB - Comparison buffer ID
+ */
+export async function diffBuffers(bufferA: string, bufferB: string): Promise<BufferDiff> {
+  return invoke("diff_buffers", { buffer_a: bufferA, buffer_b: bufferB });
+}
+
+/**
+ * How a frame ID's transmissions are spread over time.
+ * - periodic: steady schedule; mean inter-arrival time and its standard deviation
+ * - sporadic: seen more than once, but event-driven
+ * - one_shot: seen exactly once
+ */
+export type Periodicity =
+  | { periodic: { period_us: number; jitter_us: number } }
+  | "sporadic"
+  | "one_shot";
+
+/** Periodicity classification of one frame ID on one bus */
+export interface PeriodicityInfo {
+  bus: number;
+  frame_id: number;
+  is_extended: boolean;
+  count: number;
+  periodicity: Periodicity;
+}
+
+/**
+ * Classify each frame ID in a buffer as periodic, sporadic or one-shot.
+ *
+ * @param bufferId - Frame buffer ID
+ * @returns One entry per (bus, ID format, frame ID), ordered by bus, then
+ *   standard before extended, then ID
+ */
+export async function classifyPeriodicity(bufferId: string): Promise<PeriodicityInfo[]> {
+  return invoke("classify_periodicity", { buffer_id: bufferId });
+}
+
+/** An ECU seen answering on an OBD-II response ID */
+export interface ObdResponder {
+  frame_id: number;
+  is_extended: boolean;
+  frame_count: number;
+  /** PIDs 0x01-0x20 reported in reply to mode 01 PID 00 (null if not queried or no reply) */
+  supported_pids: number[] | null;
+}
+
+/** Result of detectObd */
+export interface ObdInfo {
+  /** OBD-II request or response IDs were seen */
+  present: boolean;
+  /** Distinct request IDs seen (functional and physical) */
+  request_ids: number[];
+  /** ECUs that replied, ordered by ID */
+  responders: ObdResponder[];
+  /** A mode 01 PID 00 request was sent */
+  queried: boolean;
+}
+
+/**
+ * Check a frame buffer or live session for OBD-II traffic (0x7DF, 0x7E0-0x7EF,
+ * 29-bit 0x18DAxxF1/0x18DAF1xx) and report which ECUs responded.
+ *
+ * @param bufferIdOrSession - Frame buffer ID or session ID
+ * @param queryPids - On a session with transmit armed, send mode 01 PID 00 and report supported PIDs
+ * @param bus - Bus to send the query on (default 0)
+ */
+export async function detectObd(
+  bufferIdOrSession: string,
+  queryPids?: boolean,
+  bus?: number
+): Promise<ObdInfo> {
+  return invoke("detect_obd", {
+    buffer_id_or_session: bufferIdOrSession,
+    query_pids: queryPids ?? null,
+    bus: bus ?? null,
+  });
+}
+
+/** Activity in one time bin of a buffer overview */
+export interface BucketStats {
+  start_us: number;
+  /** End of the bin (exclusive) */
+  end_us: number;
+  frame_count: number;
+  /** Frames per bus; buses without frames in the bin are left out */
+  per_bus_counts: Record<number, number>;
+  /** Distinct frame IDs in the bin */
+  unique_ids: number;
+}
+
+/**
+ * Decimated activity overview of a frame buffer, e.g. for a scrub bar mini-map.
+ *
+ * @param bufferId - Frame buffer ID
+ * @param buckets - Number of equal time bins (1-10000)
+ */
+export async function bufferOverview(bufferId: string, buckets: number): Promise<BucketStats[]> {
+  return invoke("buffer_overview", { buffer_id: bufferId, buckets });
+}
+
+/**
+ * Fingerprint of a frame buffer's ID inventory (bus, ID, extended flag, typical DLC),
+ * ignoring timing and data. Captures of the same network topology share a fingerprint.
+ *
+ * @param bufferId - Frame buffer ID
+ * @returns 16 hex digits
+ */
+export async function trafficFingerprint(bufferId: string): Promise<string> {
+  return invoke("traffic_fingerprint", { buffer_id: bufferId });
+}
+
+/**
+ * Last known value of every frame ID at a point in a frame buffer: the most
+ * recent frame for each (bus, ID) at or before the timestamp.
+ *
+ * @param bufferId - Frame buffer ID
+ * @param timestampUs - Scrub position in microseconds
+ * @param lookbackMs - Ignore frames older than this before the timestamp
+ *   (default 2 minutes, 0 for no limit)
+ */
+export async function stateAt(
+  bufferId: string,
+  timestampUs: number,
+  lookbackMs?: number
+): Promise<BufferFrame[]> {
+  return invoke("state_at", {
+    buffer_id: bufferId,
+    timestamp_us: timestampUs,
+    lookback_ms: lookbackMs,
+  });
+}
+
+/** A distinct frame ID in a buffer, returned by uniqueIds */
+export interface IdEntry {
+  frame_id: number;
+  bus: number;
+  is_extended: boolean;
+  /** Number of frames with this ID */
+  count: number;
+  /** Payload of the latest frame */
+  last_bytes: number[];
+  /** Whether the latest frame was CAN FD */
+  is_fd: boolean;
+}
+
+/**
+ * Every distinct frame ID in a frame buffer, for ID pickers. Standard and
+ * extended IDs, and the same ID on different buses, are separate entries,
+ * sorted by frame ID then bus.
+ *
+ * @param bufferId - Frame buffer ID
+ */
+export async function uniqueIds(bufferId: string): Promise<IdEntry[]> {
+  return invoke("unique_ids", { buffer_id: bufferId });
+}
+
+/** Text log format written by the backend */
+export type LogFormat = "csv" | "candump" | "asc";
+
+/** One file written by exportBufferSplitByBus */
+export interface BusExportFile {
+  bus: number;
+  path: string;
+  frame_count: number;
+}
+
+/**
+ * Export a frame buffer as one log file per bus, named `<bufferId>_bus<N>.<ext>`.
+ *
+ * @param bufferId - Frame buffer ID
+ * @param dir - Directory to write into
+ * @param format - Log format
+ * @param options.buses - Only export these buses (default: every bus in the buffer)
+ * @param options.includeEmpty - Write an empty file for a listed bus with no frames
+ * @returns The files written
+ */
+export async function exportBufferSplitByBus(
+  bufferId: string,
+  dir: string,
+  format: LogFormat,
+  options: { buses?: number[]; includeEmpty?: boolean } = {}
+): Promise<BusExportFile[]> {
+  return invoke("export_buffer_split_by_bus", {
+    buffer_id: bufferId,
+    dir,
+    format,
+    buses: options.buses,
+    include_empty: options.includeEmpty,
+  });
+}
+
+/**
+ * A single editing step for transformBuffer (applied in order).
+ * - remap_bus: move frames on bus `from` to bus `to`
+ * - filter_ids: keep only frames matching the filter
+ * - rebase_time: shift timestamps so the earliest frame is at 0
+ * - clamp_gaps: shorten gaps between frames to at most N microseconds
+ * - offset: shift every timestamp by N microseconds (clamped at 0)
+ */
+export type TransformOp =
+  | { remap_bus: { from: number; to: number } }
+  | { filter_ids: FrameFilter }
+  | "rebase_time"
+  | { clamp_gaps: number }
+  | { offset: number };
+
+/**
+ * Copy a frame buffer into a new buffer with transforms applied.
+ * The source buffer is left untouched.
+ *
+ * @param src - Source buffer ID
+ * @param dstName - Name for the new buffer
+ * @param ops - Transform steps, applied in order
+ * @returns The new buffer's ID
+ */
+export async function transformBuffer(src: string, dstName: string, ops: TransformOp[]): Promise<string> {
+  return invoke("transform_buffer", { src, dst_name: dstName, ops });
+}
+
+/**
+ * How mergeBuffers lines up its sources.
+ * - absolute: keep timestamps as captured
+ * - zero_based: rebase each source so its earliest frame is at 0
+ * - offset: shift each source by its own offset in microseconds (clamped at 0)
+ */
+export type AlignMode = "absolute" | "zero_based" | { offset: number[] };
+
+/**
+ * Merge frame buffers into a new buffer on one timeline, sorted by timestamp.
+ * The source buffers are left untouched.
+ *
+ * @param bufferIds - Buffers to merge (at least two)
+ * @param align - How to align the sources' timestamps
+ * @param busMaps - Optional per-source bus remapping (`from -> to`), one map per buffer
+ * @returns The new buffer's ID
+ */
+export async function mergeBuffers(
+  bufferIds: string[],
+  align: AlignMode,
+  busMaps?: Record<number, number>[]
+): Promise<string> {
+  return invoke("merge_buffers", { buffer_ids: bufferIds, align, bus_maps: busMaps ?? null });
+}
+
+/**
+ * Save a buffer to disk as a native snapshot.
+ * Unlike CSV/BLF exports this keeps every frame field and is meant to be
+ * restored with `loadBuffer`.
+ *
+ * @param bufferId - Buffer to save
+ * @param path - Destination file path
+ */
+export async function saveBuffer(bufferId: string, path: string): Promise<void> {
+  return invoke("save_buffer", { buffer_id: bufferId, path });
+}
+
+/**
+ * Load a native snapshot into a new buffer.
+ *
+ * @param path - Snapshot file path
+ * @returns The new buffer's ID
+ */
+export async function loadBuffer(path: string): Promise<string> {
+  return invoke("load_buffer", { path });
+}
+
 /**
  * Timestamped byte for raw serial data
  */
@@ -102,6 +102,126 @@ export async function importDbc(content: string): Promise<string> {
   return await invoke<string>("import_dbc", { content });
 }
 
+/** Range summary for one DBC signal across a buffer */
+export interface SignalStats {
+  message: string;
+  signal: string;
+  min: number;
+  max: number;
+  last: number;
+  unit: string;
+  sample_count: number;
+}
+
+/**
+ * Decode every matching frame in a buffer with a DBC and summarise each signal
+ * @param bufferId - Frame buffer to scan
+ * @param dbcContent - Raw DBC file content
+ * @returns Min/max/last per signal seen in the buffer
+ */
+export async function signalStatistics(bufferId: string, dbcContent: string): Promise<SignalStats[]> {
+  return await invoke<SignalStats[]>("signal_statistics", {
+    buffer_id: bufferId,
+    dbc_content: dbcContent,
+  });
+}
+
+/** Transmitting node for one observed frame ID */
+export interface NodeLabel {
+  frame_id: number;
+  is_extended: boolean;
+  node: string;
+}
+
+/**
+ * Map each frame ID in a buffer to the node that transmits it, for grouping by ECU.
+ * DBC transmitters take priority; with `j1939`, extended IDs fall back to their
+ * source address ("SA 0xNN" when no DBC node sends from it).
+ * @param bufferId - Frame buffer to scan
+ * @param dbcContent - Raw DBC file content, if one is loaded
+ * @param j1939 - Label extended IDs by J1939 source address
+ * @returns One label per frame ID and ID format (IDs with no known origin are omitted)
+ */
+export async function decodeNodeMap(
+  bufferId: string,
+  dbcContent: string | null,
+  j1939: boolean
+): Promise<NodeLabel[]> {
+  return await invoke<NodeLabel[]>("decode_node_map", {
+    buffer_id: bufferId,
+    dbc_content: dbcContent,
+    j1939,
+  });
+}
+
+/** Bit numbering of a probed field, as in DBC files */
+export type ProbeEndianness = "intel" | "motorola";
+
+/** Result of probeSignal */
+export interface SignalProbeResult {
+  /** Set for a live session probe; samples then arrive as `signal-probe` events */
+  probe_id: string | null;
+  /** [timestamp_us, value] pairs decoded from a buffer (empty for a live probe) */
+  samples: [number, number][];
+}
+
+/** Payload of the `signal-probe` session event */
+export interface SignalProbeEvent {
+  probe_id: string;
+  samples: [number, number][];
+}
+
+/**
+ * Decode an arbitrary bit-field of a frame as a number, without a DBC.
+ * For a buffer, returns the time series from every matching frame. For a
+ * session, starts a live probe whose samples arrive as `signal-probe` events
+ * until stopSignalProbe is called or the session is destroyed.
+ * @param bufferIdOrSession - Frame buffer ID or session ID
+ * @param frameId - Frame ID carrying the field
+ * @param isExtended - Whether frameId is a 29-bit ID (null: only if it needs more than 11 bits)
+ * @param bus - Only probe frames on this bus (null: every bus)
+ * @param startBit - LSB for Intel, MSB for Motorola (DBC numbering)
+ * @param length - Field length in bits (1-64)
+ * @param endianness - "intel" or "motorola"
+ * @param signed - Sign-extend the raw value
+ * @param scale - Physical = raw * scale + offset (default 1)
+ * @param offset - Physical offset (default 0)
+ */
+export async function probeSignal(
+  bufferIdOrSession: string,
+  frameId: number,
+  isExtended: boolean | null,
+  bus: number | null,
+  startBit: number,
+  length: number,
+  endianness: ProbeEndianness,
+  signed: boolean,
+  scale?: number,
+  offset?: number
+): Promise<SignalProbeResult> {
+  return await invoke<SignalProbeResult>("probe_signal", {
+    buffer_id_or_session: bufferIdOrSession,
+    frame_id: frameId,
+    is_extended: isExtended,
+    bus,
+    start_bit: startBit,
+    length,
+    endianness,
+    signed,
+    scale: scale ?? null,
+    offset: offset ?? null,
+  });
+}
+
+/**
+ * Stop a live probe started by probeSignal
+ * @param sessionId - Session the probe runs on
+ * @param probeId - ID returned by probeSignal
+ */
+export async function stopSignalProbe(sessionId: string, probeId: string): Promise<void> {
+  await invoke("stop_signal_probe", { session_id: sessionId, probe_id: probeId });
+}
+
 /**
  * Write raw bytes to a file (used for image export)
  */
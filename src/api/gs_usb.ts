@@ -76,6 +76,45 @@ export async function probeGsUsbDevice(
   return invoke("probe_gs_usb_device", { bus, address, serial: serial ?? null });
 }
 
+/**
+ * One phase of a computed CAN bit timing.
+ */
+export interface PhaseBitTiming {
+  brp: number;
+  /** Propagation plus phase 1 segment, in time quanta */
+  tseg1: number;
+  tseg2: number;
+  sjw: number;
+  /** Bitrate actually achieved from the clock */
+  bitrate: number;
+  /** Sample point as a percentage of the bit time */
+  sample_point: number;
+}
+
+/**
+ * Nominal and (for CAN FD) data phase bit timing.
+ */
+export interface BitTimingResult {
+  nominal: PhaseBitTiming;
+  data: PhaseBitTiming | null;
+}
+
+/**
+ * Compute the bit timing gs_usb uses for a bitrate pair, for display and
+ * validation (e.g. warning about a sample point below 75%).
+ */
+export async function computeBitTiming(
+  clockHz: number,
+  bitrate: number,
+  dataBitrate?: number | null
+): Promise<BitTimingResult> {
+  return invoke("compute_bit_timing", {
+    clockHz,
+    bitrate,
+    dataBitrate: dataBitrate ?? null,
+  });
+}
+
 /**
  * Create a unique device ID for display and selection purposes.
  * Preference order: interface_name (Linux) > serial number > bus:address
@@ -229,8 +229,26 @@ export interface CreateIOSessionOptions {
   appName?: string;
   /** Buffer ID for buffer reader sessions (e.g., "buf_1") */
   bufferId?: string;
+  /** Buffer reader: start paused, showing the snapshot at the start position */
+  startPaused?: boolean;
+  /** Buffer reader: start position in microseconds (default: first frame) */
+  startAtUs?: number;
+  /** Buffer reader: cap frame updates to this many per second (latest frame per ID kept) */
+  maxFps?: number;
+  /** Buffer reader: pace playback so this frame ID keeps its average captured period */
+  referencePacingId?: number;
+  /** Buffer reader: only count the reference pacing frame on this bus (default: any bus) */
+  referencePacingBus?: number;
+  /** Buffer reader: the reference pacing ID is extended (default: IDs above 0x7FF) */
+  referencePacingExtended?: boolean;
+  /** Buffer reader: report playback-time as original time of day (rebased buffers only) */
+  absoluteTime?: boolean;
   /** Modbus TCP poll groups as JSON string (catalog-derived, for modbus_tcp profiles) */
   modbusPollsJson?: string;
+  /** Tee every emitted frame batch to this file while the session streams */
+  captureFile?: string;
+  /** Capture file format (default: "ndjson") */
+  captureFormat?: CaptureFormat;
 }
 
 /**
@@ -246,6 +264,13 @@ export async function createIOSession(
       session_id: options.sessionId,
       buffer_id: options.bufferId,
       speed: options.speed,
+      start_paused: options.startPaused,
+      start_at_us: options.startAtUs,
+      max_fps: options.maxFps,
+      reference_pacing_id: options.referencePacingId,
+      reference_pacing_bus: options.referencePacingBus,
+      reference_pacing_extended: options.referencePacingExtended,
+      absolute_time: options.absoluteTime,
     });
   }
 
@@ -280,6 +305,9 @@ export async function createIOSession(
     app_name: options.appName,
     // Modbus TCP poll groups (catalog-derived)
     modbus_polls: options.modbusPollsJson,
+    timestamp_source: options.timestampSource,
+    capture_file: options.captureFile,
+    capture_format: options.captureFormat,
   });
 }
 
@@ -362,6 +390,21 @@ export async function stopReaderSession(sessionId: string): Promise<IOState> {
   return invoke("stop_reader_session", { session_id: sessionId });
 }
 
+/** Result of stopping one session via stopAllReaderSessions */
+export interface StopSessionOutcome {
+  session_id: string;
+  state: IOState;
+  error: string | null;
+}
+
+/**
+ * Stop every active reader session (app shutdown / panic button).
+ * Sessions stay alive and can be restarted or destroyed afterwards.
+ */
+export async function stopAllReaderSessions(): Promise<StopSessionOutcome[]> {
+  return invoke("stop_all_reader_sessions");
+}
+
 /**
  * Pause a reader session.
  * Only works for readers that support pause (e.g., PostgreSQL).
@@ -600,6 +643,21 @@ export async function updateReaderDirection(
   return invoke("update_reader_direction", { session_id: sessionId, reverse });
 }
 
+/**
+ * Enable or disable playback of one bus in a buffer reader session.
+ * Frames on disabled buses are skipped while the other buses keep their timing.
+ * @param sessionId The session ID
+ * @param bus Bus number (as listed in the session capabilities)
+ * @param enabled false to skip the bus's frames during replay
+ */
+export async function setReaderBusEnabled(
+  sessionId: string,
+  bus: number,
+  enabled: boolean
+): Promise<void> {
+  return invoke("set_reader_bus_enabled", { session_id: sessionId, bus, enabled });
+}
+
 /**
  * Result of a step operation in the buffer.
  */
@@ -622,6 +680,19 @@ export interface PlaybackPosition {
   frame_count?: number;
 }
 
+/**
+ * Playback range - emitted with playback-range events when buffer streaming
+ * starts and after each seek.
+ */
+export interface PlaybackRange {
+  /** Timestamp of the first frame in microseconds */
+  start_us: number;
+  /** Timestamp of the last frame in microseconds */
+  end_us: number;
+  /** Total frame count in buffer */
+  total_frames: number;
+}
+
 /**
  * Step one frame forward or backward in the buffer.
  * Only works for buffer readers when paused.
@@ -648,11 +719,43 @@ export async function stepBufferFrame(
   });
 }
 
+/** Result of emitBufferRange */
+export interface EmitRangeResult {
+  /** Frames emitted */
+  emitted: number;
+  /** Whether the range held more frames than were emitted */
+  truncated: boolean;
+}
+
+/**
+ * Emit the buffer frames in a time range immediately (no pacing) as ordinary
+ * frame batches, e.g. to fill the visible window after a jump.
+ * Playback position and state are unchanged: a playing stream keeps going from
+ * where it was, so pause first for a still view. At most 50,000 frames are sent.
+ * @param sessionId The session ID
+ * @param startUs Range start in microseconds (inclusive)
+ * @param endUs Range end in microseconds (inclusive)
+ * @param maxFrames Optional lower cap on the frames emitted
+ */
+export async function emitBufferRange(
+  sessionId: string,
+  startUs: number,
+  endUs: number,
+  maxFrames?: number
+): Promise<EmitRangeResult> {
+  return invoke("emit_buffer_range_cmd", {
+    session_id: sessionId,
+    start_us: startUs,
+    end_us: endUs,
+    max_frames: maxFrames,
+  });
+}
+
 /**
  * Payload sent when a stream ends (GVRET disconnect, PostgreSQL query complete, etc.)
  */
 export interface StreamEndedPayload {
-  /** Reason for stream ending: "complete", "disconnected", "error", "stopped" */
+  /** Reason for stream ending: "complete", "disconnected", "error", "stopped", "idle" (auto-stopped after no traffic) */
   reason: string;
   /** Whether the buffer has data available for replay */
   buffer_available: boolean;
@@ -805,9 +908,93 @@ export interface TransmitResult {
   error?: string;
 }
 
+/**
+ * Arm or disarm transmit on a session. Sessions start disarmed and reject
+ * every transmit ("Transmit not armed") until armed. Emits
+ * `transmit-armed:<sessionId>` with the new state.
+ * @param sessionId The session ID
+ * @param armed Whether transmits are allowed
+ */
+export async function armTransmit(sessionId: string, armed: boolean): Promise<void> {
+  return invoke("arm_transmit", { session_id: sessionId, armed });
+}
+
+/**
+ * Set how long transmits on a session wait for the device to confirm a write
+ * (default 500 ms). Raise it for slow USB adapters or a bus with no other node
+ * to ACK. A transmit that times out reports "queued but not confirmed" rather
+ * than a write failure, since the frame may still have gone out.
+ * @param sessionId The session ID
+ * @param timeoutMs Timeout in milliseconds (10 to 10000)
+ */
+export async function setSessionTransmitTimeout(
+  sessionId: string,
+  timeoutMs: number
+): Promise<void> {
+  return invoke("set_session_transmit_timeout", { session_id: sessionId, timeout_ms: timeoutMs });
+}
+
+/**
+ * Enforce a minimum gap between writes on a session. Each write waits until
+ * at least `gapUs` after the previous write was confirmed, so frames queued
+ * back-to-back go out no faster than a real ECU would send them.
+ * @param sessionId The session ID
+ * @param gapUs Minimum gap in microseconds (0 disables, max 10000000)
+ */
+export async function setMinTxGap(sessionId: string, gapUs: number): Promise<void> {
+  return invoke("set_min_tx_gap", { session_id: sessionId, gap_us: gapUs });
+}
+
+/**
+ * Choose whether a session stores its own transmitted frames in its buffer
+ * (default true). With false they still appear in the live view, but exports
+ * and replays of the buffer won't contain them, so a replay can't re-send them.
+ * @param sessionId The session ID
+ * @param enabled Store TX frames in the buffer
+ */
+export async function setBufferTxFrames(sessionId: string, enabled: boolean): Promise<void> {
+  return invoke("set_buffer_tx_frames", { session_id: sessionId, enabled });
+}
+
+/**
+ * Turn transmit through one source of a multi-source session off or on while
+ * it keeps receiving. Transmits routed to a disabled source fail.
+ * @param sessionId The session ID
+ * @param sourceIdx Source index, as in the session's bus mappings
+ * @param enabled Allow transmit through the source
+ */
+export async function setSourceTransmitEnabled(
+  sessionId: string,
+  sourceIdx: number,
+  enabled: boolean
+): Promise<void> {
+  return invoke("set_source_transmit_enabled", { session_id: sessionId, source_idx: sourceIdx, enabled });
+}
+
+/** What a session's adapter reports about itself */
+export interface DeviceInfo {
+  /** Hardware and firmware version digits (slcan 'V') */
+  version: string | null;
+  /** Detailed firmware version (slcan 'v') */
+  firmware_version: string | null;
+  /** Serial number (slcan 'N') */
+  serial_number: string | null;
+}
+
+/**
+ * Ask a running session's adapter for its firmware versions and serial
+ * number without interrupting the capture. Currently answered by slcan
+ * sources; fields the device doesn't report are null.
+ * @param sessionId The session ID
+ */
+export async function getDeviceInfo(sessionId: string): Promise<DeviceInfo> {
+  return invoke("device_info", { session_id: sessionId });
+}
+
 /**
  * Transmit a CAN frame through a session.
- * The session must be running and support transmission (can_transmit capability).
+ * The session must be running, support transmission (can_transmit capability)
+ * and be armed (see armTransmit).
  * @param sessionId The session ID
  * @param frame The CAN frame to transmit
  */
@@ -944,6 +1131,7 @@ export async function addSourceToSession(
     source_address_start_byte: source.sourceAddressStartByte,
     source_address_bytes: source.sourceAddressBytes,
     source_address_big_endian: source.sourceAddressBigEndian,
+    id_remap: source.idRemap,
   };
   return invoke("add_source_to_session_cmd", {
     session_id: sessionId,
@@ -996,6 +1184,73 @@ export async function updateSourceBusMappings(
   });
 }
 
+/** Live bus mappings of one source in a multi-source session */
+export interface SourceBusMappings {
+  /** Source index used for transmit routing */
+  sourceIdx: number;
+  profileId: string;
+  profileKind: string;
+  busMappings: BusMapping[];
+}
+
+/**
+ * Change one source's bus mappings while the session runs.
+ * Remapping or disabling buses applies in place; enabling a bus the source
+ * never opened reconnects that source only.
+ * @param sessionId The session ID
+ * @param sourceIdx Source index, as returned by getBusMappings
+ * @param mappings The new bus mappings for the source
+ * @returns Updated IOCapabilities for the session
+ */
+export async function updateBusMapping(
+  sessionId: string,
+  sourceIdx: number,
+  mappings: BusMapping[],
+): Promise<IOCapabilities> {
+  return invoke("update_bus_mapping", {
+    session_id: sessionId,
+    source_idx: sourceIdx,
+    mappings: mappings.map((m) => ({
+      device_bus: m.deviceBus,
+      enabled: m.enabled,
+      output_bus: m.outputBus,
+      interface_id: m.interfaceId,
+      traits: m.traits,
+    })),
+  });
+}
+
+/**
+ * Get the current bus mappings of each source in a multi-source session.
+ * @param sessionId The session ID
+ */
+export async function getBusMappings(sessionId: string): Promise<SourceBusMappings[]> {
+  const raw = await invoke<Array<{
+    source_idx: number;
+    profile_id: string;
+    profile_kind: string;
+    bus_mappings: Array<{
+      device_bus: number;
+      enabled: boolean;
+      output_bus: number;
+      interface_id?: string;
+      traits?: InterfaceTraits;
+    }>;
+  }>>("get_bus_mappings", { session_id: sessionId });
+  return raw.map((s) => ({
+    sourceIdx: s.source_idx,
+    profileId: s.profile_id,
+    profileKind: s.profile_kind,
+    busMappings: s.bus_mappings.map((m) => ({
+      deviceBus: m.device_bus,
+      enabled: m.enabled,
+      outputBus: m.output_bus,
+      interfaceId: m.interface_id,
+      traits: m.traits,
+    })),
+  }));
+}
+
 /**
  * Get all listeners for a session.
  * Useful for debugging and for the frontend to understand session state.
@@ -1063,6 +1318,87 @@ export async function setSessionListenerActive(
   });
 }
 
+/**
+ * Restrict which frame IDs a session emits to the frontend.
+ * Frames are still fully buffered; only frame-message IPC traffic is reduced.
+ * @param sessionId The session ID
+ * @param ids Frame IDs to emit, or null to emit all frames
+ */
+export async function setActiveListeners(
+  sessionId: string,
+  ids: number[] | null
+): Promise<void> {
+  return invoke("set_active_listeners", {
+    session_id: sessionId,
+    ids,
+  });
+}
+
+/**
+ * Enable or disable the ASCII view for a session. When enabled, emitted frames
+ * carry an `ascii` field rendering their data bytes as printable ASCII.
+ * Off by default to avoid the overhead on high-rate buses.
+ * @param sessionId The session ID
+ * @param enabled Whether to include the ASCII rendering
+ */
+export async function setSessionAsciiView(
+  sessionId: string,
+  enabled: boolean
+): Promise<void> {
+  return invoke("set_session_ascii_view_cmd", {
+    session_id: sessionId,
+    enabled,
+  });
+}
+
+/**
+ * Get a session's current frame rate (frames/sec averaged over the last ~2 s).
+ * Cheap enough to poll for a live rate indicator; 0 before any frames arrive.
+ * @param sessionId The session ID
+ */
+export async function getFrameRate(sessionId: string): Promise<number> {
+  return invoke("frame_rate", { session_id: sessionId });
+}
+
+/** A parse failure kept for diagnostics */
+export interface ParseErrorEntry {
+  /** Host UNIX timestamp in microseconds */
+  timestamp_us: number;
+  /** Index of the source within the session */
+  source_index: number;
+  /** "slcan_line", "slcan_overlong_line", "gvret_resync" or "framer_overflow" */
+  kind: string;
+  message: string;
+  /** First bytes of the offending input (at most 32) */
+  sample: number[];
+}
+
+/**
+ * Get a session's most recent parse failures (at most 50, oldest first).
+ * Kept on the backend rather than emitted, so a noisy source can't flood events.
+ * @param sessionId The session ID
+ */
+export async function getRecentParseErrors(sessionId: string): Promise<ParseErrorEntry[]> {
+  return invoke("recent_parse_errors", { session_id: sessionId });
+}
+
+/**
+ * Start the local frame server on 127.0.0.1. Connected TCP clients receive
+ * every session's frame batches as newline-delimited JSON, after emit filtering.
+ * @param port Port to listen on (0 picks a free port)
+ * @returns The port actually bound
+ */
+export async function startFrameServer(port: number): Promise<number> {
+  return invoke("start_frame_server", { port });
+}
+
+/**
+ * Stop the local frame server and disconnect its clients.
+ */
+export async function stopFrameServer(): Promise<void> {
+  return invoke("stop_frame_server");
+}
+
 // ============================================================================
 // GVRET Device Probing
 // ============================================================================
@@ -1073,6 +1409,8 @@ export async function setSessionListenerActive(
 export interface GvretDeviceInfo {
   /** Number of CAN buses available on this device (1-5) */
   bus_count: number;
+  /** Whether the firmware answered the CAN FD settings query (null if not checked) */
+  supports_fd?: boolean | null;
 }
 
 /**
@@ -1103,6 +1441,30 @@ export async function probeGvretDevice(profileId: string): Promise<GvretDeviceIn
   return invoke("probe_gvret_device", { profile_id: profileId });
 }
 
+/** Traffic seen on one device bus during a GVRET bus scan */
+export interface BusScanResult {
+  /** Bus number as reported by the device (before bus mapping) */
+  bus: number;
+  frame_count: number;
+  /** Number of distinct frame IDs seen */
+  unique_ids: number;
+  /** First few distinct IDs seen, in arrival order */
+  sample_ids: number[];
+}
+
+/**
+ * Listen to a GVRET device and report which buses carry traffic.
+ * The connection is closed afterwards; silent buses are omitted.
+ * @param profileId The ID of the GVRET profile to scan
+ * @param durationMs How long to listen
+ */
+export async function scanGvretBuses(
+  profileId: string,
+  durationMs: number
+): Promise<BusScanResult[]> {
+  return invoke("scan_gvret_buses", { profile_id: profileId, duration_ms: durationMs });
+}
+
 /**
  * Result of probing any real-time device.
  * Provides a unified structure for all device types.
@@ -1166,6 +1528,72 @@ export async function probeDevice(profileId: string): Promise<DeviceProbeResult>
   };
 }
 
+/** Result of testing a profile's connectivity with testProfile */
+export interface ProfileTestResult extends DeviceProbeResult {
+  /** Settings that look wrong even though the device answered (e.g. an unsupported bitrate) */
+  warnings: string[];
+}
+
+/**
+ * Test a profile's connectivity without starting a session.
+ *
+ * Takes the profile itself, so unsaved settings can be tested before a capture.
+ * Runs the probe for the profile's kind, briefly opens SocketCAN interfaces,
+ * and checks bitrate settings. Results are not cached.
+ *
+ * @param profile The profile to test (need not be saved)
+ * @returns Probe result plus any configuration warnings
+ */
+export async function testProfile(profile: {
+  id: string;
+  name: string;
+  kind: string;
+  connection: Record<string, unknown>;
+}): Promise<ProfileTestResult> {
+  const raw = await invoke<{
+    success: boolean;
+    device_type: string;
+    is_multi_bus: boolean;
+    bus_count: number;
+    primary_info: string | null;
+    secondary_info: string | null;
+    supports_fd: boolean | null;
+    error: string | null;
+    warnings: string[];
+  }>("test_profile", { profile });
+
+  return {
+    success: raw.success,
+    deviceType: raw.device_type,
+    isMultiBus: raw.is_multi_bus,
+    busCount: raw.bus_count,
+    primaryInfo: raw.primary_info,
+    secondaryInfo: raw.secondary_info,
+    supports_fd: raw.supports_fd,
+    error: raw.error,
+    warnings: raw.warnings,
+  };
+}
+
+/** Bitrates a profile kind can be configured with */
+export interface BitrateSupport {
+  /** Nominal bitrates to offer; the only valid ones when `arbitrary` is false */
+  fixed: number[];
+  /** Whether any bitrate can be used (timing is calculated) */
+  arbitrary: boolean;
+  /** CAN FD data phase bitrates to offer (empty if FD is not configurable) */
+  fd_data_rates: number[];
+}
+
+/**
+ * Get the bitrates a profile kind supports, from the same tables the device
+ * code uses when connecting. Rejects for kinds without a CAN bitrate.
+ * @param profileKind Profile kind, e.g. "slcan" or "gs_usb"
+ */
+export async function getSupportedBitrates(profileKind: string): Promise<BitrateSupport> {
+  return invoke("supported_bitrates", { profile_kind: profileKind });
+}
+
 /**
  * Create default bus mappings for a GVRET device.
  * All buses are enabled and map to sequential output numbers starting from offset.
@@ -1231,8 +1659,28 @@ export interface MultiSourceInput {
   sourceAddressBigEndian?: boolean;
   /** Modbus interface role (client or server) */
   modbusRole?: "client" | "server";
+  /**
+   * Frame ID relabelling (arrival ID -> captured ID), applied after bus mapping and
+   * before buffering and the emit filter. Relabelled frames keep `original_frame_id`.
+   */
+  idRemap?: Record<number, number>;
 }
 
+/**
+ * Which clock stamps received frames.
+ * - "host": host wall-clock at arrival (absolute, but with USB/network jitter)
+ * - "device": the adapter's own timestamp (precise relative timing, not wall-clock)
+ * - "device_aligned_to_host": device timing offset to the host clock at the first frame
+ */
+export type TimestampSource = "host" | "device" | "device_aligned_to_host";
+
+/**
+ * Capture file format for the streaming tee.
+ * - "ndjson": one frame JSON object per line
+ * - "native": buffer snapshot, loadable with loadBuffer
+ */
+export type CaptureFormat = "ndjson" | "native";
+
 /**
  * Options for creating a multi-source IO session.
  */
@@ -1247,6 +1695,14 @@ export interface CreateMultiSourceSessionOptions {
   appName?: string;
   /** Shared Modbus poll groups JSON (injected into all modbus_tcp sources) */
   modbusPollsJson?: string;
+  /** Frame timestamp source for every source (defaults to each profile's setting) */
+  timestampSource?: TimestampSource;
+  /** Tee every emitted frame batch to this file while the session streams */
+  captureFile?: string;
+  /** Capture file format (default: "ndjson") */
+  captureFormat?: CaptureFormat;
+  /** Also keep each source's frames in a buffer of its own (default: merged buffer only) */
+  perSourceBuffers?: boolean;
 }
 
 /**
@@ -1290,6 +1746,7 @@ export async function createMultiSourceSession(
     source_address_bytes: source.sourceAddressBytes,
     source_address_big_endian: source.sourceAddressBigEndian,
     modbus_role: source.modbusRole,
+    id_remap: source.idRemap,
   }));
 
   return invoke("create_multi_source_session", {
@@ -1298,9 +1755,22 @@ export async function createMultiSourceSession(
     listener_id: options.listenerId,
     app_name: options.appName,
     modbus_polls: options.modbusPollsJson,
+    capture_file: options.captureFile,
+    capture_format: options.captureFormat,
+    per_source_buffers: options.perSourceBuffers,
   });
 }
 
+/**
+ * A buffer holding one source's frames, alongside a multi-source session's merged buffer
+ */
+export interface SourceBufferInfo {
+  sourceIndex: number;
+  profileId: string;
+  displayName: string;
+  bufferId: string;
+}
+
 /**
  * Info about an active session (from backend)
  */
@@ -1325,8 +1795,20 @@ export interface ActiveSessionInfo {
   bufferId: string | null;
   /** Frame count in the owned buffer */
   bufferFrameCount: number | null;
+  /** Per-source buffers kept alongside the merged buffer (see perSourceBuffers) */
+  sourceBuffers: SourceBufferInfo[];
   /** Whether the session is actively streaming data */
   isStreaming: boolean;
+  /** When the session was created (microseconds since UNIX epoch) */
+  startedAtUs: number;
+  /** Number of frames emitted by this session since it was created */
+  frameCount: number;
+  /** Whether frames are being buffered without emission because the UI can't keep up */
+  backpressure: boolean;
+  /** Whether transmits are allowed (see armTransmit) */
+  transmitArmed: boolean;
+  /** Frames emitted after a newer frame because a source delivered them late */
+  reorderingNeeded: number;
 }
 
 /**
@@ -1360,7 +1842,18 @@ export async function listActiveSessions(): Promise<ActiveSessionInfo[]> {
     source_profile_ids: string[];
     buffer_id: string | null;
     buffer_frame_count: number | null;
+    source_buffers?: Array<{
+      source_index: number;
+      profile_id: string;
+      display_name: string;
+      buffer_id: string;
+    }>;
     is_streaming: boolean;
+    started_at_us: number;
+    frame_count: number;
+    backpressure?: boolean;
+    transmit_armed?: boolean;
+    reordering_needed?: number;
   }> = await invoke("list_active_sessions");
 
   return raw.map((s) => ({
@@ -1384,7 +1877,18 @@ export async function listActiveSessions(): Promise<ActiveSessionInfo[]> {
     sourceProfileIds: s.source_profile_ids ?? [],
     bufferId: s.buffer_id ?? null,
     bufferFrameCount: s.buffer_frame_count ?? null,
+    sourceBuffers: (s.source_buffers ?? []).map((b) => ({
+      sourceIndex: b.source_index,
+      profileId: b.profile_id,
+      displayName: b.display_name,
+      bufferId: b.buffer_id,
+    })),
     isStreaming: s.is_streaming ?? false,
+    startedAtUs: s.started_at_us ?? 0,
+    frameCount: s.frame_count ?? 0,
+    backpressure: s.backpressure ?? false,
+    transmitArmed: s.transmit_armed ?? false,
+    reorderingNeeded: s.reordering_needed ?? 0,
   }));
 }
 
@@ -25,6 +25,8 @@ export interface DeviceProbeResponse {
   hardware_version: string | null;
   /** Serial number (if available) */
   serial_number: string | null;
+  /** Whether the firmware accepts CAN FD commands (null if it gave no answer) */
+  supports_fd?: boolean | null;
   /** Error message (if probe failed) */
   error: string | null;
 }
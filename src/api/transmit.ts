@@ -4,6 +4,7 @@
 // Uses IO session-based transmit - the session must be started first.
 
 import { invoke } from "@tauri-apps/api/core";
+import type { BufferFrame, FrameFilter } from "./buffer";
 
 // ============================================================================
 // Types
@@ -25,6 +26,8 @@ export interface CanTransmitFrame {
   is_brs: boolean;
   /** Remote Transmission Request */
   is_rtr: boolean;
+  /** Requested length of a remote frame (defaults to `data.length`) */
+  rtr_dlc?: number;
 }
 
 /** Result of a transmit operation */
@@ -220,6 +223,95 @@ export async function ioTransmitCanFrame(
   return invoke("io_transmit_can_frame", { sessionId, frame });
 }
 
+/**
+ * Transmit a CAN frame and wait for the first received frame matching a filter.
+ * Useful for request/response exchanges (e.g. UDS) without polling the buffer.
+ * @param sessionId - IO session to use for transmission
+ * @param frame - Request frame to transmit
+ * @param responseFilter - Criteria the response frame must match
+ * @param timeoutMs - How long to wait for the response
+ * @returns The matching frame, or null if none arrived within the timeout
+ */
+export async function ioTransmitAndAwait(
+  sessionId: string,
+  frame: CanTransmitFrame,
+  responseFilter: FrameFilter,
+  timeoutMs: number
+): Promise<BufferFrame | null> {
+  return invoke("io_transmit_and_await", { sessionId, frame, responseFilter, timeoutMs });
+}
+
+/**
+ * Check a CAN frame against what a profile kind can transmit (data length,
+ * BRS, bus range, FD support) without sending it.
+ * Resolves when the frame is valid; rejects with the reason otherwise.
+ * @param profileKind - Profile kind, e.g. "slcan" or "gvret_tcp"
+ * @param frame - CAN frame to check
+ */
+export async function validateTransmitFrame(
+  profileKind: string,
+  frame: CanTransmitFrame
+): Promise<void> {
+  return invoke("validate_transmit_frame", { profileKind, frame });
+}
+
+/** Bytes a profile kind would put on the wire for a frame */
+export interface TransmitPreview {
+  bytes: number[];
+  /** Space-separated uppercase hex of bytes */
+  hex: string;
+}
+
+/**
+ * Dry-run transmit: validate a frame and encode it for a profile kind without
+ * opening a device. For gs_usb the channel byte is 0.
+ * @param profileKind - Profile kind, e.g. "slcan" or "gvret_tcp"
+ * @param frame - CAN frame to encode
+ */
+export async function encodeTransmitPreview(
+  profileKind: string,
+  frame: CanTransmitFrame
+): Promise<TransmitPreview> {
+  return invoke("encode_transmit_preview", { profileKind, frame });
+}
+
+/** Round-trip latency distribution from loopbackTest */
+export interface LoopbackStats {
+  /** Probes sent */
+  iterations: number;
+  /** Probes that came back */
+  received: number;
+  /** Probes that did not come back within the timeout */
+  lost: number;
+  /** Fastest round trip in microseconds (0 if nothing came back) */
+  min_us: number;
+  /** Slowest round trip in microseconds (0 if nothing came back) */
+  max_us: number;
+  /** Mean round trip in microseconds (0 if nothing came back) */
+  mean_us: number;
+}
+
+/**
+ * Measure round-trip latency through a loopback: transmit unique probe frames
+ * and time each until it (or the device's echo) is seen on the session.
+ * The session must be armed and either looped back or echoing transmits.
+ * @param sessionId - IO session to test
+ * @param iterations - Number of probes to send (1 to 10000)
+ * @param frameId - Probe frame ID (default 0x7FF)
+ * @param bus - Bus to send probes on (default 0)
+ * @param timeoutMs - How long to wait for each probe (default 200)
+ * @returns Latency statistics and the number of lost probes
+ */
+export async function loopbackTest(
+  sessionId: string,
+  iterations: number,
+  frameId?: number,
+  bus?: number,
+  timeoutMs?: number
+): Promise<LoopbackStats> {
+  return invoke("loopback_test", { sessionId, iterations, frameId, bus, timeoutMs });
+}
+
 /**
  * Transmit raw serial bytes through an existing IO session.
  * The session must be running a serial profile with transmit support.
@@ -234,6 +326,36 @@ export async function ioTransmitSerial(
   return invoke("io_transmit_serial", { sessionId, bytes });
 }
 
+/**
+ * Write raw bytes straight to a session's GVRET device (e.g. a device-specific
+ * control command), bypassing frame encoding. Transmit must be armed.
+ * Malformed bytes can desynchronise the device's stream until the session restarts.
+ * @param sessionId - IO session with a GVRET source
+ * @param bytes - Bytes to write as-is
+ */
+export async function transmitRaw(sessionId: string, bytes: number[]): Promise<TransmitResult> {
+  return invoke("transmit_raw", { sessionId, bytes });
+}
+
+/** Deliberate corruption applied to a serial frame before sending */
+export type SerialErrorInjection =
+  | { kind: "corrupt_checksum"; byte_index: number; length: number }
+  | { kind: "flip_bits"; byte_index: number; mask: number };
+
+/**
+ * Transmit a deliberately corrupted serial frame (device error-handling tests).
+ * Requires injectError = true so malformed frames are never sent by accident.
+ * @returns Transmit result and the bytes that were actually sent
+ */
+export async function ioTransmitSerialWithError(
+  sessionId: string,
+  bytes: number[],
+  injection: SerialErrorInjection,
+  injectError: boolean
+): Promise<[TransmitResult, number[]]> {
+  return invoke("io_transmit_serial_with_error", { sessionId, bytes, injection, injectError });
+}
+
 /**
  * Start repeat transmission through an IO session.
  * @param sessionId - IO session to use
@@ -338,6 +460,101 @@ export async function ioStopAllGroupRepeats(): Promise<void> {
   return invoke("io_stop_all_group_repeats");
 }
 
+// ============================================================================
+// Templated Transmit API
+// ============================================================================
+
+/** How wildcard bytes in a frame template are filled */
+export type TemplateFill =
+  | { kind: "counter"; start?: number }
+  | { kind: "random"; seed?: number | null }
+  | { kind: "fixed"; value: number };
+
+/** CAN frame with wildcard payload bytes (null entries in data) */
+export interface FrameTemplate {
+  frame_id: number;
+  data: (number | null)[];
+  bus?: number;
+  is_extended?: boolean;
+  is_fd?: boolean;
+  is_brs?: boolean;
+  fill: TemplateFill;
+}
+
+/**
+ * Transmit frames expanded from a template, one per interval.
+ * Runs once; stop early with ioStopRepeatTransmit(queueId).
+ * A `repeat-stopped` event fires when the run ends.
+ * @param sessionId - IO session to use
+ * @param queueId - Unique ID for this run (used to stop it)
+ * @param template - Frame with wildcard bytes and a fill strategy
+ * @param count - Number of frames to generate and send
+ * @param intervalMs - Delay between frames in milliseconds
+ */
+export async function ioStartTemplateTransmit(
+  sessionId: string,
+  queueId: string,
+  template: FrameTemplate,
+  count: number,
+  intervalMs: number
+): Promise<void> {
+  return invoke("io_start_template_transmit", {
+    sessionId,
+    queueId,
+    template,
+    count,
+    intervalMs,
+  });
+}
+
+// ============================================================================
+// Transmit Schedule API
+// ============================================================================
+
+/** One row of a transmit schedule */
+export interface ScheduleEntry {
+  frame: CanTransmitFrame;
+  period_ms: number;
+  enabled: boolean;
+}
+
+/**
+ * Load a table of periodic frames and start sending the enabled entries, each
+ * at its own period. Replaces the session's existing schedule.
+ * A `repeat-stopped` event (queue_id `schedule:<sessionId>`) fires if a
+ * permanent error stops it.
+ * @param sessionId - IO session to use
+ * @param entries - Schedule rows
+ */
+export async function loadTransmitSchedule(
+  sessionId: string,
+  entries: ScheduleEntry[]
+): Promise<void> {
+  return invoke("load_transmit_schedule", { sessionId, entries });
+}
+
+/**
+ * Enable or disable one entry of a running schedule.
+ * @param sessionId - IO session running the schedule
+ * @param index - Entry index in the loaded table
+ * @param enabled - Whether the entry should send
+ */
+export async function setScheduleEntryEnabled(
+  sessionId: string,
+  index: number,
+  enabled: boolean
+): Promise<void> {
+  return invoke("set_schedule_entry_enabled", { sessionId, index, enabled });
+}
+
+/**
+ * Stop a session's transmit schedule.
+ * @param sessionId - IO session running the schedule
+ */
+export async function stopTransmitSchedule(sessionId: string): Promise<void> {
+  return invoke("stop_transmit_schedule", { sessionId });
+}
+
 // ============================================================================
 // Replay API
 // ============================================================================
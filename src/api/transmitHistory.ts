@@ -8,7 +8,8 @@ export interface TransmitHistoryRow {
   id: number;
   session_id: string;
   timestamp_us: number;
-  kind: "can" | "serial";
+  /** "raw" = device command written with transmitRaw */
+  kind: "can" | "serial" | "raw";
   frame_id: number | null;
   dlc: number | null;
   bytes: number[];
@@ -68,6 +68,65 @@ interface DeviceConnectedPayload {
   bus_number: number | null;
 }
 
+/** Payload for frames-dropped event */
+interface FramesDroppedPayload {
+  dropped: number;
+  total_dropped: number;
+}
+
+/** Payload for parse-errors event */
+interface ParseErrorsPayload {
+  count: number;
+  samples: string[];
+}
+
+/** Payload for framer-overflow event */
+interface FramerOverflowPayload {
+  discarded: number;
+  total_discarded: number;
+}
+
+/** Payload for bus-off event */
+interface BusOffPayload {
+  interface: string;
+  restarted: boolean;
+  message: string;
+}
+
+/** Payload for can-error event */
+interface CanErrorPayload {
+  bus: number;
+  timestamp_us: number;
+  kinds: string[];
+  tx_error_count: number | null;
+  rx_error_count: number | null;
+}
+
+/** Payload for device-config event */
+interface DeviceConfigPayload {
+  device_type: string;
+  buses: { bus: number; enabled: boolean; listen_only: boolean; bitrate: number }[];
+}
+
+/** Payload for source-stale event */
+interface SourceStalePayload {
+  source_index: number;
+  display_name: string;
+  buses: number[];
+  idle_ms: number;
+  stale: boolean;
+}
+
+/** Payload for bus-safety-warning event */
+interface BusSafetyWarningPayload {
+  source_index: number;
+  display_name: string;
+  profile_kind: string;
+  bitrate: number | null;
+  obd_ids: number[];
+  message: string;
+}
+
 /** Payload for device-probe event (global, not session-scoped) */
 interface DeviceProbePayload {
   profile_id: string;
@@ -297,6 +356,9 @@ export function useSessionLogSubscription(): void {
             `Listeners: ${session.listenerCount}`,
             `Frames: ${session.bufferFrameCount ?? 0}`,
           ];
+          if (session.backpressure) {
+            parts.push("Backpressure: buffering without emit");
+          }
           if (session.capabilities?.supports_speed_control) {
             parts.push(`Speed: 1x`); // TODO: add speed to ActiveSessionInfo if needed
           }
@@ -438,6 +500,87 @@ async function setupPerSessionListeners(
       })
     );
 
+    unlistenFns.push(
+      await listen<FramesDroppedPayload>(`frames-dropped:${sessionId}`, async (e) => {
+        const { profileId, profileName } = await getProfileInfo();
+        addEntry({ eventType: "frames-dropped", sessionId, profileId, profileName, appName: null, details: `${e.payload.dropped} frames dropped (${e.payload.total_dropped} total)` });
+      })
+    );
+
+    unlistenFns.push(
+      await listen<ParseErrorsPayload>(`parse-errors:${sessionId}`, async (e) => {
+        const { profileId, profileName } = await getProfileInfo();
+        const sample = e.payload.samples.length > 0 ? `: ${e.payload.samples[0]}` : "";
+        addEntry({ eventType: "parse-errors", sessionId, profileId, profileName, appName: null, details: `${e.payload.count} malformed lines skipped${sample}` });
+      })
+    );
+
+    unlistenFns.push(
+      await listen<FramerOverflowPayload>(`framer-overflow:${sessionId}`, async (e) => {
+        const { profileId, profileName } = await getProfileInfo();
+        addEntry({ eventType: "framer-overflow", sessionId, profileId, profileName, appName: null, details: `${e.payload.discarded} bytes discarded without a frame delimiter (${e.payload.total_discarded} total)` });
+      })
+    );
+
+    unlistenFns.push(
+      await listen<BusOffPayload>(`bus-off:${sessionId}`, async (e) => {
+        const { profileId, profileName } = await getProfileInfo();
+        addEntry({ eventType: "bus-off", sessionId, profileId, profileName, appName: null, details: e.payload.message });
+      })
+    );
+
+    unlistenFns.push(
+      await listen<CanErrorPayload>(`can-error:${sessionId}`, async (e) => {
+        const { profileId, profileName } = await getProfileInfo();
+        const { bus, kinds, tx_error_count, rx_error_count } = e.payload;
+        const counters =
+          tx_error_count !== null && rx_error_count !== null ? ` (TEC ${tx_error_count}, REC ${rx_error_count})` : "";
+        addEntry({ eventType: "can-error", sessionId, profileId, profileName, appName: null, details: `Bus ${bus}: ${kinds.join(", ")}${counters}` });
+      })
+    );
+
+    unlistenFns.push(
+      await listen<DeviceConfigPayload>(`device-config:${sessionId}`, async (e) => {
+        const { profileId, profileName } = await getProfileInfo();
+        const buses = e.payload.buses
+          .map((b) =>
+            b.enabled
+              ? `Bus ${b.bus}: ${b.bitrate / 1000} kbit/s${b.listen_only ? " (listen-only)" : ""}`
+              : `Bus ${b.bus}: disabled`
+          )
+          .join(", ");
+        addEntry({ eventType: "device-config", sessionId, profileId, profileName, appName: null, details: buses });
+      })
+    );
+
+    unlistenFns.push(
+      await listen<SourceStalePayload>(`source-stale:${sessionId}`, async (e) => {
+        const { profileId, profileName } = await getProfileInfo();
+        const { display_name, buses, idle_ms, stale } = e.payload;
+        const busLabel = buses.length > 0 ? `bus ${buses.join(", ")}` : display_name;
+        const details = stale
+          ? `No traffic on ${busLabel} for ${(idle_ms / 1000).toFixed(1)}s — check bitrate/wiring`
+          : `Traffic resumed on ${busLabel}`;
+        addEntry({ eventType: "source-stale", sessionId, profileId, profileName, appName: null, details });
+      })
+    );
+
+    // Track non-silent devices ACKing what looks like a vehicle bus
+    unlistenFns.push(
+      await listen<BusSafetyWarningPayload>(`bus-safety-warning:${sessionId}`, async (e) => {
+        const { profileId, profileName } = await getProfileInfo();
+        const ids = e.payload.obd_ids.map((id) => `0x${id.toString(16).toUpperCase()}`).join(", ");
+        addEntry({
+          eventType: "bus-safety-warning",
+          sessionId,
+          profileId,
+          profileName,
+          appName: null,
+          details: `${e.payload.message} (IDs: ${ids})`,
+        });
+      })
+    );
+
     // Track state changes (play/stop/pause)
     unlistenFns.push(
       await listen<StateChangePayload>(`session-state:${sessionId}`, async (e) => {
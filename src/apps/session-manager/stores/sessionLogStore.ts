@@ -35,7 +35,15 @@ export type SessionLogEventType =
   | "buffer-orphaned"
   | "buffer-created"
   | "device-connected"
-  | "device-probe";
+  | "device-probe"
+  | "frames-dropped"
+  | "parse-errors"
+  | "framer-overflow"
+  | "bus-off"
+  | "can-error"
+  | "device-config"
+  | "source-stale"
+  | "bus-safety-warning";
 
 /** A single log entry */
 export interface LogEntry {
@@ -223,6 +231,14 @@ export const EVENT_TYPE_LABELS: Record<SessionLogEventType, string> = {
   "buffer-created": "Buffer",
   "device-connected": "Connected",
   "device-probe": "Probe",
+  "frames-dropped": "Dropped",
+  "parse-errors": "Parse",
+  "framer-overflow": "Overflow",
+  "bus-off": "Bus-off",
+  "can-error": "CAN error",
+  "device-config": "Config",
+  "source-stale": "Stale",
+  "bus-safety-warning": "Bus safety",
 };
 
 /** Badge classes for event types (using standard badge styles) */
@@ -243,6 +259,14 @@ export const EVENT_TYPE_COLOURS: Record<SessionLogEventType, string> = {
   "buffer-created": badgeSmallSuccess,
   "device-connected": badgeSmallSuccess,
   "device-probe": badgeSmallInfo,
+  "frames-dropped": badgeSmallWarning,
+  "parse-errors": badgeSmallWarning,
+  "framer-overflow": badgeSmallWarning,
+  "bus-off": badgeSmallDanger,
+  "can-error": badgeSmallWarning,
+  "device-config": badgeSmallInfo,
+  "source-stale": badgeSmallWarning,
+  "bus-safety-warning": badgeSmallDanger,
 };
 
 /** All event types for filter dropdown */
@@ -263,4 +287,12 @@ export const ALL_EVENT_TYPES: SessionLogEventType[] = [
   "buffer-created",
   "device-connected",
   "device-probe",
+  "frames-dropped",
+  "parse-errors",
+  "framer-overflow",
+  "bus-off",
+  "can-error",
+  "device-config",
+  "source-stale",
+  "bus-safety-warning",
 ];
@@ -187,6 +187,14 @@ export default function SessionLogView() {
           "buffer-created",
           "device-connected",
           "device-probe",
+          "frames-dropped",
+          "parse-errors",
+          "framer-overflow",
+          "bus-off",
+          "can-error",
+          "device-config",
+          "source-stale",
+          "bus-safety-warning",
         ] as SessionLogEventType[],
       },
     ],
@@ -17,7 +17,7 @@ export interface DeviceProbeResult {
   primaryInfo?: string | null;
   /** Secondary info to display (e.g., hardware version) */
   secondaryInfo?: string | null;
-  /** Whether device supports CAN FD (gs_usb only) */
+  /** Whether device supports CAN FD (null if the probe could not tell) */
   supports_fd?: boolean | null;
   /** Error message (if probe failed) */
   error?: string | null;
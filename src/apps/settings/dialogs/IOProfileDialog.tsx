@@ -13,7 +13,7 @@ import LinuxCanSetupHelper from "../components/LinuxCanSetupHelper";
 import SecurePasswordField from "../components/SecurePasswordField";
 import IODeviceStatus, { type DeviceProbeState, type DeviceProbeResult } from "../components/IODeviceStatus";
 import { DeviceBusConfig, type BusMappingWithProtocol } from "../../../dialogs/io-source-picker";
-import { Input, Select, FormField, PrimaryButton, SecondaryButton } from "../../../components/forms";
+import { Input, Select, Textarea, FormField, PrimaryButton, SecondaryButton } from "../../../components/forms";
 import BaudRateSelect from "../../../components/forms/BaudRateSelect";
 import {
   h2,
@@ -27,7 +27,7 @@ import {
 } from "../../../styles";
 import { probeSlcanDevice } from "../../../api/serial";
 import { probeGsUsbDevice } from "../../../api/gs_usb";
-import { probeDevice, type GvretDeviceInfo } from "../../../api/io";
+import { probeDevice, getSupportedBitrates, type BitrateSupport, type GvretDeviceInfo } from "../../../api/io";
 import { getPlatform, isWindows, isLinux, isMacOS } from "../../../utils/platform";
 import { getAvailableProfileKinds, type Platform, type ProfileKind } from "../../../utils/profileTraits";
 import type { GvretInterfaceConfig } from "../../../hooks/useSettings";
@@ -86,6 +86,25 @@ export default function IOProfileDialog({
   // slcan advanced options collapsed state
   const [slcanAdvancedOpen, setSlcanAdvancedOpen] = useState(false);
 
+  // Bitrates the selected adapter kind supports, from the backend's driver tables
+  const [bitrateSupport, setBitrateSupport] = useState<BitrateSupport | null>(null);
+
+  useEffect(() => {
+    if (!isOpen) return;
+    let cancelled = false;
+    setBitrateSupport(null);
+    getSupportedBitrates(profileForm.kind)
+      .then((support) => {
+        if (!cancelled) setBitrateSupport(support);
+      })
+      .catch(() => {
+        // Kinds without a CAN bitrate have no options to offer
+      });
+    return () => {
+      cancelled = true;
+    };
+  }, [isOpen, profileForm.kind]);
+
   // GVRET device probe state
   const [gvretProbeState, setGvretProbeState] = useState<DeviceProbeState>("idle");
   const [gvretDeviceInfo, setGvretDeviceInfo] = useState<GvretDeviceInfo | null>(null);
@@ -212,6 +231,7 @@ export default function IOProfileDialog({
         success: result.success,
         primaryInfo: result.version,
         secondaryInfo: result.hardware_version,
+        supports_fd: result.supports_fd,
         error: result.error,
       });
       setSlcanProbeState(result.success ? "success" : "error");
@@ -303,6 +323,7 @@ export default function IOProfileDialog({
         success: result.success,
         primaryInfo: result.channel_count ? `${result.channel_count} channel(s)` : undefined,
         secondaryInfo: result.supports_fd ? "CAN FD supported" : undefined,
+        supports_fd: result.supports_fd,
         error: result.error || undefined,
       });
       setGsUsbProbeState(result.success ? "success" : "error");
@@ -336,6 +357,216 @@ export default function IOProfileDialog({
     }
   }, [isOpen, profileForm.kind]);
 
+  // Shared by adapters that can stamp frames with their own receive clock
+  const timestampSourceField = (
+    <FormField label="Timestamp Source" variant="default">
+      <Select
+        variant="default"
+        value={profileForm.connection.timestamp_source || "host"}
+        onChange={(e) => onUpdateConnectionField("timestamp_source", e.target.value)}
+      >
+        <option value="host">Host (wall clock on arrival)</option>
+        <option value="device">Device (precise relative timing)</option>
+        <option value="device_aligned_to_host">Device, aligned to host</option>
+      </Select>
+    </FormField>
+  );
+
+  // Shared by serial-port adapters: port ownership and DTR/RTS lines on open
+  const lineLevelValue = (v: unknown) => (v === true ? "high" : v === false ? "low" : v || "");
+  const serialLineControlFields = (
+    <>
+      <div className="grid grid-cols-3 gap-4">
+        <FormField label="DTR on Open" variant="default">
+          <Select
+            variant="default"
+            value={lineLevelValue(profileForm.connection.set_dtr)}
+            onChange={(e) => onUpdateConnectionField("set_dtr", e.target.value)}
+          >
+            <option value="">Driver default</option>
+            <option value="high">High</option>
+            <option value="low">Low</option>
+          </Select>
+        </FormField>
+        <FormField label="RTS on Open" variant="default">
+          <Select
+            variant="default"
+            value={lineLevelValue(profileForm.connection.set_rts)}
+            onChange={(e) => onUpdateConnectionField("set_rts", e.target.value)}
+          >
+            <option value="">Driver default</option>
+            <option value="high">High</option>
+            <option value="low">Low</option>
+          </Select>
+        </FormField>
+        <FormField label="DTR Pulse (ms)" variant="default">
+          <Input
+            variant="default"
+            type="number"
+            min={0}
+            value={profileForm.connection.dtr_pulse_ms || ""}
+            onChange={(e) => onUpdateConnectionField("dtr_pulse_ms", e.target.value)}
+            placeholder="0 (off)"
+          />
+        </FormField>
+      </div>
+      <div className={flexRowGap2}>
+        <input
+          type="checkbox"
+          id="serial-exclusive"
+          checked={profileForm.connection.exclusive !== false}
+          onChange={(e) => onUpdateConnectionField("exclusive", e.target.checked)}
+          className="w-4 h-4 text-blue-600 bg-[var(--bg-primary)] border-[color:var(--border-default)] rounded focus:ring-blue-500"
+        />
+        <label htmlFor="serial-exclusive" className={textMedium}>
+          Open port exclusively
+        </label>
+      </div>
+      <p className={`${caption} -mt-2`}>
+        Some boards (e.g. ESP32) need a DTR/RTS sequence to reset or start. Non-exclusive opening is
+        not available on Windows.
+      </p>
+    </>
+  );
+
+  // Shared by CAN adapters: bitrate options from the backend, keeping the saved value
+  // listed even when it's not one of them so it isn't silently replaced
+  const bitrateOptions = (rates: number[] | undefined, current: string | undefined) => {
+    const list = [...(rates ?? [])];
+    const saved = current ? parseInt(current, 10) : NaN;
+    const unsupported = !isNaN(saved) && !list.includes(saved);
+    if (unsupported) list.push(saved);
+    return list
+      .sort((a, b) => a - b)
+      .map((rate) => (
+        <option key={rate} value={String(rate)}>
+          {formatBitrate(rate)}
+          {rate === saved && unsupported && !bitrateSupport?.arbitrary ? " (unsupported)" : ""}
+        </option>
+      ));
+  };
+
+  // Shared by bus adapters: warn when a connected source delivers no frames
+  const staleAfterField = (
+    <FormField label="No-Traffic Warning (ms)" variant="default">
+      <Input
+        variant="default"
+        type="number"
+        min={0}
+        value={profileForm.connection.stale_after_ms || ""}
+        onChange={(e) => onUpdateConnectionField("stale_after_ms", e.target.value)}
+        placeholder="0 (disabled)"
+      />
+      <p className={caption}>
+        Warns in the session log when no frames arrive for this long, e.g. a wrong bitrate or
+        unconnected bus.
+      </p>
+    </FormField>
+  );
+
+  // Shared by bus adapters: hold frames briefly so a late source is merged in order
+  const reorderWindowField = (
+    <FormField label="Reorder Window (ms)" variant="default">
+      <Input
+        variant="default"
+        type="number"
+        min={0}
+        value={profileForm.connection.reorder_window_ms || ""}
+        onChange={(e) => onUpdateConnectionField("reorder_window_ms", e.target.value)}
+        placeholder="0 (disabled)"
+      />
+      <p className={caption}>
+        Delays emission by up to this long so frames from a slower source in a multi-device session
+        are emitted in timestamp order.
+      </p>
+    </FormField>
+  );
+
+  // Shared by live sources: end a hands-off capture once traffic stops
+  const autoStopIdleField = (
+    <FormField label="Auto-Stop After Idle (ms)" variant="default">
+      <Input
+        variant="default"
+        type="number"
+        min={0}
+        value={profileForm.connection.auto_stop_after_idle_ms || ""}
+        onChange={(e) => onUpdateConnectionField("auto_stop_after_idle_ms", e.target.value)}
+        placeholder="0 (disabled)"
+      />
+      <p className={caption}>
+        Stops the capture once frames have been seen and then none arrive for this long, e.g. at
+        the end of an automated test sequence.
+      </p>
+    </FormField>
+  );
+
+  // Shared by GVRET TCP/USB: keep the device byte stream next to the decoded frames
+  const captureRawBytesField = (
+    <>
+      <div className={flexRowGap2}>
+        <input
+          type="checkbox"
+          id="gvret-capture-raw-bytes"
+          checked={profileForm.connection.capture_raw_bytes === true}
+          onChange={(e) => onUpdateConnectionField("capture_raw_bytes", e.target.checked)}
+          className="w-4 h-4 text-blue-600 bg-[var(--bg-primary)] border-[color:var(--border-default)] rounded focus:ring-blue-500"
+        />
+        <label htmlFor="gvret-capture-raw-bytes" className={textMedium}>
+          Also capture raw bytes
+        </label>
+      </div>
+      <p className={`${caption} -mt-2`}>
+        Keeps every byte read from the device in a Bytes buffer alongside the frames, for debugging
+        protocol-level issues.
+      </p>
+    </>
+  );
+
+  // Shared by slcan and GVRET TCP/USB: attach to an already-running device without setup writes
+  const passiveAttachField = (
+    <>
+      <div className={flexRowGap2}>
+        <input
+          type="checkbox"
+          id="passive-attach"
+          checked={profileForm.connection.passive_attach === true}
+          onChange={(e) => onUpdateConnectionField("passive_attach", e.target.checked)}
+          className="w-4 h-4 text-blue-600 bg-[var(--bg-primary)] border-[color:var(--border-default)] rounded focus:ring-blue-500"
+        />
+        <label htmlFor="passive-attach" className={textMedium}>
+          Passive attach (no setup commands)
+        </label>
+      </div>
+      <p className={`${caption} -mt-2`}>
+        Only reads from the device: no bitrate, mode, probe or keepalive commands are sent, so it
+        must already be configured and streaming. No wakeup sequence is sent.
+      </p>
+    </>
+  );
+
+  // Shared by transmitting adapters: frames or bytes sent as the capture starts
+  const wakeup = profileForm.connection.wakeup;
+  const wakeupField = (
+    <FormField label="Wakeup Sequence" variant="default">
+      <Textarea
+        variant="default"
+        rows={3}
+        className="font-mono text-xs"
+        value={typeof wakeup === "string" ? wakeup : wakeup ? JSON.stringify(wakeup) : ""}
+        onChange={(e) => onUpdateConnectionField("wakeup", e.target.value)}
+        placeholder={
+          profileForm.kind === "serial"
+            ? '[{"bytes": [85, 85], "delay_ms": 0}]'
+            : '[{"frame_id": 1536, "data": [1, 2], "delay_ms": 50}]'
+        }
+      />
+      <p className={caption}>
+        JSON list sent once the device opens, to wake a sleeping bus. Each step waits
+        <code> delay_ms</code> first. Ignored in listen-only and silent modes.
+      </p>
+    </FormField>
+  );
+
   return (
     <Dialog isOpen={isOpen} maxWidth="max-w-2xl">
       <div className="max-h-[90vh] overflow-y-auto">
@@ -539,6 +770,8 @@ export default function IOProfileDialog({
                   </FormField>
                 </div>
               </div>
+
+              {autoStopIdleField}
             </div>
           )}
 
@@ -576,6 +809,8 @@ export default function IOProfileDialog({
                   placeholder="1"
                 />
               </FormField>
+
+              {autoStopIdleField}
             </div>
           )}
 
@@ -769,6 +1004,8 @@ export default function IOProfileDialog({
                   </label>
                 </div>
               ))}
+
+              {autoStopIdleField}
             </div>
           )}
 
@@ -807,6 +1044,26 @@ export default function IOProfileDialog({
                 />
               </FormField>
 
+              <FormField label="Keepalive Interval (seconds, 0 = off)" variant="default">
+                <Input
+                  variant="default"
+                  type="number"
+                  min={0}
+                  value={profileForm.connection.keepalive_interval_sec || ""}
+                  onChange={(e) => onUpdateConnectionField("keepalive_interval_sec", e.target.value)}
+                  placeholder="0"
+                />
+              </FormField>
+
+              {timestampSourceField}
+
+              {staleAfterField}
+              {reorderWindowField}
+              {autoStopIdleField}
+              {wakeupField}
+              {captureRawBytesField}
+              {passiveAttachField}
+
               <div className={flexRowGap2}>
                 <input
                   type="checkbox"
@@ -825,6 +1082,24 @@ export default function IOProfileDialog({
                 </label>
               </div>
 
+              <div className={flexRowGap2}>
+                <input
+                  type="checkbox"
+                  id="tcp-nodelay"
+                  checked={profileForm.connection.tcp_nodelay !== false}
+                  onChange={(e) =>
+                    onUpdateConnectionField("tcp_nodelay", e.target.checked)
+                  }
+                  className="w-4 h-4 text-blue-600 bg-[var(--bg-primary)] border-[color:var(--border-default)] rounded focus:ring-blue-500"
+                />
+                <label
+                  htmlFor="tcp-nodelay"
+                  className={textMedium}
+                >
+                  TCP No Delay (send small writes immediately)
+                </label>
+              </div>
+
               {/* Interface Configuration */}
               <div className={`border-t ${borderDefault} pt-4 mt-4`}>
                 <div className="flex items-center justify-between mb-3">
@@ -905,6 +1180,28 @@ export default function IOProfileDialog({
                 />
               </FormField>
 
+              {serialLineControlFields}
+
+              <FormField label="Keepalive Interval (seconds, 0 = off)" variant="default">
+                <Input
+                  variant="default"
+                  type="number"
+                  min={0}
+                  value={profileForm.connection.keepalive_interval_sec || ""}
+                  onChange={(e) => onUpdateConnectionField("keepalive_interval_sec", e.target.value)}
+                  placeholder="0"
+                />
+              </FormField>
+
+              {timestampSourceField}
+
+              {staleAfterField}
+              {reorderWindowField}
+              {autoStopIdleField}
+              {wakeupField}
+              {captureRawBytesField}
+              {passiveAttachField}
+
               <div className={alertInfo}>
                 <p className="text-sm text-[color:var(--text-info)]">
                   Works with ESP32-RET, M2RET, CANDue, and other GVRET-compatible hardware over USB serial.
@@ -1070,6 +1367,44 @@ export default function IOProfileDialog({
                 </FormField>
               </div>
 
+              {serialLineControlFields}
+
+              {/* Transmit line ending */}
+              <FormField label="Transmit Line Ending" variant="default">
+                <Select
+                  variant="default"
+                  value={profileForm.connection.append_terminator || "none"}
+                  onChange={(e) => onUpdateConnectionField("append_terminator", e.target.value)}
+                >
+                  <option value="none">None</option>
+                  <option value="cr">CR</option>
+                  <option value="lf">LF</option>
+                  <option value="cr_lf">CR+LF</option>
+                </Select>
+              </FormField>
+
+              {/* Echo handling */}
+              <div className={flexRowGap2}>
+                <input
+                  type="checkbox"
+                  id="serial-expect-echo"
+                  checked={profileForm.connection.expect_echo === true}
+                  onChange={(e) => onUpdateConnectionField("expect_echo", e.target.checked)}
+                  className="w-4 h-4 text-blue-600 bg-[var(--bg-primary)] border-[color:var(--border-default)] rounded focus:ring-blue-500"
+                />
+                <label
+                  htmlFor="serial-expect-echo"
+                  className={textMedium}
+                >
+                  Device echoes transmitted bytes
+                </label>
+              </div>
+              <p className={`${caption} -mt-2`}>
+                Discards the device's echo of each transmit so only its replies are captured.
+              </p>
+
+              {wakeupField}
+
               {/* Note: Framing is now handled client-side in Discovery mode */}
             </div>
           )}
@@ -1096,6 +1431,8 @@ export default function IOProfileDialog({
                 />
               </FormField>
 
+              {serialLineControlFields}
+
               {/* Device Status */}
               {profileForm.connection.port && (
                 <IODeviceStatus
@@ -1118,18 +1455,16 @@ export default function IOProfileDialog({
                   value={profileForm.connection.bitrate || "500000"}
                   onChange={(e) => onUpdateConnectionField("bitrate", e.target.value)}
                 >
-                  <option value="10000">10 Kbit/s (S0)</option>
-                  <option value="20000">20 Kbit/s (S1)</option>
-                  <option value="50000">50 Kbit/s (S2)</option>
-                  <option value="100000">100 Kbit/s (S3)</option>
-                  <option value="125000">125 Kbit/s (S4)</option>
-                  <option value="250000">250 Kbit/s (S5)</option>
-                  <option value="500000">500 Kbit/s (S6)</option>
-                  <option value="750000">750 Kbit/s (S7)</option>
-                  <option value="1000000">1 Mbit/s (S8)</option>
+                  {bitrateOptions(bitrateSupport?.fixed, profileForm.connection.bitrate || "500000")}
                 </Select>
               </FormField>
 
+              {staleAfterField}
+              {reorderWindowField}
+              {autoStopIdleField}
+              {wakeupField}
+              {passiveAttachField}
+
               {/* Silent mode */}
               <div className={flexRowGap2}>
                 <input
@@ -1150,6 +1485,26 @@ export default function IOProfileDialog({
                 Does not participate in bus arbitration. Ideal for passive monitoring.
               </p>
 
+              {/* Strict DLC */}
+              <div className={flexRowGap2}>
+                <input
+                  type="checkbox"
+                  id="slcan_strict_dlc"
+                  checked={profileForm.connection.strict_dlc === true}
+                  onChange={(e) => onUpdateConnectionField("strict_dlc", e.target.checked)}
+                  className="w-4 h-4 text-blue-600 bg-[var(--bg-primary)] border-[color:var(--border-default)] rounded focus:ring-blue-500"
+                />
+                <label
+                  htmlFor="slcan_strict_dlc"
+                  className={textMedium}
+                >
+                  Strict DLC
+                </label>
+              </div>
+              <p className={`${caption} -mt-2`}>
+                Reject classic frames with a DLC of 9-F. When off they are read as 8 data bytes.
+              </p>
+
               {/* CAN FD Options (ELMUE firmware extension) */}
               <div className={`border-t ${borderDefault} pt-4 mt-2`}>
                 <div className={flexRowGap2}>
@@ -1157,12 +1512,19 @@ export default function IOProfileDialog({
                     type="checkbox"
                     id="slcan_enable_fd"
                     checked={profileForm.connection.enable_fd === true}
+                    disabled={slcanProbeResult?.supports_fd === false && profileForm.connection.enable_fd !== true}
                     onChange={(e) => onUpdateConnectionField("enable_fd", e.target.checked)}
                     className="w-4 h-4 text-blue-600 bg-[var(--bg-primary)] border-[color:var(--border-default)] rounded focus:ring-blue-500"
                   />
                   <label htmlFor="slcan_enable_fd" className="text-sm text-[color:var(--text-secondary)]">
                     Enable CAN FD
                   </label>
+                  {slcanProbeResult?.supports_fd === false && (
+                    <span className="text-xs text-[color:var(--text-warning)]">(firmware does not support FD)</span>
+                  )}
+                  {slcanProbeResult?.supports_fd === true && (
+                    <span className="text-xs text-[color:var(--text-success)]">(FD capable)</span>
+                  )}
                 </div>
                 <p className="text-xs text-[color:var(--text-muted)] mt-1 ml-6">
                   Enables CAN Flexible Data-rate for higher throughput and larger payloads (up to 64 bytes).
@@ -1177,12 +1539,10 @@ export default function IOProfileDialog({
                         value={profileForm.connection.data_bitrate || "2000000"}
                         onChange={(e) => onUpdateConnectionField("data_bitrate", e.target.value)}
                       >
-                        <option value="500000">500 Kbit/s (Y0)</option>
-                        <option value="1000000">1 Mbit/s (Y1)</option>
-                        <option value="2000000">2 Mbit/s (Y2)</option>
-                        <option value="4000000">4 Mbit/s (Y4)</option>
-                        <option value="5000000">5 Mbit/s (Y5)</option>
-                        <option value="8000000">8 Mbit/s (Y8)</option>
+                        {bitrateOptions(
+                          bitrateSupport?.fd_data_rates,
+                          profileForm.connection.data_bitrate || "2000000"
+                        )}
                       </Select>
                     </FormField>
                   </div>
@@ -1279,18 +1639,45 @@ export default function IOProfileDialog({
                   onChange={(e) => onUpdateConnectionField("bitrate", e.target.value)}
                 >
                   <option value="">Use system configuration</option>
-                  <option value="10000">10 Kbit/s</option>
-                  <option value="20000">20 Kbit/s</option>
-                  <option value="50000">50 Kbit/s</option>
-                  <option value="100000">100 Kbit/s</option>
-                  <option value="125000">125 Kbit/s</option>
-                  <option value="250000">250 Kbit/s</option>
-                  <option value="500000">500 Kbit/s</option>
-                  <option value="750000">750 Kbit/s</option>
-                  <option value="1000000">1 Mbit/s</option>
+                  {bitrateOptions(bitrateSupport?.fixed, profileForm.connection.bitrate)}
                 </Select>
               </FormField>
 
+              <FormField label="Receive Buffer (bytes, optional)" variant="default">
+                <Input
+                  variant="default"
+                  type="number"
+                  min={0}
+                  value={profileForm.connection.rcvbuf_bytes || ""}
+                  onChange={(e) => onUpdateConnectionField("rcvbuf_bytes", e.target.value)}
+                  placeholder="Kernel default"
+                />
+              </FormField>
+
+              {timestampSourceField}
+
+              {staleAfterField}
+              {reorderWindowField}
+              {autoStopIdleField}
+              {wakeupField}
+
+              <div className={flexRowGap2}>
+                <input
+                  type="checkbox"
+                  id="socketcan_restart_on_busoff"
+                  checked={profileForm.connection.restart_on_busoff === true}
+                  onChange={(e) => onUpdateConnectionField("restart_on_busoff", e.target.checked)}
+                  className="w-4 h-4 text-blue-600 bg-[var(--bg-primary)] border-[color:var(--border-default)] rounded focus:ring-blue-500"
+                />
+                <label htmlFor="socketcan_restart_on_busoff" className="text-sm text-[color:var(--text-secondary)]">
+                  Restart on bus-off
+                </label>
+              </div>
+              <p className={caption}>
+                Restarts the interface when the controller goes bus-off and restart-ms is not set.
+                Requires CAP_NET_ADMIN; otherwise the session log explains how to recover.
+              </p>
+
               {/* CAN FD Options - only show if bitrate is set (interface will be configured) */}
               {profileForm.connection.bitrate && (
                 <div className={`border-t ${borderDefault} pt-4 mt-2`}>
@@ -1318,11 +1705,10 @@ export default function IOProfileDialog({
                           value={profileForm.connection.data_bitrate || "2000000"}
                           onChange={(e) => onUpdateConnectionField("data_bitrate", e.target.value)}
                         >
-                          <option value="1000000">1 Mbit/s</option>
-                          <option value="2000000">2 Mbit/s</option>
-                          <option value="4000000">4 Mbit/s</option>
-                          <option value="5000000">5 Mbit/s</option>
-                          <option value="8000000">8 Mbit/s</option>
+                          {bitrateOptions(
+                            bitrateSupport?.fd_data_rates,
+                            profileForm.connection.data_bitrate || "2000000"
+                          )}
                         </Select>
                       </FormField>
                     </div>
@@ -1377,15 +1763,7 @@ export default function IOProfileDialog({
                   value={profileForm.connection.bitrate || "500000"}
                   onChange={(e) => onUpdateConnectionField("bitrate", e.target.value)}
                 >
-                  <option value="10000">10 Kbit/s</option>
-                  <option value="20000">20 Kbit/s</option>
-                  <option value="50000">50 Kbit/s</option>
-                  <option value="100000">100 Kbit/s</option>
-                  <option value="125000">125 Kbit/s</option>
-                  <option value="250000">250 Kbit/s</option>
-                  <option value="500000">500 Kbit/s</option>
-                  <option value="750000">750 Kbit/s</option>
-                  <option value="1000000">1 Mbit/s</option>
+                  {bitrateOptions(bitrateSupport?.fixed, profileForm.connection.bitrate || "500000")}
                 </Select>
               </FormField>
 
@@ -1402,6 +1780,13 @@ export default function IOProfileDialog({
                 </Select>
               </FormField>
 
+              {timestampSourceField}
+
+              {staleAfterField}
+              {reorderWindowField}
+              {autoStopIdleField}
+              {wakeupField}
+
               {/* Listen-only mode */}
               <div className={flexRowGap2}>
                 <input
@@ -1423,6 +1808,7 @@ export default function IOProfileDialog({
                     type="checkbox"
                     id="gs_usb_enable_fd"
                     checked={profileForm.connection.enable_fd === true}
+                    disabled={gsUsbProbeResult?.supports_fd === false && profileForm.connection.enable_fd !== true}
                     onChange={(e) => onUpdateConnectionField("enable_fd", e.target.checked)}
                     className="w-4 h-4 text-blue-600 bg-[var(--bg-primary)] border-[color:var(--border-default)] rounded focus:ring-blue-500"
                   />
@@ -1448,11 +1834,10 @@ export default function IOProfileDialog({
                         value={profileForm.connection.data_bitrate || "2000000"}
                         onChange={(e) => onUpdateConnectionField("data_bitrate", e.target.value)}
                       >
-                        <option value="1000000">1 Mbit/s</option>
-                        <option value="2000000">2 Mbit/s</option>
-                        <option value="4000000">4 Mbit/s</option>
-                        <option value="5000000">5 Mbit/s</option>
-                        <option value="8000000">8 Mbit/s</option>
+                        {bitrateOptions(
+                          bitrateSupport?.fd_data_rates,
+                          profileForm.connection.data_bitrate || "2000000"
+                        )}
                       </Select>
                     </FormField>
 
@@ -1517,3 +1902,10 @@ export default function IOProfileDialog({
     </Dialog>
   );
 }
+
+/** Label a bitrate in the units the adapter docs use, e.g. "500 Kbit/s" or "1 Mbit/s" */
+function formatBitrate(rate: number): string {
+  return rate >= 1_000_000 && rate % 1_000_000 === 0
+    ? `${rate / 1_000_000} Mbit/s`
+    : `${rate / 1000} Kbit/s`;
+}
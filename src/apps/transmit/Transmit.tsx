@@ -14,6 +14,7 @@ import { useMenuSessionControl } from "../../hooks/useMenuSessionControl";
 import { useSettings, type IOProfile } from "../../hooks/useSettings";
 import { useTransmitHandlers } from "./hooks/useTransmitHandlers";
 import { useTransmitHistorySubscription } from "./hooks/useTransmitHistorySubscription";
+import { useTransmitArmed } from "./hooks/useTransmitArmed";
 import {
   bgDataToolbar,
   borderDataView,
@@ -221,6 +222,16 @@ export default function Transmit() {
   // Subscribe to transmit history events from repeat transmissions
   useTransmitHistorySubscription();
 
+  // Transmit arm state for the connected session
+  const { armed: transmitArmed, setArmed: setTransmitArmed } = useTransmitArmed(
+    isConnected ? effectiveSessionId : null
+  );
+  const handleToggleArmed = useCallback(() => {
+    setTransmitArmed(!transmitArmed).catch((e) =>
+      console.error("[Transmit] Failed to change transmit arm state:", e)
+    );
+  }, [transmitArmed, setTransmitArmed]);
+
   // Set active session for child components (CanTransmitView, etc.)
   useEffect(() => {
     const store = useSessionStore.getState();
@@ -280,6 +291,8 @@ export default function Transmit() {
           isStopped={isStopped || canReturnToLive}
           ioState={session.state}
           capabilities={capabilities}
+          transmitArmed={transmitArmed}
+          onToggleArmed={handleToggleArmed}
           onOpenIoPicker={handlers.handleOpenIoPicker}
           onStop={handlers.handleStop}
           onResume={handlers.handleResume}
@@ -0,0 +1,55 @@
+// src/apps/transmit/hooks/useTransmitArmed.ts
+//
+// Tracks and toggles the per-session transmit arm state. Sessions start
+// disarmed so a monitor-only capture can't write to the bus by accident.
+
+import { useCallback, useEffect, useState } from "react";
+import { listen } from "@tauri-apps/api/event";
+import { armTransmit, listActiveSessions } from "../../../api/io";
+
+/**
+ * Returns whether transmit is armed on the session and a setter that arms or
+ * disarms it in the backend. Follows `transmit-armed` events so the state stays
+ * in sync when another app sharing the session changes it.
+ */
+export function useTransmitArmed(sessionId: string | null | undefined): {
+  armed: boolean;
+  setArmed: (armed: boolean) => Promise<void>;
+} {
+  const [armed, setArmedState] = useState(false);
+
+  useEffect(() => {
+    setArmedState(false);
+    if (!sessionId) return;
+
+    let cancelled = false;
+    listActiveSessions()
+      .then((sessions) => {
+        const session = sessions.find((s) => s.sessionId === sessionId);
+        if (!cancelled && session) setArmedState(session.transmitArmed);
+      })
+      .catch(() => {
+        // Leave disarmed; the next transmit-armed event corrects it
+      });
+
+    const unlisten = listen<boolean>(`transmit-armed:${sessionId}`, (e) => {
+      setArmedState(e.payload);
+    });
+
+    return () => {
+      cancelled = true;
+      unlisten.then((fn) => fn());
+    };
+  }, [sessionId]);
+
+  const setArmed = useCallback(
+    async (next: boolean) => {
+      if (!sessionId) return;
+      await armTransmit(sessionId, next);
+      setArmedState(next);
+    },
+    [sessionId]
+  );
+
+  return { armed, setArmed };
+}
@@ -3,7 +3,7 @@
 // Top toolbar for the Transmit app with IO picker button and session controls.
 // Uses shared AppTopBar component for consistent layout.
 
-import { Send, GitMerge } from "lucide-react";
+import { Send, GitMerge, ShieldAlert, ShieldCheck } from "lucide-react";
 import { flexRowGap2 } from "../../../styles/spacing";
 import type { IOProfile } from "../../../types/common";
 import AppTopBar from "../../../components/AppTopBar";
@@ -43,6 +43,10 @@ interface Props {
   supportsTimeRange?: boolean;
   onOpenBookmarkPicker?: () => void;
 
+  // Transmit arm state (sessions start disarmed)
+  transmitArmed?: boolean;
+  onToggleArmed?: () => void;
+
   // Frame counts (for tooltip)
   uniqueFrameCount?: number;
   totalFrameCount?: number;
@@ -73,6 +77,8 @@ export default function TransmitTopBar({
   supportsTimeRange = false,
   onOpenBookmarkPicker,
   capabilities,
+  transmitArmed = false,
+  onToggleArmed,
   uniqueFrameCount,
   totalFrameCount,
   onOpenIoPicker,
@@ -112,6 +118,21 @@ export default function TransmitTopBar({
       }}
       actions={
         <>
+          {/* Transmit arm toggle - transmits are rejected until armed */}
+          {sessionId && onToggleArmed && (
+            <button
+              type="button"
+              onClick={onToggleArmed}
+              title={transmitArmed ? "Transmit armed - click to disarm" : "Transmit disarmed - click to arm before sending"}
+              className={`text-xs px-2 py-0.5 rounded flex items-center gap-1 ${
+                transmitArmed ? "bg-red-600/30 text-red-400" : "bg-[var(--bg-surface)] text-[color:var(--text-secondary)]"
+              }`}
+            >
+              {transmitArmed ? <ShieldAlert size={12} /> : <ShieldCheck size={12} />}
+              {transmitArmed ? "Armed" : "Disarmed"}
+            </button>
+          )}
+
           {/* Loading indicator */}
           {isLoading && (
             <span className={`text-xs ${textDataSecondary}`}>Loading...</span>
@@ -129,7 +129,8 @@ export default function ReplayDialog({ isOpen, onClose, bufferId }: Props) {
         is_extended: f.is_extended ?? false,
         is_fd: f.is_fd ?? false,
         is_brs: false,
-        is_rtr: false,
+        is_rtr: f.is_rtr ?? false,
+        rtr_dlc: f.is_rtr ? f.dlc : undefined,
       },
     }));
   }, [isOpen, startIdx, endIdx, rangeError, bufferMode.enabled]); // eslint-disable-line react-hooks/exhaustive-deps
@@ -183,7 +184,8 @@ export default function ReplayDialog({ isOpen, onClose, bufferId }: Props) {
             is_extended: f.is_extended ?? false,
             is_fd: f.is_fd ?? false,
             is_brs: false,
-            is_rtr: false,
+            is_rtr: f.is_rtr ?? false,
+            rtr_dlc: f.is_rtr ? f.dlc : undefined,
           },
         }));
       } else {
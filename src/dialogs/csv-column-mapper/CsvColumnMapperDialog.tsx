@@ -42,6 +42,21 @@ function formatImportSummary(result: CsvImportResult, fileCount: number, hasSequ
   const filePart = fileCount > 1 ? ` from ${fileCount} files` : "";
   lines.push(`Frames imported: ${result.metadata.count.toLocaleString()}${filePart}`);
 
+  if (result.parse_errors.count > 0) {
+    lines.push(`Malformed rows skipped: ${result.parse_errors.count.toLocaleString()}`);
+    for (const sample of result.parse_errors.samples) {
+      lines.push(`  ${sample}`);
+    }
+  }
+
+  if (result.out_of_order_frames > 0) {
+    lines.push(`Frames out of timestamp order: ${result.out_of_order_frames.toLocaleString()}`);
+  }
+
+  if (result.invalid_length_frames > 0) {
+    lines.push(`Frames with impossible lengths: ${result.invalid_length_frames.toLocaleString()}`);
+  }
+
   if (hasSequence) {
     lines.push(`Sequence gaps: ${result.sequence_gaps.length}`);
     if (result.sequence_gaps.length > 0) {
@@ -98,6 +113,7 @@ export default function CsvColumnMapperDialog({
   const [error, setError] = useState<string | null>(null);
   const [timestampUnit, setTimestampUnit] = useState<TimestampUnit>("microseconds");
   const [negateTimestamps, setNegateTimestamps] = useState(false);
+  const [sortByTimestamp, setSortByTimestamp] = useState(false);
   const [showImportedTs, setShowImportedTs] = useState(false);
   const [importProgress, setImportProgress] = useState<string | null>(null);
   const [importSummary, setImportSummary] = useState<CsvImportResult | null>(null);
@@ -259,12 +275,28 @@ export default function CsvColumnMapperDialog({
 
         // Build per-file header flags: use provided detection or fall back to current hasHeader for all
         const perFileHeaders = hasHeaderPerFile ?? allFilePaths.map(() => hasHeader);
-        result = await importCsvBatchWithMapping(allFilePaths, mappings, perFileHeaders, timestampUnit, negateTimestamps, delimiter);
+        result = await importCsvBatchWithMapping(
+          allFilePaths,
+          mappings,
+          perFileHeaders,
+          timestampUnit,
+          negateTimestamps,
+          delimiter,
+          sortByTimestamp
+        );
 
         unlistenRef.current?.();
         unlistenRef.current = null;
       } else {
-        result = await importCsvWithMapping(filePath, mappings, hasHeader, timestampUnit, negateTimestamps, delimiter);
+        result = await importCsvWithMapping(
+          filePath,
+          mappings,
+          hasHeader,
+          timestampUnit,
+          negateTimestamps,
+          delimiter,
+          sortByTimestamp
+        );
       }
 
       setImportSummary(result);
@@ -276,7 +308,7 @@ export default function CsvColumnMapperDialog({
       unlistenRef.current?.();
       unlistenRef.current = null;
     }
-  }, [filePath, allFilePaths, isMultiFile, mappings, hasHeader, timestampUnit, negateTimestamps, delimiter, onImportComplete]);
+  }, [filePath, allFilePaths, isMultiFile, mappings, hasHeader, timestampUnit, negateTimestamps, delimiter, sortByTimestamp, onImportComplete]);
 
   // Validation
   const hasFrameId = mappings.some((m) => m.role === "frame_id" || m.role === "frame_id_data");
@@ -437,6 +469,18 @@ export default function CsvColumnMapperDialog({
                   />
                   <span>Negate timestamps</span>
                 </label>
+                <label
+                  className={`flex items-center gap-1.5 text-xs ${textSecondary} cursor-pointer select-none`}
+                  title="Sort frames by timestamp after parsing (for unsorted or concatenated logs)"
+                >
+                  <input
+                    type="checkbox"
+                    checked={sortByTimestamp}
+                    onChange={(e) => setSortByTimestamp(e.target.checked)}
+                    className="accent-blue-500"
+                  />
+                  <span>Sort by timestamp</span>
+                </label>
               </div>
             )}
 
@@ -524,6 +568,25 @@ export default function CsvColumnMapperDialog({
                     </>
                   )}
                 </p>
+                {importSummary.parse_errors.count > 0 && (
+                  <p className="text-amber-500">
+                    {importSummary.parse_errors.count.toLocaleString()} malformed row
+                    {importSummary.parse_errors.count !== 1 ? "s" : ""} skipped
+                  </p>
+                )}
+                {importSummary.out_of_order_frames > 0 && (
+                  <p className="text-amber-500">
+                    {importSummary.out_of_order_frames.toLocaleString()} frame
+                    {importSummary.out_of_order_frames !== 1 ? "s" : ""} out of timestamp order
+                    {sortByTimestamp ? " (sorted)" : ""}
+                  </p>
+                )}
+                {importSummary.invalid_length_frames > 0 && (
+                  <p className="text-amber-500">
+                    {importSummary.invalid_length_frames.toLocaleString()} frame
+                    {importSummary.invalid_length_frames !== 1 ? "s" : ""} with impossible data length (flagged incomplete)
+                  </p>
+                )}
                 {hasSequence && importSummary.wrap_points.length > 0 && (
                   <p className={textMuted}>
                     Sequence wraps at:{" "}
@@ -531,6 +594,15 @@ export default function CsvColumnMapperDialog({
                   </p>
                 )}
               </div>
+              {importSummary.parse_errors.samples.length > 0 && (
+                <div className={`mt-2 text-xs font-mono ${textMuted} border-t ${borderDefault} pt-2`}>
+                  {importSummary.parse_errors.samples.map((sample, i) => (
+                    <div key={i} className="py-0.5">
+                      {sample}
+                    </div>
+                  ))}
+                </div>
+              )}
               {importSummary.sequence_gaps.length > 0 && (
                 <div
                   className={`mt-2 text-xs font-mono ${textMuted} overflow-y-auto border-t ${borderDefault} pt-2`}
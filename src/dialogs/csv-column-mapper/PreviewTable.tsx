@@ -35,6 +35,7 @@ const ROLE_OPTIONS: { value: CsvColumnRole; label: string }[] = [
   { value: "bus", label: "Bus" },
   { value: "direction", label: "Direction" },
   { value: "sequence", label: "Sequence" },
+  { value: "rtr", label: "RTR Flag" },
 ];
 
 /** Map column role to a text colour class */
@@ -55,6 +56,7 @@ function roleColour(role: CsvColumnRole): string {
     case "extended":
     case "direction":
     case "sequence":
+    case "rtr":
       return textDataAmber;
     case "ignore":
     default:
@@ -90,7 +90,7 @@ interface LocalSessionState {
   /** Whether the session was stopped explicitly by user */
   stoppedExplicitly: boolean;
   /** Reason why the stream ended */
-  streamEndedReason: "complete" | "stopped" | "disconnected" | "error" | null;
+  streamEndedReason: "complete" | "stopped" | "disconnected" | "error" | "idle" | null;
   /** Current playback speed */
   speed: number | null;
   /** Current playback position */
@@ -181,8 +181,8 @@ export interface UseIOSessionResult {
   joinerCount: number;
   /** Whether the session was stopped explicitly by user (vs stream ending naturally) */
   stoppedExplicitly: boolean;
-  /** Reason why the stream ended: "complete" = natural end, "stopped" = explicit stop */
-  streamEndedReason: "complete" | "stopped" | "disconnected" | "error" | null;
+  /** Reason why the stream ended: "complete" = natural end, "stopped" = explicit stop, "idle" = auto-stopped after no traffic */
+  streamEndedReason: "complete" | "stopped" | "disconnected" | "error" | "idle" | null;
   /** Current playback speed (null until set, 1 = realtime, 0 = unlimited) */
   speed: number | null;
   /** Current playback position (centralised for all apps sharing this session) */
@@ -46,7 +46,9 @@ import {
   type MultiSourceInput,
   type BusMapping,
   type PlaybackPosition,
+  type PlaybackRange,
   type RawBytesPayload,
+  type TimestampSource,
 } from "../api/io";
 import type { FrameMessage } from "../types/frame";
 import { tlog } from "../api/settings";
@@ -303,11 +305,13 @@ export interface Session {
   /** Whether the session was stopped explicitly by user (vs stream ending naturally) */
   stoppedExplicitly: boolean;
   /** Reason why the stream ended (from stream-ended event) */
-  streamEndedReason: "complete" | "stopped" | "disconnected" | "error" | null;
+  streamEndedReason: "complete" | "stopped" | "disconnected" | "error" | "idle" | null;
   /** Current playback speed (null until set, 1 = realtime, 0 = unlimited) */
   speed: number | null;
   /** Current playback position (centralised for all apps sharing this session) */
   playbackPosition: PlaybackPosition | null;
+  /** Buffer playback range (start/end time and frame count) for scrubbers */
+  playbackRange: PlaybackRange | null;
   /** Decoder catalog path for this session (frontend-only, shared across apps) */
   catalogPath: string | null;
 }
@@ -359,6 +363,8 @@ export interface SessionReconfiguredPayload {
 /** Callbacks for a session - stored per listener in the frontend */
 export interface SessionCallbacks {
   onFrames?: (frames: FrameMessage[]) => void;
+  /** Called once when the first live frame arrives, ahead of the first batch */
+  onFirstFrame?: (frame: FrameMessage) => void;
   onBytes?: (payload: RawBytesPayload) => void;
   onError?: (error: string) => void;
   onTimeUpdate?: (position: PlaybackPosition) => void;
@@ -547,6 +553,15 @@ async function setupSessionEventListeners(
   );
   unlistenFunctions.push(unlistenFrames);
 
+  // First live frame - sent immediately, bypassing the batch interval
+  const unlistenFirstFrame = await listen<FrameMessage>(
+    `first-frame-received:${sessionId}`,
+    (event) => {
+      invokeCallbacks(eventListeners, "onFirstFrame", event.payload);
+    }
+  );
+  unlistenFunctions.push(unlistenFirstFrame);
+
   // Raw bytes (serial byte streams)
   const unlistenBytes = await listen<RawBytesPayload>(
     `serial-raw-bytes:${sessionId}`,
@@ -597,6 +612,15 @@ async function setupSessionEventListeners(
   );
   unlistenFunctions.push(unlistenPlaybackTime);
 
+  // Playback range (Buffer reader) - sent at stream start and after each seek
+  const unlistenPlaybackRange = await listen<PlaybackRange>(
+    `playback-range:${sessionId}`,
+    (event) => {
+      updateSession(sessionId, { playbackRange: event.payload });
+    }
+  );
+  unlistenFunctions.push(unlistenPlaybackRange);
+
   // Stream complete (buffer reader finished)
   const unlistenStreamComplete = await listen<string | boolean>(
     `stream-complete:${sessionId}`,
@@ -948,6 +972,7 @@ export const useSessionStore = create<SessionStore>((set, get) => ({
             streamEndedReason: null,
             speed: null,
             playbackPosition: null,
+            playbackRange: null,
             catalogPath: null,
           };
           set((s) => ({
@@ -1094,6 +1119,7 @@ export const useSessionStore = create<SessionStore>((set, get) => ({
         streamEndedReason: existingSession?.streamEndedReason ?? null,
         speed: existingSession?.speed ?? null,
         playbackPosition: existingSession?.playbackPosition ?? null,
+        playbackRange: existingSession?.playbackRange ?? null,
         catalogPath: existingSession?.catalogPath ?? null,
       };
 
@@ -1910,6 +1936,8 @@ export interface CreateMultiSourceOptions {
   sourceAddressBigEndian?: boolean;
   /** Shared Modbus poll groups JSON (from catalog, injected into all modbus_tcp sources) */
   modbusPollsJson?: string;
+  /** Frame timestamp source for every source (defaults to each profile's setting) */
+  timestampSource?: TimestampSource;
 }
 
 /**
@@ -2008,6 +2036,7 @@ export async function createAndStartMultiSourceSession(
     listenerId,
     appName,
     modbusPollsJson: options.modbusPollsJson,
+    timestampSource: options.timestampSource,
   });
 
   // Register this listener with the session
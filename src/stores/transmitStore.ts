@@ -419,6 +419,7 @@ export const useTransmitStore = create<TransmitState>((set, get) => ({
       is_fd: canEditor.isFd,
       is_brs: canEditor.isBrs,
       is_rtr: canEditor.isRtr,
+      rtr_dlc: canEditor.isRtr ? canEditor.dlc : undefined,
     };
   },
 
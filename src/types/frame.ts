@@ -34,4 +34,18 @@ export type FrameMessage = {
   incomplete?: boolean;
   /** Direction: "rx" for received, "tx" for transmitted */
   direction?: "rx" | "tx";
+  /** Raw DLC code from the wire, when the source reports it (classic CAN can carry 9-15 with 8 bytes) */
+  dlc_raw?: number;
+  /** Remote transmission request (classic CAN): no data, `dlc` is the requested length */
+  is_rtr?: boolean;
+  /** CAN FD bit rate switch: the data phase ran at the data bitrate */
+  is_brs?: boolean;
+  /** CAN FD error state indicator: the sender was error passive */
+  is_esi?: boolean;
+  /** ID the frame arrived with, when the source's ID remap relabelled it */
+  original_frame_id?: number;
+  /** Display name of the source the frame came from, in multi-source sessions */
+  source_label?: string;
+  /** Data bytes as printable ASCII ('.' for non-printable), when the session's ASCII view is enabled */
+  ascii?: string;
 };
@@ -23,8 +23,10 @@ function findSmallestFittingDlc(byteCount: number): number {
 
 /**
  * Export frames to CSV format
- * Format: Time Stamp,ID,Extended,Dir,Bus,LEN,D1,D2,...,Dn
- * Columns use the smallest valid CAN FD DLC that fits the largest frame
+ * Format: Time Stamp,ID,Extended,Dir,Bus,LEN,D1,D2,...,Dn[,RTR]
+ * Columns use the smallest valid CAN FD DLC that fits the largest frame.
+ * When any frame is a remote frame an RTR column is appended; RTR rows keep
+ * the requested length in LEN and leave the data columns empty.
  */
 export function exportToCsv(frames: FrameMessage[]): string {
   // Find max bytes across all frames, then round up to valid CAN FD DLC
@@ -33,13 +35,15 @@ export function exportToCsv(frames: FrameMessage[]): string {
 
   // Build header with dynamic number of data columns
   const dataHeaders = Array.from({ length: maxDataLen }, (_, i) => `D${i + 1}`);
+  const hasRtr = frames.some((f) => f.is_rtr);
   const headers = ["Time Stamp", "ID", "Extended", "Dir", "Bus", "LEN", ...dataHeaders];
+  if (hasRtr) headers.push("RTR");
 
   const rows: (string | number)[][] = [];
   for (const frame of frames) {
     // Format data bytes as hex (uppercase), pad to maxDataLen columns
     const bytes = Array.from({ length: maxDataLen }, (_, i) =>
-      i < frame.dlc && frame.bytes[i] !== undefined
+      !frame.is_rtr && i < frame.dlc && frame.bytes[i] !== undefined
         ? frame.bytes[i].toString(16).padStart(2, "0").toUpperCase()
         : ""
     );
@@ -58,6 +62,7 @@ export function exportToCsv(frames: FrameMessage[]): string {
       frame.bus,
       frame.dlc,
       ...bytes,
+      ...(hasRtr ? [frame.is_rtr ? "true" : "false"] : []),
     ]);
   }
 
@@ -76,6 +81,10 @@ export function exportToJson(frames: FrameMessage[]): string {
     dlc: frame.dlc,
     is_extended: frame.is_extended ?? false,
     is_fd: frame.is_fd ?? false,
+    ...(frame.dlc_raw !== undefined && { dlc_raw: frame.dlc_raw }),
+    ...(frame.is_rtr && { is_rtr: true }),
+    ...(frame.is_brs && { is_brs: true }),
+    ...(frame.is_esi && { is_esi: true }),
     bytes: frame.bytes,
     bytes_hex: frame.bytes.map((b) => b.toString(16).padStart(2, "0").toUpperCase()),
   }));
@@ -109,7 +118,13 @@ export function exportToCandump(frames: FrameMessage[]): string {
       .map((b) => b.toString(16).padStart(2, "0").toUpperCase())
       .join("");
 
-    lines.push(`(${timestampSec.toFixed(6)}) ${interface_} ${idStr}#${dataHex}`);
+    // Classic frames with a DLC code above 8 keep it as candump's _X suffix
+    const dlcSuffix =
+      !frame.is_fd && frame.dlc_raw !== undefined && frame.dlc_raw > 8
+        ? `_${frame.dlc_raw.toString(16).toUpperCase()}`
+        : "";
+
+    lines.push(`(${timestampSec.toFixed(6)}) ${interface_} ${idStr}#${dataHex}${dlcSuffix}`);
   }
 
   return lines.join("\n");
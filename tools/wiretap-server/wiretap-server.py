@@ -40,7 +40,9 @@ SO_TIMESTAMP = getattr(socket, 'SO_TIMESTAMP', 29)  # 29 on Linux
 
 # CAN FD constants
 SOL_CAN_RAW = 101           # from linux/can/raw.h
+CAN_RAW_ERR_FILTER = 2      # error classes delivered as error frames
 CAN_RAW_FD_FRAMES = 5       # enable CAN FD frames
+CAN_ERR_MASK = 0x1FFFFFFF   # all error classes
 CANFD_BRS = 0x01            # Bit Rate Switch flag
 CANFD_ESI = 0x02            # Error State Indicator flag
 
@@ -982,10 +984,15 @@ class GVRETClient:
             data_len = min(data_len, 8)
             dlc = data_len
 
-        # Build ID with extended flag (GVRET wants bit31 set for EFF)
+        # Build ID with extended flag (GVRET wants bit31 set for EFF).
+        # Error frames keep CAN_ERR_FLAG (bit 29) and their error class bits;
+        # stock firmware never sets bit 29, so WireTAP can tell them apart.
         is_eff = bool(can_id & CAN_EFF_FLAG)
-        arb_id = can_id & (CAN_EFF_MASK if is_eff else CAN_SFF_MASK)
-        gvret_id = arb_id | (0x80000000 if is_eff else 0)
+        if can_id & CAN_ERR_FLAG:
+            gvret_id = can_id & (CAN_ERR_FLAG | CAN_ERR_MASK)
+        else:
+            arb_id = can_id & (CAN_EFF_MASK if is_eff else CAN_SFF_MASK)
+            gvret_id = arb_id | (0x80000000 if is_eff else 0)
 
         # Timestamp (µs since start) — simple wall time is fine
         ts_us = int((time.monotonic() - self.t0) * 1_000_000) & 0xFFFFFFFF
@@ -1055,6 +1062,8 @@ class CanTcpServer:
                 s.setsockopt(socket.SOL_SOCKET, SO_TIMESTAMP, 1)
                 if can_fd:
                     s.setsockopt(SOL_CAN_RAW, CAN_RAW_FD_FRAMES, 1)
+                # Controller errors are forwarded to GVRET clients (see send_frame)
+                s.setsockopt(SOL_CAN_RAW, CAN_RAW_ERR_FILTER, struct.pack("=I", CAN_ERR_MASK))
                 s.bind((ifn,))
                 s.setblocking(False)
                 self.can_socks.append(s)
@@ -1240,6 +1249,10 @@ class CanTcpServer:
                         for c in dead:
                             self._drop(c)
 
+                    # Error frames are only forwarded to GVRET clients
+                    if can_id & CAN_ERR_FLAG:
+                        continue
+
                     # postgres ingest (non-blocking enqueue)
                     if self.pg_writer:
                         try: